@@ -0,0 +1,88 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::{collections::HashMap, marker::PhantomData};
+
+use uniffi::FfiConverter;
+
+/// Bytes that the bindings lift as a `T`, whether or not they're a valid serialization of one
+pub struct Malformed<T> {
+    bytes: Vec<u8>,
+    _type: PhantomData<T>,
+}
+
+unsafe impl<T: FfiConverter<UniFfiTag>> FfiConverter<UniFfiTag> for Malformed<T> {
+    uniffi::ffi_converter_rust_buffer_lift_and_lower!(UniFfiTag);
+    uniffi::ffi_converter_default_return!(UniFfiTag);
+
+    fn write(obj: Self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&obj.bytes);
+    }
+
+    fn try_read(_buf: &mut &[u8]) -> uniffi::Result<Self> {
+        uniffi::deps::anyhow::bail!("malformed values are only returned to foreign code")
+    }
+
+    // The bindings only see the type that the bytes are lifted as
+    const TYPE_ID_META: uniffi::MetadataBuffer = T::TYPE_ID_META;
+}
+
+// Returning a `Result` makes the Swift functions `throws`, so that they don't trap when lifting
+// the return value fails.
+#[derive(uniffi::Error, Debug)]
+pub enum BufferError {
+    Unused,
+}
+
+type MalformedResult<T> = Result<Malformed<T>, BufferError>;
+
+fn malformed<T>(parts: &[&[u8]]) -> MalformedResult<T> {
+    Ok(Malformed {
+        bytes: parts.concat(),
+        _type: PhantomData,
+    })
+}
+
+/// `["hi"]`, to check that valid buffers are still lifted
+#[uniffi::export]
+fn valid_strings() -> MalformedResult<Vec<String>> {
+    malformed(&[&1i32.to_be_bytes(), &2i32.to_be_bytes(), b"hi"])
+}
+
+#[uniffi::export]
+fn sequence_length_past_end() -> MalformedResult<Vec<u32>> {
+    malformed(&[&1000i32.to_be_bytes(), &1u32.to_be_bytes()])
+}
+
+#[uniffi::export]
+fn string_length_past_end() -> MalformedResult<Vec<String>> {
+    malformed(&[&1i32.to_be_bytes(), &100i32.to_be_bytes(), b"abc"])
+}
+
+#[uniffi::export]
+fn huge_map_length() -> MalformedResult<HashMap<u8, u8>> {
+    malformed(&[&i32::MAX.to_be_bytes(), &[1, 2]])
+}
+
+#[uniffi::export]
+fn negative_map_length() -> MalformedResult<HashMap<String, u32>> {
+    malformed(&[&(-1i32).to_be_bytes()])
+}
+
+#[uniffi::export]
+fn invalid_boolean() -> MalformedResult<Vec<bool>> {
+    malformed(&[&1i32.to_be_bytes(), &[2]])
+}
+
+#[uniffi::export]
+fn invalid_optional_tag() -> MalformedResult<Vec<Option<u8>>> {
+    malformed(&[&1i32.to_be_bytes(), &[2, 0]])
+}
+
+#[uniffi::export]
+fn invalid_utf8() -> MalformedResult<Vec<String>> {
+    malformed(&[&1i32.to_be_bytes(), &1i32.to_be_bytes(), &[0xff]])
+}
+
+uniffi::setup_scaffolding!();
@@ -0,0 +1,24 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi.fixture.buffer_checks.*
+
+fun assertInternalException(f: () -> Any) {
+    try {
+        f()
+        throw RuntimeException("Lifting a malformed buffer should have failed")
+    } catch (e: InternalException) {
+        // Expected
+    }
+}
+
+assert(validStrings() == listOf("hi"))
+assertInternalException { sequenceLengthPastEnd() }
+assertInternalException { stringLengthPastEnd() }
+assertInternalException { hugeMapLength() }
+assertInternalException { negativeMapLength() }
+assertInternalException { invalidBoolean() }
+assertInternalException { invalidOptionalTag() }
+// Kotlin decodes invalid UTF-8 as replacement characters
+assert(invalidUtf8() == listOf("\uFFFD"))
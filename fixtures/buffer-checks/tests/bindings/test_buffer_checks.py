@@ -0,0 +1,28 @@
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+import unittest
+from uniffi_buffer_checks import *
+
+class TestBufferChecks(unittest.TestCase):
+    def test_valid(self):
+        self.assertEqual(valid_strings(), ["hi"])
+
+    def test_lengths(self):
+        for f in [sequence_length_past_end, string_length_past_end, huge_map_length, negative_map_length]:
+            with self.assertRaises(InternalError):
+                f()
+
+    def test_tags(self):
+        with self.assertRaises(InternalError):
+            invalid_boolean()
+        with self.assertRaises(InternalError):
+            invalid_optional_tag()
+
+    def test_invalid_utf8(self):
+        with self.assertRaises(UnicodeDecodeError):
+            invalid_utf8()
+
+if __name__ == "__main__":
+    unittest.main()
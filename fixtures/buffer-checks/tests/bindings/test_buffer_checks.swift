@@ -0,0 +1,29 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import Foundation
+import uniffi_buffer_checks
+
+// The internal errors are `fileprivate`, so check their descriptions
+func assertInternalError<T>(_ expected: String, _ f: () throws -> T) {
+    do {
+        _ = try f()
+        fatalError("Lifting a malformed buffer should have failed")
+    } catch is BufferError {
+        fatalError("Unexpected BufferError")
+    } catch {
+        assert(error.localizedDescription == expected, "\(error.localizedDescription)")
+    }
+}
+
+let invalidLength = "Length prefix is negative or longer than the rest of the buffer"
+
+assert(try! validStrings() == ["hi"])
+assertInternalError(invalidLength) { try sequenceLengthPastEnd() }
+assertInternalError(invalidLength) { try stringLengthPastEnd() }
+assertInternalError(invalidLength) { try hugeMapLength() }
+assertInternalError(invalidLength) { try negativeMapLength() }
+assertInternalError("Unexpected boolean value; should be 0 or 1") { try invalidBoolean() }
+assertInternalError("Unexpected optional tag; should be 0 or 1") { try invalidOptionalTag() }
+assertInternalError("String data is not valid UTF-8") { try invalidUtf8() }
@@ -0,0 +1,5 @@
+uniffi::build_foreign_language_testcases!(
+    "tests/bindings/test_buffer_checks.kts",
+    "tests/bindings/test_buffer_checks.py",
+    "tests/bindings/test_buffer_checks.swift",
+);
@@ -0,0 +1,82 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone, Default)]
+struct RequestOptions {
+    url: String,
+    timeout_secs: u32,
+    headers: Vec<String>,
+}
+
+#[derive(uniffi::Object)]
+pub struct RequestBuilder {
+    inner: Mutex<RequestOptions>,
+}
+
+#[uniffi::export]
+impl RequestBuilder {
+    #[uniffi::constructor]
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self {
+            inner: Mutex::new(RequestOptions {
+                timeout_secs: 60,
+                ..Default::default()
+            }),
+        })
+    }
+
+    pub fn url(&self, url: String) {
+        self.inner.lock().unwrap().url = url;
+    }
+
+    pub fn timeout(&self, seconds: u32) {
+        self.inner.lock().unwrap().timeout_secs = seconds;
+    }
+
+    pub fn header(&self, name: String, value: String) {
+        self.inner
+            .lock()
+            .unwrap()
+            .headers
+            .push(format!("{name}: {value}"));
+    }
+
+    /// Methods that return a value are unchanged
+    pub fn header_count(&self) -> u32 {
+        self.inner.lock().unwrap().headers.len() as u32
+    }
+
+    pub fn build(&self) -> Arc<Request> {
+        Arc::new(Request {
+            options: self.inner.lock().unwrap().clone(),
+        })
+    }
+}
+
+#[derive(uniffi::Object)]
+pub struct Request {
+    options: RequestOptions,
+}
+
+#[uniffi::export]
+impl Request {
+    pub fn describe(&self) -> String {
+        let RequestOptions {
+            url,
+            timeout_secs,
+            headers,
+        } = &self.options;
+        format!(
+            "{url} (timeout: {timeout_secs}s, headers: [{}])",
+            headers.join(", ")
+        )
+    }
+
+    /// Objects that aren't builders keep methods without a return value
+    pub fn send(&self) {}
+}
+
+uniffi::setup_scaffolding!();
@@ -0,0 +1,20 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi.fixture.builders.*
+
+val builder = RequestBuilder()
+val request = builder
+    .url("https://example.com")
+    .timeout(30U)
+    .header("Accept", "text/html")
+    .build()
+assert(request.describe() == "https://example.com (timeout: 30s, headers: [Accept: text/html])")
+
+// Builder methods return the builder itself
+assert(builder.header("X-Test", "1") === builder)
+assert(builder.headerCount() == 2U)
+
+// Other objects are unchanged
+val sent: Unit = request.send()
@@ -0,0 +1,22 @@
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+import unittest
+from uniffi_builders import *
+
+class TestBuilders(unittest.TestCase):
+    def test_chaining(self):
+        request = RequestBuilder().url("https://example.com").timeout(30).header("Accept", "text/html").build()
+        self.assertEqual(request.describe(), "https://example.com (timeout: 30s, headers: [Accept: text/html])")
+
+    def test_methods_return_the_builder(self):
+        builder = RequestBuilder()
+        self.assertIs(builder.header("X-Test", "1"), builder)
+        self.assertEqual(builder.header_count(), 1)
+
+    def test_other_objects_are_unchanged(self):
+        self.assertIsNone(RequestBuilder().build().send())
+
+if __name__ == '__main__':
+    unittest.main()
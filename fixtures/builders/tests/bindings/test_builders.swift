@@ -0,0 +1,20 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi_builders
+
+let builder = RequestBuilder()
+let request = builder
+    .url(url: "https://example.com")
+    .timeout(seconds: 30)
+    .header(name: "Accept", value: "text/html")
+    .build()
+assert(request.describe() == "https://example.com (timeout: 30s, headers: [Accept: text/html])")
+
+// Builder methods return the builder itself
+assert(builder.header(name: "X-Test", value: "1") === builder)
+assert(builder.headerCount() == 2)
+
+// Other objects are unchanged
+let sent: Void = request.send()
@@ -0,0 +1,5 @@
+uniffi::build_foreign_language_testcases!(
+    "tests/bindings/test_builders.kts",
+    "tests/bindings/test_builders.swift",
+    "tests/bindings/test_builders.py",
+);
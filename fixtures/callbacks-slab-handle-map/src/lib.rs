@@ -0,0 +1,54 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::sync::{Arc, Mutex};
+
+#[uniffi::export(callback_interface)]
+pub trait Listener: Send {
+    fn on_event(&self, value: i32) -> i32;
+}
+
+/// Holds on to callback objects, so that the tests can control when Rust drops them.
+#[derive(uniffi::Object)]
+pub struct EventSource {
+    listeners: Mutex<Vec<Box<dyn Listener>>>,
+}
+
+#[uniffi::export]
+impl EventSource {
+    #[uniffi::constructor]
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self {
+            listeners: Mutex::new(Vec::new()),
+        })
+    }
+
+    pub fn add_listener(&self, listener: Box<dyn Listener>) {
+        self.listeners.lock().unwrap().push(listener);
+    }
+
+    /// Drop the oldest listener, which frees its handle.
+    pub fn remove_oldest(&self) {
+        let mut listeners = self.listeners.lock().unwrap();
+        if !listeners.is_empty() {
+            listeners.remove(0);
+        }
+    }
+
+    pub fn clear(&self) {
+        self.listeners.lock().unwrap().clear();
+    }
+
+    /// Call every listener, in the order they were added.
+    pub fn emit(&self, value: i32) -> Vec<i32> {
+        self.listeners
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|listener| listener.on_event(value))
+            .collect()
+    }
+}
+
+uniffi::setup_scaffolding!();
@@ -0,0 +1,37 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi.fixture.callbacks_slab_handle_map.*
+
+class Adder(val amount: Int) : Listener {
+    override fun onEvent(value: Int): Int = value + amount
+}
+
+val source = EventSource()
+val adder = Adder(10)
+source.addListener(Adder(1))
+source.addListener(adder)
+source.addListener(Adder(100))
+assert(source.emit(1) == listOf(2, 11, 101))
+
+// Dropping the oldest listener frees its slot, which the next listener reuses
+source.removeOldest()
+source.addListener(Adder(1000))
+assert(source.emit(1) == listOf(11, 101, 1001))
+
+// Passing the same object again takes a second slot, so it stays alive when the first is freed
+source.addListener(adder)
+source.removeOldest()
+assert(source.emit(1) == listOf(101, 1001, 11))
+
+// Slots are reused over and over without mixing up the objects
+source.clear()
+for (i in 0 until 100) {
+    source.addListener(Adder(i))
+    source.addListener(Adder(-i))
+    assert(source.emit(0) == listOf(i, -i))
+    source.clear()
+}
+
+source.destroy()
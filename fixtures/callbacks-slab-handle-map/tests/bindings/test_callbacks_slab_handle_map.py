@@ -0,0 +1,50 @@
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+import gc
+import weakref
+from uniffi_callbacks_slab_handle_map import *
+
+class Adder(Listener):
+    def __init__(self, amount):
+        self.amount = amount
+
+    def on_event(self, value):
+        return value + self.amount
+
+source = EventSource()
+adder = Adder(10)
+source.add_listener(Adder(1))
+source.add_listener(adder)
+source.add_listener(Adder(100))
+assert source.emit(1) == [2, 11, 101]
+
+# Dropping the oldest listener frees its slot, which the next listener reuses
+source.remove_oldest()
+source.add_listener(Adder(1000))
+assert source.emit(1) == [11, 101, 1001]
+
+# Passing the same object again takes a second slot, so it stays alive when the first is freed
+source.add_listener(adder)
+source.remove_oldest()
+assert source.emit(1) == [101, 1001, 11]
+
+# Freed slots release their objects
+source.clear()
+listener = Adder(5)
+listener_ref = weakref.ref(listener)
+source.add_listener(listener)
+del listener
+gc.collect()
+assert source.emit(1) == [6]
+source.clear()
+gc.collect()
+assert listener_ref() is None
+
+# Slots are reused over and over without mixing up the objects
+for i in range(100):
+    source.add_listener(Adder(i))
+    source.add_listener(Adder(-i))
+    assert source.emit(0) == [i, -i]
+    source.clear()
@@ -0,0 +1,55 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi_callbacks_slab_handle_map
+
+class Adder: Listener {
+    let amount: Int32
+
+    init(_ amount: Int32) {
+        self.amount = amount
+    }
+
+    func onEvent(value: Int32) -> Int32 {
+        return value + amount
+    }
+}
+
+let source = EventSource()
+let adder = Adder(10)
+source.addListener(listener: Adder(1))
+source.addListener(listener: adder)
+source.addListener(listener: Adder(100))
+assert(source.emit(value: 1) == [2, 11, 101])
+
+// Dropping the oldest listener frees its slot, which the next listener reuses
+source.removeOldest()
+source.addListener(listener: Adder(1000))
+assert(source.emit(value: 1) == [11, 101, 1001])
+
+// Passing the same object again takes a second slot, so it stays alive when the first is freed
+source.addListener(listener: adder)
+source.removeOldest()
+assert(source.emit(value: 1) == [101, 1001, 11])
+
+// Freed slots release their objects
+source.clear()
+weak var weakListener: Adder?
+do {
+    let listener = Adder(5)
+    weakListener = listener
+    source.addListener(listener: listener)
+}
+assert(source.emit(value: 1) == [6])
+assert(weakListener != nil)
+source.clear()
+assert(weakListener == nil)
+
+// Slots are reused over and over without mixing up the objects
+for i in Int32(0)..<100 {
+    source.addListener(listener: Adder(i))
+    source.addListener(listener: Adder(-i))
+    assert(source.emit(value: 0) == [i, -i])
+    source.clear()
+}
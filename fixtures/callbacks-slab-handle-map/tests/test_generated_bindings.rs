@@ -0,0 +1,5 @@
+uniffi::build_foreign_language_testcases!(
+    "tests/bindings/test_callbacks_slab_handle_map.kts",
+    "tests/bindings/test_callbacks_slab_handle_map.py",
+    "tests/bindings/test_callbacks_slab_handle_map.swift",
+);
@@ -0,0 +1,67 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+#[derive(uniffi::Error, Debug, PartialEq, Eq)]
+pub enum FeatureError {
+    Unavailable { capability: String },
+}
+
+impl From<uniffi::MissingCapability> for FeatureError {
+    fn from(e: uniffi::MissingCapability) -> Self {
+        Self::Unavailable {
+            capability: e.name.to_string(),
+        }
+    }
+}
+
+static PREMIUM_ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Registers the predicate for "premium" on the Rust side
+#[uniffi::export]
+fn set_premium_enabled(enabled: bool) {
+    PREMIUM_ENABLED.store(enabled, Ordering::Relaxed);
+    uniffi::register_capability("premium", || PREMIUM_ENABLED.load(Ordering::Relaxed));
+}
+
+#[uniffi::export(requires_capability = "premium")]
+fn premium_greeting(name: String) -> Result<String, FeatureError> {
+    Ok(format!("Hello, {name}!"))
+}
+
+/// "camera" is only ever made available by the foreign code
+#[uniffi::export(requires_capability = "camera")]
+fn take_photo() -> Result<String, FeatureError> {
+    Ok("📷".to_string())
+}
+
+#[derive(uniffi::Object)]
+pub struct Player;
+
+#[uniffi::export(requires_capability = "media")]
+impl Player {
+    #[uniffi::constructor]
+    fn new() -> Result<Arc<Self>, FeatureError> {
+        Ok(Arc::new(Self))
+    }
+
+    fn play(&self) -> Result<String, FeatureError> {
+        Ok("Playing".to_string())
+    }
+}
+
+// `supported_capabilities()` lists the ones that are compiled in and currently available
+uniffi::declare_capabilities!(
+    "premium",
+    "camera",
+    #[cfg(target_pointer_width = "16")]
+    "tiny",
+    "media",
+);
+
+uniffi::setup_scaffolding!();
@@ -0,0 +1,51 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi.fixture.capabilities.*
+
+fun assertUnavailable(capability: String, f: () -> Any) {
+    try {
+        f()
+        throw RuntimeException("Should have thrown FeatureException.Unavailable")
+    } catch (e: FeatureException.Unavailable) {
+        assert(e.capability == capability)
+    }
+}
+
+// Nothing is available until it's registered or set
+assert(supportedCapabilities() == listOf<String>())
+
+// A predicate registered by Rust
+assertUnavailable("premium") { premiumGreeting("Alice") }
+setPremiumEnabled(true)
+assert(premiumGreeting("Alice") == "Hello, Alice!")
+assert(supportedCapabilities() == listOf("premium"))
+setPremiumEnabled(false)
+assert(supportedCapabilities() == listOf<String>())
+assertUnavailable("premium") { premiumGreeting("Alice") }
+
+// Capabilities set by the foreign code
+assertUnavailable("camera") { takePhoto() }
+uniffiSetCapabilityAvailable("camera", true)
+assert(takePhoto() == "📷")
+assert(supportedCapabilities() == listOf("camera"))
+uniffiSetCapabilityAvailable("camera", false)
+assertUnavailable("camera") { takePhoto() }
+
+// The foreign code can override a Rust predicate
+uniffiSetCapabilityAvailable("premium", true)
+assert(premiumGreeting("Alice") == "Hello, Alice!")
+assert(supportedCapabilities() == listOf("premium"))
+
+// Constructors and methods of a guarded impl block
+assertUnavailable("media") { Player() }
+uniffiSetCapabilityAvailable("media", true)
+val player = Player()
+assert(player.play() == "Playing")
+// Capabilities are listed in the order they were declared, and compiled-out ones aren't listed
+uniffiSetCapabilityAvailable("tiny", true)
+uniffiSetCapabilityAvailable("camera", true)
+assert(supportedCapabilities() == listOf("premium", "camera", "media"))
+uniffiSetCapabilityAvailable("media", false)
+assertUnavailable("media") { player.play() }
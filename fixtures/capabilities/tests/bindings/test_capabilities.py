@@ -0,0 +1,53 @@
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+import unittest
+from uniffi_capabilities import *
+
+class TestCapabilities(unittest.TestCase):
+    def assert_unavailable(self, capability, f):
+        with self.assertRaises(FeatureError.Unavailable) as cm:
+            f()
+        self.assertEqual(cm.exception.capability, capability)
+
+    def test_rust_predicate(self):
+        self.assert_unavailable("premium", lambda: premium_greeting("Alice"))
+        set_premium_enabled(True)
+        self.assertEqual(premium_greeting("Alice"), "Hello, Alice!")
+        set_premium_enabled(False)
+        self.assert_unavailable("premium", lambda: premium_greeting("Alice"))
+
+    def test_foreign_registration(self):
+        self.assert_unavailable("camera", take_photo)
+        uniffi_set_capability_available("camera", True)
+        self.assertEqual(take_photo(), "📷")
+        uniffi_set_capability_available("camera", False)
+        self.assert_unavailable("camera", take_photo)
+
+    def test_impl_block(self):
+        self.assert_unavailable("media", Player)
+        uniffi_set_capability_available("media", True)
+        player = Player()
+        self.assertEqual(player.play(), "Playing")
+        uniffi_set_capability_available("media", False)
+        self.assert_unavailable("media", player.play)
+
+    def test_supported_capabilities(self):
+        set_premium_enabled(False)
+        for capability in ["camera", "media"]:
+            uniffi_set_capability_available(capability, False)
+        self.assertEqual(supported_capabilities(), [])
+        set_premium_enabled(True)
+        self.assertEqual(supported_capabilities(), ["premium"])
+        # Capabilities are listed in the order they were declared, and compiled-out ones aren't
+        # listed
+        for capability in ["media", "tiny", "camera"]:
+            uniffi_set_capability_available(capability, True)
+        self.assertEqual(supported_capabilities(), ["premium", "camera", "media"])
+        set_premium_enabled(False)
+        for capability in ["camera", "media", "tiny"]:
+            uniffi_set_capability_available(capability, False)
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,53 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi_capabilities
+
+func assertUnavailable<T>(_ expected: String, _ f: () throws -> T) {
+    do {
+        _ = try f()
+        fatalError("Should have thrown FeatureError.Unavailable")
+    } catch let FeatureError.Unavailable(capability) {
+        assert(capability == expected)
+    } catch {
+        fatalError("Unexpected error: \(error)")
+    }
+}
+
+// Nothing is available until it's registered or set
+assert(supportedCapabilities() == [])
+
+// A predicate registered by Rust
+assertUnavailable("premium") { try premiumGreeting(name: "Alice") }
+setPremiumEnabled(enabled: true)
+assert(try! premiumGreeting(name: "Alice") == "Hello, Alice!")
+assert(supportedCapabilities() == ["premium"])
+setPremiumEnabled(enabled: false)
+assert(supportedCapabilities() == [])
+assertUnavailable("premium") { try premiumGreeting(name: "Alice") }
+
+// Capabilities set by the foreign code
+assertUnavailable("camera") { try takePhoto() }
+uniffiSetCapabilityAvailable("camera", available: true)
+assert(try! takePhoto() == "📷")
+assert(supportedCapabilities() == ["camera"])
+uniffiSetCapabilityAvailable("camera", available: false)
+assertUnavailable("camera") { try takePhoto() }
+
+// The foreign code can override a Rust predicate
+uniffiSetCapabilityAvailable("premium", available: true)
+assert(try! premiumGreeting(name: "Alice") == "Hello, Alice!")
+assert(supportedCapabilities() == ["premium"])
+
+// Constructors and methods of a guarded impl block
+assertUnavailable("media") { try Player() }
+uniffiSetCapabilityAvailable("media", available: true)
+let player = try! Player()
+assert(try! player.play() == "Playing")
+// Capabilities are listed in the order they were declared, and compiled-out ones aren't listed
+uniffiSetCapabilityAvailable("tiny", available: true)
+uniffiSetCapabilityAvailable("camera", available: true)
+assert(supportedCapabilities() == ["premium", "camera", "media"])
+uniffiSetCapabilityAvailable("media", available: false)
+assertUnavailable("media") { try player.play() }
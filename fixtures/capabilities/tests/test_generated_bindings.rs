@@ -0,0 +1,5 @@
+uniffi::build_foreign_language_testcases!(
+    "tests/bindings/test_capabilities.kts",
+    "tests/bindings/test_capabilities.py",
+    "tests/bindings/test_capabilities.swift",
+);
@@ -10,7 +10,7 @@ use std::time::SystemTime;
 use once_cell::sync::Lazy;
 
 mod traits;
-pub use traits::{get_traits, TestTrait};
+pub use traits::{get_trait_names, get_traits, TestTrait};
 
 static NUM_ALIVE: Lazy<RwLock<u64>> = Lazy::new(|| RwLock::new(0));
 
@@ -14,6 +14,10 @@ pub fn get_traits() -> Vec<Arc<dyn TestTrait>> {
     ]
 }
 
+pub fn get_trait_names(traits: Vec<Arc<dyn TestTrait>>) -> Vec<String> {
+    traits.iter().map(|t| t.name()).collect()
+}
+
 pub trait TestTrait: Send + Sync + std::fmt::Debug {
     fn name(&self) -> String;
 
@@ -262,3 +262,10 @@ assert(d.integer == 42UL)
 Coveralls("test_bytes").use { coveralls ->
     assert(coveralls.reverse("123".toByteArray(Charsets.UTF_8)).toString(Charsets.UTF_8) == "321")
 }
+
+// Test sequences of trait objects, which mix the Rust types implementing the trait
+getTraits().let { traits ->
+    assert(getTraitNames(traits) == listOf("trait 1", "trait 2"))
+    assert(getTraitNames(traits.reversed()) == listOf("trait 2", "trait 1"))
+    assert(getTraitNames(listOf()) == listOf<String>())
+}
@@ -294,5 +294,14 @@ class TraitsTest(unittest.TestCase):
         self.assertEqual(traits[0].get_other().name(), "trait 2")
         traits[0].take_other(None)
 
+    def test_sequence(self):
+        # The sequence mixes the two Rust types that implement the trait
+        traits = get_traits()
+        self.assertEqual(get_trait_names(traits), ["trait 1", "trait 2"])
+        self.assertEqual(get_trait_names(list(reversed(traits))), ["trait 2", "trait 1"])
+        self.assertEqual(get_trait_names([]), [])
+        # Passing the sequence to Rust doesn't leak references
+        self.assertEqual(traits[0].strong_count(), 2)
+
 if __name__=='__main__':
     unittest.main()
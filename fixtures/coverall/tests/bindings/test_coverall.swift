@@ -247,3 +247,11 @@ do {
     let coveralls = Coveralls(name: "test_bytes")
     assert(coveralls.reverse(value: Data("123".utf8)) == Data("321".utf8))
 }
+
+// Test sequences of trait objects, which mix the Rust types implementing the trait
+do {
+    let traits = getTraits()
+    assert(getTraitNames(traits: traits) == ["trait 1", "trait 2"])
+    assert(getTraitNames(traits: traits.reversed()) == ["trait 2", "trait 1"])
+    assert(getTraitNames(traits: []) == [])
+}
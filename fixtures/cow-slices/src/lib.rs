@@ -0,0 +1,77 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::borrow::Cow;
+
+const EMPTY_PAYLOAD: &[u8] = b"empty";
+
+// Returns `data`, or a borrowed placeholder if it's empty
+fn payload_or_placeholder(data: Cow<[u8]>) -> Cow<'static, [u8]> {
+    if data.is_empty() {
+        Cow::Borrowed(EMPTY_PAYLOAD)
+    } else {
+        Cow::Owned(data.into_owned())
+    }
+}
+
+// Returns a borrowed empty slice if `values` is empty
+fn double(values: Cow<[i32]>) -> Cow<'static, [i32]> {
+    if values.is_empty() {
+        Cow::Borrowed(&[])
+    } else {
+        values.iter().map(|v| v * 2).collect()
+    }
+}
+
+fn udl_double_values(values: Cow<[i32]>) -> Cow<'static, [i32]> {
+    double(values)
+}
+
+fn udl_payload_or_placeholder(data: Cow<[u8]>) -> Cow<'static, [u8]> {
+    payload_or_placeholder(data)
+}
+
+pub struct Reverser;
+
+impl Reverser {
+    fn new() -> Self {
+        Self
+    }
+
+    fn reverse(&self, values: Cow<[u32]>) -> Cow<'static, [u32]> {
+        values.iter().rev().copied().collect()
+    }
+}
+
+#[uniffi::export]
+fn double_values(values: Cow<'_, [i32]>) -> Cow<'static, [i32]> {
+    double(values)
+}
+
+#[uniffi::export(name = "payload_or_placeholder")]
+fn exported_payload_or_placeholder(data: Cow<[u8]>) -> Cow<'static, [u8]> {
+    payload_or_placeholder(data)
+}
+
+#[derive(uniffi::Record)]
+pub struct Packet {
+    header: Cow<'static, [u8]>,
+    ids: Cow<'static, [u32]>,
+}
+
+/// Returns a packet whose fields are borrowed
+#[uniffi::export]
+fn default_packet() -> Packet {
+    Packet {
+        header: Cow::Borrowed(b"UF"),
+        ids: Cow::Borrowed(&[1, 2, 3]),
+    }
+}
+
+#[uniffi::export]
+fn packet_size(packet: Packet) -> u32 {
+    (packet.header.len() + packet.ids.len()) as u32
+}
+
+uniffi::include_scaffolding!("cow-slices");
@@ -0,0 +1,25 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi.fixture.cow_slices.*;
+
+// UDL functions and methods
+assert(udlDoubleValues(listOf()) == listOf<Int>())
+assert(udlDoubleValues(listOf(1, 2, 3)) == listOf(2, 4, 6))
+assert(udlPayloadOrPlaceholder(byteArrayOf()).contentEquals("empty".toByteArray()))
+assert(udlPayloadOrPlaceholder(byteArrayOf(1, 2)).contentEquals(byteArrayOf(1, 2)))
+assert(Reverser().reverse(listOf(1u, 2u, 3u)) == listOf(3u, 2u, 1u))
+
+// Proc-macro functions
+assert(doubleValues(listOf()) == listOf<Int>())
+assert(doubleValues(listOf(1, 2, 3)) == listOf(2, 4, 6))
+assert(payloadOrPlaceholder(byteArrayOf()).contentEquals("empty".toByteArray()))
+assert(payloadOrPlaceholder(byteArrayOf(1, 2)).contentEquals(byteArrayOf(1, 2)))
+
+// Records with borrowed fields
+val packet = defaultPacket()
+assert(packet.header.contentEquals("UF".toByteArray()))
+assert(packet.ids == listOf(1u, 2u, 3u))
+assert(packetSize(packet) == 5u)
+assert(packetSize(Packet(byteArrayOf(), listOf(7u))) == 1u)
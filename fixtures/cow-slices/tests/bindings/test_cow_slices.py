@@ -0,0 +1,24 @@
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+from uniffi_cow_slices import *
+
+# UDL functions and methods
+assert udl_double_values([]) == []
+assert udl_double_values([1, 2, 3]) == [2, 4, 6]
+assert udl_payload_or_placeholder(b"") == b"empty"
+assert udl_payload_or_placeholder(b"\x01\x02") == b"\x01\x02"
+assert Reverser().reverse([1, 2, 3]) == [3, 2, 1]
+
+# Proc-macro functions
+assert double_values([]) == []
+assert double_values([1, 2, 3]) == [2, 4, 6]
+assert payload_or_placeholder(b"") == b"empty"
+assert payload_or_placeholder(b"\x01\x02") == b"\x01\x02"
+
+# Records with borrowed fields
+packet = default_packet()
+assert packet == Packet(b"UF", [1, 2, 3])
+assert packet_size(packet) == 5
+assert packet_size(Packet(b"", [7])) == 1
@@ -0,0 +1,25 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import Foundation
+import uniffi_cow_slices
+
+// UDL functions and methods
+assert(udlDoubleValues(values: []) == [])
+assert(udlDoubleValues(values: [1, 2, 3]) == [2, 4, 6])
+assert(udlPayloadOrPlaceholder(data: Data()) == Data("empty".utf8))
+assert(udlPayloadOrPlaceholder(data: Data([1, 2])) == Data([1, 2]))
+assert(Reverser().reverse(values: [1, 2, 3]) == [3, 2, 1])
+
+// Proc-macro functions
+assert(doubleValues(values: []) == [])
+assert(doubleValues(values: [1, 2, 3]) == [2, 4, 6])
+assert(payloadOrPlaceholder(data: Data()) == Data("empty".utf8))
+assert(payloadOrPlaceholder(data: Data([1, 2])) == Data([1, 2]))
+
+// Records with borrowed fields
+let packet = defaultPacket()
+assert(packet == Packet(header: Data("UF".utf8), ids: [1, 2, 3]))
+assert(packetSize(packet: packet) == 5)
+assert(packetSize(packet: Packet(header: Data(), ids: [7])) == 1)
@@ -0,0 +1,5 @@
+uniffi::build_foreign_language_testcases!(
+    "tests/bindings/test_cow_slices.kts",
+    "tests/bindings/test_cow_slices.py",
+    "tests/bindings/test_cow_slices.swift",
+);
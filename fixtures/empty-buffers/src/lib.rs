@@ -0,0 +1,34 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::HashMap;
+
+#[uniffi::export]
+fn echo_strings(values: Vec<String>) -> Vec<String> {
+    values
+}
+
+#[uniffi::export]
+fn echo_dict(dict: HashMap<String, u64>) -> HashMap<String, u64> {
+    dict
+}
+
+#[uniffi::export]
+fn echo_maybe_string(value: Option<String>) -> Option<String> {
+    value
+}
+
+/// Whether the library lowers empty values as empty buffers
+#[uniffi::export]
+fn empty_buffer_sentinels_enabled() -> bool {
+    uniffi::ffi::empty_buffer_sentinels_enabled()
+}
+
+/// Start lowering empty values as empty buffers, for bindings that don't have the option
+#[uniffi::export]
+fn enable_empty_buffer_sentinels() {
+    uniffi::ffi::empty_buffer_sentinels_enable();
+}
+
+uniffi::setup_scaffolding!();
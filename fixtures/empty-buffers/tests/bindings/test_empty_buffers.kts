@@ -0,0 +1,16 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi.fixture.empty_buffers.*;
+
+// The bindings enable the option when they're loaded
+assert(emptyBufferSentinelsEnabled())
+
+// Empty values are passed as empty buffers, and non-empty ones as before
+assert(echoStrings(listOf()) == listOf<String>())
+assert(echoStrings(listOf("a", "")) == listOf("a", ""))
+assert(echoDict(mapOf()) == mapOf<String, ULong>())
+assert(echoDict(mapOf("a" to 1UL)) == mapOf("a" to 1UL))
+assert(echoMaybeString(null) == null)
+assert(echoMaybeString("") == "")
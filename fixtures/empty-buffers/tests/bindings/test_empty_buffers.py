@@ -0,0 +1,22 @@
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+import unittest
+from uniffi_empty_buffers import *
+
+class TestEmptyBuffers(unittest.TestCase):
+    def test_enabled_when_loaded(self):
+        self.assertTrue(empty_buffer_sentinels_enabled())
+
+    def test_empty_values(self):
+        # Empty values are passed as empty buffers, and non-empty ones as before
+        self.assertEqual(echo_strings([]), [])
+        self.assertEqual(echo_strings(["a", ""]), ["a", ""])
+        self.assertEqual(echo_dict({}), {})
+        self.assertEqual(echo_dict({"a": 1}), {"a": 1})
+        self.assertIsNone(echo_maybe_string(None))
+        self.assertEqual(echo_maybe_string(""), "")
+
+if __name__ == '__main__':
+    unittest.main()
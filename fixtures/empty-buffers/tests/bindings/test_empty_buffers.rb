@@ -0,0 +1,23 @@
+# frozen_string_literal: true
+
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+require 'test/unit'
+require 'uniffi_empty_buffers'
+
+class TestEmptyBuffers < Test::Unit::TestCase
+  # Ruby can't enable the option, but reads empty buffers once another binding has
+  def test_empty_values
+    UniffiEmptyBuffers.enable_empty_buffer_sentinels
+    assert(UniffiEmptyBuffers.empty_buffer_sentinels_enabled)
+
+    assert_equal(UniffiEmptyBuffers.echo_strings([]), [])
+    assert_equal(UniffiEmptyBuffers.echo_strings(['a', '']), ['a', ''])
+    assert_equal(UniffiEmptyBuffers.echo_dict({}), {})
+    assert_equal(UniffiEmptyBuffers.echo_dict({ 'a' => 1 }), { 'a' => 1 })
+    assert_nil(UniffiEmptyBuffers.echo_maybe_string(nil))
+    assert_equal(UniffiEmptyBuffers.echo_maybe_string(''), '')
+  end
+end
@@ -0,0 +1,6 @@
+uniffi::build_foreign_language_testcases!(
+    "tests/bindings/test_empty_buffers.kts",
+    "tests/bindings/test_empty_buffers.py",
+    "tests/bindings/test_empty_buffers.rb",
+    "tests/bindings/test_empty_buffers.swift",
+);
@@ -0,0 +1,32 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+pub enum ComplexError {
+    #[error("PermissionDenied: {reason}")]
+    PermissionDenied { reason: String },
+    #[error("Unknown error")]
+    UnknownError,
+}
+
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+#[uniffi(flat_error)]
+pub enum FlatError {
+    #[error("Too many variants: {num}")]
+    TooManyVariants { num: i16 },
+}
+
+#[uniffi::export]
+fn throw_permission_denied() -> Result<(), ComplexError> {
+    Err(ComplexError::PermissionDenied {
+        reason: "Forbidden".to_owned(),
+    })
+}
+
+#[uniffi::export]
+fn throw_flat_error() -> Result<(), FlatError> {
+    Err(FlatError::TooManyVariants { num: 99 })
+}
+
+uniffi::setup_scaffolding!();
@@ -0,0 +1,32 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi.fixture.error_localizer.*
+
+fun permissionDeniedMessage(): String? {
+    try {
+        throwPermissionDenied()
+        throw RuntimeException("Expected method to throw exception")
+    } catch(e: ComplexException.PermissionDenied) {
+        return e.message
+    }
+}
+
+assert(permissionDeniedMessage() == "reason=Forbidden") { "Unexpected message: ${permissionDeniedMessage()}" }
+
+uniffiSetErrorLocalizer { errorName, variantName, message ->
+    if (errorName == "ComplexError" && variantName == "PermissionDenied") "Zugriff verweigert: $message" else null
+}
+assert(permissionDeniedMessage() == "Zugriff verweigert: reason=Forbidden") { "Unexpected localized message: ${permissionDeniedMessage()}" }
+
+// Errors that the localizer doesn't handle keep their message
+try {
+    throwFlatError()
+    throw RuntimeException("Expected method to throw exception")
+} catch(e: FlatException.TooManyVariants) {
+    assert(e.message == "Too many variants: 99") { "Unexpected message: ${e.message}" }
+}
+
+uniffiSetErrorLocalizer(null)
+assert(permissionDeniedMessage() == "reason=Forbidden") { "Unexpected message: ${permissionDeniedMessage()}" }
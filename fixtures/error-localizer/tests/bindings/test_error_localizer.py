@@ -0,0 +1,41 @@
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+import unittest
+from uniffi_error_localizer import *
+
+def localizer(error_name, variant_name, message):
+    if (error_name, variant_name) == ("ComplexError", "PermissionDenied"):
+        return "Zugriff verweigert: " + message
+    return None
+
+class TestErrorLocalizer(unittest.TestCase):
+    def tearDown(self):
+        uniffi_set_error_localizer(None)
+
+    def test_default_messages(self):
+        with self.assertRaises(ComplexError.PermissionDenied) as cm:
+            throw_permission_denied()
+        self.assertEqual(str(cm.exception), "reason='Forbidden'")
+
+    def test_localized_messages(self):
+        uniffi_set_error_localizer(localizer)
+        with self.assertRaises(ComplexError.PermissionDenied) as cm:
+            throw_permission_denied()
+        self.assertEqual(str(cm.exception), "Zugriff verweigert: reason='Forbidden'")
+        self.assertEqual(repr(cm.exception), "ComplexError.PermissionDenied(reason='Forbidden')")
+
+        # Errors that the localizer doesn't handle keep their message
+        with self.assertRaisesRegex(FlatError.TooManyVariants, "Too many variants: 99"):
+            throw_flat_error()
+
+    def test_remove_localizer(self):
+        uniffi_set_error_localizer(localizer)
+        uniffi_set_error_localizer(None)
+        with self.assertRaises(ComplexError.PermissionDenied) as cm:
+            throw_permission_denied()
+        self.assertEqual(str(cm.exception), "reason='Forbidden'")
+
+if __name__ == "__main__":
+    unittest.main()
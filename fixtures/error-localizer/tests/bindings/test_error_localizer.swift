@@ -0,0 +1,43 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import Foundation
+import uniffi_error_localizer
+
+class TestErrorLocalizer: UniFfiErrorLocalizer {
+    func localize(errorName: String, variantName: String, message: String) -> String? {
+        if errorName == "ComplexError" && variantName == "PermissionDenied" {
+            return "Zugriff verweigert: \(message)"
+        }
+        return nil
+    }
+}
+
+func permissionDeniedDescription() -> String {
+    do {
+        try throwPermissionDenied()
+        fatalError("should have thrown")
+    } catch let e as ComplexError {
+        return e.localizedDescription
+    } catch {
+        fatalError("Unexpected error: \(error)")
+    }
+}
+
+let defaultDescription = "PermissionDenied(reason: \"Forbidden\")"
+assert(permissionDeniedDescription() == defaultDescription, "Unexpected description: \(permissionDeniedDescription())")
+
+uniffiSetErrorLocalizer(TestErrorLocalizer())
+assert(permissionDeniedDescription() == "Zugriff verweigert: \(defaultDescription)", "Unexpected localized description: \(permissionDeniedDescription())")
+
+// Errors that the localizer doesn't handle keep their message
+do {
+    try throwFlatError()
+    fatalError("should have thrown")
+} catch let e as FlatError {
+    assert(e.localizedDescription == "Too many variants: 99", "Unexpected description: \(e.localizedDescription)")
+}
+
+uniffiSetErrorLocalizer(nil)
+assert(permissionDeniedDescription() == defaultDescription, "Unexpected description: \(permissionDeniedDescription())")
@@ -0,0 +1,5 @@
+uniffi::build_foreign_language_testcases!(
+    "tests/bindings/test_error_localizer.kts",
+    "tests/bindings/test_error_localizer.py",
+    "tests/bindings/test_error_localizer.swift",
+);
@@ -0,0 +1,38 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+#[derive(uniffi::Record)]
+pub struct Defaults {
+    #[uniffi(default = -128)]
+    min_i8: i8,
+    #[uniffi(default = 127)]
+    max_i8: i8,
+    #[uniffi(default = -300)]
+    negative_i16: i16,
+    #[uniffi(default = 255)]
+    max_u8: u8,
+    #[uniffi(default = 65535)]
+    max_u16: u16,
+    #[uniffi(default = -7)]
+    negative_i32: i32,
+    #[uniffi(default = 7)]
+    positive_i32: i32,
+    #[uniffi(default = -9)]
+    negative_i64: i64,
+}
+
+/// Check that the foreign defaults made it to Rust unchanged
+#[uniffi::export]
+fn has_rust_defaults(defaults: Defaults) -> bool {
+    defaults.min_i8 == -128
+        && defaults.max_i8 == 127
+        && defaults.negative_i16 == -300
+        && defaults.max_u8 == 255
+        && defaults.max_u16 == 65535
+        && defaults.negative_i32 == -7
+        && defaults.positive_i32 == 7
+        && defaults.negative_i64 == -9
+}
+
+uniffi::setup_scaffolding!();
@@ -0,0 +1,17 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi.fixture.explicit_integer_literals.*
+
+val defaults = Defaults()
+assert(defaults.minI8 == (-128).toByte())
+assert(defaults.maxI8 == 127.toByte())
+assert(defaults.negativeI16 == (-300).toShort())
+assert(defaults.maxU8 == 255.toUByte())
+assert(defaults.maxU16 == 65535.toUShort())
+assert(defaults.negativeI32 == -7)
+assert(defaults.positiveI32 == 7)
+assert(defaults.negativeI64 == -9L)
+assert(hasRustDefaults(defaults))
+assert(!hasRustDefaults(defaults.copy(maxU8 = 0.toUByte())))
@@ -0,0 +1,18 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi_explicit_integer_literals
+
+var defaults = Defaults()
+assert(defaults.minI8 == Int8.min)
+assert(defaults.maxI8 == Int8.max)
+assert(defaults.negativeI16 == -300)
+assert(defaults.maxU8 == UInt8.max)
+assert(defaults.maxU16 == UInt16.max)
+assert(defaults.negativeI32 == -7)
+assert(defaults.positiveI32 == 7)
+assert(defaults.negativeI64 == -9)
+assert(hasRustDefaults(defaults: defaults))
+defaults.maxU8 = 0
+assert(!hasRustDefaults(defaults: defaults))
@@ -0,0 +1,4 @@
+uniffi::build_foreign_language_testcases!(
+    "tests/bindings/test_explicit_integer_literals.kts",
+    "tests/bindings/test_explicit_integer_literals.swift",
+);
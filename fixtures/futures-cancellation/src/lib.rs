@@ -0,0 +1,48 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::{
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
+
+static STARTED: AtomicU32 = AtomicU32::new(0);
+static CANCELLED: AtomicU32 = AtomicU32::new(0);
+
+/// Async function that sleeps, but records if the future was dropped before completing.
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn cancellable_sleep(ms: u16) -> bool {
+    struct CancelGuard {
+        completed: bool,
+    }
+
+    impl Drop for CancelGuard {
+        fn drop(&mut self) {
+            if !self.completed {
+                CANCELLED.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+    }
+
+    STARTED.fetch_add(1, Ordering::SeqCst);
+    let mut guard = CancelGuard { completed: false };
+    tokio::time::sleep(Duration::from_millis(ms.into())).await;
+    guard.completed = true;
+
+    true
+}
+
+/// How many `cancellable_sleep()` futures were polled?
+#[uniffi::export]
+pub fn sleeps_started() -> u32 {
+    STARTED.load(Ordering::SeqCst)
+}
+
+/// How many `cancellable_sleep()` futures were dropped before they completed?
+#[uniffi::export]
+pub fn sleeps_cancelled() -> u32 {
+    CANCELLED.load(Ordering::SeqCst)
+}
+
+uniffi::setup_scaffolding!();
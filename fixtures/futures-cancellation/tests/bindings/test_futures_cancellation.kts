@@ -0,0 +1,23 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi.fixture.futures_cancellation.*
+import kotlinx.coroutines.*
+
+// Futures that are not cancelled complete as usual.
+runBlocking {
+    assert(cancellableSleep(0U))
+    assert(sleepsCancelled() == 0U)
+}
+
+// Cancelling a job also cancels the Rust future.
+runBlocking {
+    val job = launch {
+        cancellableSleep(500U)
+    }
+    delay(100)
+    job.cancel()
+    job.join()
+    assert(sleepsCancelled() == 1U)
+}
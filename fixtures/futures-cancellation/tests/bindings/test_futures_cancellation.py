@@ -0,0 +1,27 @@
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+import asyncio
+import unittest
+from uniffi_futures_cancellation import *
+
+class TestFuturesCancellation(unittest.TestCase):
+    def test_cancel_rust_future(self):
+        async def test():
+            self.assertTrue(await cancellable_sleep(0))
+            cancelled = sleeps_cancelled()
+
+            task = asyncio.create_task(cancellable_sleep(500))
+            await asyncio.sleep(0.1)
+            task.cancel()
+            with self.assertRaises(asyncio.CancelledError):
+                await task
+            # Give Rust a chance to drop the future, which is scheduled on the event loop.
+            await asyncio.sleep(0.1)
+            self.assertEqual(sleeps_cancelled(), cancelled + 1)
+
+        asyncio.run(test())
+
+if __name__ == '__main__':
+    unittest.main()
@@ -0,0 +1,57 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import Foundation // To get `DispatchGroup`
+import uniffi_futures_cancellation
+
+var counter = DispatchGroup()
+
+// Futures that are not cancelled complete as usual.
+counter.enter()
+Task {
+	assert(try! await cancellableSleep(ms: 0))
+	assert(sleepsCancelled() == 0)
+	counter.leave()
+}
+counter.wait()
+
+// Cancelling a task also cancels the Rust future, and the call throws `CancellationError`.
+counter.enter()
+Task {
+	let task = Task {
+		try await cancellableSleep(ms: 500)
+	}
+	try! await Task.sleep(nanoseconds: 100_000_000)
+	task.cancel()
+	do {
+		_ = try await task.value
+		fatalError("cancellableSleep should have thrown")
+	} catch is CancellationError {
+	} catch {
+		fatalError("Unexpected error: \(error)")
+	}
+	assert(sleepsCancelled() == 1)
+	counter.leave()
+}
+counter.wait()
+
+// Tasks that are cancelled before the call never start the Rust future.
+counter.enter()
+Task {
+	let started = sleepsStarted()
+	let task = Task {
+		withUnsafeCurrentTask { $0!.cancel() }
+		return try await cancellableSleep(ms: 500)
+	}
+	do {
+		_ = try await task.value
+		fatalError("cancellableSleep should have thrown")
+	} catch is CancellationError {
+	} catch {
+		fatalError("Unexpected error: \(error)")
+	}
+	assert(sleepsStarted() == started)
+	counter.leave()
+}
+counter.wait()
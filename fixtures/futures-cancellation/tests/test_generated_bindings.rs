@@ -0,0 +1,5 @@
+uniffi::build_foreign_language_testcases!(
+    "tests/bindings/test_futures_cancellation.kts",
+    "tests/bindings/test_futures_cancellation.swift",
+    "tests/bindings/test_futures_cancellation.py",
+);
@@ -0,0 +1,60 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+/// Future that returns `Pending` once before completing, so that it's polled by the executor
+/// more than once.
+struct YieldOnce(bool);
+
+impl Future for YieldOnce {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            Poll::Ready(())
+        } else {
+            self.0 = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+#[uniffi::export]
+pub async fn add(a: u32, b: u32) -> u32 {
+    YieldOnce(false).await;
+    a + b
+}
+
+#[uniffi::export]
+pub async fn ping() -> String {
+    YieldOnce(false).await;
+    "pong".to_string()
+}
+
+#[derive(uniffi::Object)]
+pub struct Adder {
+    base: u32,
+}
+
+#[uniffi::export]
+impl Adder {
+    #[uniffi::constructor]
+    pub fn new(base: u32) -> Arc<Self> {
+        Arc::new(Self { base })
+    }
+
+    pub async fn add(&self, value: u32) -> u32 {
+        YieldOnce(false).await;
+        self.base + value
+    }
+}
+
+uniffi::setup_scaffolding!();
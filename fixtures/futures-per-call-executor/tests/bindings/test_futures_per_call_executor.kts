@@ -0,0 +1,31 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi.fixture.futures_per_call_executor.*
+import java.util.concurrent.atomic.AtomicInteger
+import kotlin.coroutines.CoroutineContext
+import kotlinx.coroutines.*
+
+// Dispatcher that counts how many times it was asked to run something.
+class CountingDispatcher : CoroutineDispatcher() {
+    val dispatched = AtomicInteger(0)
+
+    override fun dispatch(context: CoroutineContext, block: Runnable) {
+        dispatched.incrementAndGet()
+        Dispatchers.Default.dispatch(context, block)
+    }
+}
+
+runBlocking {
+    val dispatcher = CountingDispatcher()
+    val scope = CoroutineScope(dispatcher)
+
+    assert(add(1U, 2U, scope) == 3U)
+    assert(ping(scope) == "pong")
+    assert(Adder(10U).add(5U, scope) == 15U)
+
+    // The Rust futures were driven by the injected scope.
+    assert(dispatcher.dispatched.get() > 0)
+    scope.cancel()
+}
@@ -0,0 +1,27 @@
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+import asyncio
+import unittest
+from uniffi_futures_per_call_executor import *
+
+class TestFuturesPerCallExecutor(unittest.TestCase):
+    def test_injected_event_loop(self):
+        async def test():
+            loop = asyncio.get_running_loop()
+            self.assertEqual(await add(1, 2, uniffi_executor=loop), 3)
+            self.assertEqual(await ping(uniffi_executor=loop), "pong")
+            self.assertEqual(await Adder(10).add(5, uniffi_executor=loop), 15)
+
+        asyncio.run(test())
+
+    def test_executor_is_required(self):
+        async def test():
+            with self.assertRaises(TypeError):
+                await add(1, 2)
+
+        asyncio.run(test())
+
+if __name__ == '__main__':
+    unittest.main()
@@ -0,0 +1,22 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import Foundation // To get `DispatchGroup`
+import uniffi_futures_per_call_executor
+
+var counter = DispatchGroup()
+
+counter.enter()
+Task {
+	let executor = UniFfiForeignExecutor(priority: .background)
+	let sum = await add(a: 1, b: 2, uniffiExecutor: executor)
+	assert(sum == 3)
+	let pong = await ping(uniffiExecutor: executor)
+	assert(pong == "pong")
+	let total = await Adder(base: 10).add(value: 5, uniffiExecutor: executor)
+	assert(total == 15)
+	counter.leave()
+}
+
+counter.wait()
@@ -0,0 +1,5 @@
+uniffi::build_foreign_language_testcases!(
+    "tests/bindings/test_futures_per_call_executor.kts",
+    "tests/bindings/test_futures_per_call_executor.swift",
+    "tests/bindings/test_futures_per_call_executor.py",
+);
@@ -76,6 +76,12 @@ pub async fn always_ready() -> bool {
 #[uniffi::export]
 pub async fn void() {}
 
+/// Async function that returns a `'static` string, which is copied once the future completes.
+#[uniffi::export]
+pub async fn version() -> &'static str {
+    "1.0"
+}
+
 /// Async function that says something after 2s.
 #[uniffi::export]
 pub async fn say() -> String {
@@ -188,6 +194,55 @@ impl Megaphone {
     }
 }
 
+/// An object whose async `&self` methods share state across `.await` points.
+#[derive(uniffi::Object)]
+pub struct AsyncCounter {
+    value: tokio::sync::Mutex<u32>,
+}
+
+#[uniffi::export]
+impl AsyncCounter {
+    #[uniffi::constructor]
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self {
+            value: tokio::sync::Mutex::new(0),
+        })
+    }
+
+    /// Async constructor, which creates the counter after a certain time.
+    #[uniffi::constructor]
+    pub async fn starting_at(value: u32, ms: u16) -> Arc<Self> {
+        TimerFuture::new(Duration::from_millis(ms.into())).await;
+        Arc::new(Self {
+            value: tokio::sync::Mutex::new(value),
+        })
+    }
+
+    /// Async constructor that can throw.
+    #[uniffi::constructor]
+    pub async fn fallible_starting_at(value: u32, do_fail: bool) -> Result<Arc<Self>, MyError> {
+        if do_fail {
+            Err(MyError::Foo)
+        } else {
+            Ok(Self::starting_at(value, 0).await)
+        }
+    }
+
+    /// Increment the counter after a certain time, holding the lock across the wait.
+    ///
+    /// Concurrent calls are serialized by the lock, so each one returns a different value.
+    pub async fn increment_after(&self, ms: u16) -> u32 {
+        let mut value = self.value.lock().await;
+        TimerFuture::new(Duration::from_millis(ms.into())).await;
+        *value += 1;
+        *value
+    }
+
+    pub async fn value(&self) -> u32 {
+        *self.value.lock().await
+    }
+}
+
 // Say something after a certain amount of time, by using `tokio::time::sleep`
 // instead of our own `TimerFuture`.
 #[uniffi::export(async_runtime = "tokio")]
@@ -34,6 +34,11 @@ runBlocking {
     assertReturnsImmediately(time, "always_ready")
 }
 
+// Test `version`.
+runBlocking {
+    assert(version() == "1.0")
+}
+
 // Test `void`.
 runBlocking {
     val time = measureTimeMillis {
@@ -92,6 +97,42 @@ runBlocking {
     assertApproximateTime(time, 200, "async methods")
 }
 
+// Test concurrent async methods on an object with interior async state.
+runBlocking {
+    val asyncCounter = AsyncCounter()
+    val time = measureTimeMillis {
+        val results = listOf(
+            async { asyncCounter.incrementAfter(100U) },
+            async { asyncCounter.incrementAfter(100U) },
+            async { asyncCounter.incrementAfter(100U) },
+        ).awaitAll()
+
+        assert(results.sorted() == listOf(1U, 2U, 3U))
+        assert(asyncCounter.value() == 3U)
+    }
+
+    // The counter's lock serializes the calls
+    assertApproximateTime(time, 300, "concurrent async methods")
+}
+
+// Test async constructors.
+runBlocking {
+    val time = measureTimeMillis {
+        val asyncCounter = AsyncCounter.startingAt(10U, 200U)
+        assert(asyncCounter.incrementAfter(0U) == 11U)
+    }
+
+    assertApproximateTime(time, 200, "async constructor")
+
+    assert(AsyncCounter.fallibleStartingAt(20U, false).value() == 20U)
+    try {
+        AsyncCounter.fallibleStartingAt(20U, true)
+        assert(false) // should never be reached
+    } catch (exception: MyException.Foo) {
+        assert(true)
+    }
+}
+
 // Test async method returning optional object
 runBlocking {
     val megaphone = asyncMaybeNewMegaphone(true)
@@ -13,6 +13,12 @@ class TestFutures(unittest.TestCase):
 
         asyncio.run(test())
 
+    def test_version(self):
+        async def test():
+            self.assertEqual(await version(), "1.0")
+
+        asyncio.run(test())
+
     def test_void(self):
         async def test():
             self.assertEqual(await void(), None)
@@ -74,6 +80,43 @@ class TestFutures(unittest.TestCase):
 
         asyncio.run(test())
 
+    def test_concurrent_async_methods(self):
+        async def test():
+            counter = AsyncCounter()
+            t0 = now()
+            results = await asyncio.gather(
+                counter.increment_after(100),
+                counter.increment_after(100),
+                counter.increment_after(100),
+            )
+            t1 = now()
+
+            # The counter's lock serializes the calls
+            t_delta = (t1 - t0).total_seconds()
+            self.assertGreater(t_delta, 0.3)
+            self.assertEqual(sorted(results), [1, 2, 3])
+            self.assertEqual(await counter.value(), 3)
+
+        asyncio.run(test())
+
+    def test_async_constructors(self):
+        async def test():
+            t0 = now()
+            counter = await AsyncCounter.starting_at(10, 200)
+            t1 = now()
+
+            t_delta = (t1 - t0).total_seconds()
+            self.assertGreater(t_delta, 0.2)
+            self.assertEqual(await counter.increment_after(0), 11)
+
+            counter = await AsyncCounter.fallible_starting_at(20, False)
+            self.assertEqual(await counter.value(), 20)
+
+            with self.assertRaises(MyError.Foo):
+                await AsyncCounter.fallible_starting_at(20, True)
+
+        asyncio.run(test())
+
     def test_with_tokio_runtime(self):
         async def test():
             t0 = now()
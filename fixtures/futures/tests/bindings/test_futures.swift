@@ -18,6 +18,16 @@ Task {
 	counter.leave()
 }
 
+// Test `version`
+counter.enter()
+
+Task {
+	let result = await version()
+	assert(result == "1.0")
+
+	counter.leave()
+}
+
 // Test record.
 counter.enter()
 
@@ -112,6 +122,58 @@ Task {
 	counter.leave()
 }
 
+// Test concurrent async methods on an object with interior async state
+counter.enter()
+
+Task {
+	let asyncCounter = AsyncCounter()
+
+	let t0 = Date()
+	async let first = asyncCounter.incrementAfter(ms: 1000)
+	async let second = asyncCounter.incrementAfter(ms: 1000)
+	async let third = asyncCounter.incrementAfter(ms: 1000)
+	let results = await [first, second, third]
+	let t1 = Date()
+
+	// The counter's lock serializes the calls
+	let tDelta = DateInterval(start: t0, end: t1)
+	assert(tDelta.duration > 3 && tDelta.duration < 3.1)
+	assert(results.sorted() == [1, 2, 3])
+	let value = await asyncCounter.value()
+	assert(value == 3)
+
+	counter.leave()
+}
+
+// Test async constructors
+counter.enter()
+
+Task {
+	let t0 = Date()
+	let asyncCounter = await AsyncCounter.startingAt(value: 10, ms: 1000)
+	let t1 = Date()
+
+	let tDelta = DateInterval(start: t0, end: t1)
+	assert(tDelta.duration > 1 && tDelta.duration < 1.1)
+	let incremented = await asyncCounter.incrementAfter(ms: 0)
+	assert(incremented == 11)
+
+	let fallibleCounter = try! await AsyncCounter.fallibleStartingAt(value: 20, doFail: false)
+	let value = await fallibleCounter.value()
+	assert(value == 20)
+
+	do {
+		let _ = try await AsyncCounter.fallibleStartingAt(value: 20, doFail: true)
+		assert(false) // should never be reached
+	} catch MyError.Foo {
+		assert(true)
+	} catch {
+		assert(false)
+	}
+
+	counter.leave()
+}
+
 // Test async function returning an object
 counter.enter()
 
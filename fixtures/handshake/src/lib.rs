@@ -0,0 +1,29 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::sync::Arc;
+
+#[derive(uniffi::Object)]
+pub struct Counter {
+    start: u32,
+}
+
+#[uniffi::export]
+impl Counter {
+    #[uniffi::constructor]
+    fn new(start: u32) -> Arc<Self> {
+        Arc::new(Self { start })
+    }
+
+    fn plus_one(&self) -> u32 {
+        self.start + 1
+    }
+}
+
+#[uniffi::export]
+fn add(a: u32, b: u32) -> u32 {
+    a + b
+}
+
+uniffi::setup_scaffolding!();
@@ -0,0 +1,28 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi.fixture.handshake.*
+
+val libraryOverride = "uniffi.component.uniffi_handshake.libraryOverride"
+
+// A library that can't be loaded fails the handshake, rather than throwing `UnsatisfiedLinkError`
+System.setProperty(libraryOverride, "uniffi_handshake_missing")
+try {
+    uniffiCheckHandshake()
+    throw AssertionError("The handshake should have failed")
+} catch (e: RuntimeException) {
+    assert(e.message!!.contains("doesn't export the functions that these bindings use"))
+}
+System.clearProperty(libraryOverride)
+
+uniffiCheckHandshake()
+assert(uniffiHandshake() == UniffiHandshake.EXPECTED)
+
+// The library is only loaded once
+System.setProperty(libraryOverride, "uniffi_handshake_missing")
+assert(uniffiHandshake() == UniffiHandshake.EXPECTED)
+System.clearProperty(libraryOverride)
+
+assert(add(1u, 2u) == 3u)
+assert(Counter(1u).plusOne() == 2u)
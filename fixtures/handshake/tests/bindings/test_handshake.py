@@ -0,0 +1,43 @@
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+import unittest
+from uniffi_handshake import *
+# `uniffi_handshake` is also the name of a function in the bindings
+import uniffi_handshake as bindings
+
+class FakeFunction:
+    def __init__(self, value):
+        self.value = value
+
+    def __call__(self):
+        return self.value
+
+class EmptyLib:
+    """A library that doesn't export anything"""
+
+class ZeroLib:
+    """A library where every function returns 0"""
+    def __getattr__(self, name):
+        function = FakeFunction(0)
+        setattr(self, name, function)
+        return function
+
+class TestHandshake(unittest.TestCase):
+    def test_handshake(self):
+        self.assertEqual(uniffi_handshake(), UNIFFI_EXPECTED_HANDSHAKE)
+        self.assertEqual(add(1, 2), 3)
+        self.assertEqual(Counter(1).plus_one(), 2)
+
+    # The module was imported, so the real library passed these checks
+    def test_missing_functions(self):
+        with self.assertRaisesRegex(InternalError, "doesn't export the functions"):
+            bindings._uniffi_check_handshake(EmptyLib())
+
+    def test_mismatch(self):
+        with self.assertRaisesRegex(InternalError, "isn't compatible with these bindings"):
+            bindings._uniffi_check_handshake(ZeroLib())
+
+if __name__ == "__main__":
+    unittest.main()
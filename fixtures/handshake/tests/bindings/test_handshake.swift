@@ -0,0 +1,12 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi_handshake
+
+// Swift links the library's functions directly, so a mismatched library fails to link instead
+try! uniffiCheckHandshake()
+assert(uniffiHandshake() == UniffiHandshake.expected)
+
+assert(add(a: 1, b: 2) == 3)
+assert(Counter(start: 1).plusOne() == 2)
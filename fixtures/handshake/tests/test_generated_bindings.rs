@@ -0,0 +1,5 @@
+uniffi::build_foreign_language_testcases!(
+    "tests/bindings/test_handshake.kts",
+    "tests/bindings/test_handshake.py",
+    "tests/bindings/test_handshake.swift",
+);
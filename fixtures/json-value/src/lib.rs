@@ -0,0 +1,42 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use serde_json::{json, Value};
+
+#[uniffi::export]
+pub fn json_identity(value: Value) -> Value {
+    value
+}
+
+#[uniffi::export]
+pub fn json_settings() -> Value {
+    json!({
+        "name": "uniffi",
+        "max_id": u64::MAX,
+        "min_offset": i64::MIN,
+        "ratio": 0.5,
+        "tags": ["a", null],
+        "nested": { "enabled": true },
+    })
+}
+
+#[uniffi::export]
+pub fn json_lookup(value: Value, key: String) -> Option<Value> {
+    value.get(&key).cloned()
+}
+
+#[uniffi::export]
+pub fn json_describe(value: Value) -> String {
+    match value {
+        Value::Null => "null".to_string(),
+        Value::Bool(_) => "bool".to_string(),
+        Value::Number(n) if n.is_u64() || n.is_i64() => "integer".to_string(),
+        Value::Number(_) => "float".to_string(),
+        Value::String(_) => "string".to_string(),
+        Value::Array(_) => "array".to_string(),
+        Value::Object(_) => "object".to_string(),
+    }
+}
+
+uniffi::setup_scaffolding!();
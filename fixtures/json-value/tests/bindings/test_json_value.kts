@@ -0,0 +1,33 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import kotlinx.serialization.json.*
+import uniffi.fixture.json_value.*
+
+val settings = jsonSettings().jsonObject
+assert(settings["name"] == JsonPrimitive("uniffi"))
+// `JsonElement` keeps the text of numbers, so integers that don't fit in `Long` survive exactly
+assert(settings["max_id"]!!.jsonPrimitive.content == "18446744073709551615")
+assert(settings["min_offset"]!!.jsonPrimitive.long == Long.MIN_VALUE)
+assert(settings["ratio"]!!.jsonPrimitive.double == 0.5)
+assert(settings["tags"] == JsonArray(listOf(JsonPrimitive("a"), JsonNull)))
+assert(settings["nested"] == buildJsonObject { put("enabled", true) })
+
+assert(jsonIdentity(settings) == settings)
+assert(jsonIdentity(JsonNull) == JsonNull)
+val mixed = buildJsonArray {
+    add(1)
+    add("two")
+    add(3.5)
+    add(false)
+}
+assert(jsonIdentity(mixed) == mixed)
+
+assert(jsonLookup(buildJsonObject { put("a", buildJsonObject { put("b", 1) }) }, "a") == buildJsonObject { put("b", 1) })
+assert(jsonLookup(buildJsonObject { put("a", JsonNull) }, "a") == JsonNull)
+assert(jsonLookup(buildJsonObject { put("a", 1) }, "missing") == null)
+
+assert(jsonDescribe(Json.parseToJsonElement("18446744073709551615")) == "integer")
+assert(jsonDescribe(JsonPrimitive(1.5)) == "float")
+assert(jsonDescribe(JsonObject(mapOf())) == "object")
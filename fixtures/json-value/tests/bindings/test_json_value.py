@@ -0,0 +1,33 @@
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+from uniffi_json_value import *
+
+settings = json_settings()
+assert settings["name"] == "uniffi"
+# Python integers are unbounded, so 64-bit integers survive exactly
+assert settings["max_id"] == 2**64 - 1
+assert settings["min_offset"] == -2**63
+assert settings["ratio"] == 0.5
+assert settings["tags"] == ["a", None]
+assert settings["nested"] == {"enabled": True}
+
+assert json_identity(settings) == settings
+assert json_identity(None) is None
+assert json_identity([1, "two", 3.5, False]) == [1, "two", 3.5, False]
+
+assert json_lookup({"a": {"b": 1}}, "a") == {"b": 1}
+assert json_lookup({"a": None}, "a") is None
+assert json_lookup({"a": 1}, "missing") is None
+
+assert json_describe(2**64 - 1) == "integer"
+assert json_describe(1.5) == "float"
+assert json_describe({}) == "object"
+
+try:
+    json_identity(float("nan"))
+except ValueError:
+    pass
+else:
+    raise AssertionError("NaN isn't valid JSON")
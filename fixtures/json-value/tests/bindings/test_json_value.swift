@@ -0,0 +1,30 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi_json_value
+
+let settings = jsonSettings()
+guard case let .object(fields) = settings else {
+    fatalError("settings should be an object")
+}
+assert(fields["name"] == .string("uniffi"))
+// Integers that don't fit in `Int64` are decoded as `.uint`
+assert(fields["max_id"] == .uint(UInt64.max))
+assert(fields["min_offset"] == .int(Int64.min))
+assert(fields["ratio"] == .double(0.5))
+assert(fields["tags"] == .array([.string("a"), .null]))
+assert(fields["nested"] == .object(["enabled": .bool(true)]))
+
+assert(jsonIdentity(value: settings) == settings)
+assert(jsonIdentity(value: .null) == .null)
+assert(jsonIdentity(value: .array([.int(1), .string("two"), .double(3.5), .bool(false)]))
+    == .array([.int(1), .string("two"), .double(3.5), .bool(false)]))
+
+assert(jsonLookup(value: .object(["a": .object(["b": .int(1)])]), key: "a") == .object(["b": .int(1)]))
+assert(jsonLookup(value: .object(["a": .null]), key: "a") == .null)
+assert(jsonLookup(value: .object(["a": .int(1)]), key: "missing") == nil)
+
+assert(jsonDescribe(value: .uint(UInt64.max)) == "integer")
+assert(jsonDescribe(value: .double(1.5)) == "float")
+assert(jsonDescribe(value: .object([:])) == "object")
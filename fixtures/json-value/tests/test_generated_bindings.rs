@@ -0,0 +1,5 @@
+uniffi::build_foreign_language_testcases!(
+    "tests/bindings/test_json_value.kts",
+    "tests/bindings/test_json_value.swift",
+    "tests/bindings/test_json_value.py",
+);
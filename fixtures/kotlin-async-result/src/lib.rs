@@ -0,0 +1,68 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::{sync::Arc, time::Duration};
+
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+pub enum FetchError {
+    #[error("Not found")]
+    NotFound,
+}
+
+/// Async function that can fail.
+#[uniffi::export]
+pub async fn fetch(key: String) -> Result<String, FetchError> {
+    match key.as_str() {
+        "hello" => Ok("world".to_string()),
+        _ => Err(FetchError::NotFound),
+    }
+}
+
+/// Async function without a return value that can fail.
+#[uniffi::export]
+pub async fn check(ok: bool) -> Result<(), FetchError> {
+    if ok {
+        Ok(())
+    } else {
+        Err(FetchError::NotFound)
+    }
+}
+
+/// Async function that can't fail, which is unaffected by `async_result`.
+#[uniffi::export]
+pub async fn infallible() -> u32 {
+    42
+}
+
+/// Async function that can fail, but sleeps first so that it can be cancelled.
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn slow_fetch(ms: u16) -> Result<String, FetchError> {
+    tokio::time::sleep(Duration::from_millis(ms.into())).await;
+    Ok("done".to_string())
+}
+
+#[derive(uniffi::Object)]
+pub struct Store {
+    prefix: String,
+}
+
+#[uniffi::export]
+impl Store {
+    /// Async constructor that can fail.
+    #[uniffi::constructor]
+    pub async fn open(prefix: String) -> Result<Arc<Self>, FetchError> {
+        if prefix.is_empty() {
+            Err(FetchError::NotFound)
+        } else {
+            Ok(Arc::new(Self { prefix }))
+        }
+    }
+
+    /// Async method that can fail.
+    pub async fn get(&self, key: String) -> Result<String, FetchError> {
+        Ok(format!("{}{}", self.prefix, fetch(key).await?))
+    }
+}
+
+uniffi::setup_scaffolding!();
@@ -0,0 +1,40 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi.fixture.kotlin_async_result.*
+import kotlinx.coroutines.*
+
+// Fallible functions return a `kotlin.Result` instead of throwing.
+runBlocking {
+    assert(fetch("hello") == Result.success("world"))
+    assert(fetch("bye").exceptionOrNull() is FetchException.NotFound)
+
+    assert(check(true) == Result.success(Unit))
+    assert(check(false).exceptionOrNull() is FetchException.NotFound)
+
+    // Functions that can't fail are unchanged.
+    assert(infallible() == 42U)
+}
+
+// So do fallible methods and constructors.
+runBlocking {
+    val store = Store.open("my-").getOrThrow()
+    assert(store.get("hello") == Result.success("my-world"))
+    assert(store.get("bye").exceptionOrNull() is FetchException.NotFound)
+
+    assert(Store.open("").exceptionOrNull() is FetchException.NotFound)
+}
+
+// Cancelling the coroutine still throws `CancellationException` rather than returning a `Result`.
+runBlocking {
+    var result: Result<String>? = null
+    val job = launch {
+        result = slowFetch(500U)
+    }
+    delay(100)
+    job.cancel()
+    job.join()
+    assert(job.isCancelled)
+    assert(result == null)
+}
@@ -0,0 +1 @@
+uniffi::build_foreign_language_testcases!("tests/bindings/test_kotlin_async_result.kts",);
@@ -0,0 +1,18 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::time::Duration;
+
+#[uniffi::export]
+fn take_duration(v: Duration) -> Duration {
+    v
+}
+
+/// The longest Rust duration, which doesn't fit in a `Long` of nanoseconds
+#[uniffi::export]
+fn max_duration() -> Duration {
+    Duration::MAX
+}
+
+uniffi::setup_scaffolding!();
@@ -0,0 +1,24 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi.fixture.kotlin_nanos_duration.*
+
+val oneAndAHalfSeconds: UniffiDuration = 1_500_000_000L
+assert(takeDuration(0L) == 0L)
+assert(takeDuration(oneAndAHalfSeconds) == oneAndAHalfSeconds)
+assert(takeDuration(Long.MAX_VALUE) == Long.MAX_VALUE)
+
+try {
+    maxDuration()
+    throw RuntimeException("Should have thrown an InternalException!")
+} catch (e: InternalException) {
+    // It's okay!
+}
+
+try {
+    takeDuration(-1L)
+    throw RuntimeException("Should have thrown an IllegalArgumentException!")
+} catch (e: IllegalArgumentException) {
+    // It's okay!
+}
@@ -0,0 +1 @@
+uniffi::build_foreign_language_testcases!("tests/bindings/test_kotlin_nanos_duration.kts",);
@@ -0,0 +1,25 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::time::Duration;
+
+#[uniffi::export]
+fn take_duration(v: Duration) -> Duration {
+    v
+}
+
+/// 200 years and a nanosecond, which is too long for `kotlin.time.Duration` to keep nanosecond
+/// precision
+#[uniffi::export]
+fn imprecise_duration() -> Duration {
+    Duration::new(200 * 365 * 24 * 60 * 60, 1)
+}
+
+/// 200 years exactly, which `kotlin.time.Duration` can represent with millisecond precision
+#[uniffi::export]
+fn long_duration() -> Duration {
+    Duration::from_secs(200 * 365 * 24 * 60 * 60)
+}
+
+uniffi::setup_scaffolding!();
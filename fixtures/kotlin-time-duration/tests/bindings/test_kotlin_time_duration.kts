@@ -0,0 +1,32 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi.fixture.kotlin_time_duration.*
+import kotlin.time.Duration
+import kotlin.time.DurationUnit
+import kotlin.time.toDuration
+
+val oneAndAHalfSeconds: UniffiDuration = 1500.toDuration(DurationUnit.MILLISECONDS)
+assert(takeDuration(Duration.ZERO) == Duration.ZERO)
+assert(takeDuration(oneAndAHalfSeconds) == oneAndAHalfSeconds)
+val precise = 1.toDuration(DurationUnit.SECONDS) + 1.toDuration(DurationUnit.NANOSECONDS)
+assert(takeDuration(precise) == precise)
+
+// Long durations only have millisecond precision in Kotlin
+assert(longDuration() == (200L * 365 * 24 * 60 * 60).toDuration(DurationUnit.SECONDS))
+try {
+    impreciseDuration()
+    throw RuntimeException("Should have thrown an InternalException!")
+} catch (e: InternalException) {
+    // It's okay!
+}
+
+for (invalid in listOf(-oneAndAHalfSeconds, Duration.INFINITE)) {
+    try {
+        takeDuration(invalid)
+        throw RuntimeException("Should have thrown an IllegalArgumentException!")
+    } catch (e: IllegalArgumentException) {
+        // It's okay!
+    }
+}
@@ -0,0 +1,33 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+// Flat error, the variants only carry a message
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+#[uniffi(flat_error)]
+pub enum MathError {
+    #[error("Division by zero")]
+    DivisionByZero,
+}
+
+// Non-flat error, the variants carry fields
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+pub enum ParseError {
+    #[error("Invalid digit {digit}")]
+    InvalidDigit { digit: String },
+}
+
+#[uniffi::export]
+fn divide(a: u32, b: u32) -> Result<u32, MathError> {
+    a.checked_div(b).ok_or(MathError::DivisionByZero)
+}
+
+#[uniffi::export]
+fn parse_digit(text: String) -> Result<u8, ParseError> {
+    match text.as_bytes() {
+        [c @ b'0'..=b'9'] => Ok(c - b'0'),
+        _ => Err(ParseError::InvalidDigit { digit: text }),
+    }
+}
+
+uniffi::setup_scaffolding!();
@@ -0,0 +1,26 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi.fixture.kotlin_unchecked_exceptions.*
+
+// Both kinds of error extend `RuntimeException`, so Java treats them as unchecked
+assert(RuntimeException::class.java.isAssignableFrom(MathException::class.java))
+assert(RuntimeException::class.java.isAssignableFrom(ParseException::class.java))
+
+assert(divide(6u, 3u) == 2u)
+try {
+    divide(1u, 0u)
+    throw AssertionError("divide should have thrown")
+} catch (e: RuntimeException) {
+    assert(e is MathException.DivisionByZero)
+}
+
+assert(parseDigit("7") == 7.toUByte())
+try {
+    parseDigit("x")
+    throw AssertionError("parseDigit should have thrown")
+} catch (e: RuntimeException) {
+    assert(e is ParseException.InvalidDigit)
+    assert((e as ParseException.InvalidDigit).digit == "x")
+}
@@ -0,0 +1,26 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+#[uniffi::export]
+fn take_string(v: String) -> String {
+    v
+}
+
+#[uniffi::export]
+fn take_strings(v: Vec<String>) -> Vec<String> {
+    v
+}
+
+#[uniffi::export]
+fn count_chars(v: String) -> u32 {
+    v.chars().count() as u32
+}
+
+/// A string that's created by Rust, rather than round-tripped
+#[uniffi::export]
+fn rust_greeting() -> String {
+    "Hello, 世界 👋".to_string()
+}
+
+uniffi::setup_scaffolding!();
@@ -0,0 +1,14 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi.fixture.kotlin_utf16_strings.*
+
+assert(takeString("") == "")
+assert(takeString("愛") == "愛")
+assert(takeString("💖") == "💖")
+assert(takeStrings(listOf("", "a愛", "💖")) == listOf("", "a愛", "💖"))
+
+// Rust sees the same characters, so the surrogate pair for 💖 is a single `char`
+assert(countChars("a愛💖") == 3u)
+assert(rustGreeting() == "Hello, 世界 👋")
@@ -14,6 +14,18 @@ mod person {
         #[uniffi(default = "test")]
         name: String,
         age: u16,
+        #[uniffi(exclude_from_eq)]
+        visits: u32,
+    }
+}
+
+mod point {
+    #[derive(uniffi::Record, Debug)]
+    #[uniffi(pod)]
+    #[repr(C)]
+    pub struct Point {
+        x: f64,
+        y: f64,
     }
 }
 
@@ -26,6 +38,21 @@ mod weapon {
     }
 }
 
+mod permissions {
+    // Laid out like the structs generated by `bitflags` 1.x
+    #[derive(uniffi::Flags, Debug)]
+    #[uniffi(flags(READ, WRITE, ADMIN))]
+    pub struct Permissions {
+        bits: u64,
+    }
+
+    impl Permissions {
+        pub const READ: Self = Self { bits: 1 };
+        pub const WRITE: Self = Self { bits: 2 };
+        pub const ADMIN: Self = Self { bits: 1 << 40 };
+    }
+}
+
 mod state {
     use super::Person;
 
@@ -83,6 +110,10 @@ mod error {
 mod calc {
     #[derive(uniffi::Object)]
     pub struct Calculator {}
+
+    #[derive(uniffi::Object)]
+    #[uniffi(type_name)]
+    pub struct Memory {}
 }
 
 #[uniffi::export(callback_interface)]
@@ -93,6 +124,7 @@ pub trait Logger {
 pub use calc::Calculator;
 pub use error::{ComplexError, FlatError};
 pub use person::Person;
+pub use point::Point;
 pub use state::State;
 pub use weapon::Weapon;
 
@@ -134,6 +166,12 @@ mod test_type_ids {
         check_type_id::<Person>(Type::Record {
             module_path: "uniffi_fixture_metadata".into(),
             name: "Person".into(),
+            pod: false,
+        });
+        check_type_id::<point::Point>(Type::Record {
+            module_path: "uniffi_fixture_metadata".into(),
+            name: "Point".into(),
+            pod: true,
         });
         check_type_id::<Weapon>(Type::Enum {
             module_path: "uniffi_fixture_metadata".into(),
@@ -178,18 +216,55 @@ mod test_metadata {
             RecordMetadata {
                 module_path: "uniffi_fixture_metadata".into(),
                 name: "Person".into(),
+                pod: false,
                 fields: vec![
                     FieldMetadata {
                         name: "name".into(),
                         ty: Type::String,
                         default: Some(LiteralMetadata::String("test".to_owned())),
+                        exclude_from_eq: false,
                     },
                     FieldMetadata {
                         name: "age".into(),
                         ty: Type::UInt16,
                         default: None,
+                        exclude_from_eq: false,
+                    },
+                    FieldMetadata {
+                        name: "visits".into(),
+                        ty: Type::UInt32,
+                        default: None,
+                        exclude_from_eq: true,
+                    },
+                ],
+                full_module_path: "uniffi_fixture_metadata::tests::person".into(),
+            },
+        );
+    }
+
+    #[test]
+    fn test_pod_record() {
+        check_metadata(
+            &point::UNIFFI_META_UNIFFI_FIXTURE_METADATA_RECORD_POINT,
+            RecordMetadata {
+                module_path: "uniffi_fixture_metadata".into(),
+                name: "Point".into(),
+                pod: true,
+                fields: vec![
+                    FieldMetadata {
+                        name: "x".into(),
+                        ty: Type::Float64,
+                        default: None,
+                        exclude_from_eq: false,
+                    },
+                    FieldMetadata {
+                        name: "y".into(),
+                        ty: Type::Float64,
+                        default: None,
+                        exclude_from_eq: false,
                     },
                 ],
+                full_module_path: "uniffi_fixture_metadata::tests::point".into(),
             },
         );
     }
@@ -215,6 +290,34 @@ mod test_metadata {
                         fields: vec![],
                     },
                 ],
+                full_module_path: "uniffi_fixture_metadata::tests::weapon".into(),
+            },
+        );
+    }
+
+    #[test]
+    fn test_flags() {
+        check_metadata(
+            &permissions::UNIFFI_META_UNIFFI_FIXTURE_METADATA_FLAGS_PERMISSIONS,
+            FlagsMetadata {
+                module_path: "uniffi_fixture_metadata".into(),
+                name: "Permissions".into(),
+                repr: Type::UInt64,
+                flags: vec![
+                    FlagMetadata {
+                        name: "READ".into(),
+                        value: 1,
+                    },
+                    FlagMetadata {
+                        name: "WRITE".into(),
+                        value: 2,
+                    },
+                    FlagMetadata {
+                        name: "ADMIN".into(),
+                        value: 1 << 40,
+                    },
+                ],
+                full_module_path: "uniffi_fixture_metadata::tests::permissions".into(),
             },
         );
     }
@@ -237,6 +340,7 @@ mod test_metadata {
                             name: "data".into(),
                             ty: Type::String,
                             default: None,
+                            exclude_from_eq: false,
                         }],
                     },
                     VariantMetadata {
@@ -246,11 +350,14 @@ mod test_metadata {
                             ty: Type::Record {
                                 module_path: "uniffi_fixture_metadata".into(),
                                 name: "Person".into(),
+                                pod: false,
                             },
                             default: None,
+                            exclude_from_eq: false,
                         }],
                     },
                 ],
+                full_module_path: "uniffi_fixture_metadata::tests::state".into(),
             },
         );
     }
@@ -273,6 +380,7 @@ mod test_metadata {
                             fields: vec![],
                         },
                     ],
+                    full_module_path: "uniffi_fixture_metadata::tests::error".into(),
                 },
                 is_flat: true,
             },
@@ -298,6 +406,7 @@ mod test_metadata {
                                 name: "reason".into(),
                                 ty: Type::String,
                                 default: None,
+                                exclude_from_eq: false,
                             }],
                         },
                         VariantMetadata {
@@ -309,9 +418,11 @@ mod test_metadata {
                                     name: "Weapon".into(),
                                 },
                                 default: None,
+                                exclude_from_eq: false,
                             }],
                         },
                     ],
+                    full_module_path: "uniffi_fixture_metadata::tests::error".into(),
                 },
                 is_flat: false,
             },
@@ -327,9 +438,47 @@ mod test_metadata {
                 name: "Calculator".into(),
                 imp: ObjectImpl::Struct,
                 uniffi_traits: vec![],
+                rust_type_name: None,
+                full_module_path: "uniffi_fixture_metadata::tests::calc".into(),
             },
         );
     }
+
+    #[test]
+    fn test_interface_with_type_name() {
+        check_metadata(
+            &calc::UNIFFI_META_UNIFFI_FIXTURE_METADATA_INTERFACE_MEMORY,
+            ObjectMetadata {
+                module_path: "uniffi_fixture_metadata".into(),
+                name: "Memory".into(),
+                imp: ObjectImpl::Struct,
+                uniffi_traits: vec![],
+                rust_type_name: Some("uniffi_fixture_metadata::tests::calc::Memory".into()),
+                full_module_path: "uniffi_fixture_metadata::tests::calc".into(),
+            },
+        );
+    }
+
+    // Statics placed in a custom metadata section end up back-to-back in the library
+    #[test]
+    fn test_metadata_items() {
+        let section = [
+            &person::UNIFFI_META_UNIFFI_FIXTURE_METADATA_RECORD_PERSON[..],
+            &point::UNIFFI_META_UNIFFI_FIXTURE_METADATA_RECORD_POINT[..],
+            &calc::UNIFFI_META_UNIFFI_FIXTURE_METADATA_INTERFACE_CALCULATOR[..],
+        ]
+        .concat();
+        let items = uniffi_meta::read_metadata_items(&section).unwrap();
+        assert_eq!(items.len(), 3);
+        assert_eq!(
+            items[1],
+            uniffi_meta::read_metadata(&point::UNIFFI_META_UNIFFI_FIXTURE_METADATA_RECORD_POINT)
+                .unwrap()
+        );
+        assert!(matches!(&items[2], Metadata::Object(obj) if obj.name == "Calculator"));
+        // A truncated item is an error, rather than being skipped
+        assert!(uniffi_meta::read_metadata_items(&section[..section.len() - 1]).is_err());
+    }
 }
 
 mod test_function_metadata {
@@ -347,6 +496,11 @@ mod test_function_metadata {
         unimplemented!()
     }
 
+    #[uniffi::export(aliases("testFuncAlias", "test_func_old_name"))]
+    pub fn test_func_with_aliases() {
+        unimplemented!()
+    }
+
     #[uniffi::export]
     pub fn test_func_that_throws() -> Result<State, FlatError> {
         unimplemented!()
@@ -405,6 +559,7 @@ mod test_function_metadata {
                         Type::Record {
                             module_path: "uniffi_fixture_metadata".into(),
                             name: "Person".into(),
+                            pod: false,
                         },
                     ),
                     FnParamMetadata::simple(
@@ -417,7 +572,9 @@ mod test_function_metadata {
                 ],
                 return_type: Some(Type::String),
                 throws: None,
+                aliases: vec![],
                 checksum: Some(UNIFFI_META_CONST_UNIFFI_FIXTURE_METADATA_FUNC_TEST_FUNC.checksum()),
+                full_module_path: "uniffi_fixture_metadata::tests::test_function_metadata".into(),
             },
         );
     }
@@ -433,9 +590,32 @@ mod test_function_metadata {
                 inputs: vec![],
                 return_type: None,
                 throws: None,
+                aliases: vec![],
                 checksum: Some(
                     UNIFFI_META_CONST_UNIFFI_FIXTURE_METADATA_FUNC_TEST_FUNC_NO_RETURN.checksum(),
                 ),
+                full_module_path: "uniffi_fixture_metadata::tests::test_function_metadata".into(),
+            },
+        );
+    }
+
+    #[test]
+    fn test_function_with_aliases() {
+        check_metadata(
+            &UNIFFI_META_UNIFFI_FIXTURE_METADATA_FUNC_TEST_FUNC_WITH_ALIASES,
+            FnMetadata {
+                module_path: "uniffi_fixture_metadata".into(),
+                name: "test_func_with_aliases".into(),
+                is_async: false,
+                inputs: vec![],
+                return_type: None,
+                throws: None,
+                aliases: vec!["testFuncAlias".into(), "test_func_old_name".into()],
+                checksum: Some(
+                    UNIFFI_META_CONST_UNIFFI_FIXTURE_METADATA_FUNC_TEST_FUNC_WITH_ALIASES
+                        .checksum(),
+                ),
+                full_module_path: "uniffi_fixture_metadata::tests::test_function_metadata".into(),
             },
         );
     }
@@ -457,9 +637,11 @@ mod test_function_metadata {
                     module_path: "uniffi_fixture_metadata".into(),
                     name: "FlatError".into(),
                 }),
+                aliases: vec![],
                 checksum: Some(
                     UNIFFI_META_CONST_UNIFFI_FIXTURE_METADATA_FUNC_TEST_FUNC_THAT_THROWS.checksum(),
                 ),
+                full_module_path: "uniffi_fixture_metadata::tests::test_function_metadata".into(),
             },
         );
     }
@@ -478,10 +660,12 @@ mod test_function_metadata {
                     module_path: "uniffi_fixture_metadata".into(),
                     name: "FlatError".into(),
                 }),
+                aliases: vec![],
                 checksum: Some(
                     UNIFFI_META_CONST_UNIFFI_FIXTURE_METADATA_FUNC_TEST_FUNC_NO_RETURN_THAT_THROWS
                         .checksum(),
                 ),
+                full_module_path: "uniffi_fixture_metadata::tests::test_function_metadata".into(),
             },
         );
     }
@@ -523,6 +707,7 @@ mod test_function_metadata {
                         Type::Record {
                             module_path: "uniffi_fixture_metadata".into(),
                             name: "Person".into(),
+                            pod: false,
                         },
                     ),
                     FnParamMetadata::simple(
@@ -535,9 +720,11 @@ mod test_function_metadata {
                 ],
                 return_type: Some(Type::String),
                 throws: None,
+                aliases: vec![],
                 checksum: Some(
                     UNIFFI_META_CONST_UNIFFI_FIXTURE_METADATA_FUNC_TEST_ASYNC_FUNC.checksum(),
                 ),
+                full_module_path: "uniffi_fixture_metadata::tests::test_function_metadata".into(),
             },
         );
     }
@@ -559,10 +746,12 @@ mod test_function_metadata {
                     module_path: "uniffi_fixture_metadata".into(),
                     name: "FlatError".into(),
                 }),
+                aliases: vec![],
                 checksum: Some(
                     UNIFFI_META_CONST_UNIFFI_FIXTURE_METADATA_FUNC_TEST_ASYNC_FUNC_THAT_THROWS
                         .checksum(),
                 ),
+                full_module_path: "uniffi_fixture_metadata::tests::test_function_metadata".into(),
             },
         );
     }
@@ -645,6 +834,7 @@ mod test_function_metadata {
             CallbackInterfaceMetadata {
                 module_path: "uniffi_fixture_metadata".into(),
                 name: "Logger".into(),
+                full_module_path: "uniffi_fixture_metadata::tests".into(),
             },
         );
         check_metadata(
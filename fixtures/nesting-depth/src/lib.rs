@@ -0,0 +1,54 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use uniffi::FfiConverter;
+
+/// A recursive record
+#[derive(uniffi::Record)]
+pub struct Tree {
+    children: Vec<Tree>,
+}
+
+// Returning a `Result` makes the Swift functions `throws`, so that they don't trap when lifting
+// or lowering fails.
+#[derive(uniffi::Error, Debug)]
+pub enum TreeError {
+    Unused,
+}
+
+#[uniffi::export]
+fn make_tree(depth: u32) -> Tree {
+    let mut tree = Tree { children: vec![] };
+    for _ in 1..depth {
+        tree = Tree {
+            children: vec![tree],
+        };
+    }
+    tree
+}
+
+#[uniffi::export]
+fn try_make_tree(depth: u32) -> Result<Tree, TreeError> {
+    Ok(make_tree(depth))
+}
+
+#[uniffi::export]
+fn tree_depth(tree: Tree) -> u32 {
+    1 + tree.children.into_iter().map(tree_depth).max().unwrap_or(0)
+}
+
+#[uniffi::export]
+fn try_tree_depth(tree: Tree) -> Result<u32, TreeError> {
+    Ok(tree_depth(tree))
+}
+
+/// Lower a tree and lift it again, like the scaffolding does for arguments, to check the limit
+/// that the bindings passed to Rust
+#[uniffi::export]
+fn rust_can_lift_tree(depth: u32) -> bool {
+    let buf = <Tree as FfiConverter<crate::UniFfiTag>>::lower(make_tree(depth));
+    <Tree as FfiConverter<crate::UniFfiTag>>::try_lift(buf).is_ok()
+}
+
+uniffi::setup_scaffolding!();
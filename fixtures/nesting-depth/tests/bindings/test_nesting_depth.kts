@@ -0,0 +1,32 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi.fixture.nesting_depth.*
+
+fun assertInternalException(f: () -> Any) {
+    try {
+        f()
+        throw RuntimeException("Values nested too deeply should have failed")
+    } catch (e: InternalException) {
+        // Expected
+    }
+}
+
+fun buildTree(depth: Int): Tree {
+    var tree = Tree(listOf())
+    repeat(depth - 1) { tree = Tree(listOf(tree)) }
+    return tree
+}
+
+// Lifting
+assert(treeDepth(makeTree(10u)) == 10u)
+assertInternalException { makeTree(11u) }
+
+// Lowering
+assert(treeDepth(buildTree(10)) == 10u)
+assertInternalException { treeDepth(buildTree(11)) }
+
+// The bindings passed their limit to the scaffolding
+assert(rustCanLiftTree(10u))
+assert(!rustCanLiftTree(11u))
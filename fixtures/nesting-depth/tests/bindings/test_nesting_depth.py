@@ -0,0 +1,30 @@
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+import unittest
+from uniffi_nesting_depth import *
+
+def build_tree(depth):
+    tree = Tree(children=[])
+    for _ in range(depth - 1):
+        tree = Tree(children=[tree])
+    return tree
+
+class TestNestingDepth(unittest.TestCase):
+    def test_lift(self):
+        self.assertEqual(tree_depth(make_tree(10)), 10)
+        with self.assertRaises(InternalError):
+            make_tree(11)
+
+    def test_lower(self):
+        self.assertEqual(tree_depth(build_tree(10)), 10)
+        with self.assertRaises(InternalError):
+            tree_depth(build_tree(11))
+
+    def test_scaffolding_limit(self):
+        self.assertTrue(rust_can_lift_tree(10))
+        self.assertFalse(rust_can_lift_tree(11))
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,37 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import Foundation
+import uniffi_nesting_depth
+
+// The internal errors are `fileprivate`, so check their descriptions
+func assertNestedTooDeeply<T>(_ f: () throws -> T) {
+    do {
+        _ = try f()
+        fatalError("Values nested too deeply should have failed")
+    } catch is TreeError {
+        fatalError("Unexpected TreeError")
+    } catch {
+        assert(error.localizedDescription.contains("nested"), "\(error.localizedDescription)")
+    }
+}
+
+func buildTree(_ depth: Int) -> Tree {
+    var tree = Tree(children: [])
+    for _ in 1..<depth {
+        tree = Tree(children: [tree])
+    }
+    return tree
+}
+
+// Lifting
+assert(treeDepth(tree: makeTree(depth: 10)) == 10)
+assertNestedTooDeeply { try tryMakeTree(depth: 11) }
+
+// Swift doesn't check the values it lowers, the scaffolding refuses to lift them instead
+assert(treeDepth(tree: buildTree(10)) == 10)
+assertNestedTooDeeply { try tryTreeDepth(tree: buildTree(11)) }
+
+assert(rustCanLiftTree(depth: 10))
+assert(!rustCanLiftTree(depth: 11))
@@ -0,0 +1,5 @@
+uniffi::build_foreign_language_testcases!(
+    "tests/bindings/test_nesting_depth.kts",
+    "tests/bindings/test_nesting_depth.py",
+    "tests/bindings/test_nesting_depth.swift",
+);
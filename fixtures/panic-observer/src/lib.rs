@@ -0,0 +1,28 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+#[derive(uniffi::Error, Debug)]
+pub enum PanicError {
+    Panicked { reason: String },
+}
+
+impl From<uniffi::RustPanic> for PanicError {
+    fn from(e: uniffi::RustPanic) -> Self {
+        Self::Panicked { reason: e.message }
+    }
+}
+
+#[uniffi::export(catch_panics)]
+fn panic_caught(message: String) -> Result<(), PanicError> {
+    panic!("{message}");
+}
+
+// This returns a `Result` so that Swift, which can only catch errors from throwing functions, can
+// call it
+#[uniffi::export]
+fn panic_uncaught(message: String) -> Result<(), PanicError> {
+    panic!("{message}");
+}
+
+uniffi::setup_scaffolding!();
@@ -0,0 +1,37 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi.fixture.panic_observer.*;
+
+class PanicRecorder : UniFfiPanicObserver {
+    val panics = mutableListOf<String>()
+
+    override fun onPanic(message: String, file: String, line: Int) {
+        assert(file.endsWith("lib.rs") && line > 0)
+        panics.add(message)
+    }
+}
+
+val panicRecorder = PanicRecorder()
+uniffiSetPanicObserver(panicRecorder)
+try {
+    panicCaught("observed caught")
+    throw RuntimeException("panicCaught should have thrown")
+} catch (e: PanicException.Panicked) {
+    assert(e.reason == "observed caught")
+}
+try {
+    panicUncaught("observed uncaught")
+    throw RuntimeException("panicUncaught should have thrown")
+} catch (e: InternalException) {
+}
+
+// Panics aren't reported once the observer is removed
+uniffiSetPanicObserver(null)
+try {
+    panicUncaught("not observed")
+    throw RuntimeException("panicUncaught should have thrown")
+} catch (e: InternalException) {
+}
+assert(panicRecorder.panics == listOf("observed caught", "observed uncaught"))
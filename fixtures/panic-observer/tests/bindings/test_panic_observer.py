@@ -0,0 +1,35 @@
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+from uniffi_panic_observer import *
+
+observed_panics = []
+
+def record_panic(message, file, line):
+    assert file.endswith("lib.rs") and line > 0
+    observed_panics.append(message)
+
+uniffi_set_panic_observer(record_panic)
+try:
+    panic_caught("observed caught")
+except PanicError.Panicked as e:
+    assert e.reason == "observed caught"
+else:
+    raise Exception("panic_caught should have thrown")
+try:
+    panic_uncaught("observed uncaught")
+except InternalError:
+    pass
+else:
+    raise Exception("panic_uncaught should have thrown")
+
+# Panics aren't reported once the observer is removed
+uniffi_set_panic_observer(None)
+try:
+    panic_uncaught("not observed")
+except InternalError:
+    pass
+else:
+    raise Exception("panic_uncaught should have thrown")
+assert observed_panics == ["observed caught", "observed uncaught"]
@@ -0,0 +1,35 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi_panic_observer
+
+class PanicRecorder: UniFfiPanicObserver {
+    var panics: [String] = []
+
+    func onPanic(message: String, file: String, line: UInt32) {
+        assert(file.hasSuffix("lib.rs") && line > 0)
+        panics.append(message)
+    }
+}
+
+let panicRecorder = PanicRecorder()
+uniffiSetPanicObserver(panicRecorder)
+do {
+    try panicCaught(message: "observed caught")
+    fatalError("panicCaught should have thrown")
+} catch let PanicError.Panicked(reason) {
+    assert(reason == "observed caught")
+}
+do {
+    try panicUncaught(message: "observed uncaught")
+    fatalError("panicUncaught should have thrown")
+} catch is PanicError {
+    fatalError("panicUncaught should not convert the panic into a PanicError")
+} catch {
+}
+
+// Panics aren't reported once the observer is removed
+uniffiSetPanicObserver(nil)
+_ = try? panicUncaught(message: "not observed")
+assert(panicRecorder.panics == ["observed caught", "observed uncaught"])
@@ -0,0 +1,5 @@
+uniffi::build_foreign_language_testcases!(
+    "tests/bindings/test_panic_observer.kts",
+    "tests/bindings/test_panic_observer.py",
+    "tests/bindings/test_panic_observer.swift",
+);
@@ -2,7 +2,14 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use std::sync::Arc;
+use std::{
+    num::{NonZeroU32, NonZeroU64},
+    ops::{Range, RangeInclusive},
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    },
+};
 
 mod callback_interface;
 
@@ -57,6 +64,18 @@ impl Trait for TraitImpl {
     }
 }
 
+// A "find and maybe return a plugin" function, where `None` must lift to the foreign null value.
+#[uniffi::export]
+fn find_trait(name: String) -> Option<Arc<dyn Trait>> {
+    (name == "TraitImpl").then(|| Arc::new(TraitImpl {}) as Arc<dyn Trait>)
+}
+
+// A factory that can fail, which must generate a throwing function returning the trait object.
+#[uniffi::export]
+fn make_trait(name: String) -> Result<Arc<dyn Trait>, BasicError> {
+    find_trait(name).ok_or(BasicError::InvalidInput)
+}
+
 #[derive(uniffi::Object)]
 pub struct Object;
 
@@ -81,12 +100,160 @@ impl Object {
         inc.unwrap_or_else(|| Arc::new(TraitImpl {}))
     }
 
+    fn try_get_trait(&self, name: String) -> Result<Arc<dyn Trait>, BasicError> {
+        make_trait(name)
+    }
+
     fn take_error(&self, e: BasicError) -> u32 {
         assert!(matches!(e, BasicError::InvalidInput));
         42
     }
 }
 
+// An object with meaningful `Drop` behaviour: closing the foreign object releases the last
+// reference, which must run `drop()` straight away.
+static RESOURCES_CLOSED: AtomicU32 = AtomicU32::new(0);
+
+#[derive(uniffi::Object)]
+pub struct Resource;
+
+#[uniffi::export]
+impl Resource {
+    #[uniffi::constructor]
+    fn new() -> Arc<Self> {
+        Arc::new(Self)
+    }
+}
+
+impl Drop for Resource {
+    fn drop(&mut self) {
+        RESOURCES_CLOSED.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[uniffi::export]
+fn resources_closed() -> u32 {
+    RESOURCES_CLOSED.load(Ordering::SeqCst)
+}
+
+// Methods and constructors can return `Self` by value, which is wrapped in an `Arc` for them.
+#[derive(uniffi::Object)]
+#[uniffi(type_name)]
+pub struct Counter {
+    value: u32,
+}
+
+#[uniffi::export]
+impl Counter {
+    #[uniffi::constructor]
+    fn new() -> Self {
+        Self { value: 0 }
+    }
+
+    fn increment(&self, by: u32) -> Self {
+        Self {
+            value: self.value + by,
+        }
+    }
+
+    fn checked_decrement(&self, by: u32) -> Result<Self, BasicError> {
+        match self.value.checked_sub(by) {
+            Some(value) => Ok(Self { value }),
+            None => Err(BasicError::InvalidInput),
+        }
+    }
+
+    fn value(&self) -> u32 {
+        self.value
+    }
+}
+
+// Accessors returning references into the object are exported by cloning the borrowed value.
+#[derive(uniffi::Object)]
+pub struct Labelled {
+    label: String,
+    values: Vec<u32>,
+}
+
+#[uniffi::export]
+impl Labelled {
+    #[uniffi::constructor]
+    fn new(label: String, values: Vec<u32>) -> Arc<Self> {
+        Arc::new(Self { label, values })
+    }
+
+    fn label(&self) -> &str {
+        &self.label
+    }
+
+    fn values(&self) -> &[u32] {
+        &self.values
+    }
+
+    // The last argument of these is variadic in the bindings
+    #[uniffi::constructor]
+    #[uniffi::variadic]
+    fn of(label: String, values: Vec<u32>) -> Arc<Self> {
+        Arc::new(Self { label, values })
+    }
+
+    #[uniffi::variadic]
+    fn contains_all(&self, values: &[u32]) -> bool {
+        values.iter().all(|v| self.values.contains(v))
+    }
+
+    fn same_label(&self, other: &Labelled) -> bool {
+        self.label == other.label
+    }
+}
+
+#[uniffi::export]
+fn labelled_count(labelled: &Labelled) -> u32 {
+    labelled.values.len() as u32
+}
+
+// The default methods of a trait are exported for objects that implement it, unless they're
+// overridden in the impl.
+#[uniffi::default_methods]
+pub trait Describe {
+    fn name(&self) -> String;
+
+    fn describe(&self) -> String {
+        format!("This is {}", self.name())
+    }
+
+    fn shout(&self, suffix: String) -> String {
+        format!("{}{suffix}", self.name().to_uppercase())
+    }
+}
+
+#[uniffi::export(default_methods)]
+impl Describe for Labelled {
+    fn name(&self) -> String {
+        self.label.clone()
+    }
+
+    fn shout(&self, suffix: String) -> String {
+        format!("{}{suffix}!", self.label.to_uppercase())
+    }
+}
+
+// Impls of traits without `#[uniffi::default_methods]` only export the methods in the block
+pub trait Measure {
+    fn size(&self) -> u32;
+
+    fn is_empty(&self) -> bool {
+        self.size() == 0
+    }
+}
+
+#[uniffi::export]
+impl Measure for Labelled {
+    fn size(&self) -> u32 {
+        self.values.len() as u32
+    }
+}
+
 #[uniffi::export]
 fn make_one(inner: i32) -> One {
     One { inner }
@@ -139,6 +306,111 @@ fn enum_identity(value: MaybeBool) -> MaybeBool {
     value
 }
 
+#[uniffi::export]
+fn enum_is_certain(value: &MaybeBool) -> bool {
+    !matches!(value, MaybeBool::Uncertain)
+}
+
+#[uniffi::export]
+fn join_words(words: &[String], separator: &str) -> String {
+    words.join(separator)
+}
+
+#[uniffi::export(variadic)]
+fn sum_values(values: &[u32]) -> u32 {
+    values.iter().sum()
+}
+
+// Slices of the arguments are copied into new strings when they're returned
+#[uniffi::export]
+fn first_word(text: &str) -> &str {
+    text.split_whitespace().next().unwrap_or_default()
+}
+
+#[uniffi::export]
+fn find_word<'a>(text: &'a str, prefix: &str) -> Option<&'a str> {
+    text.split_whitespace().find(|w| w.starts_with(prefix))
+}
+
+#[uniffi::export]
+fn nth_word(text: &str, n: u32) -> Result<&str, BasicError> {
+    text.split_whitespace()
+        .nth(n as usize)
+        .ok_or(BasicError::InvalidInput)
+}
+
+// `'static` strings, like compile-time constants, are copied the same way
+#[uniffi::export]
+fn fixture_name() -> &'static str {
+    "proc-macro"
+}
+
+#[uniffi::export]
+fn fixture_description(verbose: bool) -> Option<&'static str> {
+    verbose.then_some("A fixture for the proc-macro frontend")
+}
+
+#[uniffi::export]
+fn shout(text: impl Into<String>) -> String {
+    text.into().to_uppercase()
+}
+
+#[uniffi::export]
+fn xor_key(key: [u8; 4], mask: u8) -> [u8; 4] {
+    key.map(|b| b ^ mask)
+}
+
+#[uniffi::export(aliases("countWords", "word_count"))]
+fn count_words(text: String) -> u32 {
+    text.split_whitespace().count() as u32
+}
+
+#[derive(uniffi::Flags, Clone, Copy, Debug, PartialEq, Eq)]
+#[uniffi(flags(READ, WRITE, EXECUTE))]
+pub struct Permissions(u8);
+
+impl Permissions {
+    pub const READ: Self = Self(1 << 0);
+    pub const WRITE: Self = Self(1 << 1);
+    pub const EXECUTE: Self = Self(1 << 2);
+}
+
+#[uniffi::export]
+fn add_write_permission(value: Permissions) -> Permissions {
+    Permissions(value.0 | Permissions::WRITE.0)
+}
+
+#[uniffi::export]
+fn double_non_zero(value: NonZeroU32) -> NonZeroU64 {
+    NonZeroU64::from(value).saturating_mul(NonZeroU64::new(2).unwrap())
+}
+
+#[uniffi::export]
+fn shift_range(range: Range<i32>, offset: i32) -> Range<i32> {
+    (range.start + offset)..(range.end + offset)
+}
+
+#[uniffi::export]
+fn range_len(range: RangeInclusive<u64>) -> u64 {
+    range.end().saturating_sub(*range.start()).saturating_add(1)
+}
+
+#[uniffi::export]
+fn byte_range_identity(range: RangeInclusive<u8>) -> RangeInclusive<u8> {
+    range
+}
+
+// These return inverted ranges, and an empty `RangeInclusive`, for non-empty ones
+#[uniffi::export]
+fn reverse_range(range: Range<i32>) -> Range<i32> {
+    range.end..range.start
+}
+
+#[uniffi::export]
+fn reverse_byte_range(range: RangeInclusive<u8>) -> RangeInclusive<u8> {
+    *range.end()..=*range.start()
+}
+
 #[derive(uniffi::Error, Debug, PartialEq, Eq)]
 #[uniffi(handle_unknown_callback_error)]
 pub enum BasicError {
@@ -158,6 +430,94 @@ fn always_fails() -> Result<(), BasicError> {
     Err(BasicError::OsError)
 }
 
+#[uniffi::export(returns(
+    /// The quotient and remainder of a division.
+    DivisionResult {
+        quotient,
+        remainder,
+    }
+))]
+fn divide(dividend: u32, divisor: u32) -> Result<(u32, u32), BasicError> {
+    if divisor == 0 {
+        return Err(BasicError::InvalidInput);
+    }
+    Ok((dividend / divisor, dividend % divisor))
+}
+
+#[uniffi::export(returns(MinMax { min, max }))]
+fn min_max(values: Vec<i32>) -> (i32, i32) {
+    let min = values.iter().copied().min().unwrap_or_default();
+    let max = values.iter().copied().max().unwrap_or_default();
+    (min, max)
+}
+
+// Passed by value as a C struct.  The `tag` field is there to check that the padding before `y`
+// matches on both sides.
+#[derive(uniffi::Record, Clone, Copy)]
+#[uniffi(pod)]
+#[repr(C)]
+pub struct Vector {
+    x: f64,
+    tag: u8,
+    y: f64,
+}
+
+#[uniffi::export]
+fn scale_vector(v: Vector, factor: f64) -> Vector {
+    Vector {
+        x: v.x * factor,
+        y: v.y * factor,
+        ..v
+    }
+}
+
+// `Vec<Vector>` and `Option<Vector>` are still serialized into a `RustBuffer`
+#[uniffi::export]
+fn sum_vectors(vectors: Vec<Vector>) -> Option<Vector> {
+    vectors.into_iter().reduce(|a, b| Vector {
+        x: a.x + b.x,
+        tag: a.tag,
+        y: a.y + b.y,
+    })
+}
+
+// `cached_length` is derived from `text`, so the generated records leave it out of equality
+#[derive(uniffi::Record)]
+pub struct Document {
+    text: String,
+    #[uniffi(exclude_from_eq)]
+    cached_length: u32,
+}
+
+#[uniffi::export]
+fn make_document(text: String) -> Document {
+    Document {
+        cached_length: text.len() as u32,
+        text,
+    }
+}
+
+#[derive(uniffi::Error, Debug, PartialEq, Eq)]
+pub enum PanicError {
+    Panicked { reason: String },
+}
+
+impl From<uniffi::RustPanic> for PanicError {
+    fn from(e: uniffi::RustPanic) -> Self {
+        Self::Panicked { reason: e.message }
+    }
+}
+
+#[uniffi::export(catch_panics)]
+fn panic_caught(message: String) -> Result<(), PanicError> {
+    panic!("{message}");
+}
+
+#[uniffi::export]
+fn panic_uncaught(message: String) -> Result<(), PanicError> {
+    panic!("{message}");
+}
+
 #[derive(Debug, thiserror::Error, uniffi::Error)]
 #[uniffi(flat_error)]
 #[non_exhaustive]
@@ -184,4 +544,18 @@ impl Object {
     }
 }
 
+// `Box<dyn Error>` errors are passed to the foreign code as a `DynError` with their message
+#[uniffi::export]
+fn parse_port(value: String) -> Result<u16, Box<dyn std::error::Error + Send + Sync>> {
+    Ok(value.parse()?)
+}
+
+#[uniffi::export]
+fn check_port(port: u16) -> Result<(), Box<dyn std::error::Error>> {
+    if port == 0 {
+        return Err("port 0 is reserved".into());
+    }
+    Ok(())
+}
+
 uniffi::include_scaffolding!("proc-macro");
@@ -14,7 +14,112 @@ var obj = Object()
 obj = Object.namedCtor(1u)
 assert(obj.isHeavy() == MaybeBool.UNCERTAIN)
 
+assert(findTrait("TraitImpl")!!.name() == "TraitImpl")
+assert(findTrait("missing") == null)
+assert(makeTrait("TraitImpl").name() == "TraitImpl")
+assert(obj.tryGetTrait("TraitImpl").name() == "TraitImpl")
+try {
+    makeTrait("missing")
+    throw RuntimeException("makeTrait should have thrown")
+} catch (e: BasicException.InvalidInput) {
+}
+try {
+    obj.tryGetTrait("missing")
+    throw RuntimeException("tryGetTrait should have thrown")
+} catch (e: BasicException.InvalidInput) {
+}
+
+val resource = Resource()
+assert(resourcesClosed() == 0u)
+resource.close()
+assert(resourcesClosed() == 1u)
+// Closing twice is harmless and doesn't drop anything else.
+resource.close()
+assert(resourcesClosed() == 1u)
+Resource().use { assert(resourcesClosed() == 1u) }
+assert(resourcesClosed() == 2u)
+
 assert(enumIdentity(MaybeBool.TRUE) == MaybeBool.TRUE)
+assert(enumIsCertain(MaybeBool.FALSE))
+assert(!enumIsCertain(MaybeBool.UNCERTAIN))
+assert(joinWords(listOf("a", "b"), "-") == "a-b")
+assert(sumValues(1u, 2u, 3u) == 6u)
+assert(sumValues() == 0u)
+assert(firstWord("hello slice world") == "hello")
+assert(fixtureName() == "proc-macro")
+assert(fixtureDescription(true) == "A fixture for the proc-macro frontend")
+assert(fixtureDescription(false) == null)
+assert(findWord("hello slice world", "sl") == "slice")
+assert(findWord("hello slice world", "x") == null)
+assert(nthWord("hello slice world", 2u) == "world")
+try {
+    nthWord("hello", 1u)
+    throw RuntimeException("Should have thrown an InvalidInput exception!")
+} catch (e: BasicException.InvalidInput) {
+}
+assert(shout("hello") == "HELLO")
+assert(xorKey(byteArrayOf(1, 2, 3, 4), 0xffu).contentEquals(byteArrayOf(-2, -3, -4, -5)))
+try {
+    xorKey(byteArrayOf(1, 2, 3), 0xffu)
+    throw RuntimeException("xorKey should reject keys of the wrong length")
+} catch (e: IllegalArgumentException) {
+}
+assert(countWords("one two three") == 3u)
+assert(word_count("one two three") == 3u)
+
+val readWrite = addWritePermission(Permissions.READ)
+assert(readWrite == (Permissions.READ or Permissions.WRITE))
+assert(Permissions.WRITE in readWrite)
+assert(Permissions.EXECUTE !in readWrite)
+
+assert(doubleNonZero(21u) == 42uL)
+
+val shifted = shiftRange(1..<4, 10)
+assert(shifted.start == 11 && shifted.endExclusive == 14)
+// Empty ranges keep their bounds
+val empty = shiftRange(5..<5, 1)
+assert(empty.start == 6 && empty.endExclusive == 6 && empty.isEmpty())
+assert(rangeLen(0uL..9uL) == 10uL)
+val byteRange = byteRangeIdentity(object : ClosedRange<UByte> {
+    override val start = 2.toUByte()
+    override val endInclusive = 7.toUByte()
+})
+assert(byteRange.start == 2.toUByte() && byteRange.endInclusive == 7.toUByte())
+// Inverted ranges keep their bounds too
+val reversed = reverseRange(1..<4)
+assert(reversed.start == 4 && reversed.endExclusive == 1 && reversed.isEmpty())
+val shiftedReversed = shiftRange(reversed, 1)
+assert(shiftedReversed.start == 5 && shiftedReversed.endExclusive == 2)
+val reversedBytes = reverseByteRange(byteRange)
+assert(reversedBytes.start == 7.toUByte() && reversedBytes.endInclusive == 2.toUByte() && reversedBytes.isEmpty())
+val roundTripped = byteRangeIdentity(reversedBytes)
+assert(roundTripped.start == 7.toUByte() && roundTripped.endInclusive == 2.toUByte())
+
+val counter = Counter().increment(2u).increment(3u)
+assert(counter.value() == 5u)
+assert(counter.uniffiTypeName() == "uniffi_proc_macro::Counter")
+assert(counter.checkedDecrement(1u).increment(1u).value() == 5u)
+try {
+    counter.checkedDecrement(6u)
+    throw RuntimeException("checkedDecrement should have thrown")
+} catch (e: BasicException.InvalidInput) {
+}
+// `checkedDecrement` is generated as an extension function, see `uniffi.toml`
+assert(CounterInterface::class.java.methods.none { it.name == "checkedDecrement" })
+
+val labelled = Labelled("label", listOf(1u, 2u, 3u))
+assert(labelled.label() == "label")
+assert(labelled.values() == listOf(1u, 2u, 3u))
+assert(labelled.name() == "label")
+assert(labelled.describe() == "This is label")
+assert(labelled.shout("?") == "LABEL?!")
+assert(labelled.size() == 3u)
+assert(labelled.containsAll(1u, 3u))
+assert(!labelled.containsAll(1u, 4u))
+assert(Labelled.of("variadic", 4u, 5u).values() == listOf(4u, 5u))
+assert(labelled.sameLabel(Labelled("label", listOf())))
+assert(!labelled.sameLabel(Labelled("other", listOf())))
+assert(labelledCount(labelled) == 3u)
 
 // just make sure this works / doesn't crash
 val three = Three(obj)
@@ -27,6 +132,35 @@ try {
 } catch (e: BasicException) {
 }
 
+assert(divide(17u, 5u) == DivisionResult(quotient = 3u, remainder = 2u))
+try {
+    divide(1u, 0u)
+    throw RuntimeException("divide should have thrown")
+} catch (e: BasicException.InvalidInput) {
+}
+assert(minMax(listOf(3, -1, 7)) == MinMax(min = -1, max = 7))
+
+assert(scaleVector(Vector(x = 1.5, tag = 7u, y = -2.0), 2.0) == Vector(x = 3.0, tag = 7u, y = -4.0))
+assert(sumVectors(listOf(Vector(1.0, 1u, 2.0), Vector(3.0, 2u, 4.0))) == Vector(4.0, 1u, 6.0))
+assert(sumVectors(listOf()) == null)
+
+assert(makeDocument("abc") == Document(text = "abc", cachedLength = 0u))
+assert(makeDocument("abc").hashCode() == Document(text = "abc", cachedLength = 0u).hashCode())
+assert(makeDocument("abc") != Document(text = "xyz", cachedLength = 3u))
+
+try {
+    panicCaught("caught")
+    throw RuntimeException("panicCaught should have thrown")
+} catch (e: PanicException.Panicked) {
+    assert(e.reason == "caught")
+}
+
+try {
+    panicUncaught("uncaught")
+    throw RuntimeException("panicUncaught should have thrown")
+} catch (e: InternalException) {
+}
+
 obj.doStuff(5u)
 
 try {
@@ -35,6 +169,21 @@ try {
 } catch (e: FlatException) {
 }
 
+assert(parsePort("8080") == 8080.toUShort())
+try {
+    parsePort("http")
+    throw RuntimeException("parsePort should have thrown")
+} catch (e: DynException.Other) {
+    assert(e.message == "invalid digit found in string")
+}
+checkPort(443u)
+try {
+    checkPort(0u)
+    throw RuntimeException("checkPort should have thrown")
+} catch (e: DynException) {
+    assert(e.message == "port 0 is reserved")
+}
+
 
 class KtTestCallbackInterface : TestCallbackInterface {
     override fun doNothing() { }
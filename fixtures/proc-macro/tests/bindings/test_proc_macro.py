@@ -18,7 +18,104 @@ trait_impl = obj.get_trait(None)
 assert trait_impl.name() == "TraitImpl"
 assert obj.get_trait(trait_impl).name() == "TraitImpl"
 
+assert find_trait("TraitImpl").name() == "TraitImpl"
+assert find_trait("missing") is None
+assert make_trait("TraitImpl").name() == "TraitImpl"
+assert obj.try_get_trait("TraitImpl").name() == "TraitImpl"
+try:
+    make_trait("missing")
+    raise Exception("make_trait should have thrown")
+except BasicError.InvalidInput:
+    pass
+try:
+    obj.try_get_trait("missing")
+    raise Exception("try_get_trait should have thrown")
+except BasicError.InvalidInput:
+    pass
+
+resource = Resource()
+assert resources_closed() == 0
+del resource
+assert resources_closed() == 1
+
 assert enum_identity(MaybeBool.TRUE) == MaybeBool.TRUE
+assert enum_is_certain(MaybeBool.FALSE)
+assert not enum_is_certain(MaybeBool.UNCERTAIN)
+assert join_words(["a", "b"], "-") == "a-b"
+assert sum_values(1, 2, 3) == 6
+assert sum_values() == 0
+assert first_word("hello slice world") == "hello"
+assert find_word("hello slice world", "sl") == "slice"
+assert find_word("hello slice world", "x") is None
+assert nth_word("hello slice world", 2) == "world"
+try:
+    nth_word("hello", 1)
+except BasicError.InvalidInput:
+    pass
+else:
+    raise Exception("nth_word should have thrown")
+assert fixture_name() == "proc-macro"
+assert fixture_description(True) == "A fixture for the proc-macro frontend"
+assert fixture_description(False) is None
+assert shout("hello") == "HELLO"
+assert xor_key(b"\x01\x02\x03\x04", 0xff) == b"\xfe\xfd\xfc\xfb"
+try:
+    xor_key(b"\x01\x02\x03", 0xff)
+except ValueError:
+    pass
+else:
+    raise Exception("xor_key should reject keys of the wrong length")
+assert count_words("one two three") == 3
+assert countWords("one two three") == 3
+assert word_count("one two three") == 3
+
+read_write = add_write_permission(Permissions.READ)
+assert read_write == Permissions.READ | Permissions.WRITE
+assert Permissions.WRITE in read_write
+assert Permissions.EXECUTE not in read_write
+
+assert double_non_zero(21) == 42
+
+assert shift_range(range(1, 4), 10) == range(11, 14)
+# Empty and inverted ranges keep their bounds
+assert shift_range(range(5, 5), 1) == range(6, 6)
+assert shift_range(range(5, 3), 1) == range(6, 4)
+assert range_len(range(0, 10)) == 10
+assert byte_range_identity(range(2, 8)) == range(2, 8)
+assert reverse_range(range(1, 4)) == range(4, 1)
+assert reverse_byte_range(range(2, 8)) == range(7, 3)
+try:
+    shift_range(range(0, 10, 2), 1)
+except ValueError:
+    pass
+else:
+    raise Exception("shift_range should reject ranges with a step")
+
+counter = Counter().increment(2).increment(3)
+assert counter.value() == 5
+assert counter.uniffi_type_name() == "uniffi_proc_macro::Counter"
+assert counter.checked_decrement(1).increment(1).value() == 5
+try:
+    counter.checked_decrement(6)
+except BasicError.InvalidInput:
+    pass
+else:
+    raise Exception("checked_decrement should have thrown")
+
+labelled = Labelled("label", [1, 2, 3])
+assert labelled.label() == "label"
+assert labelled.values() == [1, 2, 3]
+assert labelled.name() == "label"
+assert labelled.describe() == "This is label"
+assert labelled.shout("?") == "LABEL?!"
+assert labelled.size() == 3
+assert not hasattr(labelled, "is_empty")
+assert labelled.contains_all(1, 3)
+assert not labelled.contains_all(1, 4)
+assert Labelled.of("variadic", 4, 5).values() == [4, 5]
+assert labelled.same_label(Labelled("label", []))
+assert not labelled.same_label(Labelled("other", []))
+assert labelled_count(labelled) == 3
 
 # just make sure this works / doesn't crash
 three = Three(obj)
@@ -32,6 +129,36 @@ except BasicError.OsError:
 else:
     raise Exception("always_fails should have thrown")
 
+assert divide(17, 5) == DivisionResult(quotient=3, remainder=2)
+try:
+    divide(1, 0)
+except BasicError.InvalidInput:
+    pass
+else:
+    raise Exception("divide should have thrown")
+assert min_max([3, -1, 7]) == MinMax(min=-1, max=7)
+
+assert scale_vector(Vector(x=1.5, tag=7, y=-2.0), 2.0) == Vector(x=3.0, tag=7, y=-4.0)
+assert sum_vectors([Vector(x=1.0, tag=1, y=2.0), Vector(x=3.0, tag=2, y=4.0)]) == Vector(x=4.0, tag=1, y=6.0)
+assert sum_vectors([]) is None
+
+assert make_document("abc") == Document(text="abc", cached_length=0)
+assert make_document("abc") != Document(text="xyz", cached_length=3)
+
+try:
+    panic_caught("caught")
+except PanicError.Panicked as e:
+    assert e.reason == "caught"
+else:
+    raise Exception("panic_caught should have thrown")
+
+try:
+    panic_uncaught("uncaught")
+except InternalError:
+    pass
+else:
+    raise Exception("panic_uncaught should have thrown")
+
 obj.do_stuff(5)
 
 try:
@@ -41,6 +168,21 @@ except FlatError.InvalidInput:
 else:
     raise Exception("do_stuff should throw if its argument is 0")
 
+assert parse_port("8080") == 8080
+try:
+    parse_port("http")
+except DynError.Other as e:
+    assert str(e) == "invalid digit found in string"
+else:
+    raise Exception("parse_port should have thrown")
+check_port(443)
+try:
+    check_port(0)
+except DynError as e:
+    assert str(e) == "port 0 is reserved"
+else:
+    raise Exception("check_port should have thrown")
+
 class PyTestCallbackInterface(TestCallbackInterface):
     def do_nothing(self):
         pass
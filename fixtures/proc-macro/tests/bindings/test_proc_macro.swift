@@ -2,6 +2,7 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+import Foundation
 import uniffi_proc_macro
 
 let one = makeOne(inner: 123)
@@ -14,7 +15,95 @@ var obj = Object()
 obj = Object.namedCtor(arg: 1)
 assert(obj.isHeavy() == .uncertain)
 
+assert(findTrait(name: "TraitImpl")!.name() == "TraitImpl")
+assert(findTrait(name: "missing") == nil)
+assert(try! makeTrait(name: "TraitImpl").name() == "TraitImpl")
+assert(try! obj.tryGetTrait(name: "TraitImpl").name() == "TraitImpl")
+do {
+    _ = try makeTrait(name: "missing")
+    fatalError("makeTrait should have thrown")
+} catch BasicError.InvalidInput {
+}
+do {
+    _ = try obj.tryGetTrait(name: "missing")
+    fatalError("tryGetTrait should have thrown")
+} catch BasicError.InvalidInput {
+}
+
+do {
+    let resource = Resource()
+    assert(resourcesClosed() == 0)
+    _ = resource
+}
+assert(resourcesClosed() == 1)
+
 assert(enumIdentity(value: .true) == .true)
+assert(enumIsCertain(value: .false))
+assert(!enumIsCertain(value: .uncertain))
+assert(joinWords(words: ["a", "b"], separator: "-") == "a-b")
+assert(sumValues(values: 1, 2, 3) == 6)
+assert(sumValues() == 0)
+assert(firstWord(text: "hello slice world") == "hello")
+assert(fixtureName() == "proc-macro")
+assert(fixtureDescription(verbose: true) == "A fixture for the proc-macro frontend")
+assert(fixtureDescription(verbose: false) == nil)
+assert(findWord(text: "hello slice world", prefix: "sl") == "slice")
+assert(findWord(text: "hello slice world", prefix: "x") == nil)
+assert(try! nthWord(text: "hello slice world", n: 2) == "world")
+do {
+    _ = try nthWord(text: "hello", n: 1)
+    fatalError("Should have thrown")
+} catch BasicError.InvalidInput {
+}
+assert(shout(text: "hello") == "HELLO")
+assert(xorKey(key: Data([1, 2, 3, 4]), mask: 0xff) == Data([0xfe, 0xfd, 0xfc, 0xfb]))
+assert(countWords(text: "one two three") == 3)
+assert(word_count(text: "one two three") == 3)
+
+let readWrite = addWritePermission(value: .read)
+assert(readWrite == [.read, .write])
+assert(readWrite.contains(.write))
+assert(!readWrite.contains(.execute))
+
+assert(doubleNonZero(value: 21) == 42)
+
+assert(shiftRange(range: UniffiRange(1..<4), offset: 10).range == 11..<14)
+assert(shiftRange(range: UniffiRange(5..<5), offset: 1) == UniffiRange(6..<6))
+assert(rangeLen(range: UniffiClosedRange(0...9)) == 10)
+assert(byteRangeIdentity(range: UniffiClosedRange(2...7)).range == 2...7)
+// Inverted and empty ranges keep their bounds, but have no Swift range
+let reversed = reverseRange(range: UniffiRange(1..<4))
+assert(reversed == UniffiRange(lowerBound: 4, upperBound: 1))
+assert(reversed.isEmpty && reversed.range == nil && !reversed.contains(2))
+assert(shiftRange(range: reversed, offset: 1) == UniffiRange(lowerBound: 5, upperBound: 2))
+let reversedBytes = reverseByteRange(range: UniffiClosedRange(2...7))
+assert(reversedBytes == UniffiClosedRange(lowerBound: 7, upperBound: 2))
+assert(reversedBytes.isEmpty && reversedBytes.range == nil)
+assert(byteRangeIdentity(range: reversedBytes) == reversedBytes)
+
+let counter = Counter().increment(by: 2).increment(by: 3)
+assert(counter.value() == 5)
+assert(counter.uniffiTypeName() == "uniffi_proc_macro::Counter")
+assert(try! counter.checkedDecrement(by: 1).increment(by: 1).value() == 5)
+do {
+    _ = try counter.checkedDecrement(by: 6)
+    fatalError("checkedDecrement should have thrown")
+} catch BasicError.InvalidInput {
+}
+
+let labelled = Labelled(label: "label", values: [1, 2, 3])
+assert(labelled.label() == "label")
+assert(labelled.values() == [1, 2, 3])
+assert(labelled.name() == "label")
+assert(labelled.describe() == "This is label")
+assert(labelled.shout(suffix: "?") == "LABEL?!")
+assert(labelled.size() == 3)
+assert(labelled.containsAll(values: 1, 3))
+assert(!labelled.containsAll(values: 1, 4))
+assert(Labelled.of(label: "variadic", values: 4, 5).values() == [4, 5])
+assert(labelled.sameLabel(other: Labelled(label: "label", values: [])))
+assert(!labelled.sameLabel(other: Labelled(label: "other", values: [])))
+assert(labelledCount(labelled: labelled) == 3)
 
 // just make sure this works / doesn't crash
 let three = Three(obj: obj)
@@ -27,6 +116,37 @@ do {
 } catch BasicError.OsError {
 }
 
+assert(try! divide(dividend: 17, divisor: 5) == DivisionResult(quotient: 3, remainder: 2))
+do {
+    _ = try divide(dividend: 1, divisor: 0)
+    fatalError("divide should have thrown")
+} catch BasicError.InvalidInput {
+}
+assert(minMax(values: [3, -1, 7]) == MinMax(min: -1, max: 7))
+
+assert(scaleVector(v: Vector(x: 1.5, tag: 7, y: -2.0), factor: 2.0) == Vector(x: 3.0, tag: 7, y: -4.0))
+assert(sumVectors(vectors: [Vector(x: 1.0, tag: 1, y: 2.0), Vector(x: 3.0, tag: 2, y: 4.0)]) == Vector(x: 4.0, tag: 1, y: 6.0))
+assert(sumVectors(vectors: []) == nil)
+
+assert(makeDocument(text: "abc") == Document(text: "abc", cachedLength: 0))
+assert(makeDocument(text: "abc").hashValue == Document(text: "abc", cachedLength: 0).hashValue)
+assert(makeDocument(text: "abc") != Document(text: "xyz", cachedLength: 3))
+
+do {
+    try panicCaught(message: "caught")
+    fatalError("panicCaught should have thrown")
+} catch let PanicError.Panicked(reason) {
+    assert(reason == "caught")
+}
+
+do {
+    try panicUncaught(message: "uncaught")
+    fatalError("panicUncaught should have thrown")
+} catch is PanicError {
+    fatalError("panicUncaught should not convert the panic into a PanicError")
+} catch {
+}
+
 try! obj.doStuff(times: 5)
 
 do {
@@ -35,6 +155,21 @@ do {
 } catch FlatError.InvalidInput {
 }
 
+assert(try! parsePort(value: "8080") == 8080)
+do {
+    _ = try parsePort(value: "http")
+    fatalError("parsePort should have thrown")
+} catch let DynError.Other(message) {
+    assert(message == "invalid digit found in string")
+}
+try! checkPort(port: 443)
+do {
+    try checkPort(port: 0)
+    fatalError("checkPort should have thrown")
+} catch let DynError.Other(message) {
+    assert(message == "port 0 is reserved")
+}
+
 struct SomeOtherError: Error { }
 
 class SwiftTestCallbackInterface : TestCallbackInterface {
@@ -0,0 +1,57 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+#[derive(uniffi::Record)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(uniffi::Record)]
+pub struct Line {
+    start: Point,
+    end: Point,
+    #[uniffi(default = None)]
+    label: Option<String>,
+}
+
+// `cached_length` is derived from `text`, so the generated records leave it out of equality
+#[derive(uniffi::Record)]
+pub struct Document {
+    text: String,
+    #[uniffi(exclude_from_eq)]
+    cached_length: u32,
+}
+
+#[uniffi::export]
+fn translate(point: Point, dx: i32, dy: i32) -> Point {
+    Point {
+        x: point.x + dx,
+        y: point.y + dy,
+    }
+}
+
+#[uniffi::export]
+fn make_line(start: Point, end: Point) -> Line {
+    Line {
+        start,
+        end,
+        label: None,
+    }
+}
+
+#[uniffi::export]
+fn line_label(line: Line) -> String {
+    line.label.unwrap_or_default()
+}
+
+#[uniffi::export]
+fn make_document(text: String) -> Document {
+    Document {
+        cached_length: text.len() as u32,
+        text,
+    }
+}
+
+uniffi::setup_scaffolding!();
@@ -0,0 +1,35 @@
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+from uniffi_python_named_tuple_records import *
+
+# Records are named tuples, which compare like plain tuples
+point = translate(Point(1, 2), 10, 20)
+assert isinstance(point, tuple)
+assert point == Point(x=11, y=22) == (11, 22)
+x, y = point
+assert (x, y) == (11, 22)
+assert str(point) == "Point(x=11, y=22)"
+assert point._replace(y=0) == Point(11, 0)
+
+# They can't be modified after construction
+try:
+    point.x = 0
+except AttributeError:
+    pass
+else:
+    raise Exception("named tuple records should be immutable")
+
+# Default values are filled in by the constructor
+line = make_line(Point(0, 0), point)
+assert line == Line(Point(0, 0), Point(11, 22))
+assert line.label is None
+assert line_label(line) == ""
+assert line_label(Line(Point(0, 0), point, label="diagonal")) == "diagonal"
+
+# Fields left out of equality are also left out of hashing
+assert make_document("abc") == Document(text="abc", cached_length=0)
+assert make_document("abc") != Document(text="xyz", cached_length=3)
+assert hash(make_document("abc")) == hash(Document(text="abc", cached_length=0))
+assert len({make_document("abc"), Document(text="abc", cached_length=99)}) == 1
@@ -0,0 +1,71 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::HashMap;
+
+pub struct Checksum(pub String);
+
+uniffi::custom_newtype!(Checksum, String);
+
+#[derive(uniffi::Record)]
+pub struct Attachment {
+    name: String,
+    checksum: Checksum,
+    data: Vec<u8>,
+}
+
+#[derive(uniffi::Enum)]
+pub enum Status {
+    Draft,
+    Sent { at_ms: u64 },
+}
+
+#[derive(uniffi::Record)]
+pub struct Message {
+    subject: String,
+    status: Status,
+    attachments: Vec<Attachment>,
+    cover: Option<Attachment>,
+    attachments_by_name: HashMap<String, Attachment>,
+}
+
+fn make_attachment(name: &str, data: Vec<u8>) -> Attachment {
+    Attachment {
+        name: name.to_string(),
+        checksum: Checksum(format!("{:x}", data.iter().map(|b| *b as u32).sum::<u32>())),
+        data,
+    }
+}
+
+#[uniffi::export]
+fn make_message() -> Message {
+    Message {
+        subject: "Holiday pictures".to_string(),
+        status: Status::Sent {
+            at_ms: 1_700_000_000_000,
+        },
+        attachments: vec![
+            make_attachment("beach.jpg", vec![0, 1, 2, 255]),
+            make_attachment("empty.txt", vec![]),
+        ],
+        cover: Some(make_attachment("cover.png", vec![137, 80, 78, 71])),
+        attachments_by_name: HashMap::from([(
+            "notes.txt".to_string(),
+            make_attachment("notes.txt", b"hello".to_vec()),
+        )]),
+    }
+}
+
+#[uniffi::export]
+fn total_attachment_size(message: Message) -> u64 {
+    message
+        .attachments
+        .iter()
+        .chain(message.cover.iter())
+        .chain(message.attachments_by_name.values())
+        .map(|a| a.data.len() as u64)
+        .sum()
+}
+
+uniffi::setup_scaffolding!();
@@ -0,0 +1,36 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import java.io.ByteArrayInputStream
+import java.io.ByteArrayOutputStream
+import java.io.ObjectInputStream
+import java.io.ObjectOutputStream
+import uniffi.fixture.serializable_records.*
+
+fun <T : java.io.Serializable> roundTrip(value: T): T {
+    val bytes = ByteArrayOutputStream()
+    ObjectOutputStream(bytes).use { it.writeObject(value) }
+    @Suppress("UNCHECKED_CAST")
+    return ObjectInputStream(ByteArrayInputStream(bytes.toByteArray())).use { it.readObject() as T }
+}
+
+fun assertSameAttachment(a: Attachment?, b: Attachment?) {
+    assert(a!!.name == b!!.name)
+    assert(a.checksum == b.checksum)
+    // `ByteArray` fields compare by reference, so compare the contents
+    assert(a.data.contentEquals(b.data))
+}
+
+val message = makeMessage()
+val copy = roundTrip(message)
+assert(copy.subject == message.subject)
+assert(copy.status == message.status)
+assert(copy.attachments.size == 2)
+copy.attachments.zip(message.attachments).forEach { (a, b) -> assertSameAttachment(a, b) }
+assertSameAttachment(copy.cover, message.cover)
+assertSameAttachment(copy.attachmentsByName["notes.txt"], message.attachmentsByName["notes.txt"])
+assert(totalAttachmentSize(copy) == totalAttachmentSize(message))
+
+// Variants without fields stay singletons
+assert(roundTrip<Status>(Status.Draft) === Status.Draft)
@@ -0,0 +1,21 @@
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+import pickle
+from uniffi_serializable_records import *
+
+message = make_message()
+d = message.to_dict()
+# Nested records are converted to dicts, everything else is stored as-is
+assert d["subject"] == "Holiday pictures"
+assert d["attachments"][0] == {"name": "beach.jpg", "checksum": message.attachments[0].checksum, "data": b"\x00\x01\x02\xff"}
+assert d["cover"]["data"] == b"\x89PNG"
+assert d["attachments_by_name"]["notes.txt"]["data"] == b"hello"
+
+copy = Message.from_dict(d)
+assert copy == message
+assert total_attachment_size(copy) == total_attachment_size(message)
+
+# The dict form only contains plain values, so it can be serialized by the usual tools
+assert Message.from_dict(pickle.loads(pickle.dumps(d))) == message
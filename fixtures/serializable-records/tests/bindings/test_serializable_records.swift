@@ -0,0 +1,17 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import Foundation
+import uniffi_serializable_records
+
+let message = makeMessage()
+let json = try! JSONEncoder().encode(message)
+let copy = try! JSONDecoder().decode(Message.self, from: json)
+assert(copy == message)
+assert(copy.attachments[0].data == Data([0, 1, 2, 255]))
+assert(copy.attachments[0].checksum == message.attachments[0].checksum)
+assert(totalAttachmentSize(message: copy) == totalAttachmentSize(message: message))
+
+let status = try! JSONDecoder().decode(Status.self, from: try! JSONEncoder().encode(Status.draft))
+assert(status == Status.draft)
@@ -0,0 +1,5 @@
+uniffi::build_foreign_language_testcases!(
+    "tests/bindings/test_serializable_records.kts",
+    "tests/bindings/test_serializable_records.swift",
+    "tests/bindings/test_serializable_records.py",
+);
@@ -47,4 +47,10 @@ fn set_contains(set: Arc<MyHashSet>, value: String) -> bool {
 #[uniffi::export]
 fn dummy(_arg: Option<i8>) {}
 
+// Defined in the UDL file, to test the UDL syntax for variadic arguments.  The proc-macro fixture
+// tests `#[uniffi::export(variadic)]`.
+fn sum_all(values: &[u32]) -> u32 {
+    values.iter().sum()
+}
+
 uniffi::include_scaffolding!("simple-fns");
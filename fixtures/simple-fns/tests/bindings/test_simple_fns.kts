@@ -7,7 +7,10 @@ import uniffi.fixture.simple_fns.*;
 assert(getString() == "String created by Rust")
 assert(getInt() == 1289)
 assert(stringIdentity("String created by Kotlin") == "String created by Kotlin")
+assert(echoString("String created by Kotlin") == "String created by Kotlin")
 assert(byteToU32(255U) == 255U)
+assert(sumAll(1U, 2U, 3U) == 6U)
+assert(sumAll() == 0U)
 
 val aSet = newSet()
 addToSet(aSet, "foo")
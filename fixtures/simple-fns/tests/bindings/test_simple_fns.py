@@ -7,7 +7,10 @@ from uniffi_simple_fns import *
 assert get_string() == "String created by Rust"
 assert get_int() == 1289
 assert string_identity("String created by Python") == "String created by Python"
+assert echo_string("String created by Python") == "String created by Python"
 assert byte_to_u32(255) == 255
+assert sum_all(1, 2, 3) == 6
+assert sum_all() == 0
 
 a_set = new_set()
 add_to_set(a_set, "foo")
@@ -7,7 +7,10 @@ import uniffi_simple_fns
 assert(getString() == "String created by Rust")
 assert(getInt() == 1289)
 assert(stringIdentity(s: "String created by Kotlin") == "String created by Kotlin")
+assert(echoString(s: "String created by Swift") == "String created by Swift")
 assert(byteToU32(byte: 255) == 255)
+assert(sumAll(values: 1, 2, 3) == 6)
+assert(sumAll() == 0)
 
 let aSet = newSet()
 addToSet(set: aSet, value: "foo")
@@ -0,0 +1,75 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::sync::Arc;
+use tracing_subscriber::prelude::*;
+
+/// Forward `tracing` spans to the foreign span sink.
+#[uniffi::export]
+pub fn install_tracing_layer() {
+    // Ignore the error when the tests install it more than once
+    let _ = tracing::subscriber::set_global_default(
+        tracing_subscriber::registry().with(uniffi::SpanSinkLayer),
+    );
+}
+
+#[uniffi::export]
+pub fn add(a: u32, b: u32) -> u32 {
+    a + b
+}
+
+/// Does its work inside a `tracing` span, which is entered twice.
+#[uniffi::export]
+pub fn traced_sum(values: Vec<u32>) -> u32 {
+    let span = tracing::info_span!("traced_sum_inner");
+    let half = values.len() / 2;
+    let first: u32 = span.in_scope(|| values[..half].iter().sum());
+    let second: u32 = span.in_scope(|| values[half..].iter().sum());
+    first + second
+}
+
+#[uniffi::export]
+pub async fn async_add(a: u32, b: u32) -> u32 {
+    // Yield once, so that the future is polled more than once
+    YieldOnce(false).await;
+    a + b
+}
+
+struct YieldOnce(bool);
+
+impl std::future::Future for YieldOnce {
+    type Output = ();
+
+    fn poll(
+        mut self: std::pin::Pin<&mut Self>,
+        context: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<()> {
+        if self.0 {
+            std::task::Poll::Ready(())
+        } else {
+            self.0 = true;
+            context.waker().wake_by_ref();
+            std::task::Poll::Pending
+        }
+    }
+}
+
+#[derive(uniffi::Object)]
+pub struct Calculator {
+    offset: u32,
+}
+
+#[uniffi::export]
+impl Calculator {
+    #[uniffi::constructor]
+    pub fn new(offset: u32) -> Arc<Self> {
+        Arc::new(Self { offset })
+    }
+
+    pub fn add(&self, value: u32) -> u32 {
+        self.offset + value
+    }
+}
+
+uniffi::setup_scaffolding!();
@@ -0,0 +1,108 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi.fixture.span_sink.*
+import kotlinx.coroutines.*
+
+data class SpanEvent(val event: String, val spanId: ULong, val name: String)
+
+class SpanRecorder : UniFfiSpanSink {
+    private val events = mutableListOf<SpanEvent>()
+
+    @Synchronized
+    override fun enter(spanId: ULong, name: String) {
+        events.add(SpanEvent("enter", spanId, name))
+    }
+
+    @Synchronized
+    override fun exit(spanId: ULong, name: String) {
+        events.add(SpanEvent("exit", spanId, name))
+    }
+
+    @Synchronized
+    fun take(): List<SpanEvent> {
+        val taken = events.toList()
+        events.clear()
+        return taken
+    }
+
+    // The last poll of a future exits its span after completing the call, which can be after the
+    // caller resumes
+    fun takeBalanced(): List<SpanEvent> {
+        repeat(100) {
+            synchronized(this) {
+                if (events.count { it.event == "enter" } == events.count { it.event == "exit" }) {
+                    return take()
+                }
+            }
+            Thread.sleep(10)
+        }
+        return take()
+    }
+}
+
+val recorder = SpanRecorder()
+
+// Nothing is reported without a sink
+assert(add(1U, 2U) == 3U)
+uniffiSetSpanSink(recorder)
+installTracingLayer()
+recorder.take()
+
+// Sync calls report one span, with the same id for enter and exit
+assert(add(1U, 2U) == 3U)
+val addEvents = recorder.take()
+assert(addEvents.map { it.event to it.name } == listOf("enter" to "add", "exit" to "add"))
+assert(addEvents[0].spanId == addEvents[1].spanId)
+
+// Each call gets a new id
+assert(add(1U, 2U) == 3U)
+assert(recorder.take()[0].spanId != addEvents[0].spanId)
+
+// Methods and constructors are named after the object
+val calculator = Calculator(10U)
+assert(calculator.add(1U) == 11U)
+val calculatorEvents = recorder.take()
+assert(calculatorEvents.map { it.event to it.name } == listOf(
+    "enter" to "Calculator.new",
+    "exit" to "Calculator.new",
+    "enter" to "Calculator.add",
+    "exit" to "Calculator.add",
+))
+assert(calculatorEvents[0].spanId == calculatorEvents[1].spanId)
+assert(calculatorEvents[2].spanId == calculatorEvents[3].spanId)
+assert(calculatorEvents[0].spanId != calculatorEvents[2].spanId)
+calculator.destroy()
+
+// `tracing` spans are forwarded, nested inside the call, and keep their id each time they're entered
+assert(tracedSum(listOf(1U, 2U, 3U, 4U)) == 10U)
+val tracedEvents = recorder.take()
+assert(tracedEvents.map { it.event to it.name } == listOf(
+    "enter" to "traced_sum",
+    "enter" to "traced_sum_inner",
+    "exit" to "traced_sum_inner",
+    "enter" to "traced_sum_inner",
+    "exit" to "traced_sum_inner",
+    "exit" to "traced_sum",
+))
+assert(tracedEvents[0].spanId == tracedEvents[5].spanId)
+assert(tracedEvents.subList(1, 5).map { it.spanId }.toSet().size == 1)
+assert(tracedEvents[1].spanId != tracedEvents[0].spanId)
+
+// Async calls report the same span for the call and for each poll of the future
+runBlocking {
+    assert(asyncAdd(1U, 2U) == 3U)
+}
+val asyncEvents = recorder.takeBalanced()
+assert(asyncEvents.all { it.name == "async_add" })
+assert(asyncEvents.map { it.spanId }.toSet().size == 1)
+// One for the call, and at least two polls
+val asyncEnters = asyncEvents.count { it.event == "enter" }
+assert(asyncEnters >= 3 && asyncEnters == asyncEvents.count { it.event == "exit" })
+
+// Removing the sink stops reporting spans
+uniffiSetSpanSink(null)
+assert(add(1U, 2U) == 3U)
+assert(tracedSum(listOf(1U, 2U)) == 3U)
+assert(recorder.take().isEmpty())
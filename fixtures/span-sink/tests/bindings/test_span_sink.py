@@ -0,0 +1,98 @@
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+import asyncio
+import threading
+import time
+from uniffi_span_sink import *
+
+class SpanRecorder:
+    def __init__(self):
+        self.events = []
+        self.lock = threading.Lock()
+
+    def enter(self, span_id, name):
+        with self.lock:
+            self.events.append(("enter", span_id, name))
+
+    def exit(self, span_id, name):
+        with self.lock:
+            self.events.append(("exit", span_id, name))
+
+    def take(self):
+        with self.lock:
+            events, self.events = self.events, []
+            return events
+
+    # The last poll of a future exits its span after completing the call, which can be after the
+    # caller resumes
+    def take_balanced(self):
+        for _ in range(100):
+            with self.lock:
+                if sum(1 if event == "enter" else -1 for (event, _, _) in self.events) == 0:
+                    break
+            time.sleep(0.01)
+        return self.take()
+
+recorder = SpanRecorder()
+
+# Nothing is reported without a sink
+assert add(1, 2) == 3
+uniffi_set_span_sink(recorder)
+install_tracing_layer()
+recorder.take()
+
+# Sync calls report one span, with the same id for enter and exit
+assert add(1, 2) == 3
+[(enter, span_id, name), exit] = recorder.take()
+assert (enter, name) == ("enter", "add")
+assert exit == ("exit", span_id, "add")
+
+# Each call gets a new id
+assert add(1, 2) == 3
+[(_, next_span_id, _), _] = recorder.take()
+assert next_span_id != span_id
+
+# Methods and constructors are named after the object
+calculator = Calculator(10)
+assert calculator.add(1) == 11
+events = recorder.take()
+assert [(event, name) for (event, _, name) in events] == [
+    ("enter", "Calculator.new"),
+    ("exit", "Calculator.new"),
+    ("enter", "Calculator.add"),
+    ("exit", "Calculator.add"),
+]
+assert events[0][1] == events[1][1] != events[2][1] == events[3][1]
+
+# `tracing` spans are forwarded, nested inside the call, and keep their id each time they're entered
+assert traced_sum([1, 2, 3, 4]) == 10
+events = recorder.take()
+assert [(event, name) for (event, _, name) in events] == [
+    ("enter", "traced_sum"),
+    ("enter", "traced_sum_inner"),
+    ("exit", "traced_sum_inner"),
+    ("enter", "traced_sum_inner"),
+    ("exit", "traced_sum_inner"),
+    ("exit", "traced_sum"),
+]
+assert events[0][1] == events[5][1]
+assert len({span_id for (_, span_id, _) in events[1:5]}) == 1
+assert events[1][1] != events[0][1]
+
+# Async calls report the same span for the call and for each poll of the future
+assert asyncio.run(async_add(1, 2)) == 3
+events = recorder.take_balanced()
+assert all(name == "async_add" for (_, _, name) in events)
+assert len({span_id for (_, span_id, _) in events}) == 1
+enters = [event for event in events if event[0] == "enter"]
+exits = [event for event in events if event[0] == "exit"]
+# One for the call, and at least two polls
+assert len(enters) == len(exits) >= 3
+
+# Removing the sink stops reporting spans
+uniffi_set_span_sink(None)
+assert add(1, 2) == 3
+assert traced_sum([1, 2]) == 3
+assert recorder.take() == []
@@ -0,0 +1,125 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import Foundation // To get `DispatchGroup` and `NSLock`
+import uniffi_span_sink
+
+struct SpanEvent: Equatable {
+    let event: String
+    let spanId: UInt64
+    let name: String
+}
+
+class SpanRecorder: UniFfiSpanSink {
+    private var events: [SpanEvent] = []
+    private let lock = NSLock()
+
+    func enter(spanId: UInt64, name: String) {
+        lock.lock()
+        events.append(SpanEvent(event: "enter", spanId: spanId, name: name))
+        lock.unlock()
+    }
+
+    func exit(spanId: UInt64, name: String) {
+        lock.lock()
+        events.append(SpanEvent(event: "exit", spanId: spanId, name: name))
+        lock.unlock()
+    }
+
+    func take() -> [SpanEvent] {
+        lock.lock()
+        defer { lock.unlock() }
+        let taken = events
+        events = []
+        return taken
+    }
+
+    // The last poll of a future exits its span after completing the call, which can be after the
+    // caller resumes
+    func takeBalanced() -> [SpanEvent] {
+        for _ in 0..<100 {
+            lock.lock()
+            let balanced = events.filter { $0.event == "enter" }.count == events.filter { $0.event == "exit" }.count
+            lock.unlock()
+            if balanced {
+                break
+            }
+            Thread.sleep(forTimeInterval: 0.01)
+        }
+        return take()
+    }
+}
+
+func labels(_ events: [SpanEvent]) -> [String] {
+    return events.map { "\($0.event) \($0.name)" }
+}
+
+let recorder = SpanRecorder()
+
+// Nothing is reported without a sink
+assert(add(a: 1, b: 2) == 3)
+uniffiSetSpanSink(recorder)
+installTracingLayer()
+_ = recorder.take()
+
+// Sync calls report one span, with the same id for enter and exit
+assert(add(a: 1, b: 2) == 3)
+let addEvents = recorder.take()
+assert(labels(addEvents) == ["enter add", "exit add"])
+assert(addEvents[0].spanId == addEvents[1].spanId)
+
+// Each call gets a new id
+assert(add(a: 1, b: 2) == 3)
+assert(recorder.take()[0].spanId != addEvents[0].spanId)
+
+// Methods and constructors are named after the object
+let calculator = Calculator(offset: 10)
+assert(calculator.add(value: 1) == 11)
+let calculatorEvents = recorder.take()
+assert(labels(calculatorEvents) == [
+    "enter Calculator.new",
+    "exit Calculator.new",
+    "enter Calculator.add",
+    "exit Calculator.add",
+])
+assert(calculatorEvents[0].spanId == calculatorEvents[1].spanId)
+assert(calculatorEvents[2].spanId == calculatorEvents[3].spanId)
+assert(calculatorEvents[0].spanId != calculatorEvents[2].spanId)
+
+// `tracing` spans are forwarded, nested inside the call, and keep their id each time they're entered
+assert(tracedSum(values: [1, 2, 3, 4]) == 10)
+let tracedEvents = recorder.take()
+assert(labels(tracedEvents) == [
+    "enter traced_sum",
+    "enter traced_sum_inner",
+    "exit traced_sum_inner",
+    "enter traced_sum_inner",
+    "exit traced_sum_inner",
+    "exit traced_sum",
+])
+assert(tracedEvents[0].spanId == tracedEvents[5].spanId)
+assert(Set(tracedEvents[1...4].map { $0.spanId }).count == 1)
+assert(tracedEvents[1].spanId != tracedEvents[0].spanId)
+
+// Async calls report the same span for the call and for each poll of the future
+var counter = DispatchGroup()
+counter.enter()
+Task {
+    let sum = await asyncAdd(a: 1, b: 2)
+    assert(sum == 3)
+    counter.leave()
+}
+counter.wait()
+let asyncEvents = recorder.takeBalanced()
+assert(asyncEvents.allSatisfy { $0.name == "async_add" })
+assert(Set(asyncEvents.map { $0.spanId }).count == 1)
+// One for the call, and at least two polls
+let asyncEnters = asyncEvents.filter { $0.event == "enter" }.count
+assert(asyncEnters >= 3 && asyncEnters == asyncEvents.filter { $0.event == "exit" }.count)
+
+// Removing the sink stops reporting spans
+uniffiSetSpanSink(nil)
+assert(add(a: 1, b: 2) == 3)
+assert(tracedSum(values: [1, 2]) == 3)
+assert(recorder.take().isEmpty)
@@ -0,0 +1,5 @@
+uniffi::build_foreign_language_testcases!(
+    "tests/bindings/test_span_sink.kts",
+    "tests/bindings/test_span_sink.py",
+    "tests/bindings/test_span_sink.swift",
+);
@@ -54,3 +54,123 @@ stderr:
     );
     Ok(())
 }
+
+#[test]
+fn c_header() -> Result<(), anyhow::Error> {
+    let tmp_dir = std::env!("CARGO_TARGET_TMPDIR");
+    let crate_name = std::env!("CARGO_PKG_NAME");
+
+    let test_helper = UniFFITestHelper::new(crate_name)?;
+    let out_dir = test_helper.create_out_dir(tmp_dir, "c_header")?;
+
+    uniffi::generate_bindings(
+        &Utf8PathBuf::from("src/swift-bridging-header-compile.udl"),
+        None,
+        vec![TargetLanguage::C],
+        Some(&out_dir),
+        None,
+        false,
+    )?;
+
+    let header = out_dir.join("swift_bridging_header_compile.h");
+
+    // The C header should work with any compiler, not just clang, and from C++ too.
+    for (compiler, lang) in [("cc", "c"), ("c++", "c++")] {
+        let o = Command::new(compiler)
+            .args([
+                "-fsyntax-only",
+                "-Wpedantic",
+                "-Werror",
+                "-x",
+                lang,
+                // Include the header rather than compiling it directly, to avoid gcc's
+                // `#pragma once in main file` warning.
+                "-include",
+                header.as_str(),
+                "/dev/null",
+            ])
+            .output()?;
+
+        assert!(
+            o.status.success(),
+            r#"Failed to compile C header as {lang} {}:
+stdout:
+{}
+
+stderr:
+{}
+"#,
+            o.status,
+            String::from_utf8_lossy(&o.stdout),
+            String::from_utf8_lossy(&o.stderr)
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn shared_header() -> Result<(), anyhow::Error> {
+    let tmp_dir = std::env!("CARGO_TARGET_TMPDIR");
+    let crate_name = std::env!("CARGO_PKG_NAME");
+
+    let test_helper = UniFFITestHelper::new(crate_name)?;
+    let out_dir = test_helper.create_out_dir(tmp_dir, "shared_header")?;
+    let config_file = out_dir.join("uniffi.toml");
+    std::fs::write(
+        &config_file,
+        "[bindings.swift]\nshared_header_filename = \"UniffiShared.h\"\n",
+    )?;
+
+    uniffi::generate_bindings(
+        &Utf8PathBuf::from("src/swift-bridging-header-compile.udl"),
+        Some(&config_file),
+        vec![TargetLanguage::Swift],
+        Some(&out_dir),
+        None,
+        false,
+    )?;
+
+    // The shared structs move out of the component's header, which includes them instead
+    let bridging_h = std::fs::read_to_string(out_dir.join("swift_bridging_header_compileFFI.h"))?;
+    let shared_h = std::fs::read_to_string(out_dir.join("UniffiShared.h"))?;
+    assert!(bridging_h.contains("#include \"UniffiShared.h\""));
+    assert!(!bridging_h.contains("typedef struct RustBuffer"));
+    assert!(shared_h.contains("typedef struct RustBuffer"));
+
+    // Including the shared header separately, like an app with several components would, must not
+    // redeclare anything.
+    let main_m = out_dir.join("main.m");
+    std::fs::write(
+        &main_m,
+        "#include \"UniffiShared.h\"\n#include \"swift_bridging_header_compileFFI.h\"\n",
+    )?;
+    let o = Command::new("clang")
+        .args([
+            "-fsyntax-only",
+            "-x",
+            "objective-c",
+            "-Wpedantic",
+            "-Werror",
+            "-Wstrict-prototypes",
+            "-Wno-newline-eof",
+            "-I",
+            out_dir.as_str(),
+            main_m.as_str(),
+        ])
+        .output()?;
+
+    assert!(
+        o.status.success(),
+        r#"Failed to compile with the shared header {}:
+stdout:
+{}
+
+stderr:
+{}
+"#,
+        o.status,
+        String::from_utf8_lossy(&o.stdout),
+        String::from_utf8_lossy(&o.stderr)
+    );
+    Ok(())
+}
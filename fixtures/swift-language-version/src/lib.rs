@@ -0,0 +1,47 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::sync::Arc;
+
+#[uniffi::export(callback_interface)]
+pub trait Greeter {
+    fn greet(&self, name: String) -> String;
+}
+
+#[derive(uniffi::Object)]
+pub struct Person {
+    name: String,
+}
+
+#[uniffi::export]
+impl Person {
+    #[uniffi::constructor]
+    pub fn new(name: String) -> Arc<Self> {
+        Arc::new(Self { name })
+    }
+
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    /// Greet the person with a callback interface, or with a default greeting without one.
+    pub fn greet(&self, greeter: Option<Box<dyn Greeter>>) -> String {
+        match greeter {
+            Some(greeter) => greeter.greet(self.name.clone()),
+            None => format!("Hello, {}!", self.name),
+        }
+    }
+}
+
+#[uniffi::export]
+pub fn greet_with(greeter: Box<dyn Greeter>, name: String) -> String {
+    greeter.greet(name)
+}
+
+#[uniffi::export]
+pub async fn async_name(person: Arc<Person>) -> String {
+    person.name()
+}
+
+uniffi::setup_scaffolding!();
@@ -0,0 +1,32 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import Foundation // To get `DispatchGroup`
+import uniffi_swift_language_version
+
+class Shouter: Greeter {
+    func greet(name: String) -> String {
+        return "HELLO, \(name.uppercased())!"
+    }
+}
+
+// Callback interfaces are `any` existentials, including inside optionals.
+let person = Person(name: "Alice")
+let shouter: any Greeter = Shouter()
+assert(person.greet(greeter: shouter) == "HELLO, ALICE!")
+assert(person.greet(greeter: nil) == "Hello, Alice!")
+assert(greetWith(greeter: shouter, name: "Bob") == "HELLO, BOB!")
+
+// Objects are `Sendable`, so they can be passed to other tasks.
+let sendable: any Sendable = person
+assert(sendable is Person)
+
+var counter = DispatchGroup()
+counter.enter()
+Task.detached {
+    let name = await asyncName(person: person)
+    assert(name == "Alice")
+    counter.leave()
+}
+counter.wait()
@@ -1,7 +1,7 @@
 /* This Source Code Form is subject to the terms of the Mozilla Public
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 struct TraitMethods {
     val: String,
 }
@@ -10,6 +10,10 @@ impl TraitMethods {
     fn new(val: String) -> Self {
         Self { val }
     }
+
+    fn name(&self) -> String {
+        self.val.clone()
+    }
 }
 
 impl std::fmt::Display for TraitMethods {
@@ -0,0 +1,12 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi.trait_methods.*
+
+// `Clone` gives objects a `copy()` method, which returns an independent object
+val m = TraitMethods("yo")
+val c = m.copy()
+m.destroy()
+assert(c.name() == "yo")
+c.destroy()
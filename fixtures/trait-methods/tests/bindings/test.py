@@ -2,6 +2,7 @@
 # License, v. 2.0. If a copy of the MPL was not distributed with this
 # file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+import copy
 import unittest
 from trait_methods import *
 
@@ -29,5 +30,14 @@ class TestTraitMethods(unittest.TestCase):
         d[m] = "m"
         self.assertTrue(m in d)
 
+    def test_copy(self):
+        m = TraitMethods("yo")
+        c = copy.copy(m)
+        self.assertIsNot(c, m)
+        self.assertNotEqual(c._pointer, m._pointer)
+        self.assertEqual(c, m)
+        del m
+        self.assertEqual(c.name(), "yo")
+
 if __name__=='__main__':
     unittest.main()
@@ -0,0 +1,12 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import trait_methods
+
+// `Clone` gives objects a `copy()` method, which returns an independent object
+var m: TraitMethods? = TraitMethods(name: "yo")
+let c = m!.copy()
+assert(c !== m)
+m = nil
+assert(c.name() == "yo")
@@ -0,0 +1,22 @@
+fn main() { /* empty main required by `trybuild` */}
+
+#[derive(uniffi::Record)]
+#[uniffi(pod)]
+pub struct NotReprC {
+    x: f64,
+}
+
+#[derive(uniffi::Record)]
+#[uniffi(pod)]
+#[repr(C, packed)]
+pub struct Packed {
+    x: f64,
+}
+
+#[derive(uniffi::Record)]
+#[uniffi(pod)]
+#[repr(C)]
+pub struct NotPrimitive {
+    x: f64,
+    flag: bool,
+}
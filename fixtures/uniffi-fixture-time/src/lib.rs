@@ -68,6 +68,17 @@ fn set_seconds_before_unix_epoch(seconds: u64) -> Result<SystemTime> {
         .ok_or(ChronologicalError::TimeOverflow { a, b })
 }
 
+// `DateTime<Utc>` is passed the same way as `timestamp`, with the `chrono` feature of `uniffi`
+#[uniffi::export]
+fn datetime_to_string(a: DateTime<Utc>) -> String {
+    a.format("%Y-%m-%dT%H:%M:%S.%fZ").to_string()
+}
+
+#[uniffi::export]
+fn datetime_from_timestamp(a: SystemTime) -> DateTime<Utc> {
+    a.into()
+}
+
 type Result<T, E = ChronologicalError> = std::result::Result<T, E>;
 
 uniffi::include_scaffolding!("chronological");
@@ -50,3 +50,8 @@ assert(kotlinAfter.isAfter(rustNow))
 assert(optional(Instant.MAX, Duration.ofSeconds(0)))
 assert(optional(null, Duration.ofSeconds(0)) == false)
 assert(optional(Instant.MAX, null) == false)
+
+// Test that `chrono::DateTime<Utc>` is passed the same way as timestamps
+val time = Instant.ofEpochSecond(100, 1000)
+assert(datetimeToString(time) == toStringTimestamp(time))
+assert(datetimeFromTimestamp(time) == time)
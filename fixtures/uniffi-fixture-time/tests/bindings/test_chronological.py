@@ -45,3 +45,8 @@ assert abs(datetime.now(timezone.utc) - now()) <= timedelta(seconds=1)
 assert(optional(now(), timedelta(seconds=0)))
 assert(not optional(None, timedelta(seconds=0)))
 assert(not optional(now(), None))
+
+# Test that `chrono::DateTime<Utc>` is passed the same way as timestamps
+time = datetime.fromtimestamp(100.000001, timezone.utc)
+assert datetime_to_string(time) == to_string_timestamp(time)
+assert datetime_from_timestamp(time) == time
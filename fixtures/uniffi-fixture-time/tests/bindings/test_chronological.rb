@@ -73,6 +73,13 @@ class TestChronological < Test::Unit::TestCase
     assert (Time.now.utc - Chronological.now).abs <= 1.0
   end
 
+  def test_chrono_datetime_is_passed_as_a_timestamp
+    time = Time.at 100, 1, :microsecond, in: UTC
+
+    assert_equal Chronological.datetime_to_string(time), Chronological.to_string_timestamp(time)
+    assert_equal Chronological.datetime_from_timestamp(time), time
+  end
+
   private
 
   def duration(*args)
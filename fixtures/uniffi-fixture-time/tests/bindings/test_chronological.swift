@@ -26,4 +26,8 @@ let rustNow = now()
 let swiftAfter = Date.init()
 
 assert(swiftBefore <= rustNow)
-assert(swiftAfter >= rustNow)
\ No newline at end of file
+assert(swiftAfter >= rustNow)
+// Test that `chrono::DateTime<Utc>` is passed the same way as timestamps
+let time = Date.init(timeIntervalSince1970: 100.5)
+assert(datetimeToString(a: time) == toStringTimestamp(a: time))
+assert(datetimeFromTimestamp(a: time) == time)
@@ -36,6 +36,7 @@ enum Commands {
         no_format: bool,
 
         /// Path to the optional uniffi config file. If not provided, uniffi-bindgen will try to guess it from the UDL's file location.
+        /// With `--library`, this overrides the config of every crate, or only the `--crate` one if given.
         #[clap(long, short)]
         config: Option<Utf8PathBuf>,
 
@@ -51,6 +52,20 @@ enum Commands {
         #[clap(long = "crate")]
         crate_name: Option<String>,
 
+        /// When `--library` is passed, print the size of the generated files for each crate
+        #[clap(long)]
+        report_size: bool,
+
+        /// When `--library` is passed, find the cdylib that cargo built for this profile.
+        /// The source is then the cargo target directory rather than the cdylib.
+        #[clap(long)]
+        profile: Option<String>,
+
+        /// When `--profile` is passed, the package name of the cdylib crate to find, if the
+        /// workspace has several.  Use `--crate` to limit the bindings to one crate in the library.
+        #[clap(long)]
+        cdylib: Option<String>,
+
         /// Path to the UDL file, or cdylib if `library-mode` is specified
         source: Utf8PathBuf,
     },
@@ -88,25 +103,58 @@ pub fn run_main() -> anyhow::Result<()> {
             source,
             crate_name,
             library_mode,
+            report_size,
+            profile,
+            cdylib,
         } => {
             if library_mode {
                 if lib_file.is_some() {
                     panic!("--lib-file is not compatible with --library.")
                 }
-                if config.is_some() {
-                    panic!("--config is not compatible with --library.  The config file(s) will be found automatically.")
-                }
                 let out_dir = out_dir.expect("--out-dir is required when using --library");
                 if language.is_empty() {
                     panic!("please specify at least one language with --language")
                 }
-                uniffi_bindgen::library_mode::generate_bindings(
-                    &source, crate_name, &language, &out_dir, !no_format,
-                )?;
+                if cdylib.is_some() && profile.is_none() {
+                    panic!("--cdylib requires --profile.")
+                }
+                let sources = match profile {
+                    Some(profile) => {
+                        uniffi_bindgen::library_mode::generate_bindings_from_target_dir(
+                            &source,
+                            &profile,
+                            cdylib.as_deref(),
+                            crate_name,
+                            config.as_deref(),
+                            &language,
+                            &out_dir,
+                            !no_format,
+                        )?
+                    }
+                    None => uniffi_bindgen::library_mode::generate_bindings(
+                        &source,
+                        crate_name,
+                        config.as_deref(),
+                        &language,
+                        &out_dir,
+                        !no_format,
+                    )?,
+                };
+                if report_size {
+                    for source in sources {
+                        print!("{}", source.size_report()?);
+                    }
+                }
             } else {
                 if crate_name.is_some() {
                     panic!("--crate requires --library.")
                 }
+                if report_size {
+                    panic!("--report-size requires --library.")
+                }
+                if profile.is_some() {
+                    panic!("--profile requires --library.")
+                }
                 uniffi_bindgen::generate_bindings(
                     &source,
                     config.as_deref(),
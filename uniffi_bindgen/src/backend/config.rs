@@ -3,6 +3,7 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 /// Config value for template expressions
 ///
@@ -15,3 +16,289 @@ impl TemplateExpression {
         self.0.replace("{}", var)
     }
 }
+
+/// Config value for how async functions obtain the foreign executor that drives their futures
+///
+///   - `global`: the bindings pick the executor from the calling context (the current coroutine
+///     scope in Kotlin, the running event loop in Python, the current task priority in Swift).
+///   - `per_call`: every async function and method takes an extra, trailing executor argument
+///     so callers can plug in their own scheduler.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AsyncExecutor {
+    #[default]
+    Global,
+    PerCall,
+}
+
+impl AsyncExecutor {
+    pub fn is_per_call(&self) -> bool {
+        matches!(self, Self::PerCall)
+    }
+}
+
+/// Config value for the foreign-side handle map that tracks callback interface objects
+///
+///   - `concurrent`: a lock-protected pair of hash maps.  Handles are never reused and lowering
+///     the same object twice returns the same handle.
+///   - `slab`: a lock-protected vector of slots.  Every lower takes a new slot and freed slots get
+///     reused, which avoids hashing and keeps memory flat when many objects are created and
+///     destroyed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HandleMapKind {
+    #[default]
+    Concurrent,
+    Slab,
+}
+
+impl HandleMapKind {
+    pub fn is_slab(&self) -> bool {
+        matches!(self, Self::Slab)
+    }
+}
+
+/// Config value for how strictly the generated code checks buffers while lifting values
+///
+///   - `default`: only reject negative lengths, invalid optional tags and reads past the end of
+///     the buffer.
+///   - `strict`: also check length prefixes against the rest of the buffer before allocating
+///     anything, and reject booleans other than 0 or 1, reporting malformed buffers with a clear
+///     error.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BufferChecks {
+    #[default]
+    Default,
+    Strict,
+}
+
+impl BufferChecks {
+    pub fn is_strict(&self) -> bool {
+        matches!(self, Self::Strict)
+    }
+}
+
+/// Config value for what happens when names collide after they're converted to the foreign
+/// language's conventions, like the `foo_bar` and `fooBar` fields of a record
+///
+///   - `error`: fail to generate the bindings, naming the Rust names that collide.
+///   - `warn`: print a warning for each collision and generate the bindings anyway, for code that
+///     gets post-processed before it's compiled.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NameCollisions {
+    #[default]
+    Error,
+    Warn,
+}
+
+/// Config value for how the generated bindings are formatted
+///
+///   - `true` or `false`: format with the language's default formatter, or don't format at all,
+///     whether or not formatting was requested for the run.
+///   - a list of strings: format with this command, which gets the path of each generated file
+///     appended to its arguments.
+///
+/// Without this option, the bindings are formatted with the default formatter unless formatting
+/// was turned off for the run, for example with `--no-format`.  Each crate's config has its own
+/// value, so crates generated together can be formatted differently.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Formatter {
+    Enabled(bool),
+    Command(Vec<String>),
+}
+
+impl Formatter {
+    /// The command that formats a generated file, if it should be formatted
+    ///
+    /// `default_command` is the language's default formatter and `try_format_code` says whether
+    /// formatting was requested for the run.
+    pub fn command(
+        formatter: Option<&Self>,
+        default_command: &[&str],
+        try_format_code: bool,
+    ) -> Option<Vec<String>> {
+        let default_command = || default_command.iter().map(|s| s.to_string()).collect();
+        match formatter {
+            None if try_format_code => Some(default_command()),
+            None | Some(Self::Enabled(false)) => None,
+            Some(Self::Enabled(true)) => Some(default_command()),
+            Some(Self::Command(command)) if command.is_empty() => None,
+            Some(Self::Command(command)) => Some(command.clone()),
+        }
+    }
+}
+
+/// Config value for the version of the `uniffi` runtime crate that the bindings are pinned to
+///
+/// This is a `"major.minor"` or a `"major.minor.patch"` string.  `"0.24"` accepts a library built
+/// with any 0.24.x version of `uniffi`, while `"0.24.3"` only accepts 0.24.3.  The generated code
+/// only works with the runtime of the `uniffi_bindgen` that generated it, so the pin must match
+/// that version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct RuntimeVersion {
+    major: u32,
+    minor: u32,
+    patch: Option<u32>,
+}
+
+impl RuntimeVersion {
+    /// The runtime version that this `uniffi_bindgen` generates bindings for
+    pub fn bindgen() -> Self {
+        let component = |s: &str| s.parse().expect("invalid package version");
+        Self {
+            major: component(env!("CARGO_PKG_VERSION_MAJOR")),
+            minor: component(env!("CARGO_PKG_VERSION_MINOR")),
+            patch: Some(component(env!("CARGO_PKG_VERSION_PATCH"))),
+        }
+    }
+
+    /// Does a library built with the `version` runtime satisfy this pin?
+    fn accepts(&self, version: &Self) -> bool {
+        self.major == version.major
+            && self.minor == version.minor
+            && self
+                .patch
+                .map_or(true, |patch| version.patch == Some(patch))
+    }
+
+    /// The lowest runtime version that satisfies this pin, packed like
+    /// `uniffi::PACKED_PACKAGE_VERSION`
+    pub fn packed_min(&self) -> u32 {
+        self.major * 1_000_000 + self.minor * 1_000 + self.patch.unwrap_or(0)
+    }
+
+    /// The highest runtime version that satisfies this pin, packed like
+    /// `uniffi::PACKED_PACKAGE_VERSION`
+    pub fn packed_max(&self) -> u32 {
+        self.major * 1_000_000 + self.minor * 1_000 + self.patch.unwrap_or(999)
+    }
+}
+
+impl fmt::Display for RuntimeVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)?;
+        if let Some(patch) = self.patch {
+            write!(f, ".{patch}")?;
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<String> for RuntimeVersion {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let components = value
+            .split('.')
+            .map(|component| component.parse::<u32>().ok().filter(|n| *n < 1_000))
+            .collect::<Option<Vec<_>>>();
+        let version = match components.as_deref() {
+            Some(&[major, minor]) => Self {
+                major,
+                minor,
+                patch: None,
+            },
+            Some(&[major, minor, patch]) => Self {
+                major,
+                minor,
+                patch: Some(patch),
+            },
+            _ => {
+                return Err(format!(
+                "invalid runtime version `{value}`, expected `major.minor` or `major.minor.patch`"
+            ))
+            }
+        };
+        let bindgen = Self::bindgen();
+        if !version.accepts(&bindgen) {
+            return Err(format!(
+                "bindings generated by uniffi-bindgen {bindgen} can't be pinned to the uniffi {version} runtime"
+            ));
+        }
+        Ok(version)
+    }
+}
+
+impl From<RuntimeVersion> for String {
+    fn from(version: RuntimeVersion) -> Self {
+        version.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Deserialize)]
+    struct Config {
+        formatter: Option<Formatter>,
+    }
+
+    fn command(toml: &str, try_format_code: bool) -> Option<Vec<String>> {
+        let config: Config = toml::from_str(toml).unwrap();
+        Formatter::command(
+            config.formatter.as_ref(),
+            &["ktlint", "-F"],
+            try_format_code,
+        )
+    }
+
+    #[test]
+    fn test_formatter_command() {
+        let ktlint = Some(vec!["ktlint".to_string(), "-F".to_string()]);
+        // Without the option, the run decides
+        assert_eq!(command("", true), ktlint);
+        assert_eq!(command("", false), None);
+        // Otherwise the crate's config does
+        assert_eq!(command("formatter = false", true), None);
+        assert_eq!(command("formatter = true", false), ktlint);
+        assert_eq!(
+            command(r#"formatter = ["ktfmt", "--kotlinlang-style"]"#, false),
+            Some(vec!["ktfmt".to_string(), "--kotlinlang-style".to_string()])
+        );
+        assert_eq!(command("formatter = []", true), None);
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct RuntimeVersionConfig {
+        runtime_version: RuntimeVersion,
+    }
+
+    fn runtime_version(value: &str) -> Result<RuntimeVersion, String> {
+        toml::from_str::<RuntimeVersionConfig>(&format!("runtime_version = \"{value}\""))
+            .map(|config| config.runtime_version)
+            .map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn test_runtime_version() {
+        let bindgen = RuntimeVersion::bindgen();
+        let minor = format!("{}.{}", bindgen.major, bindgen.minor);
+
+        let pin = runtime_version(&minor).unwrap();
+        assert_eq!(pin.to_string(), minor);
+        assert_eq!(pin.packed_max() - pin.packed_min(), 999);
+        assert!(pin.packed_min() <= bindgen.packed_min());
+        assert!(bindgen.packed_min() <= pin.packed_max());
+
+        let pin = runtime_version(&bindgen.to_string()).unwrap();
+        assert_eq!(pin, bindgen);
+        assert_eq!(pin.packed_min(), pin.packed_max());
+
+        // Pins that this version of uniffi_bindgen can't satisfy
+        let next_minor = format!("{}.{}", bindgen.major, bindgen.minor + 1);
+        assert!(runtime_version(&next_minor)
+            .unwrap_err()
+            .contains("can't be pinned"));
+        // Malformed pins
+        for value in ["", "1", "0.x", "0.24.3.1", "0.1000"] {
+            assert!(runtime_version(value)
+                .unwrap_err()
+                .contains("invalid runtime version"));
+        }
+    }
+}
@@ -4,8 +4,13 @@
 
 mod config;
 pub mod filters;
+mod names;
 mod types;
 
 pub use crate::interface::{Literal, Type};
-pub use config::TemplateExpression;
+pub use config::{
+    AsyncExecutor, BufferChecks, Formatter, HandleMapKind, NameCollisions, RuntimeVersion,
+    TemplateExpression,
+};
+pub use names::{check_name_collisions, GeneratedNames, NameKind};
 pub use types::CodeType;
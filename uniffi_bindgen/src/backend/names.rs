@@ -0,0 +1,444 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Detect names that collide once they're converted to a foreign language's conventions
+//!
+//! Rust names are unique within their scope, but different Rust names can map to the same foreign
+//! name: `foo_bar` and `fooBar` are both `fooBar` in Kotlin.  The bindings also generate members
+//! of their own, like the `copy()` method of objects that implement `Clone`, which user-defined
+//! names can collide with.  The generated code then fails to compile, or silently shadows
+//! something, with an error that mentions neither Rust name, so the bindings check for this before
+//! rendering anything.
+
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Result};
+
+use super::NameCollisions;
+use crate::interface::{Argument, ComponentInterface, Field, UniffiTrait};
+
+/// The kinds of names that the foreign languages convert differently
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NameKind {
+    Function,
+    Variable,
+    EnumVariant,
+}
+
+/// The names that a language's bindings generate in addition to the ones in the interface
+#[derive(Debug, Default)]
+pub struct GeneratedNames<'a> {
+    /// Deprecated aliases from the `function_aliases` config option, which are converted like
+    /// function names
+    pub function_aliases: Vec<&'a str>,
+    /// Methods that every record gets, like Python's `to_dict()`
+    pub record_methods: Vec<&'static str>,
+    /// The method that objects implementing `Clone` get
+    pub clone_method: Option<&'static str>,
+}
+
+/// Check the names in `ci` for collisions after they're converted by `foreign_name`
+///
+/// Names are checked within their scope: top-level functions and their aliases, the fields and
+/// generated methods of each record, the methods of each object, and so on.  `generated` lists
+/// the names that the bindings add to those scopes, which are used as-is.
+///
+/// With [NameCollisions::Error] the first collision is an error, otherwise every collision is
+/// printed as a warning.
+pub fn check_name_collisions(
+    ci: &ComponentInterface,
+    language: &str,
+    foreign_name: impl Fn(NameKind, &str) -> String,
+    generated: &GeneratedNames<'_>,
+    handling: NameCollisions,
+) -> Result<()> {
+    let mut checker = Checker {
+        language,
+        foreign_name,
+        collisions: Vec::new(),
+    };
+
+    let mut functions = Vec::new();
+    for func in ci.function_definitions() {
+        let member = checker.member("function", NameKind::Function, func.name());
+        // Aliases from the metadata are used as-is, and skipped if they match the function's own
+        // foreign name
+        let aliases: Vec<_> = func
+            .aliases()
+            .iter()
+            .filter(|alias| **alias != member.foreign)
+            .map(|alias| Member::generated("alias", alias))
+            .collect();
+        functions.push(member);
+        functions.extend(aliases);
+    }
+    functions.extend(
+        generated
+            .function_aliases
+            .iter()
+            .map(|alias| checker.member("deprecated alias", NameKind::Function, alias)),
+    );
+    checker.check("The namespace", ci.namespace(), functions);
+    for func in ci.function_definitions() {
+        checker.check_arguments("Function", func.name(), func.arguments());
+    }
+    for rec in ci.record_definitions() {
+        let mut members = checker.fields(rec.fields());
+        members.extend(
+            generated
+                .record_methods
+                .iter()
+                .map(|name| Member::generated("generated method", name)),
+        );
+        checker.check("Record", rec.name(), members);
+    }
+    for e in ci.enum_definitions() {
+        let variants = e
+            .variants()
+            .iter()
+            .map(|v| checker.member("variant", NameKind::EnumVariant, v.name()))
+            .collect();
+        checker.check("Enum", e.name(), variants);
+        for v in e.variants() {
+            let owner = format!("{}::{}", e.name(), v.name());
+            let fields = checker.fields(v.fields());
+            checker.check("Variant", &owner, fields);
+        }
+    }
+    for obj in ci.object_definitions() {
+        let mut members: Vec<_> = obj
+            .methods()
+            .into_iter()
+            .map(|m| checker.member("method", NameKind::Function, m.name()))
+            .collect();
+        if let Some(clone_method) = generated.clone_method {
+            let implements_clone = obj
+                .uniffi_traits()
+                .into_iter()
+                .any(|t| matches!(t, UniffiTrait::Clone { .. }));
+            if implements_clone {
+                members.push(Member::generated("generated method", clone_method));
+            }
+        }
+        checker.check("Object", obj.name(), members);
+        for cons in obj.constructors() {
+            let owner = format!("{}::{}", obj.name(), cons.name());
+            checker.check_arguments("Constructor", &owner, cons.arguments());
+        }
+        for meth in obj.methods() {
+            let owner = format!("{}::{}", obj.name(), meth.name());
+            checker.check_arguments("Method", &owner, meth.arguments());
+        }
+    }
+    for cbi in ci.callback_interface_definitions() {
+        let methods = cbi
+            .methods()
+            .into_iter()
+            .map(|m| checker.member("method", NameKind::Function, m.name()))
+            .collect();
+        checker.check("Callback interface", cbi.name(), methods);
+        for meth in cbi.methods() {
+            let owner = format!("{}::{}", cbi.name(), meth.name());
+            checker.check_arguments("Method", &owner, meth.arguments());
+        }
+    }
+
+    match (handling, checker.collisions.first()) {
+        (_, None) => Ok(()),
+        (NameCollisions::Error, Some(collision)) => bail!(
+            "{collision}. Rename one of them, or set `name_collisions = \"warn\"` in the \
+             `[bindings.{}]` section to generate the bindings anyway",
+            language.to_ascii_lowercase()
+        ),
+        (NameCollisions::Warn, Some(_)) => {
+            for collision in checker.collisions {
+                println!("Warning: {collision}");
+            }
+            Ok(())
+        }
+    }
+}
+
+/// A name in one of the scopes that are checked
+struct Member {
+    /// What the name is, like "field" or "generated method"
+    what: &'static str,
+    /// The name as it's reported
+    name: String,
+    /// The name in the foreign language
+    foreign: String,
+}
+
+impl Member {
+    /// A name that the bindings generate, which is already a foreign name
+    fn generated(what: &'static str, name: &str) -> Self {
+        Self {
+            what,
+            name: name.to_string(),
+            foreign: name.to_string(),
+        }
+    }
+
+    fn describe_with(&self, other: &Member) -> String {
+        if self.what == other.what {
+            let plural = if self.what.ends_with('s') { "es" } else { "s" };
+            format!("{}{plural} `{}` and `{}`", self.what, self.name, other.name)
+        } else {
+            format!(
+                "{} `{}` and {} `{}`",
+                self.what, self.name, other.what, other.name
+            )
+        }
+    }
+}
+
+struct Checker<'a, F> {
+    language: &'a str,
+    foreign_name: F,
+    collisions: Vec<String>,
+}
+
+impl<'a, F: Fn(NameKind, &str) -> String> Checker<'a, F> {
+    fn member(&self, what: &'static str, kind: NameKind, name: &str) -> Member {
+        Member {
+            what,
+            name: name.to_string(),
+            // Kotlin quotes names in backticks, which aren't part of the name
+            foreign: (self.foreign_name)(kind, name)
+                .trim_matches('`')
+                .to_string(),
+        }
+    }
+
+    fn check(&mut self, owner_kind: &str, owner: &str, members: Vec<Member>) {
+        let mut seen: BTreeMap<&str, &Member> = BTreeMap::new();
+        for member in &members {
+            match seen.get(member.foreign.as_str()) {
+                Some(previous) => self.collisions.push(format!(
+                    "{owner_kind} `{owner}` has {}, which are both named `{}` in {}",
+                    previous.describe_with(member),
+                    member.foreign,
+                    self.language
+                )),
+                None => {
+                    seen.insert(&member.foreign, member);
+                }
+            }
+        }
+    }
+
+    fn fields(&self, fields: &[Field]) -> Vec<Member> {
+        fields
+            .iter()
+            .map(|f| self.member("field", NameKind::Variable, f.name()))
+            .collect()
+    }
+
+    fn check_arguments(&mut self, owner_kind: &str, owner: &str, arguments: Vec<&Argument>) {
+        let arguments = arguments
+            .into_iter()
+            .map(|a| self.member("argument", NameKind::Variable, a.name()))
+            .collect();
+        self.check(owner_kind, owner, arguments);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use heck::ToLowerCamelCase;
+    use uniffi_meta::{FnMetadata, Metadata, MetadataGroup, NamespaceMetadata};
+
+    fn check(ci: &ComponentInterface, generated: &GeneratedNames<'_>) -> Result<()> {
+        check_name_collisions(
+            ci,
+            "Kotlin",
+            |_, nm| format!("`{}`", nm.to_lower_camel_case()),
+            generated,
+            NameCollisions::Error,
+        )
+    }
+
+    fn collisions(udl: &str, handling: NameCollisions) -> Result<()> {
+        let ci = ComponentInterface::from_webidl(udl).unwrap();
+        check_name_collisions(
+            &ci,
+            "Kotlin",
+            |_, nm| format!("`{}`", nm.to_lower_camel_case()),
+            &GeneratedNames::default(),
+            handling,
+        )
+    }
+
+    fn collision(udl: &str, generated: &GeneratedNames<'_>) -> String {
+        let ci = ComponentInterface::from_webidl(udl).unwrap();
+        let err = check(&ci, generated).unwrap_err().to_string();
+        err.split_once(". Rename").unwrap().0.to_string()
+    }
+
+    #[test]
+    fn test_name_collisions() {
+        const UDL: &str = r#"
+            namespace test {};
+            dictionary Point {
+                u32 x_pos;
+                u32 xPos;
+            };
+        "#;
+        let err = collisions(UDL, NameCollisions::Error).unwrap_err();
+        assert!(err.to_string().starts_with(
+            "Record `Point` has fields `x_pos` and `xPos`, which are both named `xPos` in Kotlin."
+        ));
+        assert!(collisions(UDL, NameCollisions::Warn).is_ok());
+
+        const METHODS_UDL: &str = r#"
+            namespace test {};
+            interface Counter {
+                void reset_all();
+                void resetAll(u32 by_count, u32 byCount);
+            };
+        "#;
+        let err = collisions(METHODS_UDL, NameCollisions::Error).unwrap_err();
+        assert!(err.to_string().starts_with(
+            "Object `Counter` has methods `reset_all` and `resetAll`, which are both named `resetAll` in Kotlin."
+        ));
+
+        const OK_UDL: &str = r#"
+            namespace test {};
+            dictionary Point {
+                u32 x;
+                u32 y;
+            };
+        "#;
+        assert!(collisions(OK_UDL, NameCollisions::Error).is_ok());
+    }
+
+    #[test]
+    fn test_generated_name_collisions() {
+        // Fields collide with the methods that the bindings add to every record
+        let record_methods = GeneratedNames {
+            record_methods: vec!["count", "index"],
+            ..Default::default()
+        };
+        const RECORD_UDL: &str = r#"
+            namespace test {};
+            dictionary Tally {
+                u32 count;
+            };
+        "#;
+        assert_eq!(
+            collision(RECORD_UDL, &record_methods),
+            "Record `Tally` has field `count` and generated method `count`, which are both named `count` in Kotlin"
+        );
+        assert!(collisions(RECORD_UDL, NameCollisions::Error).is_ok());
+
+        // Methods collide with the `copy()` method of objects that implement `Clone`
+        let clone_method = GeneratedNames {
+            clone_method: Some("copy"),
+            ..Default::default()
+        };
+        assert_eq!(
+            collision(
+                r#"
+                    namespace test {};
+                    [Traits=(Clone)]
+                    interface Shape {
+                        void copy();
+                    };
+                "#,
+                &clone_method
+            ),
+            "Object `Shape` has method `copy` and generated method `copy`, which are both named `copy` in Kotlin"
+        );
+        let ci = ComponentInterface::from_webidl(
+            r#"
+                namespace test {};
+                interface Shape {
+                    void copy();
+                };
+            "#,
+        )
+        .unwrap();
+        assert!(check(&ci, &clone_method).is_ok());
+
+        // Deprecated aliases from the config are converted like function names
+        let function_aliases = GeneratedNames {
+            function_aliases: vec!["get_value"],
+            ..Default::default()
+        };
+        assert_eq!(
+            collision(
+                r#"
+                    namespace test {
+                        u32 getValue();
+                    };
+                "#,
+                &function_aliases
+            ),
+            "The namespace `test` has function `getValue` and deprecated alias `get_value`, which are both named `getValue` in Kotlin"
+        );
+
+        // The `uniffi_type_name()` method of `#[uniffi(type_name)]` objects is exported like any
+        // other method, so it's checked with the rest of them
+        assert_eq!(
+            collision(
+                r#"
+                    namespace test {};
+                    interface Store {
+                        string uniffi_type_name();
+                        string uniffiTypeName();
+                    };
+                "#,
+                &GeneratedNames::default()
+            ),
+            "Object `Store` has methods `uniffi_type_name` and `uniffiTypeName`, which are both named `uniffiTypeName` in Kotlin"
+        );
+    }
+
+    #[test]
+    fn test_alias_name_collisions() {
+        let func = |name: &str, aliases: &[&str]| {
+            Metadata::Func(FnMetadata {
+                module_path: "test".into(),
+                name: name.into(),
+                is_async: false,
+                inputs: vec![],
+                return_type: None,
+                throws: None,
+                aliases: aliases.iter().map(|a| a.to_string()).collect(),
+                checksum: None,
+                full_module_path: "test".into(),
+            })
+        };
+        let ci = |items: Vec<Metadata>| {
+            let mut group = MetadataGroup {
+                namespace: NamespaceMetadata {
+                    crate_name: "test".into(),
+                    name: "test".into(),
+                },
+                items: Default::default(),
+            };
+            for item in items {
+                group.add_item(item);
+            }
+            ComponentInterface::from_metadata(group).unwrap()
+        };
+
+        // An alias that matches the function's own foreign name isn't generated
+        let ci_ok = ci(vec![func("get_value", &["getValue"])]);
+        assert!(check(&ci_ok, &GeneratedNames::default()).is_ok());
+
+        // Aliases are used as-is, and collide with the converted names of other functions
+        let ci_err = ci(vec![func("fetch", &["getValue"]), func("get_value", &[])]);
+        assert_eq!(
+            check(&ci_err, &GeneratedNames::default())
+                .unwrap_err()
+                .to_string()
+                .split_once(". Rename")
+                .unwrap()
+                .0,
+            "The namespace `test` has alias `getValue` and function `get_value`, which are both named `getValue` in Kotlin"
+        );
+    }
+}
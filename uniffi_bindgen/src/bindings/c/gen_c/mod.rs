@@ -0,0 +1,131 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::HashMap;
+
+use askama::Template;
+use serde::{Deserialize, Serialize};
+
+use crate::backend::Formatter;
+use crate::interface::*;
+use crate::BindingsConfig;
+
+// Config options to customize the generated C header.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Config {
+    header_filename: Option<String>,
+    formatter: Option<Formatter>,
+}
+
+impl Config {
+    /// The filename for the generated header.
+    ///
+    /// This defaults to `<namespace>.h` once the config has been updated from the component
+    /// interface, and to `uniffi.h` before that.
+    pub fn header_filename(&self) -> String {
+        self.header_filename
+            .clone()
+            .unwrap_or_else(|| "uniffi.h".to_string())
+    }
+
+    /// How the generated bindings are formatted, see [Formatter].  This overrides whether
+    /// formatting was requested when generating the bindings.
+    pub fn formatter(&self) -> Option<&Formatter> {
+        self.formatter.as_ref()
+    }
+}
+
+impl BindingsConfig for Config {
+    const TOML_KEY: &'static str = "c";
+
+    fn update_from_ci(&mut self, ci: &ComponentInterface) {
+        self.header_filename
+            .get_or_insert_with(|| format!("{}.h", ci.namespace()));
+    }
+
+    fn update_from_cdylib_name(&mut self, _cdylib_name: &str) {}
+
+    fn update_from_dependency_configs(&mut self, _config_map: HashMap<&str, &Self>) {}
+}
+
+/// Template for generating the standalone C header for a component.
+///
+/// Unlike the Swift bridging header, this doesn't rely on Clang extensions: the nullability
+/// annotations are defined away for other compilers and the declarations are wrapped in
+/// `extern "C"` so the header can be used from C++.
+#[derive(Template)]
+#[template(syntax = "c", escape = "none", path = "CHeaderTemplate.h")]
+pub struct CHeader<'a> {
+    ci: &'a ComponentInterface,
+}
+
+impl<'a> CHeader<'a> {
+    pub fn new(ci: &'a ComponentInterface) -> Self {
+        Self { ci }
+    }
+}
+
+pub mod filters {
+    use super::*;
+    pub use crate::backend::filters::ffi_type;
+
+    /// The C type used for an FFI type.
+    pub fn ffi_type_name(ffi_type: &FfiType) -> Result<String, askama::Error> {
+        Ok(match ffi_type {
+            FfiType::Int8 => "int8_t".into(),
+            FfiType::UInt8 => "uint8_t".into(),
+            FfiType::Int16 => "int16_t".into(),
+            FfiType::UInt16 => "uint16_t".into(),
+            FfiType::Int32 => "int32_t".into(),
+            FfiType::UInt32 => "uint32_t".into(),
+            FfiType::Int64 => "int64_t".into(),
+            FfiType::UInt64 => "uint64_t".into(),
+            FfiType::Float32 => "float".into(),
+            FfiType::Float64 => "double".into(),
+            FfiType::RustArcPtr(_) => "void *_Nonnull".into(),
+            FfiType::RustBuffer(_) => "RustBuffer".into(),
+            FfiType::ForeignBytes => "ForeignBytes".into(),
+            FfiType::Struct(name) => format!("UniFfiStruct{name}"),
+            FfiType::ForeignCallback => "ForeignCallback _Nonnull".into(),
+            FfiType::ForeignExecutorCallback => "UniFfiForeignExecutorCallback _Nonnull".into(),
+            FfiType::ForeignExecutorHandle => "size_t".into(),
+            FfiType::FutureCallback { return_type } => format!(
+                "UniFfiFutureCallback{} _Nonnull",
+                ffi_canonical_name(return_type)?
+            ),
+            FfiType::FutureCallbackData => "void *_Nonnull".into(),
+        })
+    }
+
+    /// A name for an FFI type that can be used as part of a C identifier.
+    ///
+    /// These match the names used by the Swift bridging header, so that both headers declare
+    /// the same future callback typedefs.
+    pub fn ffi_canonical_name(ffi_type: &FfiType) -> Result<String, askama::Error> {
+        Ok(match ffi_type {
+            FfiType::Int8 => "Int8".into(),
+            FfiType::UInt8 => "UInt8".into(),
+            FfiType::Int16 => "Int16".into(),
+            FfiType::UInt16 => "UInt16".into(),
+            FfiType::Int32 => "Int32".into(),
+            FfiType::UInt32 => "UInt32".into(),
+            FfiType::Int64 => "Int64".into(),
+            FfiType::UInt64 => "UInt64".into(),
+            FfiType::Float32 => "Float".into(),
+            FfiType::Float64 => "Double".into(),
+            FfiType::RustArcPtr(_) | FfiType::FutureCallbackData => {
+                "UnsafeMutableRawPointer".into()
+            }
+            FfiType::RustBuffer(_) => "RustBuffer".into(),
+            FfiType::ForeignBytes => "ForeignBytes".into(),
+            FfiType::Struct(name) => format!("UniFfiStruct{name}"),
+            FfiType::ForeignCallback => "ForeignCallback".into(),
+            FfiType::ForeignExecutorHandle => "Int".into(),
+            FfiType::ForeignExecutorCallback => "ForeignExecutorCallback".into(),
+            FfiType::FutureCallback { return_type } => {
+                format!("UniFfiFutureCallback{}", ffi_canonical_name(return_type)?)
+            }
+        })
+    }
+}
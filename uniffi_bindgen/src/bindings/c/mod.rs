@@ -0,0 +1,50 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! # C header backend for UniFFI
+//!
+//! This isn't a full bindings backend: it generates a single `.h` file declaring the low-level
+//! FFI functions exported by a component, along with the `RustBuffer`, `ForeignBytes` and
+//! `RustCallStatus` structs they use. This is useful for writing C/C++ consumers directly, or
+//! as a starting point for bindings in languages that UniFFI doesn't support.
+//!
+//! Callers are responsible for lowering and lifting values themselves, following the scheme
+//! described in the "Lifting, Lowering, and Serialization" section of the manual.
+
+use anyhow::{Context, Result};
+use camino::{Utf8Path, Utf8PathBuf};
+use fs_err as fs;
+
+pub mod gen_c;
+pub use gen_c::{CHeader, Config};
+
+use super::super::interface::ComponentInterface;
+
+/// Write the C header for the given ComponentInterface, in the given output directory.
+pub fn write_bindings(
+    config: &Config,
+    ci: &ComponentInterface,
+    out_dir: &Utf8Path,
+    try_format_code: bool,
+) -> Result<Vec<Utf8PathBuf>> {
+    let header_file = out_dir.join(config.header_filename());
+    fs::write(&header_file, generate_c_header(ci)?)?;
+
+    super::format_generated_file(
+        config.formatter(),
+        &["clang-format", "-i"],
+        try_format_code,
+        &header_file,
+    );
+
+    Ok(vec![header_file])
+}
+
+/// Generate the C header for the given ComponentInterface, as a string.
+pub fn generate_c_header(ci: &ComponentInterface) -> Result<String> {
+    use askama::Template;
+    CHeader::new(ci)
+        .render()
+        .context("failed to render C header")
+}
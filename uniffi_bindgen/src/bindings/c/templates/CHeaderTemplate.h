@@ -0,0 +1,55 @@
+// This file was autogenerated by some hot garbage in the `uniffi` crate.
+// Trust me, you don't want to mess with it!
+
+#pragma once
+
+// The nullability annotations below are a Clang extension.
+#if !defined(__clang__)
+#define _Nonnull
+#define _Nullable
+#endif
+
+{% include "SharedHeaderBody.h" %}
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// `#[repr(C)]` records, which are passed by value
+{%- for rec in ci.record_definitions() %}
+{%- if rec.is_pod() %}
+typedef struct UniFfiStruct{{ rec.name() }} {
+    {%- for field in rec.fields() %}
+    {{ field|ffi_type|ffi_type_name }} {{ field.name() }};
+    {%- endfor %}
+} UniFfiStruct{{ rec.name() }};
+{%- endif %}
+{%- endfor %}
+
+// Callbacks for UniFFI Futures
+{%- for ffi_type in ci.iter_future_callback_params() %}
+typedef void (*UniFfiFutureCallback{{ ffi_type|ffi_canonical_name }})(const void * _Nonnull, {{ ffi_type|ffi_type_name }}, RustCallStatus);
+{%- endfor %}
+
+// Scaffolding functions
+//
+// Optionals, sequences and maps are passed as a `RustBuffer` holding their serialized value.  Once
+// any bindings of the library enable the `empty_buffer_sentinels` option, `None` and empty values
+// are passed as an empty buffer instead, with a `len` of 0 and possibly a `NULL` `data` pointer.
+// Serialized values are never empty, so an empty buffer always holds the empty value.
+{%- for func in ci.iter_ffi_function_definitions() %}
+{% match func.return_type() -%}{%- when Some with (type_) %}{{ type_|ffi_type_name }}{% when None %}void{% endmatch %} {{ func.name() }}(
+    {%- if func.arguments().len() > 0 %}
+        {%- for arg in func.arguments() %}
+            {{- arg.type_()|ffi_type_name }} {{ arg.name() -}}{% if !loop.last || func.has_rust_call_status_arg() %}, {% endif %}
+        {%- endfor %}
+        {%- if func.has_rust_call_status_arg() %}RustCallStatus *_Nonnull out_status{% endif %}
+    {%- else %}
+        {%- if func.has_rust_call_status_arg() %}RustCallStatus *_Nonnull out_status{%- else %}void{% endif %}
+    {% endif %}
+);
+{%- endfor %}
+
+#ifdef __cplusplus
+} // extern "C"
+#endif
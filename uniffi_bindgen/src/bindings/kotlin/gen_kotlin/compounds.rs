@@ -53,6 +53,39 @@ macro_rules! impl_code_type_for_compound {
 impl_code_type_for_compound!(OptionalCodeType, "{}?", "Optional{}");
 impl_code_type_for_compound!(SequenceCodeType, "List<{}>", "Sequence{}");
 
+/// Ranges are a `ClosedRange`, or an `OpenEndRange` for exclusive ranges, which needs Kotlin 1.9
+#[derive(Debug)]
+pub struct RangeCodeType {
+    inner: Type,
+    inclusive: bool,
+}
+
+impl RangeCodeType {
+    pub fn new(inner: Type, inclusive: bool) -> Self {
+        Self { inner, inclusive }
+    }
+}
+
+impl CodeType for RangeCodeType {
+    fn type_label(&self) -> String {
+        let inner = super::KotlinCodeOracle.find(&self.inner).type_label();
+        if self.inclusive {
+            format!("ClosedRange<{inner}>")
+        } else {
+            format!("OpenEndRange<{inner}>")
+        }
+    }
+
+    fn canonical_name(&self) -> String {
+        let inner = super::KotlinCodeOracle.find(&self.inner).canonical_name();
+        if self.inclusive {
+            format!("RangeInclusive{inner}")
+        } else {
+            format!("Range{inner}")
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct MapCodeType {
     key: Type,
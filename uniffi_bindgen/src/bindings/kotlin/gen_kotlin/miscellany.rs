@@ -30,4 +30,13 @@ macro_rules! impl_code_type_for_miscellany {
 
 impl_code_type_for_miscellany!(TimestampCodeType, "java.time.Instant", "Timestamp");
 
-impl_code_type_for_miscellany!(DurationCodeType, "java.time.Duration", "Duration");
+// Durations are mapped to the type that the `duration_type` config option selects, through the
+// `UniffiDuration` typealias in `DurationHelper.kt`
+impl_code_type_for_miscellany!(DurationCodeType, "UniffiDuration", "Duration");
+
+// Needs `kotlinx-serialization-json` on the classpath
+impl_code_type_for_miscellany!(
+    JsonValueCodeType,
+    "kotlinx.serialization.json.JsonElement",
+    "JsonValue"
+);
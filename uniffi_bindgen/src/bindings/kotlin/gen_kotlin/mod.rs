@@ -11,7 +11,10 @@ use askama::Template;
 use heck::{ToLowerCamelCase, ToShoutySnakeCase, ToUpperCamelCase};
 use serde::{Deserialize, Serialize};
 
-use crate::backend::{CodeType, TemplateExpression};
+use crate::backend::{
+    check_name_collisions, AsyncExecutor, BufferChecks, CodeType, Formatter, GeneratedNames,
+    HandleMapKind, NameCollisions, NameKind, RuntimeVersion, TemplateExpression,
+};
 use crate::interface::*;
 use crate::BindingsConfig;
 
@@ -37,6 +40,103 @@ pub struct Config {
     custom_types: HashMap<String, CustomTypeConfig>,
     #[serde(default)]
     external_packages: HashMap<String, String>,
+    #[serde(default)]
+    async_executor: AsyncExecutor,
+    #[serde(default)]
+    builders: HashSet<String>,
+    #[serde(default)]
+    function_aliases: HashMap<String, Vec<String>>,
+    #[serde(default)]
+    extension_methods: HashMap<String, Vec<String>>,
+    unchecked_exceptions: Option<bool>,
+    async_cancellation: Option<bool>,
+    async_result: Option<bool>,
+    #[serde(default)]
+    handle_map: HandleMapKind,
+    #[serde(default)]
+    buffer_checks: BufferChecks,
+    max_nesting_depth: Option<u32>,
+    handshake: Option<bool>,
+    #[serde(default)]
+    duration_type: DurationType,
+    #[serde(default)]
+    string_encoding: StringEncoding,
+    span_sink: Option<bool>,
+    panic_observer: Option<bool>,
+    capability_registration: Option<bool>,
+    error_localizer: Option<bool>,
+    empty_buffer_sentinels: Option<bool>,
+    formatter: Option<Formatter>,
+    serializable_records: Option<bool>,
+    runtime_version: Option<RuntimeVersion>,
+    #[serde(default)]
+    name_collisions: NameCollisions,
+    explicit_integer_literals: Option<bool>,
+}
+
+/// Config value for the Kotlin type that durations are mapped to
+///
+///   - `java`: `java.time.Duration`, which needs Android API level 26 or later.
+///   - `kotlin`: `kotlin.time.Duration`, which needs Kotlin 1.6 or later.
+///   - `nanos`: a `Long` number of nanoseconds, which works everywhere but only covers durations up
+///     to about 292 years.
+///
+/// Durations that the type can't represent exactly fail to lift, rather than being rounded.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DurationType {
+    #[default]
+    Java,
+    Kotlin,
+    Nanos,
+}
+
+impl DurationType {
+    /// The Kotlin type name for durations
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Self::Java => "java.time.Duration",
+            Self::Kotlin => "kotlin.time.Duration",
+            Self::Nanos => "Long",
+        }
+    }
+}
+
+/// Config value for the encoding that strings are transferred with
+///
+///   - `utf8`: the default, which every binding uses.
+///   - `utf16`: UTF-16LE, which saves transcoding between Kotlin and the wire format but takes more
+///     bytes for mostly-ASCII text.  Rust still transcodes, into its UTF-8 `String`.
+///
+/// The encoding applies to the whole library, so every binding that loads it must agree.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StringEncoding {
+    #[default]
+    Utf8,
+    Utf16,
+}
+
+impl StringEncoding {
+    /// The `java.nio.charset.Charset` expression for the encoding
+    pub fn charset(&self) -> &'static str {
+        match self {
+            Self::Utf8 => "Charsets.UTF_8",
+            Self::Utf16 => "Charsets.UTF_16LE",
+        }
+    }
+
+    /// The value passed to `string_encoding_set` in the scaffolding
+    pub fn ffi_value(&self) -> u8 {
+        match self {
+            Self::Utf8 => 1,
+            Self::Utf16 => 2,
+        }
+    }
+
+    pub fn is_utf16(&self) -> bool {
+        matches!(self, Self::Utf16)
+    }
 }
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
@@ -63,6 +163,172 @@ impl Config {
             "uniffi".into()
         }
     }
+
+    /// How async functions obtain the `CoroutineScope` used to drive Rust futures.
+    pub fn async_executor(&self) -> AsyncExecutor {
+        self.async_executor
+    }
+
+    /// Should cancelling the coroutine of an async call also cancel the Rust future?  If so, the
+    /// future is dropped rather than running to completion in the background.
+    pub fn async_cancellation(&self) -> bool {
+        self.async_cancellation.unwrap_or(false)
+    }
+
+    /// Should async functions that throw return a `kotlin.Result` instead?  Errors are then
+    /// returned as a failed `Result`, while cancellation still throws `CancellationException`.
+    pub fn async_result(&self) -> bool {
+        self.async_result.unwrap_or(false)
+    }
+
+    /// Which handle map implementation tracks callback interface objects passed to Rust.
+    pub fn handle_map(&self) -> HandleMapKind {
+        self.handle_map
+    }
+
+    /// How strictly lifting code checks the buffers it reads values from.
+    pub fn buffer_checks(&self) -> BufferChecks {
+        self.buffer_checks
+    }
+
+    /// The maximum depth that records and enums can be nested in values that are lifted or
+    /// lowered, or `None` for no limit.  Values nested deeper than this fail with an internal
+    /// error, instead of overflowing the stack.
+    pub fn max_nesting_depth(&self) -> Option<u32> {
+        self.max_nesting_depth
+    }
+
+    /// Should the bindings expose a handshake, which reports the contract version and interface
+    /// fingerprint of the loaded library so that foreign code can check it's compatible?
+    pub fn handshake(&self) -> bool {
+        self.handshake.unwrap_or(false)
+    }
+
+    /// The Kotlin type that durations are mapped to.
+    pub fn duration_type(&self) -> DurationType {
+        self.duration_type
+    }
+
+    /// The encoding that strings are transferred with.
+    pub fn string_encoding(&self) -> StringEncoding {
+        self.string_encoding
+    }
+
+    /// Should the bindings let foreign code register a span sink, to see the spans of calls into Rust?
+    pub fn span_sink(&self) -> bool {
+        self.span_sink.unwrap_or(false)
+    }
+
+    /// Should the bindings let foreign code register a panic observer, to be notified of Rust panics?
+    pub fn panic_observer(&self) -> bool {
+        self.panic_observer.unwrap_or(false)
+    }
+
+    /// Should the bindings let foreign code decide if the capabilities of functions exported with
+    /// `requires_capability` are available?
+    pub fn capability_registration(&self) -> bool {
+        self.capability_registration.unwrap_or(false)
+    }
+
+    /// Should the bindings let foreign code register a localizer, which the messages of error types
+    /// are routed through?
+    pub fn error_localizer(&self) -> bool {
+        self.error_localizer.unwrap_or(false)
+    }
+
+    /// Should empty lists and maps, and `null`, be passed as empty buffers rather than allocating
+    /// a buffer for them?
+    pub fn empty_buffer_sentinels(&self) -> bool {
+        self.empty_buffer_sentinels.unwrap_or(false)
+    }
+
+    /// How the generated bindings are formatted, see [Formatter].  This overrides whether
+    /// formatting was requested when generating the bindings.
+    pub fn formatter(&self) -> Option<&Formatter> {
+        self.formatter.as_ref()
+    }
+
+    /// Should records and enums implement `java.io.Serializable`?
+    ///
+    /// Defaults to the `serializable_records` option of the `[bindings]` section.
+    pub fn serializable_records(&self) -> bool {
+        self.serializable_records.unwrap_or(false)
+    }
+
+    pub(crate) fn set_default_serializable_records(&mut self, default: bool) {
+        self.serializable_records.get_or_insert(default);
+    }
+
+    /// The `uniffi` runtime version that the bindings check for when they load the library, if
+    /// they're pinned to one.
+    ///
+    /// Defaults to the `runtime_version` option of the `[bindings]` section.
+    pub fn runtime_version(&self) -> Option<RuntimeVersion> {
+        self.runtime_version
+    }
+
+    /// The `uniffi` runtime version that the generated file header documents: the pinned version,
+    /// or else the version that generated the bindings.
+    pub fn required_runtime_version(&self) -> RuntimeVersion {
+        self.runtime_version.unwrap_or_else(RuntimeVersion::bindgen)
+    }
+
+    pub(crate) fn set_default_runtime_version(&mut self, default: Option<RuntimeVersion>) {
+        if self.runtime_version.is_none() {
+            self.runtime_version = default;
+        }
+    }
+
+    /// What happens when names collide after they're converted to Kotlin conventions
+    pub fn name_collisions(&self) -> NameCollisions {
+        self.name_collisions
+    }
+
+    /// Should `Byte`, `Short`, `UByte` and `UShort` literals be converted explicitly, rather than
+    /// relying on Kotlin to infer their type from the declaration?
+    pub fn explicit_integer_literals(&self) -> bool {
+        self.explicit_integer_literals.unwrap_or(false)
+    }
+
+    /// Is this object a builder?  Builder methods that don't return anything return `this` instead,
+    /// so that calls can be chained.
+    pub fn is_builder(&self, object_name: &str) -> bool {
+        self.builders.contains(object_name)
+    }
+
+    /// Whether error types should extend `RuntimeException` rather than `Exception`.
+    ///
+    /// Kotlin doesn't distinguish between them, but Java treats subclasses of `Exception` as
+    /// checked exceptions and requires callers to handle them.
+    pub fn unchecked_exceptions(&self) -> bool {
+        self.unchecked_exceptions.unwrap_or(false)
+    }
+
+    /// The base class of generated error types.
+    pub fn error_base_class(&self) -> &'static str {
+        if self.unchecked_exceptions() {
+            "kotlin.RuntimeException"
+        } else {
+            "kotlin.Exception"
+        }
+    }
+
+    /// Previous names of a top-level function.  Each one gets a deprecated wrapper that calls the
+    /// function by its current name, so that code written against the old name keeps working.
+    pub fn function_aliases(&self, function_name: &str) -> &[String] {
+        self.function_aliases
+            .get(function_name)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Should this object method be generated as an extension function of the object class,
+    /// rather than as a member?  Extension methods aren't part of the object's interface.
+    pub fn is_extension_method(&self, object_name: &str, method_name: &str) -> bool {
+        self.extension_methods
+            .get(object_name)
+            .map_or(false, |methods| methods.iter().any(|m| m == method_name))
+    }
 }
 
 impl BindingsConfig for Config {
@@ -92,6 +358,26 @@ impl BindingsConfig for Config {
 
 // Generate kotlin bindings for the given ComponentInterface, as a string.
 pub fn generate_bindings(config: &Config, ci: &ComponentInterface) -> Result<String> {
+    check_name_collisions(
+        ci,
+        "Kotlin",
+        |kind, nm| match kind {
+            NameKind::Function => KotlinCodeOracle.fn_name(nm),
+            NameKind::Variable => KotlinCodeOracle.var_name(nm),
+            NameKind::EnumVariant => KotlinCodeOracle.enum_variant_name(nm),
+        },
+        &GeneratedNames {
+            function_aliases: ci
+                .function_definitions()
+                .iter()
+                .flat_map(|func| config.function_aliases(func.name()))
+                .map(String::as_str)
+                .collect(),
+            record_methods: vec![],
+            clone_method: Some("copy"),
+        },
+        config.name_collisions(),
+    )?;
     KotlinWrapper::new(config.clone(), ci)
         .render()
         .context("failed to render kotlin bindings")
@@ -276,7 +562,9 @@ impl KotlinCodeOracle {
 
     fn ffi_type_label_by_value(ffi_type: &FfiType) -> String {
         match ffi_type {
-            FfiType::RustBuffer(_) => format!("{}.ByValue", Self::ffi_type_label(ffi_type)),
+            FfiType::RustBuffer(_) | FfiType::Struct(_) => {
+                format!("{}.ByValue", Self::ffi_type_label(ffi_type))
+            }
             _ => Self::ffi_type_label(ffi_type),
         }
     }
@@ -297,6 +585,7 @@ impl KotlinCodeOracle {
                 format!("RustBuffer{}", maybe_suffix.as_deref().unwrap_or_default())
             }
             FfiType::ForeignBytes => "ForeignBytes.ByValue".to_string(),
+            FfiType::Struct(name) => format!("UniFfiStruct{name}"),
             FfiType::ForeignCallback => "ForeignCallback".to_string(),
             FfiType::ForeignExecutorHandle => "USize".to_string(),
             FfiType::ForeignExecutorCallback => "UniFfiForeignExecutorCallback".to_string(),
@@ -335,9 +624,11 @@ impl<T: AsType> AsCodeType for T {
             Type::Boolean => Box::new(primitives::BooleanCodeType),
             Type::String => Box::new(primitives::StringCodeType),
             Type::Bytes => Box::new(primitives::BytesCodeType),
+            Type::ByteArray { len } => Box::new(primitives::FixedBytesCodeType::new(len)),
 
             Type::Timestamp => Box::new(miscellany::TimestampCodeType),
             Type::Duration => Box::new(miscellany::DurationCodeType),
+            Type::JsonValue => Box::new(miscellany::JsonValueCodeType),
 
             Type::Enum { name, .. } => Box::new(enum_::EnumCodeType::new(name)),
             Type::Object { name, .. } => Box::new(object::ObjectCodeType::new(name)),
@@ -356,6 +647,10 @@ impl<T: AsType> AsCodeType for T {
                 key_type,
                 value_type,
             } => Box::new(compounds::MapCodeType::new(*key_type, *value_type)),
+            Type::Range {
+                inner_type,
+                inclusive,
+            } => Box::new(compounds::RangeCodeType::new(*inner_type, inclusive)),
             Type::External { name, .. } => Box::new(external::ExternalCodeType::new(name)),
             Type::Custom { name, .. } => Box::new(custom::CustomCodeType::new(name)),
         }
@@ -441,8 +736,14 @@ pub mod filters {
     pub fn render_literal(
         literal: &Literal,
         as_ct: &impl AsCodeType,
+        config: &Config,
     ) -> Result<String, askama::Error> {
-        Ok(as_ct.as_codetype().literal(literal))
+        let rendered = as_ct.as_codetype().literal(literal);
+        Ok(if config.explicit_integer_literals() {
+            primitives::explicit_integer_literal(literal, rendered)
+        } else {
+            rendered
+        })
     }
 
     /// Get the Kotlin syntax for representing a given low-level `FfiType`.
@@ -454,6 +755,15 @@ pub mod filters {
         Ok(KotlinCodeOracle::ffi_type_label_by_value(type_))
     }
 
+    /// Get the initial value for a primitive field of a JNA `Structure`.
+    pub fn ffi_default_value(type_: &FfiType) -> Result<String, askama::Error> {
+        Ok(match type_ {
+            FfiType::Float32 => "0.0f".to_string(),
+            FfiType::Float64 => "0.0".to_string(),
+            _ => "0".to_string(),
+        })
+    }
+
     // Some FfiTypes have the same ffi_type_label - this makes a vec of them unique.
     pub fn unique_ffi_types(
         types: impl Iterator<Item = FfiType>,
@@ -473,6 +783,18 @@ pub mod filters {
         Ok(KotlinCodeOracle.fn_name(nm))
     }
 
+    /// Get the Kotlin renderings of a function's aliases.  Aliases are used as-is, and any that
+    /// match the function's own Kotlin name are skipped.
+    pub fn fn_aliases(func: &Function) -> Result<Vec<String>, askama::Error> {
+        let name = KotlinCodeOracle.fn_name(func.name());
+        Ok(func
+            .aliases()
+            .iter()
+            .map(|alias| format!("`{alias}`"))
+            .filter(|alias| *alias != name)
+            .collect())
+    }
+
     /// Get the idiomatic Kotlin rendering of a variable name.
     pub fn var_name(nm: &str) -> Result<String, askama::Error> {
         Ok(KotlinCodeOracle.var_name(nm))
@@ -25,6 +25,9 @@ fn render_literal(literal: &Literal) -> String {
     match literal {
         Literal::Boolean(v) => format!("{v}"),
         Literal::String(s) => format!("\"{s}\""),
+        // Kotlin can't write `Long.MIN_VALUE` as a literal, since `9223372036854775808L` is out of
+        // range before being negated.
+        Literal::Int(i64::MIN, _, Type::Int64) => "Long.MIN_VALUE".into(),
         Literal::Int(i, radix, type_) => typed_number(
             type_,
             match radix {
@@ -47,6 +50,27 @@ fn render_literal(literal: &Literal) -> String {
     }
 }
 
+/// Convert a rendered literal so that its type doesn't depend on where it's used.
+///
+/// Kotlin only infers `Byte`, `Short`, `UByte` and `UShort` literals from the expected type, so
+/// these get an explicit conversion.  The other literals already carry a suffix, or have their
+/// type by default.
+pub(super) fn explicit_integer_literal(literal: &Literal, rendered: String) -> String {
+    let conversion = match literal {
+        Literal::Int(_, _, Type::Int8) => "toByte",
+        Literal::Int(_, _, Type::Int16) => "toShort",
+        Literal::UInt(_, _, Type::UInt8) => "toUByte",
+        Literal::UInt(_, _, Type::UInt16) => "toUShort",
+        _ => return rendered,
+    };
+    if rendered.starts_with('-') {
+        // `-1.toByte()` would negate the `Byte`, which results in an `Int`.
+        format!("({rendered}).{conversion}()")
+    } else {
+        format!("{rendered}.{conversion}()")
+    }
+}
+
 macro_rules! impl_code_type_for_primitive {
     ($T:ty, $class_name:literal) => {
         paste! {
@@ -79,3 +103,25 @@ impl_code_type_for_primitive!(UInt32CodeType, "UInt");
 impl_code_type_for_primitive!(UInt64CodeType, "ULong");
 impl_code_type_for_primitive!(Float32CodeType, "Float");
 impl_code_type_for_primitive!(Float64CodeType, "Double");
+
+/// `[u8; N]` uses the same type as `bytes`, with its own converter that checks the length
+#[derive(Debug)]
+pub struct FixedBytesCodeType {
+    len: u32,
+}
+
+impl FixedBytesCodeType {
+    pub fn new(len: u32) -> Self {
+        Self { len }
+    }
+}
+
+impl CodeType for FixedBytesCodeType {
+    fn type_label(&self) -> String {
+        "ByteArray".into()
+    }
+
+    fn canonical_name(&self) -> String {
+        format!("ByteArray{}", self.len)
+    }
+}
@@ -5,7 +5,6 @@
 use anyhow::Result;
 use camino::{Utf8Path, Utf8PathBuf};
 use fs_err as fs;
-use std::process::Command;
 
 pub mod gen_kotlin;
 pub use gen_kotlin::{generate_bindings, Config};
@@ -19,20 +18,18 @@ pub fn write_bindings(
     ci: &ComponentInterface,
     out_dir: &Utf8Path,
     try_format_code: bool,
-) -> Result<()> {
+) -> Result<Vec<Utf8PathBuf>> {
     let mut kt_file = full_bindings_path(config, out_dir);
     fs::create_dir_all(&kt_file)?;
     kt_file.push(format!("{}.kt", ci.namespace()));
     fs::write(&kt_file, generate_bindings(config, ci)?)?;
-    if try_format_code {
-        if let Err(e) = Command::new("ktlint").arg("-F").arg(&kt_file).output() {
-            println!(
-                "Warning: Unable to auto-format {} using ktlint: {e:?}",
-                kt_file.file_name().unwrap(),
-            );
-        }
-    }
-    Ok(())
+    super::format_generated_file(
+        config.formatter(),
+        &["ktlint", "-F"],
+        try_format_code,
+        &kt_file,
+    );
+    Ok(vec![kt_file])
 }
 
 fn full_bindings_path(config: &Config, out_dir: &Utf8Path) -> Utf8PathBuf {
@@ -10,14 +10,57 @@
 {{ self.add_import("kotlinx.coroutines.coroutineScope") }}
 
 // Stores all active future callbacks to ensure they're not GC'ed while waiting for the Rust code to
-// complete the callback
-val uniffiActiveFutureCallbacks = mutableSetOf<Any>()
+// complete the callback.  Callbacks remove themselves on the thread that Rust invokes them from, so
+// this needs to be a concurrent set.
+val uniffiActiveFutureCallbacks: MutableSet<Any> = java.util.Collections.newSetFromMap(java.util.concurrent.ConcurrentHashMap<Any, Boolean>())
+{%- if kotlin_config.async_result() %}
+
+// Runs the body of an async function that returns a `Result`.  Errors of type `E` become a failed
+// `Result`.  Any other exception, in particular the `CancellationException` thrown when the
+// coroutine is cancelled, is rethrown so that cancellation keeps propagating through the caller.
+internal inline fun <T, reified E : Throwable> uniffiCatchResult(body: () -> T): kotlin.Result<T> {
+    return try {
+        kotlin.Result.success(body())
+    } catch (e: Throwable) {
+        if (e is E) kotlin.Result.failure(e) else throw e
+    }
+}
+{%- endif %}
+{%- if kotlin_config.async_cancellation() %}
+{%- match ci.ffi_rust_future_cancel() %}
+{%- when Some(cancel_func) %}
+
+// Each async call gets a unique callbackData value, which identifies the call when cancelling its
+// Rust future
+private val uniffiNextFutureCallbackData = java.util.concurrent.atomic.AtomicLong(1)
+
+// Create the callbackData for an async call and mark the call as cancellable.  Rust only tracks
+// the futures of marked calls, and the mark applies to the next scaffolding call on this thread.
+internal fun uniffiNewFutureCallbackData(): USize {
+    val callbackData = USize(uniffiNextFutureCallbackData.getAndIncrement())
+    rustCall { status ->
+        _UniFFILib.INSTANCE.{{ ci.ffi_rust_future_cancellable().unwrap().name() }}(callbackData, status)
+    }
+    return callbackData
+}
+
+// Cancel the Rust future for an async call.  Rust drops the future and invokes the callback handler,
+// which removes itself from `uniffiActiveFutureCallbacks`.
+internal fun uniffiCancelRustFuture(callbackData: USize) {
+    rustCall { status ->
+        _UniFFILib.INSTANCE.{{ cancel_func.name() }}(callbackData, status)
+    }
+}
+{%- when None %}
+{%- endmatch %}
+{%- endif %}
 
 // FFI type for callback handlers
 {%- for callback_param in ci.iter_future_callback_params()|unique_ffi_types %}
 internal interface UniFfiFutureCallback{{ callback_param|ffi_type_name }} : com.sun.jna.Callback {
-    // Note: callbackData is always 0.  We could pass Rust a pointer/usize to represent the
-    // continuation, but with JNA it's easier to just store it in the callback handler.
+    // Note: callbackData is only used to identify the call when cancelling it.  We could pass Rust
+    // a pointer/usize to represent the continuation, but with JNA it's easier to just store it in
+    // the callback handler.
     fun callback(_callbackData: USize, returnValue: {{ callback_param|ffi_type_name_by_value }}?, callStatus: RustCallStatus.ByValue);
 }
 {%- endfor %}
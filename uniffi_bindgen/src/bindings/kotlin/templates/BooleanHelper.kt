@@ -1,6 +1,10 @@
 public object FfiConverterBoolean: FfiConverter<Boolean, Byte> {
     override fun lift(value: Byte): Boolean {
+        {%- if kotlin_config.buffer_checks().is_strict() %}
+        return uniffiCheckReadTag(value)
+        {%- else %}
         return value.toInt() != 0
+        {%- endif %}
     }
 
     override fun read(buf: ByteBuffer): Boolean {
@@ -1,6 +1,9 @@
 public object FfiConverterByteArray: FfiConverterRustBuffer<ByteArray> {
     override fun read(buf: ByteBuffer): ByteArray {
         val len = buf.getInt()
+        {%- if kotlin_config.buffer_checks().is_strict() %}
+        uniffiCheckReadLength(len, 1, buf)
+        {%- endif %}
         val byteArr = ByteArray(len)
         buf.get(byteArr)
         return byteArr
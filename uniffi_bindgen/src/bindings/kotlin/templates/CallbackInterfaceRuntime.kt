@@ -1,4 +1,42 @@
 internal typealias Handle = Long
+{%- if kotlin_config.handle_map().is_slab() %}
+// A slab of slots indexed by handle.  Freed slots are reused, so memory stays flat when many
+// callback objects are created and destroyed.
+internal class SlabHandleMap<T> {
+    private val lock = java.util.concurrent.locks.ReentrantLock()
+    private val slots = ArrayList<T?>()
+    private val freeSlots = ArrayDeque<Int>()
+
+    fun insert(obj: T): Handle =
+        lock.withLock {
+            val index = freeSlots.removeLastOrNull()
+            if (index != null) {
+                slots[index] = obj
+                index.toLong()
+            } else {
+                slots.add(obj)
+                (slots.size - 1).toLong()
+            }
+        }
+
+    fun get(handle: Handle) = lock.withLock {
+        slots.getOrNull(handle.toInt())
+    }
+
+    fun delete(handle: Handle) {
+        this.remove(handle)
+    }
+
+    fun remove(handle: Handle): T? =
+        lock.withLock {
+            val index = handle.toInt()
+            slots.getOrNull(index)?.also {
+                slots[index] = null
+                freeSlots.addLast(index)
+            }
+        }
+}
+{%- else %}
 internal class ConcurrentHandleMap<T>(
     private val leftMap: MutableMap<Handle, T> = mutableMapOf(),
     private val rightMap: MutableMap<T, Handle> = mutableMapOf()
@@ -33,6 +71,7 @@ internal class ConcurrentHandleMap<T>(
             }
         }
 }
+{%- endif %}
 
 interface ForeignCallback : com.sun.jna.Callback {
     public fun callback(handle: Handle, method: Int, argsData: Pointer, argsLen: Int, outBuf: RustBufferByReference): Int
@@ -49,7 +88,7 @@ internal const val UNIFFI_CALLBACK_UNEXPECTED_ERROR = 2
 public abstract class FfiConverterCallbackInterface<CallbackInterface>(
     protected val foreignCallback: ForeignCallback
 ): FfiConverter<CallbackInterface, Handle> {
-    private val handleMap = ConcurrentHandleMap<CallbackInterface>()
+    private val handleMap = {% if kotlin_config.handle_map().is_slab() %}SlabHandleMap{% else %}ConcurrentHandleMap{% endif %}<CallbackInterface>()
 
     // Registers the foreign callback with the Rust side.
     // This method is generated for each callback interface.
@@ -11,7 +11,7 @@
 
 public interface {{ type_name }} {
     {% for meth in cbi.methods() -%}
-    fun {{ meth.name()|fn_name }}({% call kt::arg_list_decl(meth) %})
+    fun {{ meth.name()|fn_name }}({% call kt::arg_list_decl(meth, kotlin_config) %})
     {%- match meth.return_type() -%}
     {%- when Some with (return_type) %}: {{ return_type|type_name -}}
     {%- else -%}
@@ -0,0 +1,8 @@
+// Decide if the capabilities of functions exported with `requires_capability` are available.
+//
+// This replaces the predicate that the Rust code registered for the capability, if any.  Calling
+// a function whose capability isn't available throws the function's error instead.
+public fun uniffiSetCapabilityAvailable(name: String, available: Boolean) {
+    val nameData = name.toByteArray(Charsets.UTF_8)
+    _UniFFILib.INSTANCE.ffi_{{ ci.ffi_namespace() }}_capability_set(nameData, nameData.size, (if (available) 1 else 0).toByte())
+}
@@ -1,3 +1,15 @@
+{%- let duration_type = kotlin_config.duration_type() %}
+/**
+ * The Kotlin type that durations are mapped to, which is set by the `duration_type` config option
+ *
+ * This is internal so that components in the same package don't clash when they're in different
+ * modules.
+ */
+internal typealias UniffiDuration = {{ duration_type.type_name() }}
+
+{%- match duration_type %}
+{%- when DurationType::Java %}
+
 public object FfiConverterDuration: FfiConverterRustBuffer<java.time.Duration> {
     override fun read(buf: ByteBuffer): java.time.Duration {
         // Type mismatch (should be u64) but we check for overflow/underflow below
@@ -34,3 +46,75 @@ public object FfiConverterDuration: FfiConverterRustBuffer<java.time.Duration> {
         buf.putInt(value.nano)
     }
 }
+{%- when DurationType::Kotlin %}
+{{- self.add_import("kotlin.time.DurationUnit") }}
+{{- self.add_import("kotlin.time.toDuration") }}
+
+public object FfiConverterDuration: FfiConverterRustBuffer<kotlin.time.Duration> {
+    override fun read(buf: ByteBuffer): kotlin.time.Duration {
+        // Type mismatch (should be u64) but we check for overflow/underflow below
+        val seconds = buf.getLong()
+        // Type mismatch (should be u32) but we check for overflow/underflow below
+        val nanoseconds = buf.getInt()
+        if (seconds < 0) {
+            throw InternalException("Duration exceeds minimum or maximum value supported by uniffi")
+        }
+        if (nanoseconds < 0) {
+            throw InternalException("Duration nanoseconds exceed minimum or maximum supported by uniffi")
+        }
+        val duration = seconds.toDuration(DurationUnit.SECONDS) + nanoseconds.toDuration(DurationUnit.NANOSECONDS)
+        // Kotlin durations longer than about 146 years only have millisecond precision, fail rather
+        // than silently rounding them.
+        duration.toComponents { s, ns ->
+            if (s != seconds || ns != nanoseconds) {
+                throw InternalException("Duration of ${seconds}s ${nanoseconds}ns can't be represented exactly by kotlin.time.Duration")
+            }
+        }
+        return duration
+    }
+
+    // 8 bytes for seconds, 4 bytes for nanoseconds
+    override fun allocationSize(value: kotlin.time.Duration) = 12
+
+    override fun write(value: kotlin.time.Duration, buf: ByteBuffer) {
+        if (value.isNegative()) {
+            // Rust does not support negative Durations
+            throw IllegalArgumentException("Invalid duration, must be non-negative")
+        }
+        if (value.isInfinite()) {
+            throw IllegalArgumentException("Invalid duration, must be finite")
+        }
+        value.toComponents { seconds, nanoseconds ->
+            buf.putLong(seconds)
+            buf.putInt(nanoseconds)
+        }
+    }
+}
+{%- when DurationType::Nanos %}
+
+// Durations are passed as a `Long` number of nanoseconds
+public object FfiConverterDuration: FfiConverterRustBuffer<Long> {
+    override fun read(buf: ByteBuffer): Long {
+        // Type mismatch (should be u64) but we check for overflow/underflow below
+        val seconds = buf.getLong()
+        // Type mismatch (should be u32) but we check for overflow/underflow below
+        val nanoseconds = buf.getInt().toLong()
+        if (seconds < 0 || nanoseconds < 0 || seconds > (Long.MAX_VALUE - nanoseconds) / 1_000_000_000L) {
+            throw InternalException("Duration of ${seconds.toULong()}s ${nanoseconds}ns doesn't fit in a Long of nanoseconds")
+        }
+        return seconds * 1_000_000_000L + nanoseconds
+    }
+
+    // 8 bytes for seconds, 4 bytes for nanoseconds
+    override fun allocationSize(value: Long) = 12
+
+    override fun write(value: Long, buf: ByteBuffer) {
+        if (value < 0) {
+            // Rust does not support negative Durations
+            throw IllegalArgumentException("Invalid duration, must be non-negative")
+        }
+        buf.putLong(value / 1_000_000_000L)
+        buf.putInt((value % 1_000_000_000L).toInt())
+    }
+}
+{%- endmatch %}
@@ -3,9 +3,39 @@
 // but is a little nicer for consumers than its `sealed class` enum pattern.
 // So, we switch here, using `enum class` for enums with no associated data
 // and `sealed class` for the general case.
+//
+// Flags are different again: they're a set of named bits, so we wrap the integer in a
+// `data class` with the flags as constants and the usual bitwise operators.
 #}
 
-{%- if e.is_flat() %}
+{%- let is_serializable = kotlin_config.serializable_records() && ci.item_is_serializable(type_) %}
+
+{%- if e.is_flags() %}
+{%- let flags_type = e.flags_type().unwrap() %}
+
+data class {{ type_name }}(val bits: {{ flags_type|type_name }}){% if is_serializable %} : java.io.Serializable{% endif %} {
+    infix fun or(other: {{ type_name }}) = {{ type_name }}(bits or other.bits)
+    infix fun and(other: {{ type_name }}) = {{ type_name }}(bits and other.bits)
+    operator fun contains(other: {{ type_name }}) = (bits and other.bits) == other.bits
+
+    companion object {
+        {%- for variant in e.variants() %}
+        val {{ variant|variant_name }} = {{ type_name }}({{ variant.flag_value().unwrap() }}uL.to{{ flags_type|type_name }}())
+        {%- endfor %}
+    }
+}
+
+public object {{ e|ffi_converter_name }}: FfiConverterRustBuffer<{{ type_name }}> {
+    override fun read(buf: ByteBuffer) = {{ type_name }}({{ flags_type|read_fn }}(buf))
+
+    override fun allocationSize(value: {{ type_name }}) = {{ flags_type|allocation_size_fn }}(value.bits)
+
+    override fun write(value: {{ type_name }}, buf: ByteBuffer) {
+        {{ flags_type|write_fn }}(value.bits, buf)
+    }
+}
+
+{% else if e.is_flat() %}
 
 enum class {{ type_name }} {
     {% for variant in e.variants() -%}
@@ -29,10 +59,15 @@ public object {{ e|ffi_converter_name }}: FfiConverterRustBuffer<{{ type_name }}
 
 {% else %}
 
-sealed class {{ type_name }}{% if contains_object_references %}: Disposable {% endif %} {
+sealed class {{ type_name }}{% if contains_object_references %}: Disposable {% else if is_serializable %}: java.io.Serializable {% endif %} {
     {% for variant in e.variants() -%}
     {% if !variant.has_fields() -%}
     object {{ variant|enum_variant|type_name }} : {{ type_name }}()
+    {%- if is_serializable %} {
+        // Keep the object a singleton when it's deserialized
+        private fun readResolve(): Any = {{ variant|enum_variant|type_name }}
+    }
+    {%- endif %}
     {% else -%}
     data class {{ variant|enum_variant|type_name }}(
         {% for field in variant.fields() -%}
@@ -62,7 +97,7 @@ sealed class {{ type_name }}{% if contains_object_references %}: Disposable {% e
 
 public object {{ e|ffi_converter_name }} : FfiConverterRustBuffer<{{ type_name }}>{
     override fun read(buf: ByteBuffer): {{ type_name }} {
-        return when(buf.getInt()) {
+        return {% call kt::nested_start(kotlin_config) %}when(buf.getInt()) {
             {%- for variant in e.variants() %}
             {{ loop.index }} -> {{ type_name }}.{{ variant|enum_variant|type_name }}{% if variant.has_fields() %}(
                 {% for field in variant.fields() -%}
@@ -71,10 +106,10 @@ public object {{ e|ffi_converter_name }} : FfiConverterRustBuffer<{{ type_name }
             ){%- endif -%}
             {%- endfor %}
             else -> throw RuntimeException("invalid enum value, something is very wrong!!")
-        }
+        }{% call kt::nested_end(kotlin_config) %}
     }
 
-    override fun allocationSize(value: {{ type_name }}) = when(value) {
+    override fun allocationSize(value: {{ type_name }}) = {% call kt::nested_start(kotlin_config) %}when(value) {
         {%- for variant in e.variants() %}
         is {{ type_name }}.{{ variant|enum_variant|type_name }} -> {
             // Add the size for the Int that specifies the variant plus the size needed for all fields
@@ -86,10 +121,10 @@ public object {{ e|ffi_converter_name }} : FfiConverterRustBuffer<{{ type_name }
             )
         }
         {%- endfor %}
-    }
+    }{% call kt::nested_end(kotlin_config) %}
 
     override fun write(value: {{ type_name }}, buf: ByteBuffer) {
-        when(value) {
+        {% call kt::nested_start(kotlin_config) %}when(value) {
             {%- for variant in e.variants() %}
             is {{ type_name }}.{{ variant|enum_variant|type_name }} -> {
                 buf.putInt({{ loop.index }})
@@ -99,7 +134,7 @@ public object {{ e|ffi_converter_name }} : FfiConverterRustBuffer<{{ type_name }
                 Unit
             }
             {%- endfor %}
-        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
+        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }{% call kt::nested_end(kotlin_config) %}
     }
 }
 
@@ -0,0 +1,28 @@
+// Localize the messages of errors from Rust.
+//
+// Once a localizer is registered with `uniffiSetErrorLocalizer()`, the `message` of every error
+// type is routed through it.
+
+public fun interface UniFfiErrorLocalizer {
+    // Return the localized message for an error variant, or `null` to keep the default message.
+    //
+    // `errorName` and `variantName` are the names of the Rust enum and variant, so that they're the
+    // same in all the bindings.  The default message is the Rust message for flat errors, and a
+    // list of the fields otherwise.
+    fun localize(errorName: String, variantName: String, message: String): String?
+}
+
+internal object UniFfiErrorLocalizerHolder {
+    @Volatile
+    var localizer: UniFfiErrorLocalizer? = null
+}
+
+internal fun uniffiLocalizeError(errorName: String, variantName: String, message: String): String {
+    val localizer = UniFfiErrorLocalizerHolder.localizer ?: return message
+    return localizer.localize(errorName, variantName, message) ?: message
+}
+
+// Register the localizer for error messages, or pass `null` to stop localizing them.
+public fun uniffiSetErrorLocalizer(localizer: UniFfiErrorLocalizer?) {
+    UniFfiErrorLocalizerHolder.localizer = localizer
+}
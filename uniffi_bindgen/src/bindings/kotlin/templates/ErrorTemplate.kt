@@ -3,11 +3,16 @@
 {%- let canonical_type_name = type_|error_canonical_name %}
 
 {% if e.is_flat() %}
-sealed class {{ type_name }}(message: String): Exception(message){% if contains_object_references %}, Disposable {% endif %} {
+sealed class {{ type_name }}(message: String): {{ kotlin_config.error_base_class() }}(message){% if contains_object_references %}, Disposable {% endif %} {
         // Each variant is a nested class
         // Flat enums carries a string error message, so no special implementation is necessary.
         {% for variant in e.variants() -%}
         class {{ variant|error_variant|type_name }}(message: String) : {{ type_name }}(message)
+        {%- if kotlin_config.error_localizer() %} {
+            override val message: String
+                get() = uniffiLocalizeError("{{ e.name() }}", "{{ variant.name() }}", super.message ?: "")
+        }
+        {%- endif %}
         {% endfor %}
 
     companion object ErrorHandler : CallStatusErrorHandler<{{ type_name }}> {
@@ -15,7 +20,7 @@ sealed class {{ type_name }}(message: String): Exception(message){% if contains_
     }
 }
 {%- else %}
-sealed class {{ type_name }}: Exception(){% if contains_object_references %}, Disposable {% endif %} {
+sealed class {{ type_name }}: {{ kotlin_config.error_base_class() }}(){% if contains_object_references %}, Disposable {% endif %} {
     // Each variant is a nested class
     {% for variant in e.variants() -%}
     {%- let variant_name = variant|error_variant|type_name %}
@@ -25,7 +30,11 @@ sealed class {{ type_name }}: Exception(){% if contains_object_references %}, Di
         {% endfor -%}
     ) : {{ type_name }}() {
         override val message
+            {%- if kotlin_config.error_localizer() %}
+            get() = uniffiLocalizeError("{{ e.name() }}", "{{ variant.name() }}", "{%- for field in variant.fields() %}{{ field.name()|var_name|unquote }}=${ {{field.name()|var_name }} }{% if !loop.last %}, {% endif %}{% endfor %}")
+            {%- else %}
             get() = "{%- for field in variant.fields() %}{{ field.name()|var_name|unquote }}=${ {{field.name()|var_name }} }{% if !loop.last %}, {% endif %}{% endfor %}"
+            {%- endif %}
     }
     {% endfor %}
 
@@ -63,7 +72,7 @@ public object {{ e|ffi_converter_name }} : FfiConverterRustBuffer<{{ type_name }
         }
         {% else %}
 
-        return when(buf.getInt()) {
+        return {% call kt::nested_start(kotlin_config) %}when(buf.getInt()) {
             {%- for variant in e.variants() %}
             {{ loop.index }} -> {{ type_name }}.{{ variant|error_variant|type_name }}({% if variant.has_fields() %}
                 {% for field in variant.fields() -%}
@@ -72,7 +81,7 @@ public object {{ e|ffi_converter_name }} : FfiConverterRustBuffer<{{ type_name }
             {%- endif -%})
             {%- endfor %}
             else -> throw RuntimeException("invalid error enum value, something is very wrong!!")
-        }
+        }{% call kt::nested_end(kotlin_config) %}
         {%- endif %}
     }
 
@@ -80,7 +89,7 @@ public object {{ e|ffi_converter_name }} : FfiConverterRustBuffer<{{ type_name }
         {%- if e.is_flat() %}
         return 4
         {%- else %}
-        return when(value) {
+        return {% call kt::nested_start(kotlin_config) %}when(value) {
             {%- for variant in e.variants() %}
             is {{ type_name }}.{{ variant|error_variant|type_name }} -> (
                 // Add the size for the Int that specifies the variant plus the size needed for all fields
@@ -90,12 +99,12 @@ public object {{ e|ffi_converter_name }} : FfiConverterRustBuffer<{{ type_name }
                 {%- endfor %}
             )
             {%- endfor %}
-        }
+        }{% call kt::nested_end(kotlin_config) %}
         {%- endif %}
     }
 
     override fun write(value: {{ type_name }}, buf: ByteBuffer) {
-        when(value) {
+        {% call kt::nested_start(kotlin_config) %}when(value) {
             {%- for variant in e.variants() %}
             is {{ type_name }}.{{ variant|error_variant|type_name }} -> {
                 buf.putInt({{ loop.index }})
@@ -105,7 +114,7 @@ public object {{ e|ffi_converter_name }} : FfiConverterRustBuffer<{{ type_name }
                 Unit
             }
             {%- endfor %}
-        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
+        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }{% call kt::nested_end(kotlin_config) %}
     }
 
 }
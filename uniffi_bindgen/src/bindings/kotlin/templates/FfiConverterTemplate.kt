@@ -58,6 +58,10 @@ public interface FfiConverter<KotlinType, FfiType> {
                throw RuntimeException("junk remaining in buffer after lifting, something is very wrong!!")
            }
            return item
+        {%- if config.buffer_checks().is_strict() %}
+        } catch (e: java.nio.BufferUnderflowException) {
+            throw InternalException("Reading the requested value would read past the end of the buffer")
+        {%- endif %}
         } finally {
             RustBuffer.free(rbuf)
         }
@@ -0,0 +1,25 @@
+// `[u8; {{ len }}]` is a `ByteArray` that must have exactly {{ len }} bytes
+public object {{ ffi_converter_name }}: FfiConverterRustBuffer<ByteArray> {
+    override fun read(buf: ByteBuffer): ByteArray {
+        val len = buf.getInt()
+        if (len != {{ len }}) {
+            throw InternalException("Expected a byte array of length {{ len }}, got $len bytes")
+        }
+        {%- if kotlin_config.buffer_checks().is_strict() %}
+        uniffiCheckReadLength(len, 1, buf)
+        {%- endif %}
+        val byteArr = ByteArray(len)
+        buf.get(byteArr)
+        return byteArr
+    }
+    override fun allocationSize(value: ByteArray): Int {
+        return 4 + value.size
+    }
+    override fun write(value: ByteArray, buf: ByteBuffer) {
+        require(value.size == {{ len }}) {
+            "Expected a byte array of length {{ len }}, got ${value.size} bytes"
+        }
+        buf.putInt(value.size)
+        buf.put(value)
+    }
+}
@@ -19,9 +19,58 @@ internal open class RustCallStatus : Structure() {
     fun isPanic(): Boolean {
         return code == 2.toByte()
     }
+
+    fun isCancelled(): Boolean {
+        return code == 3.toByte()
+    }
 }
 
 class InternalException(message: String) : Exception(message)
+{%- if config.buffer_checks().is_strict() %}
+
+// Check a length prefix read from a buffer before using it, so that a malformed buffer can't make
+// us allocate huge amounts of memory.  Each item takes at least `minItemSize` bytes in the buffer.
+internal fun uniffiCheckReadLength(len: Int, minItemSize: Int, buf: ByteBuffer) {
+    if (len < 0 || len.toLong() * minItemSize > buf.remaining()) {
+        throw InternalException("Invalid length $len, with ${buf.remaining()} bytes remaining in the buffer")
+    }
+}
+
+// Check a boolean or optional tag, which must be 0 or 1
+internal fun uniffiCheckReadTag(tag: Byte): Boolean {
+    return when (tag.toInt()) {
+        0 -> false
+        1 -> true
+        else -> throw InternalException("Unexpected tag $tag, should be 0 or 1")
+    }
+}
+{%- endif %}
+
+{%- match config.max_nesting_depth() %}
+{%- when Some with(max_depth) %}
+
+// How deeply records and enums are nested in the value that's currently being read or written
+// on this thread.
+internal val uniffiNestingDepth = object : ThreadLocal<IntArray>() {
+    override fun initialValue() = IntArray(1)
+}
+
+// Run `block` one level of nesting deeper, so that deeply nested values fail cleanly rather than
+// overflowing the stack.
+internal inline fun <T> uniffiNested(block: () -> T): T {
+    val depth = uniffiNestingDepth.get()
+    if (depth[0] >= {{ max_depth }}) {
+        throw InternalException("Values can't be nested more than {{ max_depth }} levels deep")
+    }
+    depth[0] += 1
+    try {
+        return block()
+    } finally {
+        depth[0] -= 1
+    }
+}
+{%- else %}
+{%- endmatch %}
 
 // Each top-level error class has a companion object that can lift the error from the call status's rust buffer
 interface CallStatusErrorHandler<E> {
@@ -55,6 +104,8 @@ private fun<E: Exception> checkCallStatus(errorHandler: CallStatusErrorHandler<E
         } else {
             throw InternalException("Rust panic")
         }
+    } else if (status.isCancelled()) {
+        throw kotlin.coroutines.cancellation.CancellationException("Rust future cancelled")
     } else {
         throw InternalException("Unknown rust call status: $status.code")
     }
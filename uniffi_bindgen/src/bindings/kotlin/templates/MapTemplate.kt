@@ -1,10 +1,31 @@
 {%- let key_type_name = key_type|type_name %}
 {%- let value_type_name = value_type|type_name %}
 public object {{ ffi_converter_name }}: FfiConverterRustBuffer<Map<{{ key_type_name }}, {{ value_type_name }}>> {
+    // Rust can pass empty maps as an empty buffer, see `empty_buffer_sentinels`.  These aren't allocated,
+    // so there's nothing to free.
+    override fun lift(value: RustBuffer.ByValue): Map<{{ key_type_name }}, {{ value_type_name }}> {
+        if (value.len == 0) {
+            return mapOf()
+        }
+        return liftFromRustBuffer(value)
+    }
+    {%- if kotlin_config.empty_buffer_sentinels() %}
+
+    override fun lower(value: Map<{{ key_type_name }}, {{ value_type_name }}>): RustBuffer.ByValue {
+        if (value.isEmpty()) {
+            return RustBuffer.ByValue()
+        }
+        return lowerIntoRustBuffer(value)
+    }
+    {%- endif %}
+
     override fun read(buf: ByteBuffer): Map<{{ key_type_name }}, {{ value_type_name }}> {
         // TODO: Once Kotlin's `buildMap` API is stabilized we should use it here.
         val items : MutableMap<{{ key_type_name }}, {{ value_type_name }}> = mutableMapOf()
         val len = buf.getInt()
+        {%- if kotlin_config.buffer_checks().is_strict() %}
+        uniffiCheckReadLength(len, {{ ci.min_serialized_size(key_type) + ci.min_serialized_size(value_type) }}, buf)
+        {%- endif %}
         repeat(len) {
             val k = {{ key_type|read_fn }}(buf)
             val v = {{ value_type|read_fn }}(buf)
@@ -22,7 +22,21 @@ internal interface _UniFFILib : Library {
             loadIndirect<_UniFFILib>(componentName = "{{ ci.namespace() }}")
             .also { lib: _UniFFILib ->
                 uniffiCheckContractApiVersion(lib)
+                {%- if config.runtime_version().is_some() %}
+                uniffiCheckRuntimeVersion(lib)
+                {%- endif %}
                 uniffiCheckApiChecksums(lib)
+                {%- if config.string_encoding().is_utf16() %}
+                uniffiSetStringEncoding(lib)
+                {%- endif %}
+                {%- if config.empty_buffer_sentinels() %}
+                lib.ffi_{{ ci.ffi_namespace() }}_empty_buffer_sentinels_enable()
+                {%- endif %}
+                {%- match config.max_nesting_depth() %}
+                {%- when Some with(max_depth) %}
+                lib.ffi_{{ ci.ffi_namespace() }}_max_nesting_depth_set({{ max_depth }})
+                {%- else %}
+                {%- endmatch %}
                 {% for fn in self.initialization_fns() -%}
                 {{ fn }}(lib)
                 {% endfor -%}
@@ -35,6 +49,27 @@ internal interface _UniFFILib : Library {
         {%- call kt::arg_list_ffi_decl(func) %}
     ): {% match func.return_type() %}{% when Some with (return_type) %}{{ return_type.borrow()|ffi_type_name_by_value }}{% when None %}Unit{% endmatch %}
     {% endfor %}
+    {%- if config.string_encoding().is_utf16() %}
+    fun ffi_{{ ci.ffi_namespace() }}_string_encoding_set(encoding: Byte): Byte
+    {%- endif %}
+    {%- if config.runtime_version().is_some() %}
+    fun ffi_{{ ci.ffi_namespace() }}_uniffi_runtime_version(): Int
+    {%- endif %}
+    {%- if config.span_sink() %}
+    fun ffi_{{ ci.ffi_namespace() }}_span_sink_set(callback: UniFfiSpanSinkCallback): Unit
+    {%- endif %}
+    {%- if config.panic_observer() %}
+    fun ffi_{{ ci.ffi_namespace() }}_panic_observer_set(callback: UniFfiPanicObserverCallback): Unit
+    {%- endif %}
+    {%- if config.empty_buffer_sentinels() %}
+    fun ffi_{{ ci.ffi_namespace() }}_empty_buffer_sentinels_enable(): Unit
+    {%- endif %}
+    {%- if config.max_nesting_depth().is_some() %}
+    fun ffi_{{ ci.ffi_namespace() }}_max_nesting_depth_set(maxDepth: Int): Unit
+    {%- endif %}
+    {%- if config.capability_registration() %}
+    fun ffi_{{ ci.ffi_namespace() }}_capability_set(nameData: ByteArray, nameLen: Int, available: Byte): Unit
+    {%- endif %}
 }
 
 private fun uniffiCheckContractApiVersion(lib: _UniFFILib) {
@@ -47,6 +82,28 @@ private fun uniffiCheckContractApiVersion(lib: _UniFFILib) {
     }
 }
 
+{%- match config.runtime_version() %}
+{%- when Some with (runtime_version) %}
+
+private fun uniffiCheckRuntimeVersion(lib: _UniFFILib) {
+    // The version is packed as `major * 1_000_000 + minor * 1_000 + patch`
+    val version = lib.ffi_{{ ci.ffi_namespace() }}_uniffi_runtime_version()
+    if (version < {{ runtime_version.packed_min() }} || version > {{ runtime_version.packed_max() }}) {
+        throw RuntimeException("UniFFI runtime version mismatch: these bindings need uniffi {{ runtime_version }}, but the library was built with uniffi ${version / 1000000}.${version / 1000 % 1000}.${version % 1000}")
+    }
+}
+{%- when None %}
+{%- endmatch %}
+{%- if config.string_encoding().is_utf16() %}
+
+private fun uniffiSetStringEncoding(lib: _UniFFILib) {
+    // Make Rust encode strings the same way that `FfiConverterString` does
+    if (lib.ffi_{{ ci.ffi_namespace() }}_string_encoding_set({{ config.string_encoding().ffi_value() }}.toByte()) != 1.toByte()) {
+        throw RuntimeException("UniFFI string encoding mismatch: the library was already loaded by bindings that use a different `string_encoding`")
+    }
+}
+{%- endif %}
+
 @Suppress("UNUSED_PARAMETER")
 private fun uniffiCheckApiChecksums(lib: _UniFFILib) {
     {%- for (name, expected_checksum) in ci.iter_checksums() %}
@@ -55,3 +112,63 @@ private fun uniffiCheckApiChecksums(lib: _UniFFILib) {
     }
     {%- endfor %}
 }
+{%- if config.handshake() %}
+
+/**
+ * The contract version and interface fingerprint of a Rust library
+ *
+ * Two libraries with the same values are interchangeable as far as these bindings are concerned.
+ */
+data class UniffiHandshake(val contractVersion: Int, val fingerprint: Int) {
+    companion object {
+        /**
+         * The values that these bindings were generated for
+         */
+        val EXPECTED = UniffiHandshake({{ ci.uniffi_contract_version() }}, {{ ci.interface_fingerprint() }}.toInt())
+    }
+}
+
+// The library, without the checks that `_UniFFILib.INSTANCE` runs when it loads it
+private val uniffiHandshakeLib: _UniFFILib by lazy {
+    loadIndirect<_UniFFILib>(componentName = "{{ ci.namespace() }}")
+}
+
+/**
+ * Read the contract version and interface fingerprint of the loaded Rust library
+ *
+ * Unlike every other call into the library, this doesn't check that the library matches the
+ * bindings first.
+ */
+fun uniffiHandshake(): UniffiHandshake {
+    val lib = uniffiHandshakeLib
+    var fingerprint = 0
+    {%- for (name, _) in ci.iter_checksums() %}
+    fingerprint = fingerprint * 31 + (lib.{{ name }}().toInt() and 0xffff)
+    {%- endfor %}
+    return UniffiHandshake(lib.{{ ci.ffi_uniffi_contract_version().name() }}(), fingerprint)
+}
+
+/**
+ * Check that the loaded Rust library is compatible with these bindings
+ *
+ * Call this at startup to fail fast, with a message that says what doesn't match.
+ *
+ * @throws RuntimeException if the library isn't compatible
+ */
+fun uniffiCheckHandshake() {
+    val actual = try {
+        uniffiHandshake()
+    } catch (e: UnsatisfiedLinkError) {
+        throw RuntimeException("The Rust library for `{{ ci.namespace() }}` doesn't export the functions that these bindings use: ${e.message}", e)
+    }
+    val expected = UniffiHandshake.EXPECTED
+    if (actual != expected) {
+        throw RuntimeException(
+            "The Rust library for `{{ ci.namespace() }}` isn't compatible with these bindings: " +
+            "the bindings expect contract version ${expected.contractVersion} and interface fingerprint ${expected.fingerprint.toUInt()}, " +
+            "but the library has contract version ${actual.contractVersion} and interface fingerprint ${actual.fingerprint.toUInt()}. " +
+            "Regenerate the bindings from the library that you ship."
+        )
+    }
+}
+{%- endif %}
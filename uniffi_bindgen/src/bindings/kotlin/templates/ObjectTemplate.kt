@@ -1,24 +1,31 @@
 {%- let obj = ci|get_object_definition(name) %}
+{%- let is_builder = kotlin_config.is_builder(name) %}
 {%- if self.include_once_check("ObjectRuntime.kt") %}{% include "ObjectRuntime.kt" %}{% endif %}
 {{- self.add_import("java.util.concurrent.atomic.AtomicLong") }}
 {{- self.add_import("java.util.concurrent.atomic.AtomicBoolean") }}
 
 public interface {{ type_name }}Interface {
     {% for meth in obj.methods() -%}
+    {%- if !kotlin_config.is_extension_method(name, meth.name()) -%}
     {%- match meth.throws_type() -%}
     {%- when Some with (throwable) -%}
     @Throws({{ throwable|error_type_name }}::class)
     {%- when None -%}
     {%- endmatch %}
     {% if meth.is_async() -%}
-    suspend fun {{ meth.name()|fn_name }}({% call kt::arg_list_decl(meth) %})
+    suspend fun {{ meth.name()|fn_name }}({% call kt::arg_list_decl(meth, kotlin_config) %}{%- if kotlin_config.async_executor().is_per_call() %}{% if !meth.arguments().is_empty() %}, {% endif %}uniffiExecutor: CoroutineScope{% endif %})
     {%- else -%}
-    fun {{ meth.name()|fn_name }}({% call kt::arg_list_decl(meth) %})
+    fun {{ meth.name()|fn_name }}({% call kt::arg_list_decl(meth, kotlin_config) %})
     {%- endif %}
+    {%- if meth.is_async() && kotlin_config.async_result() && meth.throws_type().is_some() %}: kotlin.Result<{% call kt::result_ok_type(meth) %}>
+    {%- else %}
     {%- match meth.return_type() -%}
     {%- when Some with (return_type) %}: {{ return_type|type_name -}}
     {%- when None -%}
+    {%- if is_builder && !meth.is_async() %}: {{ type_name }}{% endif -%}
     {%- endmatch -%}
+    {%- endif %}
+    {%- endif %}
 
     {% endfor %}
 }
@@ -29,7 +36,7 @@ class {{ type_name }}(
 
     {%- match obj.primary_constructor() %}
     {%- when Some with (cons) %}
-    constructor({% call kt::arg_list_decl(cons) -%}) :
+    constructor({% call kt::arg_list_decl(cons, kotlin_config) -%}) :
         this({% call kt::to_ffi_call(cons) %})
     {%- when None %}
     {%- endmatch %}
@@ -49,69 +56,91 @@ class {{ type_name }}(
     }
 
     {% for meth in obj.methods() -%}
-    {%- match meth.throws_type() -%}
-    {%- when Some with (throwable) %}
-    @Throws({{ throwable|error_type_name }}::class)
-    {%- else -%}
-    {%- endmatch -%}
-    {%- if meth.is_async() %}
-    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun {{ meth.name()|fn_name }}({%- call kt::arg_list_decl(meth) -%}){% match meth.return_type() %}{% when Some with (return_type) %} : {{ return_type|type_name }}{% when None %}{%- endmatch %} {
-        // Create a new `CoroutineScope` for this operation, suspend the coroutine, and call the
-        // scaffolding function, passing it one of the callback handlers from `AsyncTypes.kt`.
-        return coroutineScope {
-            val scope = this
-            return@coroutineScope suspendCancellableCoroutine { continuation ->
-                try {
-                    val callback = {{ meth.result_type().borrow()|future_callback_handler }}(continuation)
-                    uniffiActiveFutureCallbacks.add(callback)
-                    continuation.invokeOnCancellation { uniffiActiveFutureCallbacks.remove(callback) }
-                    callWithPointer { thisPtr ->
+    {%- if !kotlin_config.is_extension_method(name, meth.name()) %}
+    {% call kt::object_method(meth, type_name, is_builder, kotlin_config, false) %}
+    {%- endif %}
+    {% endfor %}
+
+    {%- for tm in obj.uniffi_traits() %}
+    {%- match tm %}
+    {%- when UniffiTrait::Clone { clone } %}
+    /**
+     * Create a new object with a copy of this object's Rust value, using its `Clone` implementation.
+     *
+     * The copy is independent of this object and needs to be destroyed separately.
+     */
+    fun copy(): {{ type_name }} =
+        callWithPointer {
+            {%- call kt::to_ffi_call_with_prefix("it", clone) %}
+        }.let {
+            {{ clone.return_type().unwrap()|lift_fn }}(it)
+        }
+    {%- else %}
+    {%- endmatch %}
+    {%- endfor %}
+
+    {% if !obj.alternate_constructors().is_empty() -%}
+    companion object {
+        {% for cons in obj.alternate_constructors() -%}
+        {%- if cons.is_async() %}
+        {%- let returns_result = kotlin_config.async_result() && cons.throws_type().is_some() %}
+        {%- match cons.throws_type() -%}
+        {%- when Some with (throwable) %}
+        {%- if !returns_result %}
+        @Throws({{ throwable|error_type_name }}::class)
+        {%- endif %}
+        {%- else -%}
+        {%- endmatch %}
+        @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+        suspend fun {{ cons.name()|fn_name }}({%- call kt::arg_list_decl(cons, kotlin_config) -%}{%- if kotlin_config.async_executor().is_per_call() %}{% if !cons.arguments().is_empty() %}, {% endif %}uniffiExecutor: CoroutineScope{% endif %}){% if returns_result %} : kotlin.Result<{{ type_name }}>{% else %} : {{ type_name }}{% endif %} {
+            {%- if returns_result %}
+            // Errors from Rust are returned as a failed `Result`, see `uniffiCatchResult()`
+            return uniffiCatchResult<{{ type_name }}, {{ cons.throws_type().unwrap()|error_type_name }}> {
+            {%- endif %}
+            // Async constructors work like async functions that return the object
+            {% if !returns_result %}return {% endif %}coroutineScope {
+                val scope = {% if kotlin_config.async_executor().is_per_call() %}uniffiExecutor{% else %}this{% endif %}
+                return@coroutineScope suspendCancellableCoroutine { continuation ->
+                    try {
+                        val callback = {{ cons.result_type().borrow()|future_callback_handler }}(continuation)
+                        uniffiActiveFutureCallbacks.add(callback)
+                        {%- if kotlin_config.async_cancellation() %}
+                        val callbackData = uniffiNewFutureCallbackData()
+                        continuation.invokeOnCancellation { uniffiCancelRustFuture(callbackData) }
+                        {%- else %}
+                        continuation.invokeOnCancellation { uniffiActiveFutureCallbacks.remove(callback) }
+                        {%- endif %}
                         rustCall { status ->
-                            _UniFFILib.INSTANCE.{{ meth.ffi_func().name() }}(
-                                thisPtr,
-                                {% call kt::arg_list_lowered(meth) %}
+                            _UniFFILib.INSTANCE.{{ cons.ffi_func().name() }}(
+                                {% call kt::arg_list_lowered(cons) %}
                                 FfiConverterForeignExecutor.lower(scope),
                                 callback,
-                                USize(0),
+                                {% if kotlin_config.async_cancellation() %}callbackData{% else %}USize(0){% endif %},
                                 status,
                             )
                         }
+                    } catch (e: Exception) {
+                        continuation.resumeWithException(e)
                     }
-                } catch (e: Exception) {
-                    continuation.resumeWithException(e)
                 }
             }
+            {%- if returns_result %}
+            }
+            {%- endif %}
         }
-    }
-    {%- else -%}
-    {%- match meth.return_type() -%}
-    {%- when Some with (return_type) -%}
-    override fun {{ meth.name()|fn_name }}({% call kt::arg_list_protocol(meth) %}): {{ return_type|type_name }} =
-        callWithPointer {
-            {%- call kt::to_ffi_call_with_prefix("it", meth) %}
-        }.let {
-            {{ return_type|lift_fn }}(it)
-        }
-
-    {%- when None -%}
-    override fun {{ meth.name()|fn_name }}({% call kt::arg_list_protocol(meth) %}) =
-        callWithPointer {
-            {%- call kt::to_ffi_call_with_prefix("it", meth) %}
-        }
-    {% endmatch %}
-    {% endif %}
-    {% endfor %}
-
-    {% if !obj.alternate_constructors().is_empty() -%}
-    companion object {
-        {% for cons in obj.alternate_constructors() -%}
-        fun {{ cons.name()|fn_name }}({% call kt::arg_list_decl(cons) %}): {{ type_name }} =
+        {%- else %}
+        fun {{ cons.name()|fn_name }}({% call kt::arg_list_decl(cons, kotlin_config) %}): {{ type_name }} =
             {{ type_name }}({% call kt::to_ffi_call(cons) %})
+        {%- endif %}
         {% endfor %}
     }
     {% endif %}
 }
+{%- for meth in obj.methods() %}
+{%- if kotlin_config.is_extension_method(name, meth.name()) %}
+{% call kt::object_method(meth, type_name, is_builder, kotlin_config, true) %}
+{%- endif %}
+{%- endfor %}
 
 public object {{ obj|ffi_converter_name }}: FfiConverter<{{ type_name }}, Pointer> {
     override fun lower(value: {{ type_name }}): Pointer = value.callWithPointer { it }
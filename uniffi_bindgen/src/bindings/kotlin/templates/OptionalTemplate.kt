@@ -1,8 +1,30 @@
 {%- let inner_type_name = inner_type|type_name %}
 
 public object {{ ffi_converter_name }}: FfiConverterRustBuffer<{{ inner_type_name }}?> {
+    // Rust can pass `null` as an empty buffer, see `empty_buffer_sentinels`.  These aren't allocated,
+    // so there's nothing to free.
+    override fun lift(value: RustBuffer.ByValue): {{ inner_type_name }}? {
+        if (value.len == 0) {
+            return null
+        }
+        return liftFromRustBuffer(value)
+    }
+    {%- if kotlin_config.empty_buffer_sentinels() %}
+
+    override fun lower(value: {{ inner_type_name }}?): RustBuffer.ByValue {
+        if (value == null) {
+            return RustBuffer.ByValue()
+        }
+        return lowerIntoRustBuffer(value)
+    }
+    {%- endif %}
+
     override fun read(buf: ByteBuffer): {{ inner_type_name }}? {
+        {%- if kotlin_config.buffer_checks().is_strict() %}
+        if (!uniffiCheckReadTag(buf.get())) {
+        {%- else %}
         if (buf.get().toInt() == 0) {
+        {%- endif %}
             return null
         }
         return {{ inner_type|read_fn }}(buf)
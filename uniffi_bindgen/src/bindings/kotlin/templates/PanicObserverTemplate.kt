@@ -0,0 +1,36 @@
+// Report Rust panics to foreign crash-reporting tools.
+//
+// Once an observer is registered with `uniffiSetPanicObserver()`, every Rust panic that unwinds to
+// the scaffolding is reported to it, before it's thrown as an exception.
+
+public interface UniFfiPanicObserver {
+    // Rust code panicked with `message` at `file`:`line`.  `line` is 0 if the location is unknown.
+    fun onPanic(message: String, file: String, line: Int)
+}
+
+internal interface UniFfiPanicObserverCallback : com.sun.jna.Callback {
+    fun callback(messageData: Pointer, messageLen: Int, fileData: Pointer, fileLen: Int, line: Int)
+}
+
+internal object UniFfiPanicObserverCallbackImpl : UniFfiPanicObserverCallback {
+    @Volatile
+    var observer: UniFfiPanicObserver? = null
+
+    override fun callback(messageData: Pointer, messageLen: Int, fileData: Pointer, fileLen: Int, line: Int) {
+        val observer = this.observer ?: return
+        val message = messageData.getByteArray(0, messageLen).toString(Charsets.UTF_8)
+        val file = fileData.getByteArray(0, fileLen).toString(Charsets.UTF_8)
+        try {
+            observer.onPanic(message, file, line)
+        } catch (e: Throwable) {
+            // Exceptions can't be thrown across the FFI, so the observer is responsible for
+            // handling its own errors.
+        }
+    }
+}
+
+// Register the observer that receives Rust panics, or pass `null` to stop reporting them.
+public fun uniffiSetPanicObserver(observer: UniFfiPanicObserver?) {
+    UniFfiPanicObserverCallbackImpl.observer = observer
+    _UniFFILib.INSTANCE.ffi_{{ ci.ffi_namespace() }}_panic_observer_set(UniFfiPanicObserverCallbackImpl)
+}
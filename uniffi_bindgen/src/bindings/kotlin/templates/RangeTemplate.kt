@@ -0,0 +1,67 @@
+{%- let inner_type_name = inner_type|type_name %}
+
+public object {{ ffi_converter_name }}: FfiConverterRustBuffer<{{ type_name }}> {
+    {%- if !inclusive %}
+    // Keeps the bounds exactly as Rust passed them, so that empty and inverted ranges round-trip.
+    // `rangeUntil` would normalize them.
+    private data class Bounds(
+        override val start: {{ inner_type_name }},
+        override val endExclusive: {{ inner_type_name }},
+    ) : OpenEndRange<{{ inner_type_name }}> {
+        override fun toString() = "$start..<$endExclusive"
+    }
+    {%- else %}
+    {%- match inner_type.as_ref() %}
+    {%- when Type::Int32 %}
+    {%- when Type::Int64 %}
+    {%- when Type::UInt32 %}
+    {%- when Type::UInt64 %}
+    {%- else %}
+    // Kotlin only has concrete range classes for some integer types
+    private data class Bounds(
+        override val start: {{ inner_type_name }},
+        override val endInclusive: {{ inner_type_name }},
+    ) : ClosedRange<{{ inner_type_name }}> {
+        override fun toString() = "$start..$endInclusive"
+    }
+    {%- endmatch %}
+    {%- endif %}
+
+    override fun read(buf: ByteBuffer): {{ type_name }} {
+        val start = {{ inner_type|read_fn }}(buf)
+        val end = {{ inner_type|read_fn }}(buf)
+        {%- if !inclusive %}
+        return Bounds(start, end)
+        {%- else %}
+        {%- match inner_type.as_ref() %}
+        {%- when Type::Int32 %}
+        return IntRange(start, end)
+        {%- when Type::Int64 %}
+        return LongRange(start, end)
+        {%- when Type::UInt32 %}
+        return UIntRange(start, end)
+        {%- when Type::UInt64 %}
+        return ULongRange(start, end)
+        {%- else %}
+        return Bounds(start, end)
+        {%- endmatch %}
+        {%- endif %}
+    }
+
+    override fun allocationSize(value: {{ type_name }}): Int {
+        {%- if !inclusive %}
+        return {{ inner_type|allocation_size_fn }}(value.start) + {{ inner_type|allocation_size_fn }}(value.endExclusive)
+        {%- else %}
+        return {{ inner_type|allocation_size_fn }}(value.start) + {{ inner_type|allocation_size_fn }}(value.endInclusive)
+        {%- endif %}
+    }
+
+    override fun write(value: {{ type_name }}, buf: ByteBuffer) {
+        {{ inner_type|write_fn }}(value.start, buf)
+        {%- if !inclusive %}
+        {{ inner_type|write_fn }}(value.endExclusive, buf)
+        {%- else %}
+        {{ inner_type|write_fn }}(value.endInclusive, buf)
+        {%- endif %}
+    }
+}
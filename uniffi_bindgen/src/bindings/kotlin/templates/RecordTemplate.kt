@@ -1,41 +1,101 @@
 {%- let rec = ci|get_record_definition(name) %}
+{%- let is_serializable = kotlin_config.serializable_records() && ci.item_is_serializable(type_) %}
 
 data class {{ type_name }} (
     {%- for field in rec.fields() %}
     var {{ field.name()|var_name }}: {{ field|type_name -}}
     {%- match field.default_value() %}
-        {%- when Some with(literal) %} = {{ literal|render_literal(field) }}
+        {%- when Some with(literal) %} = {{ literal|render_literal(field, kotlin_config) }}
         {%- else %}
     {%- endmatch -%}
     {% if !loop.last %}, {% endif %}
     {%- endfor %}
-) {% if contains_object_references %}: Disposable {% endif %}{
+) {% if contains_object_references %}: Disposable {% else if is_serializable %}: java.io.Serializable {% endif %}{
     {% if contains_object_references %}
     @Suppress("UNNECESSARY_SAFE_CALL") // codegen is much simpler if we unconditionally emit safe calls here
     override fun destroy() {
         {% call kt::destroy_fields(rec) %}
     }
     {% endif %}
+    {%- if rec.has_custom_eq() %}
+
+    // Some fields are excluded from equality, so we can't use the data class implementations
+    override fun equals(other: Any?): Boolean {
+        if (this === other) return true
+        if (other !is {{ type_name }}) return false
+        {%- for field in rec.eq_fields() %}
+        if ({{ field.name()|var_name }} != other.{{ field.name()|var_name }}) return false
+        {%- endfor %}
+        return true
+    }
+
+    override fun hashCode(): Int {
+        var result = 0
+        {%- for field in rec.eq_fields() %}
+        result = 31 * result + {{ field.name()|var_name }}.hashCode()
+        {%- endfor %}
+        return result
+    }
+    {%- endif %}
+}
+
+{%- if rec.is_pod() %}
+{%- let struct_name = "UniFfiStruct{}"|format(rec.name()) %}
+
+// `#[repr(C)]` struct that's passed by value to and from Rust
+@Structure.FieldOrder({% for field in rec.fields() %}"{{ field.name() }}"{% if !loop.last %}, {% endif %}{% endfor %})
+open class {{ struct_name }} : Structure() {
+    {%- for field in rec.fields() %}
+    {%- let ffi_type = field|ffi_type %}
+    @JvmField var `{{ field.name() }}`: {{ ffi_type|ffi_type_name }} = {{ ffi_type|ffi_default_value }}
+    {%- endfor %}
+
+    class ByValue: {{ struct_name }}(), Structure.ByValue
 }
 
+public object {{ rec|ffi_converter_name }}: FfiConverter<{{ type_name }}, {{ struct_name }}.ByValue> {
+    override fun lift(value: {{ struct_name }}.ByValue): {{ type_name }} {
+        return {{ type_name }}(
+        {%- for field in rec.fields() %}
+            {{ field|lift_fn }}(value.`{{ field.name() }}`),
+        {%- endfor %}
+        )
+    }
+
+    override fun lower(value: {{ type_name }}): {{ struct_name }}.ByValue {
+        return {{ struct_name }}.ByValue().apply {
+        {%- for field in rec.fields() %}
+            `{{ field.name() }}` = {{ field|lower_fn }}(value.{{ field.name()|var_name }})
+        {%- endfor %}
+        }
+    }
+{% else %}
+
 public object {{ rec|ffi_converter_name }}: FfiConverterRustBuffer<{{ type_name }}> {
+{%- endif %}
     override fun read(buf: ByteBuffer): {{ type_name }} {
-        return {{ type_name }}(
+        return {% call kt::nested_start(kotlin_config) %}{{ type_name }}(
         {%- for field in rec.fields() %}
             {{ field|read_fn }}(buf),
         {%- endfor %}
-        )
+        ){% call kt::nested_end(kotlin_config) %}
     }
 
-    override fun allocationSize(value: {{ type_name }}) = (
+    override fun allocationSize(value: {{ type_name }}) = {% call kt::nested_start(kotlin_config) %}(
         {%- for field in rec.fields() %}
             {{ field|allocation_size_fn }}(value.{{ field.name()|var_name }}){% if !loop.last %} +{% endif%}
         {%- endfor %}
-    )
+    ){% call kt::nested_end(kotlin_config) %}
 
     override fun write(value: {{ type_name }}, buf: ByteBuffer) {
+        {%- if kotlin_config.max_nesting_depth().is_some() %}
+        uniffiNested {
+        {%- endif %}
         {%- for field in rec.fields() %}
             {{ field|write_fn }}(value.{{ field.name()|var_name }}, buf)
         {%- endfor %}
+        {%- if kotlin_config.max_nesting_depth().is_some() %}
+        }
+        {%- endif %}
     }
 }
@@ -1,8 +1,29 @@
 {%- let inner_type_name = inner_type|type_name %}
 
 public object {{ ffi_converter_name }}: FfiConverterRustBuffer<List<{{ inner_type_name }}>> {
+    // Rust can pass empty lists as an empty buffer, see `empty_buffer_sentinels`.  These aren't allocated,
+    // so there's nothing to free.
+    override fun lift(value: RustBuffer.ByValue): List<{{ inner_type_name }}> {
+        if (value.len == 0) {
+            return listOf()
+        }
+        return liftFromRustBuffer(value)
+    }
+    {%- if kotlin_config.empty_buffer_sentinels() %}
+
+    override fun lower(value: List<{{ inner_type_name }}>): RustBuffer.ByValue {
+        if (value.isEmpty()) {
+            return RustBuffer.ByValue()
+        }
+        return lowerIntoRustBuffer(value)
+    }
+    {%- endif %}
+
     override fun read(buf: ByteBuffer): List<{{ inner_type_name }}> {
         val len = buf.getInt()
+        {%- if kotlin_config.buffer_checks().is_strict() %}
+        uniffiCheckReadLength(len, {{ ci.min_serialized_size(inner_type) }}, buf)
+        {%- endif %}
         return List<{{ inner_type_name }}>(len) {
             {{ inner_type|read_fn }}(buf)
         }
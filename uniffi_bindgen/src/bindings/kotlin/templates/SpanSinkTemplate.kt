@@ -0,0 +1,44 @@
+// Report Rust spans to foreign tracing tools.
+//
+// Once a sink is registered with `uniffiSetSpanSink()`, every call into Rust is reported as a span
+// named after the function or method, along with any spans that the Rust code reports itself.
+// `spanId` is the same for the enter and exit events of a span.
+
+public interface UniFfiSpanSink {
+    // The current thread entered a Rust span
+    fun enter(spanId: ULong, name: String)
+    // The current thread exited a Rust span
+    fun exit(spanId: ULong, name: String)
+}
+
+internal interface UniFfiSpanSinkCallback : com.sun.jna.Callback {
+    fun callback(event: Byte, spanId: Long, nameData: Pointer, nameLen: Int)
+}
+
+internal object UniFfiSpanSinkCallbackImpl : UniFfiSpanSinkCallback {
+    @Volatile
+    var sink: UniFfiSpanSink? = null
+
+    override fun callback(event: Byte, spanId: Long, nameData: Pointer, nameLen: Int) {
+        val sink = this.sink ?: return
+        val name = nameData.getByteArray(0, nameLen).toString(Charsets.UTF_8)
+        try {
+            if (event == UNIFFI_SPAN_ENTER) {
+                sink.enter(spanId.toULong(), name)
+            } else {
+                sink.exit(spanId.toULong(), name)
+            }
+        } catch (e: Throwable) {
+            // Exceptions can't be thrown across the FFI, so the sink is responsible for handling
+            // its own errors.
+        }
+    }
+}
+
+internal const val UNIFFI_SPAN_ENTER: Byte = 0
+
+// Register the sink that receives Rust spans, or pass `null` to stop reporting them.
+public fun uniffiSetSpanSink(sink: UniFfiSpanSink?) {
+    UniFfiSpanSinkCallbackImpl.sink = sink
+    _UniFFILib.INSTANCE.ffi_{{ ci.ffi_namespace() }}_span_sink_set(UniFfiSpanSinkCallbackImpl)
+}
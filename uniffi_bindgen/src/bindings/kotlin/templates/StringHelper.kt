@@ -6,7 +6,7 @@ public object FfiConverterString: FfiConverter<String, RustBuffer.ByValue> {
         try {
             val byteArr = ByteArray(value.len)
             value.asByteBuffer()!!.get(byteArr)
-            return byteArr.toString(Charsets.UTF_8)
+            return byteArr.toString({{ kotlin_config.string_encoding().charset() }})
         } finally {
             RustBuffer.free(value)
         }
@@ -14,21 +14,24 @@ public object FfiConverterString: FfiConverter<String, RustBuffer.ByValue> {
 
     override fun read(buf: ByteBuffer): String {
         val len = buf.getInt()
+        {%- if kotlin_config.buffer_checks().is_strict() %}
+        uniffiCheckReadLength(len, 1, buf)
+        {%- endif %}
         val byteArr = ByteArray(len)
         buf.get(byteArr)
-        return byteArr.toString(Charsets.UTF_8)
+        return byteArr.toString({{ kotlin_config.string_encoding().charset() }})
     }
 
-    fun toUtf8(value: String): ByteBuffer {
+    fun encodeString(value: String): ByteBuffer {
         // Make sure we don't have invalid UTF-16, check for lone surrogates.
-        return Charsets.UTF_8.newEncoder().run {
+        return {{ kotlin_config.string_encoding().charset() }}.newEncoder().run {
             onMalformedInput(CodingErrorAction.REPORT)
             encode(CharBuffer.wrap(value))
         }
     }
 
     override fun lower(value: String): RustBuffer.ByValue {
-        val byteBuf = toUtf8(value)
+        val byteBuf = encodeString(value)
         // Ideally we'd pass these bytes to `ffi_bytebuffer_from_bytes`, but doing so would require us
         // to copy them into a JNA `Memory`. So we might as well directly copy them into a `RustBuffer`.
         val rbuf = RustBuffer.alloc(byteBuf.limit())
@@ -36,6 +39,14 @@ public object FfiConverterString: FfiConverter<String, RustBuffer.ByValue> {
         return rbuf
     }
 
+    {%- if kotlin_config.string_encoding().is_utf16() %}
+    // Strings are sent as UTF-16LE, which takes exactly 2 bytes per code unit.
+    override fun allocationSize(value: String): Int {
+        val sizeForLength = 4
+        val sizeForString = value.length * 2
+        return sizeForLength + sizeForString
+    }
+    {%- else %}
     // We aren't sure exactly how many bytes our string will be once it's UTF-8
     // encoded.  Allocate 3 bytes per UTF-16 code unit which will always be
     // enough.
@@ -44,9 +55,10 @@ public object FfiConverterString: FfiConverter<String, RustBuffer.ByValue> {
         val sizeForString = value.length * 3
         return sizeForLength + sizeForString
     }
+    {%- endif %}
 
     override fun write(value: String, buf: ByteBuffer) {
-        val byteBuf = toUtf8(value)
+        val byteBuf = encodeString(value)
         buf.putInt(byteBuf.limit())
         buf.put(byteBuf)
     }
@@ -1,27 +1,39 @@
 {%- if func.is_async() %}
+{%- let returns_result = config.async_result() && func.throws_type().is_some() %}
 {%- match func.throws_type() -%}
 {%- when Some with (throwable) %}
+{%- if !returns_result %}
 @Throws({{ throwable|error_type_name }}::class)
+{%- endif %}
 {%- else -%}
 {%- endmatch %}
 
 @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-suspend fun {{ func.name()|fn_name }}({%- call kt::arg_list_decl(func) -%}){% match func.return_type() %}{% when Some with (return_type) %} : {{ return_type|type_name }}{% when None %}{%- endmatch %} {
+suspend fun {{ func.name()|fn_name }}({%- call kt::arg_list_decl(func, config) -%}{%- if config.async_executor().is_per_call() %}{% if !func.arguments().is_empty() %}, {% endif %}uniffiExecutor: CoroutineScope{% endif %}){% if returns_result %} : kotlin.Result<{% call kt::result_ok_type(func) %}>{% else %}{% match func.return_type() %}{% when Some with (return_type) %} : {{ return_type|type_name }}{% when None %}{%- endmatch %}{% endif %} {
+    {%- if returns_result %}
+    // Errors from Rust are returned as a failed `Result`, see `uniffiCatchResult()`
+    return uniffiCatchResult<{% call kt::result_ok_type(func) %}, {{ func.throws_type().unwrap()|error_type_name }}> {
+    {%- endif %}
     // Create a new `CoroutineScope` for this operation, suspend the coroutine, and call the
     // scaffolding function, passing it one of the callback handlers from `AsyncTypes.kt`.
-    return coroutineScope {
-        val scope = this
+    {% if !returns_result %}return {% endif %}coroutineScope {
+        val scope = {% if config.async_executor().is_per_call() %}uniffiExecutor{% else %}this{% endif %}
         return@coroutineScope suspendCancellableCoroutine { continuation ->
             try {
                 val callback = {{ func.result_type().borrow()|future_callback_handler }}(continuation)
                 uniffiActiveFutureCallbacks.add(callback)
+                {%- if config.async_cancellation() %}
+                val callbackData = uniffiNewFutureCallbackData()
+                continuation.invokeOnCancellation { uniffiCancelRustFuture(callbackData) }
+                {%- else %}
                 continuation.invokeOnCancellation { uniffiActiveFutureCallbacks.remove(callback) }
+                {%- endif %}
                 rustCall { status ->
                     _UniFFILib.INSTANCE.{{ func.ffi_func().name() }}(
                         {% call kt::arg_list_lowered(func) %}
                         FfiConverterForeignExecutor.lower(scope),
                         callback,
-                        USize(0),
+                        {% if config.async_cancellation() %}callbackData{% else %}USize(0){% endif %},
                         status,
                     )
                 }
@@ -30,6 +42,9 @@ suspend fun {{ func.name()|fn_name }}({%- call kt::arg_list_decl(func) -%}){% ma
             }
         }
     }
+    {%- if returns_result %}
+    }
+    {%- endif %}
 }
 
 {%- else %}
@@ -42,13 +57,36 @@ suspend fun {{ func.name()|fn_name }}({%- call kt::arg_list_decl(func) -%}){% ma
 {%- match func.return_type() -%}
 {%- when Some with (return_type) %}
 
-fun {{ func.name()|fn_name }}({%- call kt::arg_list_decl(func) -%}): {{ return_type|type_name }} {
+fun {{ func.name()|fn_name }}({%- call kt::arg_list_decl(func, config) -%}): {{ return_type|type_name }} {
     return {{ return_type|lift_fn }}({% call kt::to_ffi_call(func) %})
 }
 {% when None %}
 
-fun {{ func.name()|fn_name }}({% call kt::arg_list_decl(func) %}) =
+fun {{ func.name()|fn_name }}({% call kt::arg_list_decl(func, config) %}) =
     {% call kt::to_ffi_call(func) %}
 
 {% endmatch %}
 {%- endif %}
+
+{%- for alias in func|fn_aliases %}
+{%- match func.throws_type() -%}
+{%- when Some with (throwable) %}
+
+@Throws({{ throwable|error_type_name }}::class)
+{%- else %}
+{% endmatch %}
+{% if func.is_async() %}suspend {% endif %}fun {{ alias }}({%- call kt::arg_list_decl(func, config) -%}{%- if func.is_async() && config.async_executor().is_per_call() %}{% if !func.arguments().is_empty() %}, {% endif %}uniffiExecutor: CoroutineScope{% endif %}) =
+    {{ func.name()|fn_name }}({% call kt::arg_list_forwarded(func) %})
+{% endfor %}
+
+{%- for alias in config.function_aliases(func.name()) %}
+
+@Deprecated("Use {{ func.name()|fn_name }} instead", ReplaceWith("{{ func.name()|fn_name }}"))
+{%- match func.throws_type() -%}
+{%- when Some with (throwable) %}
+@Throws({{ throwable|error_type_name }}::class)
+{%- else -%}
+{%- endmatch %}
+{% if func.is_async() %}suspend {% endif %}fun {{ alias|fn_name }}({%- call kt::arg_list_decl(func, config) -%}{%- if func.is_async() && config.async_executor().is_per_call() %}{% if !func.arguments().is_empty() %}, {% endif %}uniffiExecutor: CoroutineScope{% endif %}) =
+    {{ func.name()|fn_name }}({% call kt::arg_list_forwarded(func) %})
+{% endfor %}
@@ -56,6 +56,9 @@
 {%- when Type::Bytes %}
 {%- include "ByteArrayHelper.kt" %}
 
+{%- when Type::ByteArray { len } %}
+{%- include "FixedByteArrayHelper.kt" %}
+
 {%- when Type::Enum { name, module_path } %}
 {%- let e = ci.get_enum_definition(name).unwrap() %}
 {%- if !ci.is_name_used_as_error(name) %}
@@ -67,7 +70,7 @@
 {%- when Type::Object { module_path, name, imp } %}
 {% include "ObjectTemplate.kt" %}
 
-{%- when Type::Record { name, module_path } %}
+{%- when Type::Record { name, module_path, pod } %}
 {% include "RecordTemplate.kt" %}
 
 {%- when Type::Optional { inner_type } %}
@@ -79,6 +82,9 @@
 {%- when Type::Map { key_type, value_type } %}
 {% include "MapTemplate.kt" %}
 
+{%- when Type::Range { inner_type, inclusive } %}
+{% include "RangeTemplate.kt" %}
+
 {%- when Type::CallbackInterface { module_path, name } %}
 {% include "CallbackInterfaceTemplate.kt" %}
 
@@ -91,6 +97,9 @@
 {%- when Type::Duration %}
 {% include "DurationHelper.kt" %}
 
+{%- when Type::JsonValue %}
+{% include "JsonValueHelper.kt" %}
+
 {%- when Type::Custom { module_path, name, builtin } %}
 {% include "CustomTypeTemplate.kt" %}
 
@@ -31,7 +31,11 @@
 
 {%- macro arg_list_lowered(func) %}
     {%- for arg in func.arguments() %}
+        {%- if arg.is_variadic() %}
+        {{- arg|lower_fn }}({{ arg.name()|var_name }}.toList()),
+        {%- else %}
         {{- arg|lower_fn }}({{ arg.name()|var_name }}),
+        {%- endif %}
     {%- endfor %}
 {%- endmacro -%}
 
@@ -40,24 +44,53 @@
 // Note the var_name and type_name filters.
 -#}
 
-{% macro arg_list_decl(func) %}
+{% macro arg_list_decl(func, config) %}
     {%- for arg in func.arguments() -%}
+        {%- match arg.variadic_element_type() %}
+        {%- when Some with (elem) %}vararg {{ arg.name()|var_name }}: {{ elem|type_name -}}
+        {%- when None -%}
         {{ arg.name()|var_name }}: {%- if !ci.is_name_used_as_error(arg|type_name) %} {{ arg|type_name -}} {%- else %} {{ arg|error_type_name -}} {% endif -%}
+        {%- endmatch %}
         {%- match arg.default_value() %}
-        {%- when Some with(literal) %} = {{ literal|render_literal(arg) }}
+        {%- when Some with(literal) %} = {{ literal|render_literal(arg, config) }}
         {%- else %}
         {%- endmatch %}
         {%- if !loop.last %}, {% endif -%}
     {%- endfor %}
 {%- endmacro %}
 
+{#-
+// Arglist as used when one function forwards its arguments to another, for example the
+// deprecated aliases of renamed functions.
+-#}
+{% macro arg_list_forwarded(func) %}
+    {%- for arg in func.arguments() -%}
+        {% if arg.is_variadic() %}*{% endif %}{{ arg.name()|var_name }}
+        {%- if !loop.last %}, {% endif -%}
+    {%- endfor %}
+    {%- if func.is_async() && config.async_executor().is_per_call() %}{% if !func.arguments().is_empty() %}, {% endif %}uniffiExecutor{% endif %}
+{%- endmacro %}
+
 {% macro arg_list_protocol(func) %}
     {%- for arg in func.arguments() -%}
+        {%- match arg.variadic_element_type() %}
+        {%- when Some with (elem) %}vararg {{ arg.name()|var_name }}: {{ elem|type_name -}}
+        {%- when None -%}
         {{ arg.name()|var_name }}: {%- if !ci.is_name_used_as_error(arg|type_name) %} {{ arg|type_name -}} {%- else %} {{ arg|error_type_name -}} {% endif -%}
+        {%- endmatch %}
         {%- if !loop.last %}, {% endif -%}
     {%- endfor %}
 {%- endmacro %}
 {#-
+// The value type of the `kotlin.Result` returned by async functions with the `async_result` option.
+-#}
+{%- macro result_ok_type(func) %}
+    {%- match func.return_type() %}
+    {%- when Some with (return_type) %}{{ return_type|type_name }}
+    {%- when None %}Unit
+    {%- endmatch %}
+{%- endmacro %}
+{#-
 // Arglist as used in the _UniFFILib function declarations.
 // Note unfiltered name but ffi_type_name filters.
 -#}
@@ -75,3 +108,104 @@
         this.{{ field.name()|var_name }}{%- if !loop.last %}, {% endif -%}
     {% endfor -%})
 {%- endmacro -%}
+
+// Macros for wrapping the code that reads or writes a record or enum in `uniffiNested`, when the
+// `max_nesting_depth` option is set
+{%- macro nested_start(config) -%}
+{%- if config.max_nesting_depth().is_some() %}uniffiNested { {% endif -%}
+{%- endmacro -%}
+
+{%- macro nested_end(config) -%}
+{%- if config.max_nesting_depth().is_some() %} }{% endif -%}
+{%- endmacro -%}
+
+{#-
+// A method of an object.  With `extension`, it's generated as an extension function of the object
+// class rather than a member, for methods listed in the `extension_methods` config.
+-#}
+{%- macro object_method(meth, type_name, is_builder, config, extension) %}
+    {%- let returns_result = meth.is_async() && config.async_result() && meth.throws_type().is_some() %}
+    {%- match meth.throws_type() -%}
+    {%- when Some with (throwable) %}
+    {%- if !returns_result %}
+    @Throws({{ throwable|error_type_name }}::class)
+    {%- endif %}
+    {%- else -%}
+    {%- endmatch -%}
+    {%- if meth.is_async() %}
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    {% if extension %}suspend fun {{ type_name }}.{% else %}override suspend fun {% endif %}{{ meth.name()|fn_name }}({%- call arg_list_decl(meth, config) -%}{%- if config.async_executor().is_per_call() %}{% if !meth.arguments().is_empty() %}, {% endif %}uniffiExecutor: CoroutineScope{% endif %}){% if returns_result %} : kotlin.Result<{% call result_ok_type(meth) %}>{% else %}{% match meth.return_type() %}{% when Some with (return_type) %} : {{ return_type|type_name }}{% when None %}{%- endmatch %}{% endif %} {
+        {%- if returns_result %}
+        // Errors from Rust are returned as a failed `Result`, see `uniffiCatchResult()`
+        return uniffiCatchResult<{% call result_ok_type(meth) %}, {{ meth.throws_type().unwrap()|error_type_name }}> {
+        {%- endif %}
+        // Create a new `CoroutineScope` for this operation, suspend the coroutine, and call the
+        // scaffolding function, passing it one of the callback handlers from `AsyncTypes.kt`.
+        {% if !returns_result %}return {% endif %}coroutineScope {
+            val scope = {% if config.async_executor().is_per_call() %}uniffiExecutor{% else %}this{% endif %}
+            return@coroutineScope suspendCancellableCoroutine { continuation ->
+                try {
+                    val callback = {{ meth.result_type().borrow()|future_callback_handler }}(continuation)
+                    uniffiActiveFutureCallbacks.add(callback)
+                    {%- if config.async_cancellation() %}
+                    val callbackData = uniffiNewFutureCallbackData()
+                    continuation.invokeOnCancellation { uniffiCancelRustFuture(callbackData) }
+                    {%- else %}
+                    continuation.invokeOnCancellation { uniffiActiveFutureCallbacks.remove(callback) }
+                    {%- endif %}
+                    callWithPointer { thisPtr ->
+                        rustCall { status ->
+                            _UniFFILib.INSTANCE.{{ meth.ffi_func().name() }}(
+                                thisPtr,
+                                {% call arg_list_lowered(meth) %}
+                                FfiConverterForeignExecutor.lower(scope),
+                                callback,
+                                {% if config.async_cancellation() %}callbackData{% else %}USize(0){% endif %},
+                                status,
+                            )
+                        }
+                    }
+                } catch (e: Exception) {
+                    continuation.resumeWithException(e)
+                }
+            }
+        }
+        {%- if returns_result %}
+        }
+        {%- endif %}
+    }
+    {%- else -%}
+    {%- match meth.return_type() -%}
+    {%- when Some with (return_type) -%}
+    {% call object_method_decl(meth, type_name, config, extension) %}: {{ return_type|type_name }} =
+        callWithPointer {
+            {%- call to_ffi_call_with_prefix("it", meth) %}
+        }.let {
+            {{ return_type|lift_fn }}(it)
+        }
+
+    {%- when None -%}
+    {%- if is_builder %}
+    {% call object_method_decl(meth, type_name, config, extension) %}: {{ type_name }} {
+        callWithPointer {
+            {%- call to_ffi_call_with_prefix("it", meth) %}
+        }
+        return this
+    }
+    {%- else %}
+    {% call object_method_decl(meth, type_name, config, extension) %} =
+        callWithPointer {
+            {%- call to_ffi_call_with_prefix("it", meth) %}
+        }
+    {%- endif %}
+    {% endmatch %}
+    {% endif %}
+{%- endmacro %}
+
+{%- macro object_method_decl(meth, type_name, config, extension) %}
+    {%- if extension -%}
+    fun {{ type_name }}.{{ meth.name()|fn_name }}({% call arg_list_decl(meth, config) %})
+    {%- else -%}
+    override fun {{ meth.name()|fn_name }}({% call arg_list_protocol(meth) %})
+    {%- endif -%}
+{%- endmacro %}
@@ -1,5 +1,6 @@
 // This file was autogenerated by some hot garbage in the `uniffi` crate.
 // Trust me, you don't want to mess with it!
+// The Rust library must be built with uniffi {{ config.required_runtime_version() }}.
 
 @file:Suppress("NAME_SHADOWING")
 
@@ -45,6 +46,22 @@ import java.util.concurrent.ConcurrentHashMap
 // Public interface members begin here.
 {{ type_helper_code }}
 
+{%- if config.span_sink() %}
+{% include "SpanSinkTemplate.kt" %}
+{%- endif %}
+
+{%- if config.panic_observer() %}
+{% include "PanicObserverTemplate.kt" %}
+{%- endif %}
+
+{%- if config.capability_registration() %}
+{% include "CapabilityTemplate.kt" %}
+{%- endif %}
+
+{%- if config.error_localizer() %}
+{% include "ErrorLocalizerTemplate.kt" %}
+{%- endif %}
+
 {%- for func in ci.function_definitions() %}
 {%- include "TopLevelFunctionTemplate.kt" %}
 {%- endfor %}
@@ -40,6 +40,7 @@ pub fn run_script(
     generate_bindings(
         &cdylib_path,
         None,
+        None,
         &[TargetLanguage::Kotlin],
         &out_dir,
         false,
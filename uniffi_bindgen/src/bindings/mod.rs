@@ -8,12 +8,16 @@
 //! along with some helpers for executing foreign language scripts or tests.
 
 use anyhow::{bail, Result};
-use camino::Utf8Path;
+use camino::{Utf8Path, Utf8PathBuf};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
 use std::fmt;
+use std::process::Command;
 
+use crate::backend::{Formatter, RuntimeVersion};
 use crate::interface::ComponentInterface;
 
+pub mod c;
 pub mod kotlin;
 pub mod python;
 pub mod ruby;
@@ -25,13 +29,15 @@ pub mod swift;
 /// on the provided `TargetLanguage`. For convenience of calling code we also provide
 /// a few `TryFrom` implementations to help guess the correct target language from
 /// e.g. a file extension of command-line argument.
-#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
 pub enum TargetLanguage {
     Kotlin,
     Swift,
     Python,
     Ruby,
+    /// A C header declaring the low-level FFI, rather than full bindings.
+    C,
 }
 
 impl fmt::Display for TargetLanguage {
@@ -41,6 +47,7 @@ impl fmt::Display for TargetLanguage {
             Self::Swift => write!(f, "swift"),
             Self::Python => write!(f, "python"),
             Self::Ruby => write!(f, "ruby"),
+            Self::C => write!(f, "c"),
         }
     }
 }
@@ -67,6 +74,7 @@ impl TryFrom<&str> for TargetLanguage {
             "swift" => TargetLanguage::Swift,
             "python" | "py" => TargetLanguage::Python,
             "ruby" | "rb" => TargetLanguage::Ruby,
+            "c" | "h" => TargetLanguage::C,
             _ => bail!("Unknown or unsupported target language: \"{value}\""),
         })
     }
@@ -99,17 +107,87 @@ pub struct Config {
     pub(crate) python: python::Config,
     #[serde(default)]
     pub(crate) ruby: ruby::Config,
+    #[serde(default)]
+    pub(crate) c: c::Config,
+    /// Make records implement the serialization protocol of each language, unless the language's
+    /// own section sets `serializable_records`.
+    #[serde(default)]
+    pub(crate) serializable_records: bool,
+    /// Pin the bindings of each language to this `uniffi` runtime version, unless the language's
+    /// own section sets `runtime_version`.
+    #[serde(default)]
+    pub(crate) runtime_version: Option<RuntimeVersion>,
+    /// The `[bindings.<language>]` sections marked with `inherit_dependency_configs = false`.
+    #[serde(skip)]
+    pub(crate) dependency_configs_disabled: BTreeSet<String>,
+}
+
+impl Config {
+    /// Apply the options that are shared by all languages to the language sections.
+    pub(crate) fn apply_shared_options(&mut self) {
+        self.kotlin
+            .set_default_serializable_records(self.serializable_records);
+        self.swift
+            .set_default_serializable_records(self.serializable_records);
+        self.python
+            .set_default_serializable_records(self.serializable_records);
+        self.kotlin
+            .set_default_runtime_version(self.runtime_version);
+        self.swift.set_default_runtime_version(self.runtime_version);
+        self.python
+            .set_default_runtime_version(self.runtime_version);
+    }
+
+    /// Should the section for this language take values from the configs of dependent crates?
+    pub(crate) fn inherits_dependency_configs(&self, language: &str) -> bool {
+        !self.dependency_configs_disabled.contains(language)
+    }
+}
+
+/// Does the config section for this language take any values from the configs of dependent crates?
+///
+/// Kotlin and Python look up the package or module of external types in them, the other
+/// languages don't need anything from dependent crates.
+pub(crate) fn takes_dependency_configs(language: &str) -> bool {
+    matches!(language, "kotlin" | "python")
+}
+
+/// The foreign namespace that the bindings for a component are generated into.
+///
+/// Components that share an output namespace will end up with their declarations side by side,
+/// which is only valid if their names don't collide.
+pub fn output_namespace(
+    config: &Config,
+    ci: &ComponentInterface,
+    language: TargetLanguage,
+) -> String {
+    match language {
+        TargetLanguage::Kotlin => config.kotlin.package_name(),
+        TargetLanguage::Swift => config.swift.module_name(),
+        TargetLanguage::Python | TargetLanguage::Ruby | TargetLanguage::C => {
+            ci.namespace().to_string()
+        }
+    }
+}
+
+/// A file written by one of the bindings backends.
+#[derive(Debug, Clone)]
+pub struct GeneratedFile {
+    pub language: TargetLanguage,
+    pub path: Utf8PathBuf,
 }
 
 /// Generate foreign language bindings from a compiled `uniffi` library.
+///
+/// Returns the files that were written, in the order the backend wrote them.
 pub fn write_bindings(
     config: &Config,
     ci: &ComponentInterface,
     out_dir: &Utf8Path,
     language: TargetLanguage,
     try_format_code: bool,
-) -> Result<()> {
-    match language {
+) -> Result<Vec<GeneratedFile>> {
+    let paths = match language {
         TargetLanguage::Kotlin => {
             kotlin::write_bindings(&config.kotlin, ci, out_dir, try_format_code)?
         }
@@ -120,6 +198,38 @@ pub fn write_bindings(
             python::write_bindings(&config.python, ci, out_dir, try_format_code)?
         }
         TargetLanguage::Ruby => ruby::write_bindings(&config.ruby, ci, out_dir, try_format_code)?,
+        TargetLanguage::C => c::write_bindings(&config.c, ci, out_dir, try_format_code)?,
+    };
+    Ok(paths
+        .into_iter()
+        .map(|path| GeneratedFile { language, path })
+        .collect())
+}
+
+/// Format a generated file, as configured by the `formatter` option of its crate's config
+///
+/// `default_command` is the language's default formatter, which is used unless the config names
+/// a different one.  Formatting is best-effort: if the formatter can't be run we print a warning
+/// and keep the unformatted file.
+pub(crate) fn format_generated_file(
+    formatter: Option<&Formatter>,
+    default_command: &[&str],
+    try_format_code: bool,
+    path: &Utf8Path,
+) {
+    let command = match Formatter::command(formatter, default_command, try_format_code) {
+        Some(command) => command,
+        None => return,
+    };
+    if let Err(e) = Command::new(&command[0])
+        .args(&command[1..])
+        .arg(path)
+        .output()
+    {
+        println!(
+            "Warning: Unable to auto-format {} using {}: {e:?}",
+            path.file_name().unwrap(),
+            command[0],
+        );
     }
-    Ok(())
 }
@@ -105,3 +105,30 @@ impl CodeType for MapCodeType {
         }
     }
 }
+
+#[derive(Debug)]
+pub struct RangeCodeType {
+    inner: Type,
+    inclusive: bool,
+}
+
+impl RangeCodeType {
+    pub fn new(inner: Type, inclusive: bool) -> Self {
+        Self { inner, inclusive }
+    }
+}
+
+impl CodeType for RangeCodeType {
+    fn type_label(&self) -> String {
+        "range".into()
+    }
+
+    fn canonical_name(&self) -> String {
+        let inner = super::PythonCodeOracle.find(&self.inner).canonical_name();
+        if self.inclusive {
+            format!("RangeInclusive{inner}")
+        } else {
+            format!("Range{inner}")
+        }
+    }
+}
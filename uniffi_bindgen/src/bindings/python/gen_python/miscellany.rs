@@ -31,3 +31,5 @@ macro_rules! impl_code_type_for_miscellany {
 impl_code_type_for_miscellany!(TimestampCodeType, "Timestamp");
 
 impl_code_type_for_miscellany!(DurationCodeType, "Duration");
+
+impl_code_type_for_miscellany!(JsonValueCodeType, "JsonValue");
@@ -2,16 +2,19 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use askama::Template;
 use heck::{ToShoutySnakeCase, ToSnakeCase, ToUpperCamelCase};
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 use std::borrow::Borrow;
 use std::cell::RefCell;
-use std::collections::{BTreeSet, HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
-use crate::backend::{CodeType, TemplateExpression};
+use crate::backend::{
+    check_name_collisions, AsyncExecutor, BufferChecks, CodeType, Formatter, GeneratedNames,
+    HandleMapKind, NameCollisions, NameKind, RuntimeVersion, TemplateExpression,
+};
 use crate::interface::*;
 use crate::BindingsConfig;
 
@@ -74,9 +77,54 @@ static KEYWORDS: Lazy<HashSet<String>> = Lazy::new(|| {
 pub struct Config {
     cdylib_name: Option<String>,
     #[serde(default)]
+    async_executor: AsyncExecutor,
+    async_cancellation: Option<bool>,
+    #[serde(default)]
+    handle_map: HandleMapKind,
+    #[serde(default)]
+    buffer_checks: BufferChecks,
+    max_nesting_depth: Option<u32>,
+    handshake: Option<bool>,
+    span_sink: Option<bool>,
+    panic_observer: Option<bool>,
+    capability_registration: Option<bool>,
+    error_localizer: Option<bool>,
+    empty_buffer_sentinels: Option<bool>,
+    formatter: Option<Formatter>,
+    serializable_records: Option<bool>,
+    runtime_version: Option<RuntimeVersion>,
+    #[serde(default)]
+    name_collisions: NameCollisions,
+    #[serde(default)]
+    record_style: RecordStyle,
+    #[serde(default)]
+    builders: HashSet<String>,
+    #[serde(default)]
+    module_namespaces: HashMap<String, String>,
+    #[serde(default)]
+    external_packages: HashMap<String, String>,
+    // The module that the bindings are generated into, which is named after the namespace
+    #[serde(skip)]
+    module_name: Option<String>,
+    #[serde(default)]
+    function_aliases: HashMap<String, Vec<String>>,
+    #[serde(default)]
     custom_types: HashMap<String, CustomTypeConfig>,
 }
 
+/// Config value for the kind of Python class generated for records
+///
+///   - `class`: a plain class with mutable attributes, compared field by field.
+///   - `named_tuple`: an immutable `collections.namedtuple` subclass.  Records then compare equal to
+///     plain tuples with the same values, and fields can't be assigned after construction.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordStyle {
+    #[default]
+    Class,
+    NamedTuple,
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CustomTypeConfig {
     // This `CustomTypeConfig` doesn't have a `type_name` like the others -- which is why we have
@@ -94,6 +142,162 @@ impl Config {
             "uniffi".into()
         }
     }
+
+    /// How async functions obtain the event loop used to drive Rust futures.
+    pub fn async_executor(&self) -> AsyncExecutor {
+        self.async_executor
+    }
+
+    /// Should cancelling the asyncio task of an async call also cancel the Rust future?  If so, the
+    /// future is dropped rather than running to completion in the background.
+    pub fn async_cancellation(&self) -> bool {
+        self.async_cancellation.unwrap_or(false)
+    }
+
+    /// Which handle map implementation tracks callback interface objects passed to Rust.
+    pub fn handle_map(&self) -> HandleMapKind {
+        self.handle_map
+    }
+
+    /// How strictly lifting code checks the buffers it reads values from.
+    pub fn buffer_checks(&self) -> BufferChecks {
+        self.buffer_checks
+    }
+
+    /// The maximum depth that records and enums can be nested in values that are lifted or
+    /// lowered, or `None` for no limit.  Values nested deeper than this fail with an internal
+    /// error, instead of overflowing the stack.
+    pub fn max_nesting_depth(&self) -> Option<u32> {
+        self.max_nesting_depth
+    }
+
+    /// Should the bindings expose a handshake, which reports the contract version and interface
+    /// fingerprint of the loaded library so that foreign code can check it's compatible?
+    pub fn handshake(&self) -> bool {
+        self.handshake.unwrap_or(false)
+    }
+
+    /// Should the bindings let foreign code register a span sink, to see the spans of calls into Rust?
+    pub fn span_sink(&self) -> bool {
+        self.span_sink.unwrap_or(false)
+    }
+
+    /// Should the bindings let foreign code register a panic observer, to be notified of Rust panics?
+    pub fn panic_observer(&self) -> bool {
+        self.panic_observer.unwrap_or(false)
+    }
+
+    /// Should the bindings let foreign code decide if the capabilities of functions exported with
+    /// `requires_capability` are available?
+    pub fn capability_registration(&self) -> bool {
+        self.capability_registration.unwrap_or(false)
+    }
+
+    /// Should the bindings let foreign code register a localizer, which the messages of error types
+    /// are routed through?
+    pub fn error_localizer(&self) -> bool {
+        self.error_localizer.unwrap_or(false)
+    }
+
+    /// Should empty lists and dicts, and `None`, be passed as empty buffers rather than allocating
+    /// a buffer for them?
+    pub fn empty_buffer_sentinels(&self) -> bool {
+        self.empty_buffer_sentinels.unwrap_or(false)
+    }
+
+    /// How the generated bindings are formatted, see [Formatter].  This overrides whether
+    /// formatting was requested when generating the bindings.
+    pub fn formatter(&self) -> Option<&Formatter> {
+        self.formatter.as_ref()
+    }
+
+    /// Should records have `to_dict()` and `from_dict()` methods?
+    ///
+    /// Defaults to the `serializable_records` option of the `[bindings]` section.
+    pub fn serializable_records(&self) -> bool {
+        self.serializable_records.unwrap_or(false)
+    }
+
+    pub(crate) fn set_default_serializable_records(&mut self, default: bool) {
+        self.serializable_records.get_or_insert(default);
+    }
+
+    /// The `uniffi` runtime version that the bindings check for when they load the library, if
+    /// they're pinned to one.
+    ///
+    /// Defaults to the `runtime_version` option of the `[bindings]` section.
+    pub fn runtime_version(&self) -> Option<RuntimeVersion> {
+        self.runtime_version
+    }
+
+    /// The `uniffi` runtime version that the generated file header documents: the pinned version,
+    /// or else the version that generated the bindings.
+    pub fn required_runtime_version(&self) -> RuntimeVersion {
+        self.runtime_version.unwrap_or_else(RuntimeVersion::bindgen)
+    }
+
+    pub(crate) fn set_default_runtime_version(&mut self, default: Option<RuntimeVersion>) {
+        if self.runtime_version.is_none() {
+            self.runtime_version = default;
+        }
+    }
+
+    /// What happens when names collide after they're converted to Python conventions
+    pub fn name_collisions(&self) -> NameCollisions {
+        self.name_collisions
+    }
+
+    /// Should records be generated as immutable named tuples, rather than plain classes?
+    pub fn named_tuple_records(&self) -> bool {
+        matches!(self.record_style, RecordStyle::NamedTuple)
+    }
+
+    /// Is this object a builder?  Builder methods that don't return anything return `self` instead,
+    /// so that calls can be chained.
+    pub fn is_builder(&self, object_name: &str) -> bool {
+        self.builders.contains(object_name)
+    }
+
+    /// Previous names of a top-level function.  Each one gets a deprecated wrapper that calls the
+    /// function by its current name, so that code written against the old name keeps working.
+    pub fn function_aliases(&self, function_name: &str) -> &[String] {
+        self.function_aliases
+            .get(function_name)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Do any top-level functions have aliases?
+    pub fn has_function_aliases(&self) -> bool {
+        self.function_aliases
+            .values()
+            .any(|aliases| !aliases.is_empty())
+    }
+
+    /// The module to import an external type from.
+    ///
+    /// `external_packages` maps crate names to the modules their bindings are generated into.
+    /// In library mode it's filled in from the configs of the dependent crates.
+    pub fn external_module_name(&self, module_path: &str) -> String {
+        let crate_name = module_path.split("::").next().unwrap();
+        match self.external_packages.get(crate_name) {
+            Some(name) => name.clone(),
+            None => PythonCodeOracle.fn_name(module_path),
+        }
+    }
+
+    /// The sub-namespace that items from a Rust module should be exposed in, if any.
+    ///
+    /// `module_namespaces` maps Rust module paths to namespace names.  An entry also applies to
+    /// the submodules of its module path, with the longest matching path winning.  Only the
+    /// Python bindings support this option.
+    pub fn namespace_for_module(&self, module_path: &str) -> Option<&str> {
+        self.module_namespaces
+            .iter()
+            .filter(|(path, _)| is_in_module(module_path, path))
+            .max_by_key(|(path, _)| path.len())
+            .map(|(_, namespace)| namespace.as_str())
+    }
 }
 
 impl BindingsConfig for Config {
@@ -102,6 +306,7 @@ impl BindingsConfig for Config {
     fn update_from_ci(&mut self, ci: &ComponentInterface) {
         self.cdylib_name
             .get_or_insert_with(|| format!("uniffi_{}", ci.namespace()));
+        self.module_name = Some(ci.namespace().to_string());
     }
 
     fn update_from_cdylib_name(&mut self, cdylib_name: &str) {
@@ -109,11 +314,22 @@ impl BindingsConfig for Config {
             .get_or_insert_with(|| cdylib_name.to_string());
     }
 
-    fn update_from_dependency_configs(&mut self, _config_map: HashMap<&str, &Self>) {}
+    fn update_from_dependency_configs(&mut self, config_map: HashMap<&str, &Self>) {
+        for (crate_name, config) in config_map {
+            if let Some(module_name) = &config.module_name {
+                self.external_packages
+                    .entry(crate_name.to_string())
+                    .or_insert_with(|| module_name.clone());
+            }
+        }
+    }
 }
 
 // Generate python bindings for the given ComponentInterface, as a string.
 pub fn generate_python_bindings(config: &Config, ci: &ComponentInterface) -> Result<String> {
+    check_python_name_collisions(config, ci)?;
+    check_module_namespaces(config, ci)?;
+    check_named_tuple_fields(config, ci)?;
     PythonWrapper::new(config.clone(), ci)
         .render()
         .context("failed to render python bindings")
@@ -251,6 +467,142 @@ impl<'a> PythonWrapper<'a> {
     pub fn imports(&self) -> Vec<ImportRequirement> {
         self.type_imports.iter().cloned().collect()
     }
+
+    /// Items grouped by the sub-namespace that their Rust module maps to.
+    ///
+    /// The items are still defined at the top level of the module, the sub-namespaces only
+    /// provide an additional way to access them that mirrors the Rust module layout.
+    pub fn sub_namespaces(&self) -> BTreeMap<String, Vec<String>> {
+        let mut namespaces: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for (module_path, name) in module_items(self.ci) {
+            if let Some(namespace) = self.config.namespace_for_module(module_path) {
+                namespaces
+                    .entry(namespace.to_string())
+                    .or_default()
+                    .push(name);
+            }
+        }
+        namespaces
+    }
+}
+
+// Is `module_path` the module `path`, or one of its submodules?
+fn is_in_module(module_path: &str, path: &str) -> bool {
+    module_path == path
+        || module_path
+            .strip_prefix(path)
+            .map_or(false, |rest| rest.starts_with("::"))
+}
+
+// The Rust module path and Python name of every item that `module_namespaces` can apply to.
+fn module_items(ci: &ComponentInterface) -> impl Iterator<Item = (&str, String)> {
+    ci.function_definitions()
+        .iter()
+        .map(|f| (f.full_module_path(), PythonCodeOracle.fn_name(f.name())))
+        .chain(
+            ci.object_definitions()
+                .iter()
+                .map(|o| (o.full_module_path(), PythonCodeOracle.class_name(o.name()))),
+        )
+        .chain(
+            ci.record_definitions()
+                .map(|r| (r.full_module_path(), PythonCodeOracle.class_name(r.name()))),
+        )
+        .chain(
+            ci.enum_definitions()
+                .map(|e| (e.full_module_path(), PythonCodeOracle.class_name(e.name()))),
+        )
+        .chain(ci.callback_interface_definitions().iter().map(|cbi| {
+            (
+                cbi.full_module_path(),
+                PythonCodeOracle.class_name(cbi.name()),
+            )
+        }))
+}
+
+// Check that the `module_namespaces` config can be rendered.
+//
+// The namespace names become module-level variables, so they must be identifiers that don't
+// shadow any of the module's own items.  An entry that doesn't match any item is most likely a
+// typo, so that's an error too.
+fn check_module_namespaces(config: &Config, ci: &ComponentInterface) -> Result<()> {
+    let is_identifier = |name: &str| {
+        name.chars().next().map_or(false, |c| !c.is_ascii_digit())
+            && name.chars().all(|c| c == '_' || c.is_ascii_alphanumeric())
+    };
+    let item_names: HashSet<String> = module_items(ci)
+        .map(|(_, name)| name)
+        .chain(std::iter::once("InternalError".to_string()))
+        .collect();
+    for (module_path, namespace) in &config.module_namespaces {
+        if !module_path.split("::").all(is_identifier) {
+            bail!("module_namespaces: `{module_path}` is not a Rust module path");
+        }
+        if !is_identifier(namespace) || KEYWORDS.contains(namespace) {
+            bail!("module_namespaces: `{namespace}` is not a valid Python identifier");
+        }
+        if item_names.contains(namespace) {
+            bail!("module_namespaces: `{namespace}` has the same name as an exported item");
+        }
+        if !module_items(ci).any(|(item_path, _)| is_in_module(item_path, module_path)) {
+            bail!("module_namespaces: `{module_path}` doesn't contain any exported items");
+        }
+    }
+    Ok(())
+}
+
+fn check_python_name_collisions(config: &Config, ci: &ComponentInterface) -> Result<()> {
+    let mut record_methods = vec![];
+    if config.serializable_records() {
+        record_methods.extend(["to_dict", "from_dict"]);
+    }
+    if config.named_tuple_records() {
+        // Fields named after the tuple methods would shadow them
+        record_methods.extend(["count", "index"]);
+    }
+    check_name_collisions(
+        ci,
+        "Python",
+        |kind, nm| match kind {
+            NameKind::Function => PythonCodeOracle.fn_name(nm),
+            NameKind::Variable => PythonCodeOracle.var_name(nm),
+            NameKind::EnumVariant => PythonCodeOracle.enum_variant_name(nm),
+        },
+        &GeneratedNames {
+            function_aliases: ci
+                .function_definitions()
+                .iter()
+                .flat_map(|func| config.function_aliases(func.name()))
+                .map(String::as_str)
+                .collect(),
+            record_methods,
+            // Objects implement `__copy__()`, which can't collide with an exported name
+            clone_method: None,
+        },
+        config.name_collisions(),
+    )
+}
+
+// Check that records can be rendered as named tuples.
+//
+// `collections.namedtuple` rejects field names starting with an underscore.  Fields named after
+// the tuple methods are checked by `check_python_name_collisions()`.
+fn check_named_tuple_fields(config: &Config, ci: &ComponentInterface) -> Result<()> {
+    if !config.named_tuple_records() {
+        return Ok(());
+    }
+    for rec in ci.record_definitions() {
+        for field in rec.fields() {
+            let field_name = PythonCodeOracle.var_name(field.name());
+            if field_name.starts_with('_') {
+                bail!(
+                    "record_style: `{}.{field_name}` can't be a named tuple field, because it starts with an underscore",
+                    rec.name()
+                );
+            }
+        }
+    }
+    Ok(())
 }
 
 fn fixup_keyword(name: String) -> String {
@@ -307,6 +659,7 @@ impl PythonCodeOracle {
                 None => "_UniffiRustBuffer".to_string(),
             },
             FfiType::ForeignBytes => "_UniffiForeignBytes".to_string(),
+            FfiType::Struct(name) => format!("_UniffiStruct{name}"),
             FfiType::ForeignCallback => "_UNIFFI_FOREIGN_CALLBACK_T".to_string(),
             // Pointer to an `asyncio.EventLoop` instance
             FfiType::ForeignExecutorHandle => "ctypes.c_size_t".to_string(),
@@ -322,6 +675,50 @@ impl PythonCodeOracle {
     }
 }
 
+/// Direction of the conversions in the `to_dict()`/`from_dict()` methods of serializable records
+#[derive(Clone, Copy)]
+enum DictConversion {
+    ToDict,
+    FromDict,
+}
+
+/// Python expression that converts `value`, of type `type_`, to or from its dict form.
+///
+/// Only nested records need converting, everything else is stored in the dict as-is.
+fn dict_conversion(type_: &Type, value: &str, direction: DictConversion, depth: usize) -> String {
+    if !type_.iter_types().any(|t| matches!(t, Type::Record { .. })) {
+        return value.to_string();
+    }
+    match type_ {
+        Type::Record { .. } => match direction {
+            DictConversion::ToDict => format!("{value}.to_dict()"),
+            DictConversion::FromDict => {
+                format!("{}.from_dict({value})", type_.as_codetype().type_label())
+            }
+        },
+        Type::Optional { inner_type } => format!(
+            "(None if {value} is None else {})",
+            dict_conversion(inner_type, value, direction, depth)
+        ),
+        Type::Sequence { inner_type } => {
+            let item = format!("_uniffi_v{depth}");
+            format!(
+                "[{} for {item} in {value}]",
+                dict_conversion(inner_type, &item, direction, depth + 1)
+            )
+        }
+        Type::Map { value_type, .. } => {
+            let key = format!("_uniffi_k{depth}");
+            let item = format!("_uniffi_v{depth}");
+            format!(
+                "{{{key}: {} for {key}, {item} in {value}.items()}}",
+                dict_conversion(value_type, &item, direction, depth + 1)
+            )
+        }
+        _ => value.to_string(),
+    }
+}
+
 pub trait AsCodeType {
     fn as_codetype(&self) -> Box<dyn CodeType>;
 }
@@ -349,9 +746,11 @@ impl<T: AsType> AsCodeType for T {
             Type::Boolean => Box::new(primitives::BooleanCodeType),
             Type::String => Box::new(primitives::StringCodeType),
             Type::Bytes => Box::new(primitives::BytesCodeType),
+            Type::ByteArray { len } => Box::new(primitives::FixedBytesCodeType::new(len)),
 
             Type::Timestamp => Box::new(miscellany::TimestampCodeType),
             Type::Duration => Box::new(miscellany::DurationCodeType),
+            Type::JsonValue => Box::new(miscellany::JsonValueCodeType),
 
             Type::Enum { name, .. } => Box::new(enum_::EnumCodeType::new(name)),
             Type::Object { name, .. } => Box::new(object::ObjectCodeType::new(name)),
@@ -370,6 +769,10 @@ impl<T: AsType> AsCodeType for T {
                 key_type,
                 value_type,
             } => Box::new(compounds::MapCodeType::new(*key_type, *value_type)),
+            Type::Range {
+                inner_type,
+                inclusive,
+            } => Box::new(compounds::RangeCodeType::new(*inner_type, inclusive)),
             Type::External { name, .. } => Box::new(external::ExternalCodeType::new(name)),
             Type::Custom { name, .. } => Box::new(custom::CustomCodeType::new(name)),
         }
@@ -423,6 +826,28 @@ pub mod filters {
         ))
     }
 
+    /// The value of a record field in the record's `to_dict()` form
+    pub fn field_to_dict(field: &Field) -> Result<String, askama::Error> {
+        let value = format!("self.{}", PythonCodeOracle.var_name(field.name()));
+        Ok(dict_conversion(
+            &field.as_type(),
+            &value,
+            DictConversion::ToDict,
+            0,
+        ))
+    }
+
+    /// The value of a record field, converted back from the `d` argument of `from_dict()`
+    pub fn field_from_dict(field: &Field) -> Result<String, askama::Error> {
+        let value = format!("d[\"{}\"]", PythonCodeOracle.var_name(field.name()));
+        Ok(dict_conversion(
+            &field.as_type(),
+            &value,
+            DictConversion::FromDict,
+            0,
+        ))
+    }
+
     pub fn literal_py(literal: &Literal, as_ct: &impl AsCodeType) -> Result<String, askama::Error> {
         Ok(as_ct.as_codetype().literal(literal))
     }
@@ -442,6 +867,18 @@ pub mod filters {
         Ok(PythonCodeOracle.fn_name(nm))
     }
 
+    /// Get the Python renderings of a function's aliases.  Aliases are used as-is, and any that
+    /// match the function's own Python name are skipped.
+    pub fn fn_aliases(func: &Function) -> Result<Vec<String>, askama::Error> {
+        let name = PythonCodeOracle.fn_name(func.name());
+        Ok(func
+            .aliases()
+            .iter()
+            .map(|alias| fixup_keyword(alias.clone()))
+            .filter(|alias| *alias != name)
+            .collect())
+    }
+
     /// Get the idiomatic Python rendering of a variable name.
     pub fn var_name(nm: &str) -> Result<String, askama::Error> {
         Ok(PythonCodeOracle.var_name(nm))
@@ -452,3 +889,175 @@ pub mod filters {
         Ok(PythonCodeOracle.enum_variant_name(nm))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use uniffi_meta::{
+        FieldMetadata, FnMetadata, Metadata, MetadataGroup, NamespaceMetadata, RecordMetadata,
+    };
+
+    fn component_interface(module_paths: &[(&str, &str)]) -> ComponentInterface {
+        let mut group = MetadataGroup {
+            namespace: NamespaceMetadata {
+                crate_name: "mycrate".into(),
+                name: "mycrate".into(),
+            },
+            items: Default::default(),
+        };
+        for (module_path, name) in module_paths {
+            group.add_item(Metadata::Func(FnMetadata {
+                module_path: "mycrate".into(),
+                name: name.to_string(),
+                is_async: false,
+                inputs: vec![],
+                return_type: None,
+                throws: None,
+                aliases: vec![],
+                checksum: None,
+                full_module_path: module_path.to_string(),
+            }));
+        }
+        ComponentInterface::from_metadata(group).unwrap()
+    }
+
+    fn config(module_namespaces: &str) -> Config {
+        toml::from_str(&format!("module_namespaces = {{ {module_namespaces} }}")).unwrap()
+    }
+
+    #[test]
+    fn test_namespace_for_module() {
+        let config =
+            config(r#""mycrate::geometry" = "geometry", "mycrate::geometry::shapes" = "shapes""#);
+        assert_eq!(config.namespace_for_module("mycrate"), None);
+        assert_eq!(
+            config.namespace_for_module("mycrate::geometry"),
+            Some("geometry")
+        );
+        assert_eq!(
+            config.namespace_for_module("mycrate::geometry::lines"),
+            Some("geometry")
+        );
+        assert_eq!(
+            config.namespace_for_module("mycrate::geometry::shapes::circle"),
+            Some("shapes")
+        );
+        // Only whole path segments match
+        assert_eq!(config.namespace_for_module("mycrate::geometryx"), None);
+    }
+
+    #[test]
+    fn test_sub_namespaces() {
+        let ci = component_interface(&[
+            ("mycrate", "version"),
+            ("mycrate::geometry", "area"),
+            ("mycrate::geometry::lines", "length"),
+            ("mycrate::io", "read_file"),
+        ]);
+        let config = config(r#""mycrate::geometry" = "geometry", "mycrate::io" = "io""#);
+        let namespaces = PythonWrapper::new(config, &ci).sub_namespaces();
+        assert_eq!(
+            namespaces.into_iter().collect::<Vec<_>>(),
+            vec![
+                (
+                    "geometry".to_string(),
+                    vec!["area".to_string(), "length".to_string()]
+                ),
+                ("io".to_string(), vec!["read_file".to_string()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_check_module_namespaces() {
+        let ci = component_interface(&[("mycrate::geometry", "area")]);
+        check_module_namespaces(&config(r#""mycrate::geometry" = "geometry""#), &ci).unwrap();
+
+        let err = |module_namespaces| {
+            check_module_namespaces(&config(module_namespaces), &ci)
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(
+            err(r#""mycrate::geometry" = "geo-metry""#),
+            "module_namespaces: `geo-metry` is not a valid Python identifier"
+        );
+        assert_eq!(
+            err(r#""mycrate::geometry" = "lambda""#),
+            "module_namespaces: `lambda` is not a valid Python identifier"
+        );
+        assert_eq!(
+            err(r#""mycrate::geometry" = "area""#),
+            "module_namespaces: `area` has the same name as an exported item"
+        );
+        assert_eq!(
+            err(r#""mycrate.geometry" = "geometry""#),
+            "module_namespaces: `mycrate.geometry` is not a Rust module path"
+        );
+        assert_eq!(
+            err(r#""mycrate::io" = "io""#),
+            "module_namespaces: `mycrate::io` doesn't contain any exported items"
+        );
+        assert_eq!(
+            err(r#""mycrate::geometry::shapes" = "shapes""#),
+            "module_namespaces: `mycrate::geometry::shapes` doesn't contain any exported items"
+        );
+    }
+
+    #[test]
+    fn test_check_named_tuple_fields() {
+        let ci = |field_name: &str| {
+            let mut group = MetadataGroup {
+                namespace: NamespaceMetadata {
+                    crate_name: "mycrate".into(),
+                    name: "mycrate".into(),
+                },
+                items: Default::default(),
+            };
+            group.add_item(Metadata::Record(RecordMetadata {
+                module_path: "mycrate".into(),
+                name: "Tally".into(),
+                pod: false,
+                fields: vec![FieldMetadata {
+                    name: field_name.into(),
+                    ty: Type::UInt32,
+                    default: None,
+                    exclude_from_eq: false,
+                }],
+                full_module_path: "mycrate".into(),
+            }));
+            ComponentInterface::from_metadata(group).unwrap()
+        };
+        let named_tuple: Config = toml::from_str(r#"record_style = "named_tuple""#).unwrap();
+        check_named_tuple_fields(&named_tuple, &ci("total")).unwrap();
+        check_python_name_collisions(&named_tuple, &ci("total")).unwrap();
+        // Plain class records can have any field names
+        check_python_name_collisions(&Config::default(), &ci("count")).unwrap();
+
+        // Fields can't shadow the tuple methods
+        for field_name in ["count", "index"] {
+            let err = check_python_name_collisions(&named_tuple, &ci(field_name))
+                .unwrap_err()
+                .to_string();
+            assert!(err.starts_with(&format!(
+                "Record `Tally` has field `{field_name}` and generated method `{field_name}`, which are both named `{field_name}` in Python."
+            )));
+        }
+        // Or the `to_dict()` and `from_dict()` methods of serializable records
+        let serializable: Config = toml::from_str("serializable_records = true").unwrap();
+        check_python_name_collisions(&serializable, &ci("dict")).unwrap();
+        let err = check_python_name_collisions(&serializable, &ci("to_dict"))
+            .unwrap_err()
+            .to_string();
+        assert!(err.starts_with(
+            "Record `Tally` has field `to_dict` and generated method `to_dict`, which are both named `to_dict` in Python."
+        ));
+        // Keywords are escaped with a leading underscore, which `namedtuple` rejects
+        assert_eq!(
+            check_named_tuple_fields(&named_tuple, &ci("from"))
+                .unwrap_err()
+                .to_string(),
+            "record_style: `Tally._from` can't be a named tuple field, because it starts with an underscore"
+        );
+    }
+}
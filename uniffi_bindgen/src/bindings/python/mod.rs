@@ -2,10 +2,8 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use std::process::Command;
-
 use anyhow::Result;
-use camino::Utf8Path;
+use camino::{Utf8Path, Utf8PathBuf};
 use fs_err as fs;
 
 pub mod gen_python;
@@ -20,18 +18,11 @@ pub fn write_bindings(
     ci: &ComponentInterface,
     out_dir: &Utf8Path,
     try_format_code: bool,
-) -> Result<()> {
+) -> Result<Vec<Utf8PathBuf>> {
     let py_file = out_dir.join(format!("{}.py", ci.namespace()));
     fs::write(&py_file, generate_python_bindings(config, ci)?)?;
 
-    if try_format_code {
-        if let Err(e) = Command::new("yapf").arg(&py_file).output() {
-            println!(
-                "Warning: Unable to auto-format {} using yapf: {e:?}",
-                py_file.file_name().unwrap(),
-            )
-        }
-    }
+    super::format_generated_file(config.formatter(), &["yapf"], try_format_code, &py_file);
 
-    Ok(())
+    Ok(vec![py_file])
 }
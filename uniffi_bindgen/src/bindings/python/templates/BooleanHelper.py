@@ -13,4 +13,8 @@ class _UniffiConverterBool(_UniffiConverterPrimitive):
 
     @staticmethod
     def lift(value):
+        {%- if python_config.buffer_checks().is_strict() %}
+        if value not in (0, 1):
+            raise InternalError("Unexpected boolean value; should be 0 or 1")
+        {%- endif %}
         return value != 0
@@ -4,6 +4,9 @@ class _UniffiConverterBytes(_UniffiConverterRustBuffer):
         size = buf.read_i32()
         if size < 0:
             raise InternalError("Unexpected negative byte string length")
+        {%- if python_config.buffer_checks().is_strict() %}
+        buf.check_length(size, 1)
+        {%- endif %}
         return buf.read(size)
 
     @staticmethod
@@ -1,5 +1,40 @@
 import threading
 
+{%- if python_config.handle_map().is_slab() %}
+class SlabHandleMap:
+    """
+    A slab of slots indexed by handle, synchronized with a lock.  Freed slots are reused, so memory
+    stays flat when many callback objects are created and destroyed.
+    """
+
+    def __init__(self):
+        self._slots = []  # type: List[Optional[Any]]
+        self._free_slots = []  # type: List[Handle]
+        self._lock = threading.Lock()
+
+    def insert(self, obj):
+        with self._lock:
+            if self._free_slots:
+                handle = self._free_slots.pop()
+                self._slots[handle] = obj
+            else:
+                handle = len(self._slots)
+                self._slots.append(obj)
+            return handle
+
+    def get(self, handle):
+        with self._lock:
+            if handle < len(self._slots):
+                return self._slots[handle]
+
+    def remove(self, handle):
+        with self._lock:
+            if handle < len(self._slots) and self._slots[handle] is not None:
+                obj = self._slots[handle]
+                self._slots[handle] = None
+                self._free_slots.append(handle)
+                return obj
+{%- else %}
 class ConcurrentHandleMap:
     """
     A map where inserting, getting and removing data is synchronized with a lock.
@@ -36,6 +71,7 @@ class ConcurrentHandleMap:
                 obj = self._left_map.pop(handle)
                 del self._right_map[obj]
                 return obj
+{%- endif %}
 
 # Magic number for the Rust proxy to call using the same mechanism as every other method,
 # to free the callback once it's dropped by Rust.
@@ -46,7 +82,7 @@ _UNIFFI_CALLBACK_ERROR = 1
 _UNIFFI_CALLBACK_UNEXPECTED_ERROR = 2
 
 class _UniffiConverterCallbackInterface:
-    _handle_map = ConcurrentHandleMap()
+    _handle_map = {% if python_config.handle_map().is_slab() %}SlabHandleMap{% else %}ConcurrentHandleMap{% endif %}()
 
     def __init__(self, cb):
         self._foreign_callback = cb
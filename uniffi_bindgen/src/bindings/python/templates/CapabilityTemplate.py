@@ -0,0 +1,14 @@
+# Decide if the capabilities of functions exported with `requires_capability` are available.
+
+_UniffiLib.ffi_{{ ci.ffi_namespace() }}_capability_set.argtypes = (ctypes.c_char_p, ctypes.c_int32, ctypes.c_int8)
+_UniffiLib.ffi_{{ ci.ffi_namespace() }}_capability_set.restype = None
+
+def uniffi_set_capability_available(name, available):
+    """
+    Make a capability available or unavailable.
+
+    This replaces the predicate that the Rust code registered for the capability, if any.  Calling
+    a function whose capability isn't available raises the function's error instead.
+    """
+    name_data = name.encode("utf-8")
+    _UniffiLib.ffi_{{ ci.ffi_namespace() }}_capability_set(name_data, len(name_data), 1 if available else 0)
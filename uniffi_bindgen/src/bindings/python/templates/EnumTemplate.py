@@ -3,8 +3,15 @@
 # variants with associated data. So, we switch here, and generate a stdlib `enum`
 # when none of the variants have associated data, or a generic nested-class
 # construct when they do.
+# Flags map onto the stdlib `enum.IntFlag`, which supports combining values with `|`.
 #}
-{% if e.is_flat() %}
+{% if e.is_flags() %}
+
+class {{ type_name }}(enum.IntFlag):
+    {% for variant in e.variants() -%}
+    {{ variant.name()|enum_variant_py }} = {{ variant.flag_value().unwrap() }}
+    {% endfor %}
+{% elif e.is_flat() %}
 
 class {{ type_name }}(enum.Enum):
     {% for variant in e.variants() -%}
@@ -63,7 +70,18 @@ class {{ type_name }}:
 {% endif %}
 
 class {{ ffi_converter_name }}(_UniffiConverterRustBuffer):
+    {%- if e.is_flags() %}
+    {%- let flags_type = e.flags_type().unwrap() %}
+    @staticmethod
+    def read(buf):
+        return {{ type_name }}({{ flags_type|read_fn }}(buf))
+
+    @staticmethod
+    def write(value, buf):
+        {{ flags_type|write_fn }}(int(value), buf)
+    {%- else %}
     @staticmethod
+    {%- call py::nested_decorator() %}
     def read(buf):
         variant = buf.read_i32()
 
@@ -81,6 +99,8 @@ class {{ ffi_converter_name }}(_UniffiConverterRustBuffer):
         {%- endfor %}
         raise InternalError("Raw enum value doesn't match any cases")
 
+    @staticmethod
+    {%- call py::nested_decorator() %}
     def write(value, buf):
         {%- for variant in e.variants() %}
         {%- if e.is_flat() %}
@@ -94,4 +114,4 @@ class {{ ffi_converter_name }}(_UniffiConverterRustBuffer):
             {%- endfor %}
         {%- endif %}
         {%- endfor %}
-
+    {%- endif %}
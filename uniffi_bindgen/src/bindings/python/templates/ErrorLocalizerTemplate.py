@@ -0,0 +1,25 @@
+# Localize the messages of errors from Rust.
+#
+# Once a localizer is registered with `uniffi_set_error_localizer()`, the `str()` of every error
+# type is routed through it.
+
+_uniffi_error_localizer = None
+
+def _uniffi_localize_error(error_name, variant_name, message):
+    localizer = _uniffi_error_localizer
+    if localizer is None:
+        return message
+    localized = localizer(error_name, variant_name, message)
+    return message if localized is None else localized
+
+def uniffi_set_error_localizer(localizer):
+    """
+    Register the localizer for error messages, or pass `None` to stop localizing them.
+
+    The localizer is called as `localizer(error_name, variant_name, message)` and returns the
+    localized message, or `None` to keep the default message.  `error_name` and `variant_name` are
+    the names of the Rust enum and variant, so that they're the same in all the bindings.  The
+    default message is the Rust message for flat errors, and a list of the fields otherwise.
+    """
+    global _uniffi_error_localizer
+    _uniffi_error_localizer = localizer
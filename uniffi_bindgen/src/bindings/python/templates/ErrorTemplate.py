@@ -15,7 +15,11 @@ class {{ type_name }}:  # type: ignore
     {%- if e.is_flat() %}
     class {{ variant_type_name }}(_UniffiTemp{{ type_name }}):
         def __repr__(self):
-            return "{{ type_name }}.{{ variant_type_name }}({})".format(repr(str(self)))
+            return "{{ type_name }}.{{ variant_type_name }}({})".format(repr(Exception.__str__(self)))
+        {%- if python_config.error_localizer() %}
+        def __str__(self):
+            return _uniffi_localize_error("{{ e.name() }}", "{{ variant.name() }}", Exception.__str__(self))
+        {%- endif %}
     {%- else %}
     class {{ variant_type_name }}(_UniffiTemp{{ type_name }}):
         def __init__(self{% for field in variant.fields() %}, {{ field.name()|var_name }}{% endfor %}):
@@ -32,7 +36,11 @@ class {{ type_name }}:  # type: ignore
             pass
             {%- endif %}
         def __repr__(self):
-            return "{{ type_name }}.{{ variant_type_name }}({})".format(str(self))
+            return "{{ type_name }}.{{ variant_type_name }}({})".format(Exception.__str__(self))
+        {%- if python_config.error_localizer() %}
+        def __str__(self):
+            return _uniffi_localize_error("{{ e.name() }}", "{{ variant.name() }}", Exception.__str__(self))
+        {%- endif %}
     {%- endif %}
     _UniffiTemp{{ type_name }}.{{ variant_type_name }} = {{ variant_type_name }} # type: ignore
     {%- endfor %}
@@ -43,6 +51,7 @@ del _UniffiTemp{{ type_name }}
 
 class {{ ffi_converter_name }}(_UniffiConverterRustBuffer):
     @staticmethod
+    {%- call py::nested_decorator() %}
     def read(buf):
         variant = buf.read_i32()
         {%- for variant in e.variants() %}
@@ -60,6 +69,7 @@ class {{ ffi_converter_name }}(_UniffiConverterRustBuffer):
         raise InternalError("Raw enum value doesn't match any cases")
 
     @staticmethod
+    {%- call py::nested_decorator() %}
     def write(value, buf):
         {%- for variant in e.variants() %}
         if isinstance(value, {{ type_name }}.{{ variant.name()|class_name }}):
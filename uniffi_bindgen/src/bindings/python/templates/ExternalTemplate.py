@@ -1,4 +1,4 @@
-{%- let mod_name = module_path|fn_name %}
+{%- let mod_name = python_config.external_module_name(module_path) %}
 
 {%- let ffi_converter_name = "_UniffiConverterType{}"|format(name) %}
 {{ self.add_import_of(mod_name, ffi_converter_name) }}
@@ -0,0 +1,22 @@
+# `[u8; {{ len }}]` is `bytes` that must have exactly {{ len }} bytes
+class {{ ffi_converter_name }}(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        size = buf.read_i32()
+        if size != {{ len }}:
+            raise InternalError("Expected a byte string of length {{ len }}, got {} bytes".format(size))
+        {%- if python_config.buffer_checks().is_strict() %}
+        buf.check_length(size, 1)
+        {%- endif %}
+        return buf.read(size)
+
+    @staticmethod
+    def write(value, buf):
+        try:
+            memoryview(value)
+        except TypeError:
+            raise TypeError("a bytes-like object is required, not {!r}".format(type(value).__name__))
+        if len(value) != {{ len }}:
+            raise ValueError("Expected a byte string of length {{ len }}, got {} bytes".format(len(value)))
+        buf.write_i32(len(value))
+        buf.write(value)
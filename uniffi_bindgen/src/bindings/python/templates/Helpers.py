@@ -3,6 +3,34 @@
 
 class InternalError(Exception):
     pass
+{%- match config.max_nesting_depth() %}
+{%- when Some with(max_depth) %}
+
+import functools
+import threading
+
+# How deeply records and enums are nested in the value that's currently being read or written on
+# each thread.
+_UNIFFI_NESTING = threading.local()
+
+def _uniffi_nested(func):
+    """
+    Decorator for the `read()` and `write()` methods of records and enums, so that deeply nested
+    values fail cleanly rather than hitting Python's recursion limit.
+    """
+    @functools.wraps(func)
+    def wrapper(*args):
+        depth = getattr(_UNIFFI_NESTING, "depth", 0)
+        if depth >= {{ max_depth }}:
+            raise InternalError("Values can't be nested more than {{ max_depth }} levels deep")
+        _UNIFFI_NESTING.depth = depth + 1
+        try:
+            return func(*args)
+        finally:
+            _UNIFFI_NESTING.depth = depth
+    return wrapper
+{%- else %}
+{%- endmatch %}
 
 class _UniffiRustCallStatus(ctypes.Structure):
     """
@@ -17,6 +45,7 @@ class _UniffiRustCallStatus(ctypes.Structure):
     CALL_SUCCESS = 0
     CALL_ERROR = 1
     CALL_PANIC = 2
+    CALL_CANCELLED = 3
 
     def __str__(self):
         if self.code == _UniffiRustCallStatus.CALL_SUCCESS:
@@ -25,6 +54,8 @@ class _UniffiRustCallStatus(ctypes.Structure):
             return "_UniffiRustCallStatus(CALL_ERROR)"
         elif self.code == _UniffiRustCallStatus.CALL_PANIC:
             return "_UniffiRustCallStatus(CALL_PANIC)"
+        elif self.code == _UniffiRustCallStatus.CALL_CANCELLED:
+            return "_UniffiRustCallStatus(CALL_CANCELLED)"
         else:
             return "_UniffiRustCallStatus(<invalid code>)"
 
@@ -44,23 +75,49 @@ def _rust_call_with_error(error_ffi_converter, fn, *args):
     _uniffi_check_call_status(error_ffi_converter, call_status)
     return result
 
-def _rust_call_async(scaffolding_fn, callback_fn, *args):
+def _rust_call_async(scaffolding_fn, callback_fn, *args, eventloop=None):
     # Call the scaffolding function, passing it a callback handler for `AsyncTypes.py` and a pointer
     # to a python Future object.  The async function then awaits the Future.
-    uniffi_eventloop = asyncio.get_running_loop()
+    #
+    # `eventloop` is only passed when the executor is injected per-call.  The Future is created on
+    # that loop, so the caller must await the function from it.
+    uniffi_eventloop = eventloop if eventloop is not None else asyncio.get_running_loop()
     uniffi_py_future = uniffi_eventloop.create_future()
     uniffi_call_status = _UniffiRustCallStatus(code=_UniffiRustCallStatus.CALL_SUCCESS, error_buf=_UniffiRustBuffer(0, 0, None))
+    # Note: It's tempting to skip the pointer manager and just use a `py_object` pointing to a
+    # local variable like we do in Swift.  However, Python doesn't use cooperative cancellation
+    # -- asyncio can cancel a task at anytime.  This means if we use a local variable, the Rust
+    # callback could fire with a dangling pointer.
+    uniffi_future_ptr = _UniffiPyFuturePointerManager.new_pointer(uniffi_py_future)
+    {%- if config.async_cancellation() %}
+    {%- match ci.ffi_rust_future_cancellable() %}
+    {%- when Some(cancellable_func) %}
+    # Mark the call as cancellable.  Rust only tracks the futures of marked calls, and the mark
+    # applies to the next scaffolding call on this thread.
+    _rust_call(_UniffiLib.{{ cancellable_func.name() }}, uniffi_future_ptr)
+    {%- when None %}
+    {%- endmatch %}
+    {%- endif %}
     scaffolding_fn(*args,
        _UniffiConverterForeignExecutor._pointer_manager.new_pointer(uniffi_eventloop),
        callback_fn,
-       # Note: It's tempting to skip the pointer manager and just use a `py_object` pointing to a
-       # local variable like we do in Swift.  However, Python doesn't use cooperative cancellation
-       # -- asyncio can cancel a task at anytime.  This means if we use a local variable, the Rust
-       # callback could fire with a dangling pointer.
-       _UniffiPyFuturePointerManager.new_pointer(uniffi_py_future),
+       uniffi_future_ptr,
        ctypes.byref(uniffi_call_status),
     )
     _uniffi_check_call_status(None, uniffi_call_status)
+    {%- if config.async_cancellation() %}
+    {%- match ci.ffi_rust_future_cancel() %}
+    {%- when Some(cancel_func) %}
+
+    # Cancel the Rust future when the awaiting task is cancelled.  Rust drops the future and invokes
+    # the callback handler, which releases `uniffi_future_ptr`.
+    def uniffi_cancel_rust_future(future):
+        if future.cancelled():
+            _rust_call(_UniffiLib.{{ cancel_func.name() }}, uniffi_future_ptr)
+    uniffi_py_future.add_done_callback(uniffi_cancel_rust_future)
+    {%- when None %}
+    {%- endmatch %}
+    {%- endif %}
     return uniffi_py_future
 
 def _uniffi_check_call_status(error_ffi_converter, call_status):
@@ -81,6 +138,8 @@ def _uniffi_check_call_status(error_ffi_converter, call_status):
         else:
             msg = "Unknown rust panic"
         raise InternalError(msg)
+    elif call_status.code == _UniffiRustCallStatus.CALL_CANCELLED:
+        raise asyncio.CancelledError()
     else:
         raise InternalError("Invalid _UniffiRustCallStatus code: {}".format(
             call_status.code))
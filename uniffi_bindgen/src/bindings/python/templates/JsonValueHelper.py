@@ -0,0 +1,15 @@
+{{ self.add_import("json") }}
+# The JsonValue type: whatever `json.loads()` returns.  Python integers are unbounded, so integers
+# round-trip exactly.
+JsonValue = typing.Any
+
+# JSON values are passed as their JSON text.
+class _UniffiConverterJsonValue(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        return json.loads(_UniffiConverterString.read(buf))
+
+    @staticmethod
+    def write(value, buf):
+        # Rust can't parse `NaN` or `Infinity`, so fail here rather than on the other side
+        _UniffiConverterString.write(json.dumps(value, allow_nan=False), buf)
@@ -2,6 +2,22 @@
 {%- let value_ffi_converter = value_type|ffi_converter_name %}
 
 class {{ ffi_converter_name }}(_UniffiConverterRustBuffer):
+    @classmethod
+    def lift(cls, rbuf):
+        # Rust can pass empty dicts as an empty buffer, see `empty_buffer_sentinels`.  These aren't
+        # allocated, so there's nothing to free.
+        if rbuf.len == 0:
+            return {}
+        return super().lift(rbuf)
+{%- if python_config.empty_buffer_sentinels() %}
+
+    @classmethod
+    def lower(cls, value):
+        if len(value) == 0:
+            return _UniffiRustBuffer(0, 0, None)
+        return super().lower(value)
+{%- endif %}
+
     @classmethod
     def write(cls, items, buf):
         buf.write_i32(len(items))
@@ -14,6 +30,9 @@ class {{ ffi_converter_name }}(_UniffiConverterRustBuffer):
         count = buf.read_i32()
         if count < 0:
             raise InternalError("Unexpected negative map size")
+        {%- if python_config.buffer_checks().is_strict() %}
+        buf.check_length(count, {{ ci.min_serialized_size(key_type) + ci.min_serialized_size(value_type) }})
+        {%- endif %}
 
         # It would be nice to use a dict comprehension,
         # but in Python 3.7 and before the evaluation order is not according to spec,
@@ -60,6 +60,19 @@ def _uniffi_check_contract_api_version(lib):
     if bindings_contract_version != scaffolding_contract_version:
         raise InternalError("UniFFI contract version mismatch: try cleaning and rebuilding your project")
 
+{%- match config.runtime_version() %}
+{%- when Some with (runtime_version) %}
+
+def _uniffi_check_runtime_version(lib):
+    lib.ffi_{{ ci.ffi_namespace() }}_uniffi_runtime_version.argtypes = ()
+    lib.ffi_{{ ci.ffi_namespace() }}_uniffi_runtime_version.restype = ctypes.c_uint32
+    # The version is packed as `major * 1_000_000 + minor * 1_000 + patch`
+    version = lib.ffi_{{ ci.ffi_namespace() }}_uniffi_runtime_version()
+    if not {{ runtime_version.packed_min() }} <= version <= {{ runtime_version.packed_max() }}:
+        raise InternalError(f"UniFFI runtime version mismatch: these bindings need uniffi {{ runtime_version }}, but the library was built with uniffi {version // 1000000}.{version // 1000 % 1000}.{version % 1000}")
+{%- when None %}
+{%- endmatch %}
+
 def _uniffi_check_api_checksums(lib):
     {%- for (name, expected_checksum) in ci.iter_checksums() %}
     if lib.{{ name }}() != {{ expected_checksum }}:
@@ -68,10 +81,77 @@ def _uniffi_check_api_checksums(lib):
     pass
     {%- endfor %}
 
+{%- if config.handshake() %}
+
+class UniffiHandshake(typing.NamedTuple):
+    """
+    The contract version and interface fingerprint of a Rust library
+
+    Two libraries with the same values are interchangeable as far as these bindings are concerned.
+    """
+    contract_version: int
+    fingerprint: int
+
+# The values that these bindings were generated for
+UNIFFI_EXPECTED_HANDSHAKE = UniffiHandshake({{ ci.uniffi_contract_version() }}, {{ ci.interface_fingerprint() }})
+
+def _uniffi_read_handshake(lib):
+    # This runs before the other functions are looked up, so look up the ones it needs here, and
+    # report the ones that an incompatible library doesn't have.
+    try:
+        contract_version_fn = lib.{{ ci.ffi_uniffi_contract_version().name() }}
+        checksum_fns = [
+            {%- for (name, _) in ci.iter_checksums() %}
+            lib.{{ name }},
+            {%- endfor %}
+        ]
+    except AttributeError as e:
+        raise InternalError(
+            "The Rust library for `{{ ci.namespace() }}` doesn't export the functions that these bindings use: "
+            f"{e}. Regenerate the bindings from the library that you ship."
+        ) from e
+    contract_version_fn.argtypes = ()
+    contract_version_fn.restype = ctypes.c_uint32
+    fingerprint = 0
+    for checksum_fn in checksum_fns:
+        checksum_fn.argtypes = ()
+        checksum_fn.restype = ctypes.c_uint16
+        fingerprint = (fingerprint * 31 + checksum_fn()) & 0xFFFFFFFF
+    return UniffiHandshake(contract_version_fn(), fingerprint)
+
+def _uniffi_check_handshake(lib):
+    actual = _uniffi_read_handshake(lib)
+    expected = UNIFFI_EXPECTED_HANDSHAKE
+    if actual != expected:
+        raise InternalError(
+            "The Rust library for `{{ ci.namespace() }}` isn't compatible with these bindings: "
+            f"the bindings expect contract version {expected.contract_version} and interface fingerprint {expected.fingerprint}, "
+            f"but the library has contract version {actual.contract_version} and interface fingerprint {actual.fingerprint}. "
+            "Regenerate the bindings from the library that you ship."
+        )
+{%- endif %}
+
+{%- for rec in ci.record_definitions() %}
+{%- if rec.is_pod() %}
+
+# `#[repr(C)]` record, which is passed by value to and from Rust
+class _UniffiStruct{{ rec.name() }}(ctypes.Structure):
+    _fields_ = [
+        {%- for field in rec.fields() %}
+        ("{{ field.name() }}", {{ field|ffi_type|ffi_type_name }}),
+        {%- endfor %}
+    ]
+{%- endif %}
+{%- endfor %}
+
 # A ctypes library to expose the extern-C FFI definitions.
 # This is an implementation detail which will be called internally by the public API.
 
 _UniffiLib = _uniffi_load_indirect()
+{%- if config.handshake() %}
+{#- Check the handshake first, since an incompatible library might not have the other functions #}
+_uniffi_check_handshake(_UniffiLib)
+{%- endif %}
 {%- for func in ci.iter_ffi_function_definitions() %}
 _UniffiLib.{{ func.name() }}.argtypes = (
     {%- call py::arg_list_ffi_decl(func) -%}
@@ -79,5 +159,31 @@ _UniffiLib.{{ func.name() }}.argtypes = (
 _UniffiLib.{{ func.name() }}.restype = {% match func.return_type() %}{% when Some with (type_) %}{{ type_|ffi_type_name }}{% when None %}None{% endmatch %}
 {%- endfor %}
 {# Ensure to call the contract verification only after we defined all functions. -#}
+{%- if config.handshake() %}
+
+def uniffi_handshake():
+    """
+    Read the contract version and interface fingerprint of the loaded Rust library
+
+    The bindings already check these when they're imported, this is for reporting them.
+    """
+    return _uniffi_read_handshake(_UniffiLib)
+{%- else %}
 _uniffi_check_contract_api_version(_UniffiLib)
 _uniffi_check_api_checksums(_UniffiLib)
+{%- endif %}
+{%- if config.runtime_version().is_some() %}
+_uniffi_check_runtime_version(_UniffiLib)
+{%- endif %}
+{%- if config.empty_buffer_sentinels() %}
+_UniffiLib.ffi_{{ ci.ffi_namespace() }}_empty_buffer_sentinels_enable.argtypes = ()
+_UniffiLib.ffi_{{ ci.ffi_namespace() }}_empty_buffer_sentinels_enable.restype = None
+_UniffiLib.ffi_{{ ci.ffi_namespace() }}_empty_buffer_sentinels_enable()
+{%- endif %}
+{%- match config.max_nesting_depth() %}
+{%- when Some with(max_depth) %}
+_UniffiLib.ffi_{{ ci.ffi_namespace() }}_max_nesting_depth_set.argtypes = (ctypes.c_uint32,)
+_UniffiLib.ffi_{{ ci.ffi_namespace() }}_max_nesting_depth_set.restype = None
+_UniffiLib.ffi_{{ ci.ffi_namespace() }}_max_nesting_depth_set({{ max_depth }})
+{%- else %}
+{%- endmatch %}
@@ -1,4 +1,5 @@
 {%- let obj = ci|get_object_definition(name) %}
+{%- let is_builder = python_config.is_builder(name) %}
 
 class {{ type_name }}:
     _pointer: ctypes.c_void_p
@@ -27,6 +28,21 @@ class {{ type_name }}:
         return inst
 
 {%- for cons in obj.alternate_constructors() %}
+{%- if cons.is_async() %}
+
+    # Async constructors work like async functions that return the object
+    @classmethod
+    async def {{ cons.name()|fn_name }}(cls, {% call py::arg_list_decl(cons) %}{%- if python_config.async_executor().is_per_call() %}{% if !cons.arguments().is_empty() %}, {% endif %}{% if !cons.has_variadic_argument() %}*, {% endif %}uniffi_executor{% endif %}):
+        {%- call py::setup_args_extra_indent(cons) %}
+        return await _rust_call_async(
+            _UniffiLib.{{ cons.ffi_func().name() }},
+            {{ cons.result_type().borrow()|async_callback_fn }},
+            {% call py::arg_list_lowered(cons) %}
+            {%- if python_config.async_executor().is_per_call() %}{% if !cons.arguments().is_empty() %},{% endif %}
+            eventloop=uniffi_executor,
+            {%- endif %}
+        )
+{%- else %}
 
     @classmethod
     def {{ cons.name()|fn_name }}(cls, {% call py::arg_list_decl(cons) %}):
@@ -34,18 +50,19 @@ class {{ type_name }}:
         # Call the (fallible) function before creating any half-baked object instances.
         pointer = {% call py::to_ffi_call(cons) %}
         return cls._make_instance_(pointer)
+{%- endif %}
 {% endfor %}
 
 {%- for meth in obj.methods() -%}
-    {%- call py::method_decl(meth.name()|fn_name, meth) %}
+    {%- call py::method_decl(meth.name()|fn_name, meth, is_builder) %}
 {% endfor %}
 
 {%- for tm in obj.uniffi_traits() -%}
 {%-     match tm %}
 {%-         when UniffiTrait::Debug { fmt } %}
-            {%- call py::method_decl("__repr__", fmt) %}
+            {%- call py::method_decl("__repr__", fmt, false) %}
 {%-         when UniffiTrait::Display { fmt } %}
-            {%- call py::method_decl("__str__", fmt) %}
+            {%- call py::method_decl("__str__", fmt, false) %}
 {%-         when UniffiTrait::Eq { eq, ne } %}
     def __eq__(self, other: object) -> {{ eq.return_type().unwrap()|type_name }}:
         if not isinstance(other, {{ type_name }}):
@@ -59,7 +76,9 @@ class {{ type_name }}:
 
         return {{ ne.return_type().unwrap()|lift_fn }}({% call py::to_ffi_call_with_prefix("self._pointer", ne) %})
 {%-         when UniffiTrait::Hash { hash } %}
-            {%- call py::method_decl("__hash__", hash) %}
+            {%- call py::method_decl("__hash__", hash, false) %}
+{%-         when UniffiTrait::Clone { clone } %}
+            {%- call py::method_decl("__copy__", clone, false) %}
 {%      endmatch %}
 {% endfor %}
 
@@ -1,6 +1,22 @@
 {%- let inner_ffi_converter = inner_type|ffi_converter_name %}
 
 class {{ ffi_converter_name }}(_UniffiConverterRustBuffer):
+    @classmethod
+    def lift(cls, rbuf):
+        # Rust can pass `None` as an empty buffer, see `empty_buffer_sentinels`.  These aren't
+        # allocated, so there's nothing to free.
+        if rbuf.len == 0:
+            return None
+        return super().lift(rbuf)
+{%- if python_config.empty_buffer_sentinels() %}
+
+    @classmethod
+    def lower(cls, value):
+        if value is None:
+            return _UniffiRustBuffer(0, 0, None)
+        return super().lower(value)
+{%- endif %}
+
     @classmethod
     def write(cls, value, buf):
         if value is None:
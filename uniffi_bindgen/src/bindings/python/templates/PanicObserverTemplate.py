@@ -0,0 +1,35 @@
+# Report Rust panics to foreign crash-reporting tools.
+#
+# Once an observer is registered with `uniffi_set_panic_observer()`, every Rust panic that unwinds to
+# the scaffolding is reported to it, before it's raised as an exception.
+
+_UNIFFI_PANIC_OBSERVER_CALLBACK_T = ctypes.CFUNCTYPE(None, ctypes.POINTER(ctypes.c_uint8), ctypes.c_int32, ctypes.POINTER(ctypes.c_uint8), ctypes.c_int32, ctypes.c_uint32)
+_UniffiLib.ffi_{{ ci.ffi_namespace() }}_panic_observer_set.argtypes = (_UNIFFI_PANIC_OBSERVER_CALLBACK_T,)
+_UniffiLib.ffi_{{ ci.ffi_namespace() }}_panic_observer_set.restype = None
+
+_uniffi_panic_observer = None
+
+@_UNIFFI_PANIC_OBSERVER_CALLBACK_T
+def _uniffi_panic_observer_callback(message_data, message_len, file_data, file_len, line):
+    observer = _uniffi_panic_observer
+    if observer is None:
+        return
+    message = ctypes.string_at(message_data, message_len).decode("utf-8")
+    file = ctypes.string_at(file_data, file_len).decode("utf-8")
+    try:
+        observer(message, file, line)
+    except Exception:
+        # Exceptions can't be raised across the FFI, so the observer is responsible for handling
+        # its own errors.
+        pass
+
+def uniffi_set_panic_observer(observer):
+    """
+    Register the observer that receives Rust panics, or pass `None` to stop reporting them.
+
+    The observer is called as `observer(message, file, line)` when Rust code panics, where `line`
+    is 0 if the location is unknown.
+    """
+    global _uniffi_panic_observer
+    _uniffi_panic_observer = observer
+    _UniffiLib.ffi_{{ ci.ffi_namespace() }}_panic_observer_set(_uniffi_panic_observer_callback)
@@ -0,0 +1,25 @@
+{%- let inner_ffi_converter = inner_type|ffi_converter_name %}
+
+class {{ ffi_converter_name }}(_UniffiConverterRustBuffer):
+    # Rust ranges are passed as a `range` with a step of 1.  Empty and inverted ranges keep their
+    # bounds, for example `5..3` is `range(5, 3)`.
+    @classmethod
+    def write(cls, value, buf):
+        if value.step != 1:
+            raise ValueError("Only ranges with a step of 1 can be passed to Rust")
+        {{ inner_ffi_converter }}.write(value.start, buf)
+        {%- if !inclusive %}
+        {{ inner_ffi_converter }}.write(value.stop, buf)
+        {%- else %}
+        {{ inner_ffi_converter }}.write(value.stop - 1, buf)
+        {%- endif %}
+
+    @classmethod
+    def read(cls, buf):
+        start = {{ inner_ffi_converter }}.read(buf)
+        end = {{ inner_ffi_converter }}.read(buf)
+        {%- if !inclusive %}
+        return range(start, end)
+        {%- else %}
+        return range(start, end + 1)
+        {%- endif %}
@@ -1,4 +1,52 @@
 {%- let rec = ci|get_record_definition(name) %}
+{%- let is_serializable = python_config.serializable_records() && ci.item_is_serializable(type_) %}
+{%- if python_config.named_tuple_records() %}
+{{- self.add_import("collections") }}
+class {{ type_name }}(collections.namedtuple("{{ type_name }}", [{% for field in rec.fields() %}"{{ field.name()|var_name }}"{% if !loop.last %}, {% endif %}{% endfor %}])):
+    __slots__ = ()
+
+    @typing.no_type_check
+    def __new__(cls, {% for field in rec.fields() %}
+    {{- field.name()|var_name }}: "{{- field|type_name }}"
+    {%- if field.default_value().is_some() %} = _DEFAULT{% endif %}
+    {%- if !loop.last %}, {% endif %}
+    {%- endfor %}):
+        {%- for field in rec.fields() %}
+        {%- let field_name = field.name()|var_name %}
+        {%- match field.default_value() %}
+        {%- when None %}
+        {%- when Some with(literal) %}
+        if {{ field_name }} is _DEFAULT:
+            {{ field_name }} = {{ literal|literal_py(field) }}
+        {%- endmatch %}
+        {%- endfor %}
+        return super().__new__(cls, {% for field in rec.fields() %}{{ field.name()|var_name }}{% if !loop.last %}, {% endif %}{% endfor %})
+
+    def __str__(self):
+        return "{{ type_name }}({% for field in rec.fields() %}{{ field.name()|var_name }}={}{% if loop.last %}{% else %}, {% endif %}{% endfor %})".format({% for field in rec.fields() %}self.{{ field.name()|var_name }}{% if loop.last %}{% else %}, {% endif %}{% endfor %})
+    {%- if rec.has_custom_eq() %}
+
+    # Some fields are excluded from equality, so we can't use the tuple implementations
+    def __eq__(self, other):
+        if not isinstance(other, {{ type_name }}):
+            return NotImplemented
+        {%- for field in rec.eq_fields() %}
+        if self.{{ field.name()|var_name }} != other.{{ field.name()|var_name }}:
+            return False
+        {%- endfor %}
+        return True
+
+    def __ne__(self, other):
+        result = self.__eq__(other)
+        return result if result is NotImplemented else not result
+
+    def __hash__(self):
+        return hash(({% for field in rec.eq_fields() %}self.{{ field.name()|var_name }}, {% endfor %}))
+    {%- endif %}
+    {%- if is_serializable %}
+    {%- call py::dict_methods(rec) %}
+    {%- endif %}
+{%- else %}
 class {{ type_name }}:
     {% for field in rec.fields() %}
         {{- field.name()|var_name }}: "{{- field|type_name }}";
@@ -27,14 +75,41 @@ class {{ type_name }}:
         return "{{ type_name }}({% for field in rec.fields() %}{{ field.name()|var_name }}={}{% if loop.last %}{% else %}, {% endif %}{% endfor %})".format({% for field in rec.fields() %}self.{{ field.name()|var_name }}{% if loop.last %}{% else %}, {% endif %}{% endfor %})
 
     def __eq__(self, other):
-        {%- for field in rec.fields() %}
+        {%- for field in rec.eq_fields() %}
         if self.{{ field.name()|var_name }} != other.{{ field.name()|var_name }}:
             return False
         {%- endfor %}
         return True
+    {%- if is_serializable %}
+    {%- call py::dict_methods(rec) %}
+    {%- endif %}
+{%- endif %}
+
+{%- if rec.is_pod() %}
+
+# Passed by value as a `_UniffiStruct{{ rec.name() }}`
+class {{ ffi_converter_name }}:
+    @staticmethod
+    def lift(value):
+        return {{ type_name }}(
+            {%- for field in rec.fields() %}
+            {{ field.name()|var_name }}={{ field|lift_fn }}(value.{{ field.name() }}),
+            {%- endfor %}
+        )
+
+    @staticmethod
+    def lower(value):
+        return _UniffiStruct{{ rec.name() }}(
+            {%- for field in rec.fields() %}
+            {{ field|lower_fn }}(value.{{ field.name()|var_name }}),
+            {%- endfor %}
+        )
+{% else %}
 
 class {{ ffi_converter_name }}(_UniffiConverterRustBuffer):
+{%- endif %}
     @staticmethod
+    {%- call py::nested_decorator() %}
     def read(buf):
         return {{ type_name }}(
             {%- for field in rec.fields() %}
@@ -43,6 +118,7 @@ class {{ ffi_converter_name }}(_UniffiConverterRustBuffer):
         )
 
     @staticmethod
+    {%- call py::nested_decorator() %}
     def write(value, buf):
         {%- for field in rec.fields() %}
         {{ field|write_fn }}(value.{{ field.name()|var_name }}, buf)
@@ -91,6 +91,14 @@ class _UniffiRustBufferStream:
 
     def remaining(self):
         return self.len - self.offset
+{%- if config.buffer_checks().is_strict() %}
+
+    def check_length(self, length, min_item_size):
+        # Check a length prefix against the rest of the buffer, before using it.  Each item takes at
+        # least `min_item_size` bytes.
+        if length * min_item_size > self.remaining():
+            raise InternalError("Invalid length {}, with {} bytes remaining in the buffer".format(length, self.remaining()))
+{%- endif %}
 
     def _unpack_from(self, size, format):
         if self.offset + size > self.len:
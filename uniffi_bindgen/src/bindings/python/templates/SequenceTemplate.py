@@ -1,6 +1,22 @@
 {%- let inner_ffi_converter = inner_type|ffi_converter_name %}
 
 class {{ ffi_converter_name}}(_UniffiConverterRustBuffer):
+    @classmethod
+    def lift(cls, rbuf):
+        # Rust can pass empty lists as an empty buffer, see `empty_buffer_sentinels`.  These aren't
+        # allocated, so there's nothing to free.
+        if rbuf.len == 0:
+            return []
+        return super().lift(rbuf)
+{%- if python_config.empty_buffer_sentinels() %}
+
+    @classmethod
+    def lower(cls, value):
+        if len(value) == 0:
+            return _UniffiRustBuffer(0, 0, None)
+        return super().lower(value)
+{%- endif %}
+
     @classmethod
     def write(cls, value, buf):
         items = len(value)
@@ -13,6 +29,9 @@ class {{ ffi_converter_name}}(_UniffiConverterRustBuffer):
         count = buf.read_i32()
         if count < 0:
             raise InternalError("Unexpected negative sequence length")
+        {%- if python_config.buffer_checks().is_strict() %}
+        buf.check_length(count, {{ ci.min_serialized_size(inner_type) }})
+        {%- endif %}
 
         return [
             {{ inner_ffi_converter }}.read(buf) for i in range(count)
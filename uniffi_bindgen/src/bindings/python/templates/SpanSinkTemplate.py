@@ -0,0 +1,39 @@
+# Report Rust spans to foreign tracing tools.
+#
+# Once a sink is registered with `uniffi_set_span_sink()`, every call into Rust is reported as a span
+# named after the function or method, along with any spans that the Rust code reports itself.
+# `span_id` is the same for the enter and exit events of a span.
+
+_UNIFFI_SPAN_SINK_CALLBACK_T = ctypes.CFUNCTYPE(None, ctypes.c_int8, ctypes.c_uint64, ctypes.POINTER(ctypes.c_uint8), ctypes.c_int32)
+_UniffiLib.ffi_{{ ci.ffi_namespace() }}_span_sink_set.argtypes = (_UNIFFI_SPAN_SINK_CALLBACK_T,)
+_UniffiLib.ffi_{{ ci.ffi_namespace() }}_span_sink_set.restype = None
+
+_UNIFFI_SPAN_ENTER = 0
+_uniffi_span_sink = None
+
+@_UNIFFI_SPAN_SINK_CALLBACK_T
+def _uniffi_span_sink_callback(event, span_id, name_data, name_len):
+    sink = _uniffi_span_sink
+    if sink is None:
+        return
+    name = ctypes.string_at(name_data, name_len).decode("utf-8")
+    try:
+        if event == _UNIFFI_SPAN_ENTER:
+            sink.enter(span_id, name)
+        else:
+            sink.exit(span_id, name)
+    except Exception:
+        # Exceptions can't be raised across the FFI, so the sink is responsible for handling its
+        # own errors.
+        pass
+
+def uniffi_set_span_sink(sink):
+    """
+    Register the sink that receives Rust spans, or pass `None` to stop reporting them.
+
+    The sink needs `enter(span_id, name)` and `exit(span_id, name)` methods, which are called when
+    the current thread enters and exits a Rust span.
+    """
+    global _uniffi_span_sink
+    _uniffi_span_sink = sink
+    _UniffiLib.ffi_{{ ci.ffi_namespace() }}_span_sink_set(_uniffi_span_sink_callback)
@@ -10,6 +10,9 @@ class _UniffiConverterString:
         size = buf.read_i32()
         if size < 0:
             raise InternalError("Unexpected negative string length")
+        {%- if python_config.buffer_checks().is_strict() %}
+        buf.check_length(size, 1)
+        {%- endif %}
         utf8_bytes = buf.read(size)
         return utf8_bytes.decode("utf-8")
 
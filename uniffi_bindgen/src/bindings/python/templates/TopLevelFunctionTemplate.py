@@ -1,11 +1,14 @@
 {%- if func.is_async() %}
 
-async def {{ func.name()|fn_name }}({%- call py::arg_list_decl(func) -%}):
+async def {{ func.name()|fn_name }}({%- call py::arg_list_decl(func) -%}{%- if config.async_executor().is_per_call() %}{% if !func.arguments().is_empty() %}, {% endif %}{% if !func.has_variadic_argument() %}*, {% endif %}uniffi_executor{% endif %}):
     {%- call py::setup_args(func) %}
     return await _rust_call_async(
         _UniffiLib.{{ func.ffi_func().name() }},
         {{ func.result_type().borrow()|async_callback_fn }},
         {% call py::arg_list_lowered(func) %}
+        {%- if config.async_executor().is_per_call() %}{% if !func.arguments().is_empty() %},{% endif %}
+        eventloop=uniffi_executor,
+        {%- endif %}
     )
 
 {%- else %}
@@ -22,3 +25,15 @@ def {{ func.name()|fn_name }}({%- call py::arg_list_decl(func) -%}):
     {% call py::to_ffi_call(func) %}
 {% endmatch %}
 {%- endif %}
+
+{%- for alias in func|fn_aliases %}
+
+{{ alias }} = {{ func.name()|fn_name }}
+{%- endfor %}
+
+{%- for alias in config.function_aliases(func.name()) %}
+
+def {{ alias|fn_name }}(*args, **kwargs):
+    warnings.warn("{{ alias|fn_name }} is deprecated, use {{ func.name()|fn_name }} instead", DeprecationWarning, stacklevel=2)
+    return {{ func.name()|fn_name }}(*args, **kwargs)
+{%- endfor %}
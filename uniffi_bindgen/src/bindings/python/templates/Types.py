@@ -55,6 +55,9 @@
 {%- when Type::Bytes %}
 {%- include "BytesHelper.py" %}
 
+{%- when Type::ByteArray { len } %}
+{%- include "FixedBytesHelper.py" %}
+
 {%- when Type::Enum { name, module_path } %}
 {%- let e = ci.get_enum_definition(name).unwrap() %}
 {# For enums, there are either an error *or* an enum, they can't be both. #}
@@ -64,7 +67,7 @@
 {%- include "EnumTemplate.py" %}
 {% endif %}
 
-{%- when Type::Record { name, module_path } %}
+{%- when Type::Record { name, module_path, pod } %}
 {%- include "RecordTemplate.py" %}
 
 {%- when Type::Object { name, module_path, imp } %}
@@ -76,6 +79,9 @@
 {%- when Type::Duration %}
 {%- include "DurationHelper.py" %}
 
+{%- when Type::JsonValue %}
+{%- include "JsonValueHelper.py" %}
+
 {%- when Type::Optional { inner_type } %}
 {%- include "OptionalTemplate.py" %}
 
@@ -85,6 +91,9 @@
 {%- when Type::Map { key_type, value_type } %}
 {%- include "MapTemplate.py" %}
 
+{%- when Type::Range { inner_type, inclusive } %}
+{%- include "RangeTemplate.py" %}
+
 {%- when Type::CallbackInterface { name: id, module_path } %}
 {%- include "CallbackInterfaceTemplate.py" %}
 
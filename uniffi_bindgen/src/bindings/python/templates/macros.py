@@ -44,11 +44,15 @@ _rust_call(
 
 {% macro arg_list_decl(func) %}
     {%- for arg in func.arguments() -%}
+        {%- match arg.variadic_element_type() %}
+        {%- when Some with (elem) %}*{{ arg.name()|var_name }}: "{{ elem|type_name -}}"
+        {%- when None -%}
         {{ arg.name()|var_name }}
         {%- match arg.default_value() %}
         {%- when Some with(literal) %}: "typing.Union[object, {{ arg|type_name -}}]" = _DEFAULT
         {%- else %}: "{{ arg|type_name -}}"
         {%- endmatch %}
+        {%- endmatch %}
         {%- if !loop.last %},{% endif -%}
     {%- endfor %}
 {%- endmacro %}
@@ -96,17 +100,22 @@ _rust_call(
 
 {#
  # Macro to call methods
+ #
+ # If `returns_self` is set, methods without a return value return `self`, for fluent builders.
  #}
-{%- macro method_decl(py_method_name, meth) %}
+{%- macro method_decl(py_method_name, meth, returns_self) %}
 {%  if meth.is_async() %}
 
-    async def {{ py_method_name }}(self, {% call arg_list_decl(meth) %}):
+    async def {{ py_method_name }}(self, {% call arg_list_decl(meth) %}{%- if python_config.async_executor().is_per_call() %}{% if !meth.arguments().is_empty() %}, {% endif %}{% if !meth.has_variadic_argument() %}*, {% endif %}uniffi_executor{% endif %}):
         {%- call setup_args_extra_indent(meth) %}
         return await _rust_call_async(
             _UniffiLib.{{ func.ffi_func().name() }},
             {{ func.result_type().borrow()|async_callback_fn }},
             self._pointer,
             {% call arg_list_lowered(func) %}
+            {%- if python_config.async_executor().is_per_call() %}{% if !func.arguments().is_empty() %},{% endif %}
+            eventloop=uniffi_executor,
+            {%- endif %}
         )
 
 {%- else -%}
@@ -125,7 +134,42 @@ _rust_call(
     def {{ py_method_name }}(self, {% call arg_list_decl(meth) %}):
         {%- call setup_args_extra_indent(meth) %}
         {% call to_ffi_call_with_prefix("self._pointer", meth) %}
+        {%- if returns_self %}
+        return self
+        {%- endif %}
 {%      endmatch %}
 {%  endif %}
 
 {% endmacro %}
+
+{#-
+// Decorates the `read()` and `write()` methods of records and enums, when the
+// `max_nesting_depth` option is set.
+-#}
+{%- macro nested_decorator() %}
+    {%- if python_config.max_nesting_depth().is_some() %}
+    @_uniffi_nested
+    {%- endif %}
+{%- endmacro -%}
+
+{#-
+// The `to_dict()` and `from_dict()` methods of records, when the
+// `serializable_records` option is set.
+-#}
+{%- macro dict_methods(rec) %}
+
+    def to_dict(self):
+        return {
+            {%- for field in rec.fields() %}
+            "{{ field.name()|var_name }}": {{ field|field_to_dict }},
+            {%- endfor %}
+        }
+
+    @classmethod
+    def from_dict(cls, d):
+        return cls(
+            {%- for field in rec.fields() %}
+            {{ field.name()|var_name }}={{ field|field_from_dict }},
+            {%- endfor %}
+        )
+{%- endmacro -%}
@@ -1,5 +1,6 @@
 # This file was autogenerated by some hot garbage in the `uniffi` crate.
 # Trust me, you don't want to mess with it!
+# The Rust library must be built with uniffi {{ config.required_runtime_version() }}.
 
 # Common helper code.
 #
@@ -21,6 +22,10 @@ import struct
 import contextlib
 import datetime
 import typing
+import types
+{%- if config.has_function_aliases() %}
+import warnings
+{%- endif %}
 {%- if ci.has_async_fns() %}
 import asyncio
 {%- endif %}
@@ -43,12 +48,43 @@ _DEFAULT = object()
 # Public interface members begin here.
 {{ type_helper_code }}
 
+{%- if config.span_sink() %}
+{% include "SpanSinkTemplate.py" %}
+{%- endif %}
+
+{%- if config.panic_observer() %}
+{% include "PanicObserverTemplate.py" %}
+{%- endif %}
+
+{%- if config.capability_registration() %}
+{% include "CapabilityTemplate.py" %}
+{%- endif %}
+
+{%- if config.error_localizer() %}
+{% include "ErrorLocalizerTemplate.py" %}
+{%- endif %}
+
 {%- for func in ci.function_definitions() %}
 {%- include "TopLevelFunctionTemplate.py" %}
 {%- endfor %}
 
+{%- let sub_namespaces = self.sub_namespaces() %}
+{%- for (namespace, members) in sub_namespaces %}
+
+{{ namespace }} = types.SimpleNamespace(
+    {%- for member in members %}
+    {{ member }}={{ member }},
+    {%- endfor %}
+)
+{%- endfor %}
+
 __all__ = [
     "InternalError",
+    {%- if config.handshake() %}
+    "UniffiHandshake",
+    "UNIFFI_EXPECTED_HANDSHAKE",
+    "uniffi_handshake",
+    {%- endif %}
     {%- for e in ci.enum_definitions() %}
     "{{ e|type_name }}",
     {%- endfor %}
@@ -57,6 +93,12 @@ __all__ = [
     {%- endfor %}
     {%- for func in ci.function_definitions() %}
     "{{ func.name()|fn_name }}",
+    {%- for alias in func|fn_aliases %}
+    "{{ alias }}",
+    {%- endfor %}
+    {%- for alias in config.function_aliases(func.name()) %}
+    "{{ alias|fn_name }}",
+    {%- endfor %}
     {%- endfor %}
     {%- for obj in ci.object_definitions() %}
     "{{ obj|type_name }}",
@@ -64,6 +106,21 @@ __all__ = [
     {%- for c in ci.callback_interface_definitions() %}
     "{{ c.name()|class_name }}",
     {%- endfor %}
+    {%- for namespace in sub_namespaces.keys() %}
+    "{{ namespace }}",
+    {%- endfor %}
+    {%- if config.span_sink() %}
+    "uniffi_set_span_sink",
+    {%- endif %}
+    {%- if config.panic_observer() %}
+    "uniffi_set_panic_observer",
+    {%- endif %}
+    {%- if config.capability_registration() %}
+    "uniffi_set_capability_available",
+    {%- endif %}
+    {%- if config.error_localizer() %}
+    "uniffi_set_error_localizer",
+    {%- endif %}
 ]
 
 {% import "macros.py" as py %}
@@ -41,6 +41,7 @@ pub fn run_script(
     generate_bindings(
         &cdylib_path,
         None,
+        None,
         &[TargetLanguage::Python],
         &out_dir,
         false,
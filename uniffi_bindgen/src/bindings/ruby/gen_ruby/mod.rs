@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 use std::borrow::Borrow;
 use std::collections::HashMap;
 
+use crate::backend::Formatter;
 use crate::interface::*;
 use crate::BindingsConfig;
 
@@ -44,6 +45,7 @@ pub fn canonical_name(t: &Type) -> String {
         Type::Float64 => "f64".into(),
         Type::String => "string".into(),
         Type::Bytes => "bytes".into(),
+        Type::ByteArray { len } => format!("bytes{len}"),
         Type::Boolean => "bool".into(),
         // API defined types.
         // Note that these all get unique names, and the parser ensures that the names do not
@@ -57,6 +59,7 @@ pub fn canonical_name(t: &Type) -> String {
         Type::CallbackInterface { name, .. } => format!("CallbackInterface{name}"),
         Type::Timestamp => "Timestamp".into(),
         Type::Duration => "Duration".into(),
+        Type::JsonValue => "JsonValue".into(),
         Type::ForeignExecutor => "ForeignExecutor".into(),
         // Recursive types.
         // These add a prefix to the name of the underlying type.
@@ -73,6 +76,14 @@ pub fn canonical_name(t: &Type) -> String {
             canonical_name(key_type).to_upper_camel_case(),
             canonical_name(value_type).to_upper_camel_case()
         ),
+        Type::Range {
+            inner_type,
+            inclusive: false,
+        } => format!("Range{}", canonical_name(inner_type)),
+        Type::Range {
+            inner_type,
+            inclusive: true,
+        } => format!("RangeInclusive{}", canonical_name(inner_type)),
         // A type that exists externally.
         Type::External { name, .. } | Type::Custom { name, .. } => format!("Type{name}"),
     }
@@ -85,6 +96,7 @@ pub fn canonical_name(t: &Type) -> String {
 pub struct Config {
     cdylib_name: Option<String>,
     cdylib_path: Option<String>,
+    formatter: Option<Formatter>,
 }
 
 impl Config {
@@ -101,6 +113,12 @@ impl Config {
     pub fn cdylib_path(&self) -> String {
         self.cdylib_path.clone().unwrap_or_default()
     }
+
+    /// How the generated bindings are formatted, see [Formatter].  This overrides whether
+    /// formatting was requested when generating the bindings.
+    pub fn formatter(&self) -> Option<&Formatter> {
+        self.formatter.as_ref()
+    }
 }
 
 impl BindingsConfig for Config {
@@ -155,6 +173,11 @@ mod filters {
             FfiType::RustArcPtr(_) => ":pointer".to_string(),
             FfiType::RustBuffer(_) => "RustBuffer.by_value".to_string(),
             FfiType::ForeignBytes => "ForeignBytes".to_string(),
+            FfiType::Struct(name) => {
+                return Err(askama::Error::Custom(
+                    format!("`#[uniffi(pod)]` record `{name}` is not supported").into(),
+                ))
+            }
             FfiType::ForeignCallback => unimplemented!("Callback interfaces are not implemented"),
             FfiType::ForeignExecutorCallback => {
                 unimplemented!("Foreign executors are not implemented")
@@ -262,6 +285,9 @@ mod filters {
                     )
                 }
             }
+            Type::Range { .. } => panic!("No support for ranges, yet"),
+            Type::ByteArray { .. } => panic!("No support for fixed-size byte arrays, yet"),
+            Type::JsonValue => panic!("No support for JSON values, yet"),
             Type::External { .. } => panic!("No support for external types, yet"),
             Type::Custom { .. } => panic!("No support for custom types, yet"),
             Type::ForeignExecutor => unimplemented!("Foreign executors are not implemented"),
@@ -298,6 +324,9 @@ mod filters {
                 class_name_rb(&canonical_name(type_))?,
                 nm
             ),
+            Type::Range { .. } => panic!("No support for lowering ranges, yet"),
+            Type::ByteArray { .. } => panic!("No support for lowering fixed-size byte arrays, yet"),
+            Type::JsonValue => panic!("No support for lowering JSON values, yet"),
             Type::External { .. } => panic!("No support for lowering external types, yet"),
             Type::Custom { .. } => panic!("No support for lowering custom types, yet"),
             Type::ForeignExecutor => unimplemented!("Foreign executors are not implemented"),
@@ -339,6 +368,9 @@ mod filters {
                 nm,
                 class_name_rb(&canonical_name(type_))?
             ),
+            Type::Range { .. } => panic!("No support for lifting ranges, yet"),
+            Type::ByteArray { .. } => panic!("No support for lifting fixed-size byte arrays, yet"),
+            Type::JsonValue => panic!("No support for lifting JSON values, yet"),
             Type::External { .. } => panic!("No support for lifting external types, yet"),
             Type::Custom { .. } => panic!("No support for lifting custom types, yet"),
             Type::ForeignExecutor => unimplemented!("Foreign executors are not implemented"),
@@ -2,10 +2,8 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use std::process::Command;
-
-use anyhow::{Context, Result};
-use camino::Utf8Path;
+use anyhow::{bail, Context, Result};
+use camino::{Utf8Path, Utf8PathBuf};
 use fs_err as fs;
 
 pub mod gen_ruby;
@@ -22,26 +20,30 @@ pub fn write_bindings(
     ci: &ComponentInterface,
     out_dir: &Utf8Path,
     try_format_code: bool,
-) -> Result<()> {
+) -> Result<Vec<Utf8PathBuf>> {
     let rb_file = out_dir.join(format!("{}.rb", ci.namespace()));
     fs::write(&rb_file, generate_ruby_bindings(config, ci)?)?;
 
-    if try_format_code {
-        if let Err(e) = Command::new("rubocop").arg("-A").arg(&rb_file).output() {
-            println!(
-                "Warning: Unable to auto-format {} using rubocop: {e:?}",
-                rb_file.file_name().unwrap(),
-            )
-        }
-    }
+    super::format_generated_file(
+        config.formatter(),
+        &["rubocop", "-A"],
+        try_format_code,
+        &rb_file,
+    );
 
-    Ok(())
+    Ok(vec![rb_file])
 }
 
 // Generate ruby bindings for the given ComponentInterface, as a string.
 
 pub fn generate_ruby_bindings(config: &Config, ci: &ComponentInterface) -> Result<String> {
     use askama::Template;
+    if let Some(rec) = ci.record_definitions().find(|rec| rec.is_pod()) {
+        bail!(
+            "`#[uniffi(pod)]` records are not supported by the Ruby bindings (found `{}`)",
+            rec.name()
+        );
+    }
     RubyWrapper::new(config.clone(), ci)
         .render()
         .context("failed to render ruby bindings")
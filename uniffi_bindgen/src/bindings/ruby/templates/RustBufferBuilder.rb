@@ -188,7 +188,7 @@ class RustBufferBuilder
  end
    {% endif %}
 
-  {% when Type::Record { name: record_name, module_path } -%}
+  {% when Type::Record { name: record_name, module_path, pod } -%}
   {%- let rec = ci|get_record_definition(record_name) -%}
   # The Record type {{ record_name }}.
 
@@ -124,7 +124,7 @@ class RustBuffer < FFI::Struct
     end
   end
 
-  {% when Type::Record { name: record_name, module_path } -%}
+  {% when Type::Record { name: record_name, module_path, pod } -%}
   {%- let rec = ci|get_record_definition(record_name) -%}
   # The Record type {{ record_name }}.
 
@@ -171,6 +171,10 @@ class RustBuffer < FFI::Struct
   end
 
   def consumeInto{{ canonical_type_name }}
+    # Rust can pass `nil` as an empty buffer, see `empty_buffer_sentinels`.  These aren't
+    # allocated, so there's nothing to free.
+    return nil if len.zero?
+
     consumeWithStream do |stream|
       return stream.read{{ canonical_type_name }}
     end
@@ -187,6 +191,10 @@ class RustBuffer < FFI::Struct
   end
 
   def consumeInto{{ canonical_type_name }}
+    # Rust can pass empty arrays as an empty buffer, see `empty_buffer_sentinels`.  These aren't
+    # allocated, so there's nothing to free.
+    return [] if len.zero?
+
     consumeWithStream do |stream|
       return stream.read{{ canonical_type_name }}
     end
@@ -203,6 +211,10 @@ class RustBuffer < FFI::Struct
   end
 
   def consumeInto{{ canonical_type_name }}
+    # Rust can pass empty hashes as an empty buffer, see `empty_buffer_sentinels`.  These aren't
+    # allocated, so there's nothing to free.
+    return {} if len.zero?
+
     consumeWithStream do |stream|
       return stream.read{{ canonical_type_name }}
     end
@@ -105,3 +105,35 @@ impl CodeType for MapCodeType {
         }
     }
 }
+
+#[derive(Debug)]
+pub struct RangeCodeType {
+    inner: Type,
+    inclusive: bool,
+}
+
+impl RangeCodeType {
+    pub fn new(inner: Type, inclusive: bool) -> Self {
+        Self { inner, inclusive }
+    }
+}
+
+impl CodeType for RangeCodeType {
+    fn type_label(&self) -> String {
+        let inner = super::SwiftCodeOracle.find(&self.inner).type_label();
+        if self.inclusive {
+            format!("UniffiClosedRange<{inner}>")
+        } else {
+            format!("UniffiRange<{inner}>")
+        }
+    }
+
+    fn canonical_name(&self) -> String {
+        let inner = super::SwiftCodeOracle.find(&self.inner).canonical_name();
+        if self.inclusive {
+            format!("RangeInclusive{inner}")
+        } else {
+            format!("Range{inner}")
+        }
+    }
+}
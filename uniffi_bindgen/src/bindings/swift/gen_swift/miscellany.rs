@@ -29,3 +29,16 @@ impl CodeType for DurationCodeType {
         "Duration".into()
     }
 }
+
+#[derive(Debug)]
+pub struct JsonValueCodeType;
+
+impl CodeType for JsonValueCodeType {
+    fn type_label(&self) -> String {
+        "JsonValue".into()
+    }
+
+    fn canonical_name(&self) -> String {
+        "JsonValue".into()
+    }
+}
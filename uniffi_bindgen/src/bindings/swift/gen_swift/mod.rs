@@ -7,13 +7,16 @@ use std::borrow::Borrow;
 use std::cell::RefCell;
 use std::collections::{BTreeSet, HashMap, HashSet};
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use askama::Template;
 use heck::{ToLowerCamelCase, ToUpperCamelCase};
 use serde::{Deserialize, Serialize};
 
 use super::Bindings;
-use crate::backend::{CodeType, TemplateExpression};
+use crate::backend::{
+    check_name_collisions, AsyncExecutor, BufferChecks, CodeType, Formatter, GeneratedNames,
+    HandleMapKind, NameCollisions, NameKind, RuntimeVersion, TemplateExpression,
+};
 use crate::interface::*;
 use crate::BindingsConfig;
 
@@ -136,6 +139,32 @@ pub struct Config {
     ffi_module_filename: Option<String>,
     generate_module_map: Option<bool>,
     omit_argument_labels: Option<bool>,
+    shared_header_filename: Option<String>,
+    #[serde(default)]
+    async_executor: AsyncExecutor,
+    async_cancellation: Option<bool>,
+    #[serde(default)]
+    handle_map: HandleMapKind,
+    #[serde(default)]
+    buffer_checks: BufferChecks,
+    max_nesting_depth: Option<u32>,
+    handshake: Option<bool>,
+    span_sink: Option<bool>,
+    panic_observer: Option<bool>,
+    capability_registration: Option<bool>,
+    error_localizer: Option<bool>,
+    empty_buffer_sentinels: Option<bool>,
+    formatter: Option<Formatter>,
+    serializable_records: Option<bool>,
+    runtime_version: Option<RuntimeVersion>,
+    #[serde(default)]
+    name_collisions: NameCollisions,
+    explicit_integer_literals: Option<bool>,
+    swift_language_version: Option<String>,
+    #[serde(default)]
+    builders: HashSet<String>,
+    #[serde(default)]
+    function_aliases: HashMap<String, Vec<String>>,
     #[serde(default)]
     custom_types: HashMap<String, CustomTypeConfig>,
 }
@@ -197,10 +226,172 @@ impl Config {
         self.generate_module_map.unwrap_or(true)
     }
 
+    /// The name of a separate `.h` file for the C structs shared by all UniFFI components.
+    ///
+    /// By default these are declared in each component's header.  When several components are
+    /// linked into the same app, it can be better to declare them once and have each header
+    /// include them.
+    pub fn shared_header_filename(&self) -> Option<&str> {
+        self.shared_header_filename.as_deref()
+    }
+
     /// Whether to omit argument labels in Swift function definitions.
     pub fn omit_argument_labels(&self) -> bool {
         self.omit_argument_labels.unwrap_or(false)
     }
+
+    /// How async functions obtain the `UniFfiForeignExecutor` used to drive Rust futures.
+    pub fn async_executor(&self) -> AsyncExecutor {
+        self.async_executor
+    }
+
+    /// Should cancelling the `Task` of an async call also cancel the Rust future?  If so, async
+    /// functions are declared `throws`, since they throw `CancellationError` when cancelled.
+    pub fn async_cancellation(&self) -> bool {
+        self.async_cancellation.unwrap_or(false)
+    }
+
+    /// Which handle map implementation tracks callback interface objects passed to Rust.
+    pub fn handle_map(&self) -> HandleMapKind {
+        self.handle_map
+    }
+
+    /// How strictly lifting code checks the buffers it reads values from.
+    pub fn buffer_checks(&self) -> BufferChecks {
+        self.buffer_checks
+    }
+
+    /// The maximum depth that records and enums can be nested in values that are lifted, or `None`
+    /// for no limit.  Values nested deeper than this fail with an internal error, instead of
+    /// overflowing the stack.  Lowered values are checked by the scaffolding, which gets the same
+    /// limit.
+    pub fn max_nesting_depth(&self) -> Option<u32> {
+        self.max_nesting_depth
+    }
+
+    /// Should the bindings expose a handshake, which reports the contract version and interface
+    /// fingerprint of the loaded library so that foreign code can check it's compatible?
+    pub fn handshake(&self) -> bool {
+        self.handshake.unwrap_or(false)
+    }
+
+    /// Should the bindings let foreign code register a span sink, to see the spans of calls into Rust?
+    pub fn span_sink(&self) -> bool {
+        self.span_sink.unwrap_or(false)
+    }
+
+    /// Should the bindings let foreign code register a panic observer, to be notified of Rust panics?
+    pub fn panic_observer(&self) -> bool {
+        self.panic_observer.unwrap_or(false)
+    }
+
+    /// Should the bindings let foreign code decide if the capabilities of functions exported with
+    /// `requires_capability` are available?
+    pub fn capability_registration(&self) -> bool {
+        self.capability_registration.unwrap_or(false)
+    }
+
+    /// Should the bindings let foreign code register a localizer, which the messages of error types
+    /// are routed through?
+    pub fn error_localizer(&self) -> bool {
+        self.error_localizer.unwrap_or(false)
+    }
+
+    /// Should empty arrays and dictionaries, and `nil`, be passed as empty buffers rather than
+    /// allocating a buffer for them?
+    pub fn empty_buffer_sentinels(&self) -> bool {
+        self.empty_buffer_sentinels.unwrap_or(false)
+    }
+
+    /// How the generated bindings are formatted, see [Formatter].  This overrides whether
+    /// formatting was requested when generating the bindings.
+    pub fn formatter(&self) -> Option<&Formatter> {
+        self.formatter.as_ref()
+    }
+
+    /// Should records and enums implement `Codable`?
+    ///
+    /// Defaults to the `serializable_records` option of the `[bindings]` section.
+    pub fn serializable_records(&self) -> bool {
+        self.serializable_records.unwrap_or(false)
+    }
+
+    pub(crate) fn set_default_serializable_records(&mut self, default: bool) {
+        self.serializable_records.get_or_insert(default);
+    }
+
+    /// The `uniffi` runtime version that the bindings check for when they load the library, if
+    /// they're pinned to one.
+    ///
+    /// Defaults to the `runtime_version` option of the `[bindings]` section.
+    pub fn runtime_version(&self) -> Option<RuntimeVersion> {
+        self.runtime_version
+    }
+
+    /// The `uniffi` runtime version that the generated file header documents: the pinned version,
+    /// or else the version that generated the bindings.
+    pub fn required_runtime_version(&self) -> RuntimeVersion {
+        self.runtime_version.unwrap_or_else(RuntimeVersion::bindgen)
+    }
+
+    pub(crate) fn set_default_runtime_version(&mut self, default: Option<RuntimeVersion>) {
+        if self.runtime_version.is_none() {
+            self.runtime_version = default;
+        }
+    }
+
+    /// What happens when names collide after they're converted to Swift conventions
+    pub fn name_collisions(&self) -> NameCollisions {
+        self.name_collisions
+    }
+
+    /// Should `Int32` literals be converted explicitly, like the other sized numbers, rather than
+    /// relying on Swift to infer their type from the declaration?
+    pub fn explicit_integer_literals(&self) -> bool {
+        self.explicit_integer_literals.unwrap_or(false)
+    }
+
+    /// The oldest Swift language version that the generated code must compile with, as
+    /// `(major, minor)`.  `None` when not configured, in which case the generated code only uses
+    /// syntax that's supported by every Swift version that UniFFI supports.
+    pub fn swift_language_version(&self) -> Result<Option<(u32, u32)>> {
+        self.swift_language_version
+            .as_deref()
+            .map(parse_swift_language_version)
+            .transpose()
+    }
+
+    /// Is the configured Swift language version at least `major.minor`?
+    fn targets_swift(&self, major: u32, minor: u32) -> bool {
+        matches!(self.swift_language_version(), Ok(Some(v)) if v >= (major, minor))
+    }
+
+    /// Should objects declare `Sendable` conformance?  This requires Swift 5.5, so it's only
+    /// done when the configured language version supports it.
+    pub fn sendable_objects(&self) -> bool {
+        self.targets_swift(5, 5)
+    }
+
+    /// Should callback interface types be spelled `any Protocol`?  The `any` keyword requires
+    /// Swift 5.6, and Swift 6 warns about existential types that don't use it.
+    pub fn existential_any(&self) -> bool {
+        self.targets_swift(5, 6)
+    }
+
+    /// Is this object a builder?  Builder methods that don't return anything return `self` instead,
+    /// so that calls can be chained.
+    pub fn is_builder(&self, object_name: &str) -> bool {
+        self.builders.contains(object_name)
+    }
+
+    /// Previous names of a top-level function.  Each one gets a deprecated wrapper that calls the
+    /// function by its current name, so that code written against the old name keeps working.
+    pub fn function_aliases(&self, function_name: &str) -> &[String] {
+        self.function_aliases
+            .get(function_name)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
 }
 
 impl BindingsConfig for Config {
@@ -221,15 +412,71 @@ impl BindingsConfig for Config {
     fn update_from_dependency_configs(&mut self, _config_map: HashMap<&str, &Self>) {}
 }
 
+/// Parse a Swift language version like `5.5` or `5` into `(major, minor)`
+fn parse_swift_language_version(version: &str) -> Result<(u32, u32)> {
+    let mut parts = version.split('.').map(str::parse::<u32>);
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(Ok(major)), None, None) => Ok((major, 0)),
+        (Some(Ok(major)), Some(Ok(minor)), None) => Ok((major, minor)),
+        _ => bail!("Invalid swift_language_version `{version}`, expected a version like `5.5`"),
+    }
+}
+
 /// Generate UniFFI component bindings for Swift, as strings in memory.
 ///
 pub fn generate_bindings(config: &Config, ci: &ComponentInterface) -> Result<Bindings> {
+    check_name_collisions(
+        ci,
+        "Swift",
+        |kind, nm| match kind {
+            NameKind::Function => SwiftCodeOracle.fn_name(nm),
+            NameKind::Variable => SwiftCodeOracle.var_name(nm),
+            NameKind::EnumVariant => SwiftCodeOracle.enum_variant_name(nm),
+        },
+        &GeneratedNames {
+            function_aliases: ci
+                .function_definitions()
+                .iter()
+                .flat_map(|func| config.function_aliases(func.name()))
+                .map(String::as_str)
+                .collect(),
+            record_methods: vec![],
+            clone_method: Some("copy"),
+        },
+        config.name_collisions(),
+    )?;
+    if let Some((major, minor)) = config.swift_language_version()? {
+        // async/await was introduced in Swift 5.5, and there's no equivalent that keeps the
+        // same API for older versions.
+        if (major, minor) < (5, 5) && ci.has_async_fns() {
+            bail!(
+                "Async functions require Swift 5.5 or later, but swift_language_version is {major}.{minor}"
+            );
+        }
+    }
+    for func in ci.function_definitions() {
+        // Swift can't forward variadic arguments, so there's no way to call the renamed function.
+        if func.has_variadic_argument() && !config.function_aliases(func.name()).is_empty() {
+            bail!(
+                "Function aliases are not supported for variadic functions ({})",
+                func.name()
+            );
+        }
+    }
     let header = BridgingHeader::new(config, ci)
         .render()
         .context("failed to render Swift bridging header")?;
     let library = SwiftWrapper::new(config.clone(), ci)
         .render()
         .context("failed to render Swift library")?;
+    let shared_header = match config.shared_header_filename() {
+        Some(_) => Some(
+            SharedHeader
+                .render()
+                .context("failed to render Swift shared header")?,
+        ),
+        None => None,
+    };
     let modulemap = if config.generate_module_map() {
         Some(
             ModuleMap::new(config, ci)
@@ -242,6 +489,7 @@ pub fn generate_bindings(config: &Config, ci: &ComponentInterface) -> Result<Bin
     Ok(Bindings {
         library,
         header,
+        shared_header,
         modulemap,
     })
 }
@@ -303,19 +551,25 @@ impl<'a> TypeRenderer<'a> {
 #[derive(Template)]
 #[template(syntax = "c", escape = "none", path = "BridgingHeaderTemplate.h")]
 pub struct BridgingHeader<'config, 'ci> {
-    _config: &'config Config,
+    config: &'config Config,
     ci: &'ci ComponentInterface,
 }
 
 impl<'config, 'ci> BridgingHeader<'config, 'ci> {
     pub fn new(config: &'config Config, ci: &'ci ComponentInterface) -> Self {
-        Self {
-            _config: config,
-            ci,
-        }
+        Self { config, ci }
     }
 }
 
+/// Template for generating the `.h` file with the C structs shared by all components.
+///
+/// This is only used when the `shared_header_filename` config is set, otherwise the same
+/// declarations are part of each [`BridgingHeader`].  The output doesn't depend on the
+/// component, so writing it once per component is harmless.
+#[derive(Template)]
+#[template(syntax = "c", escape = "none", path = "SharedHeaderTemplate.h")]
+pub struct SharedHeader;
+
 /// Template for generating the `.modulemap` file that exposes the low-level C FFI.
 ///
 /// This file defines how the low-level C FFI from [`BridgingHeader`] gets exposed
@@ -395,9 +649,11 @@ impl SwiftCodeOracle {
             Type::Boolean => Box::new(primitives::BooleanCodeType),
             Type::String => Box::new(primitives::StringCodeType),
             Type::Bytes => Box::new(primitives::BytesCodeType),
+            Type::ByteArray { len } => Box::new(primitives::FixedBytesCodeType::new(len)),
 
             Type::Timestamp => Box::new(miscellany::TimestampCodeType),
             Type::Duration => Box::new(miscellany::DurationCodeType),
+            Type::JsonValue => Box::new(miscellany::JsonValueCodeType),
 
             Type::Enum { name, .. } => Box::new(enum_::EnumCodeType::new(name)),
             Type::Object { name, .. } => Box::new(object::ObjectCodeType::new(name)),
@@ -416,6 +672,10 @@ impl SwiftCodeOracle {
                 key_type,
                 value_type,
             } => Box::new(compounds::MapCodeType::new(*key_type, *value_type)),
+            Type::Range {
+                inner_type,
+                inclusive,
+            } => Box::new(compounds::RangeCodeType::new(*inner_type, inclusive)),
             Type::External { name, .. } => Box::new(external::ExternalCodeType::new(name)),
             Type::Custom { name, .. } => Box::new(custom::CustomCodeType::new(name)),
         }
@@ -460,6 +720,7 @@ impl SwiftCodeOracle {
             FfiType::RustArcPtr(_) => "UnsafeMutableRawPointer".into(),
             FfiType::RustBuffer(_) => "RustBuffer".into(),
             FfiType::ForeignBytes => "ForeignBytes".into(),
+            FfiType::Struct(name) => format!("UniFfiStruct{name}"),
             FfiType::ForeignCallback => "ForeignCallback".into(),
             FfiType::ForeignExecutorHandle => "Int".into(),
             FfiType::ForeignExecutorCallback => "ForeignExecutorCallback".into(),
@@ -494,8 +755,35 @@ pub mod filters {
         &SwiftCodeOracle
     }
 
-    pub fn type_name(as_type: &impl AsType) -> Result<String, askama::Error> {
-        Ok(oracle().find(&as_type.as_type()).type_label())
+    pub fn type_name(as_type: &impl AsType, config: &Config) -> Result<String, askama::Error> {
+        Ok(type_label(&as_type.as_type(), config))
+    }
+
+    /// The Swift type for a `Type`.  This is the oracle's type label, except that callback
+    /// interfaces are existential types, which are spelled `any Protocol` when the configured
+    /// Swift version supports it.
+    fn type_label(type_: &Type, config: &Config) -> String {
+        if !config.existential_any() {
+            return oracle().find(type_).type_label();
+        }
+        match type_ {
+            Type::CallbackInterface { .. } => format!("any {}", oracle().find(type_).type_label()),
+            Type::Optional { inner_type } => match type_label(inner_type, config) {
+                // `any Protocol?` would make the protocol optional, rather than the existential
+                inner if inner.starts_with("any ") => format!("({inner})?"),
+                inner => format!("{inner}?"),
+            },
+            Type::Sequence { inner_type } => format!("[{}]", type_label(inner_type, config)),
+            Type::Map {
+                key_type,
+                value_type,
+            } => format!(
+                "[{}: {}]",
+                type_label(key_type, config),
+                type_label(value_type, config)
+            ),
+            _ => oracle().find(type_).type_label(),
+        }
     }
 
     pub fn canonical_name(as_type: &impl AsType) -> Result<String, askama::Error> {
@@ -525,8 +813,15 @@ pub mod filters {
     pub fn literal_swift(
         literal: &Literal,
         as_type: &impl AsType,
+        config: &Config,
     ) -> Result<String, askama::Error> {
-        Ok(oracle().find(&as_type.as_type()).literal(literal))
+        let rendered = oracle().find(&as_type.as_type()).literal(literal);
+        Ok(match literal {
+            Literal::Int(_, _, Type::Int32) if config.explicit_integer_literals() => {
+                format!("Int32({rendered})")
+            }
+            _ => rendered,
+        })
     }
 
     /// Get the Swift type for an FFIType
@@ -555,6 +850,7 @@ pub mod filters {
             FfiType::RustArcPtr(_) => "void*_Nonnull".into(),
             FfiType::RustBuffer(_) => "RustBuffer".into(),
             FfiType::ForeignBytes => "ForeignBytes".into(),
+            FfiType::Struct(name) => format!("UniFfiStruct{name}"),
             FfiType::ForeignCallback => "ForeignCallback _Nonnull".into(),
             FfiType::ForeignExecutorCallback => "UniFfiForeignExecutorCallback _Nonnull".into(),
             FfiType::ForeignExecutorHandle => "size_t".into(),
@@ -576,6 +872,18 @@ pub mod filters {
         Ok(quote_general_keyword(oracle().fn_name(nm)))
     }
 
+    /// Get the Swift renderings of a function's aliases.  Aliases are used as-is, and any that
+    /// match the function's own Swift name are skipped.
+    pub fn fn_aliases(func: &Function) -> Result<Vec<String>, askama::Error> {
+        let name = fn_name(func.name())?;
+        Ok(func
+            .aliases()
+            .iter()
+            .map(|alias| quote_general_keyword(alias.clone()))
+            .filter(|alias| *alias != name)
+            .collect())
+    }
+
     /// Get the idiomatic Swift rendering of a variable name.
     pub fn var_name(nm: &str) -> Result<String, askama::Error> {
         Ok(quote_general_keyword(oracle().var_name(nm)))
@@ -619,13 +927,100 @@ pub mod filters {
         ))
     }
 
-    pub fn future_continuation_type(result: &ResultType) -> Result<String, askama::Error> {
+    pub fn future_continuation_type(
+        result: &ResultType,
+        config: &Config,
+    ) -> Result<String, askama::Error> {
         Ok(format!(
             "CheckedContinuation<{}, Error>",
             match &result.return_type {
-                Some(return_type) => type_name(return_type)?,
+                Some(return_type) => type_name(return_type, config)?,
                 None => "()".into(),
             }
         ))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use uniffi_meta::{FnMetadata, Metadata, MetadataGroup, NamespaceMetadata};
+
+    fn config(swift_language_version: &str) -> Config {
+        toml::from_str(&format!(
+            "swift_language_version = \"{swift_language_version}\""
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse_swift_language_version() {
+        assert_eq!(parse_swift_language_version("5").unwrap(), (5, 0));
+        assert_eq!(parse_swift_language_version("5.5").unwrap(), (5, 5));
+        assert!(parse_swift_language_version("5.5.1").is_err());
+        assert!(parse_swift_language_version("five").is_err());
+        assert!(config("5.x").swift_language_version().is_err());
+        assert_eq!(Config::default().swift_language_version().unwrap(), None);
+    }
+
+    #[test]
+    fn test_async_fns_require_swift_5_5() {
+        let mut group = MetadataGroup {
+            namespace: NamespaceMetadata {
+                crate_name: "test".into(),
+                name: "test".into(),
+            },
+            items: Default::default(),
+        };
+        group.add_item(Metadata::Func(FnMetadata {
+            module_path: "test".into(),
+            name: "sleep".into(),
+            is_async: true,
+            inputs: vec![],
+            return_type: None,
+            throws: None,
+            aliases: vec![],
+            checksum: None,
+            full_module_path: "test".into(),
+        }));
+        let ci = ComponentInterface::from_metadata(group).unwrap();
+
+        let err = generate_bindings(&config("5.4"), &ci).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Async functions require Swift 5.5 or later, but swift_language_version is 5.4"
+        );
+        assert!(generate_bindings(&config("5.5"), &ci).is_ok());
+        assert!(generate_bindings(&Config::default(), &ci).is_ok());
+    }
+
+    #[test]
+    fn test_sendable_and_existential_any() {
+        const UDL: &str = r#"
+            namespace test {
+                string greet(Greeter? greeter);
+            };
+            callback interface Greeter {
+                string greet();
+            };
+            interface Person {
+                constructor();
+            };
+        "#;
+        let ci = ComponentInterface::from_webidl(UDL).unwrap();
+
+        let library = generate_bindings(&Config::default(), &ci).unwrap().library;
+        assert!(library.contains("public protocol Greeter : AnyObject"));
+        assert!(library.contains("greeter: Greeter?"));
+        assert!(!library.contains("Sendable"));
+
+        let library = generate_bindings(&config("5.5"), &ci).unwrap().library;
+        assert!(library.contains("greeter: Greeter?"));
+        assert!(library.contains("PersonProtocol, @unchecked Sendable"));
+
+        let library = generate_bindings(&config("5.6"), &ci).unwrap().library;
+        assert!(library.contains("public protocol Greeter : AnyObject"));
+        assert!(library.contains("greeter: (any Greeter)?"));
+        assert!(library.contains("typealias SwiftType = any Greeter"));
+    }
+}
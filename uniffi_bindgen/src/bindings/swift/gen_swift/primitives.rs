@@ -88,3 +88,25 @@ impl_code_type_for_primitive!(UInt32CodeType, "UInt32");
 impl_code_type_for_primitive!(UInt64CodeType, "UInt64");
 impl_code_type_for_primitive!(Float32CodeType, "Float");
 impl_code_type_for_primitive!(Float64CodeType, "Double");
+
+/// `[u8; N]` uses the same type as `bytes`, with its own converter that checks the length
+#[derive(Debug)]
+pub struct FixedBytesCodeType {
+    len: u32,
+}
+
+impl FixedBytesCodeType {
+    pub fn new(len: u32) -> Self {
+        Self { len }
+    }
+}
+
+impl CodeType for FixedBytesCodeType {
+    fn type_label(&self) -> String {
+        "Data".into()
+    }
+
+    fn canonical_name(&self) -> String {
+        format!("Data{}", self.len)
+    }
+}
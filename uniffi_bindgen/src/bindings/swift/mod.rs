@@ -29,10 +29,8 @@
 //!  * How to read from and write into a byte buffer.
 //!
 
-use std::process::Command;
-
 use anyhow::Result;
-use camino::Utf8Path;
+use camino::{Utf8Path, Utf8PathBuf};
 use fs_err as fs;
 
 pub mod gen_swift;
@@ -49,6 +47,8 @@ pub struct Bindings {
     library: String,
     /// The contents of the generated `.h` file, as a string.
     header: String,
+    /// The contents of the generated shared `.h` file, if `shared_header_filename` is set.
+    shared_header: Option<String>,
     /// The contents of the generated `.modulemap` file, as a string.
     modulemap: Option<String>,
 }
@@ -63,10 +63,11 @@ pub fn write_bindings(
     ci: &ComponentInterface,
     out_dir: &Utf8Path,
     try_format_code: bool,
-) -> Result<()> {
+) -> Result<Vec<Utf8PathBuf>> {
     let Bindings {
         header,
         library,
+        shared_header,
         modulemap,
     } = generate_bindings(config, ci)?;
 
@@ -74,24 +75,27 @@ pub fn write_bindings(
     fs::write(&source_file, library)?;
 
     let header_file = out_dir.join(config.header_filename());
-    fs::write(header_file, header)?;
+    fs::write(&header_file, header)?;
 
+    let mut written = vec![source_file.clone(), header_file];
+    if let (Some(shared_header), Some(filename)) = (shared_header, config.shared_header_filename())
+    {
+        let shared_header_file = out_dir.join(filename);
+        fs::write(&shared_header_file, shared_header)?;
+        written.push(shared_header_file);
+    }
     if let Some(modulemap) = modulemap {
         let modulemap_file = out_dir.join(config.modulemap_filename());
-        fs::write(modulemap_file, modulemap)?;
+        fs::write(&modulemap_file, modulemap)?;
+        written.push(modulemap_file);
     }
 
-    if try_format_code {
-        if let Err(e) = Command::new("swiftformat")
-            .arg(source_file.as_str())
-            .output()
-        {
-            println!(
-                "Warning: Unable to auto-format {} using swiftformat: {e:?}",
-                source_file.file_name().unwrap(),
-            );
-        }
-    }
+    super::format_generated_file(
+        config.formatter(),
+        &["swiftformat"],
+        try_format_code,
+        &source_file,
+    );
 
-    Ok(())
+    Ok(written)
 }
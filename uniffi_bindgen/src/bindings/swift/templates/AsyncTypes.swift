@@ -1,4 +1,26 @@
 // Callbacks for async functions
+{%- if config.async_cancellation() %}
+{%- match ci.ffi_rust_future_cancel() %}
+{%- when Some(cancel_func) %}
+
+// Mark the next async call as cancellable.  Rust only tracks the futures of marked calls, and the
+// mark applies to the next scaffolding call on this thread.
+fileprivate func uniffiMarkRustFutureCancellable(callbackData: UInt) {
+    try! rustCall() {
+        {{ ci.ffi_rust_future_cancellable().unwrap().name() }}(UnsafeMutableRawPointer(bitPattern: callbackData)!, $0)
+    }
+}
+
+// Cancel the Rust future for an async call.  Rust drops the future and invokes the callback, which
+// resumes the continuation by throwing `CancellationError`.
+fileprivate func uniffiCancelRustFuture(callbackData: UInt) {
+    try! rustCall() {
+        {{ cancel_func.name() }}(UnsafeMutableRawPointer(bitPattern: callbackData)!, $0)
+    }
+}
+{%- when None %}
+{%- endmatch %}
+{%- endif %}
 
 // Callback handlers for an async calls.  These are invoked by Rust when the future is ready.  They
 // lift the return value or error and resume the suspended function.
@@ -9,7 +31,7 @@ fileprivate func {{ result_type|future_callback }}(
     callStatus: RustCallStatus) {
 
     let continuation = rawContinutation.bindMemory(
-        to: {{ result_type|future_continuation_type }}.self,
+        to: {{ result_type|future_continuation_type(config) }}.self,
         capacity: 1
     )
 
@@ -3,7 +3,15 @@ fileprivate struct FfiConverterBool : FfiConverter {
     typealias SwiftType = Bool
 
     public static func lift(_ value: Int8) throws -> Bool {
+        {%- if config.buffer_checks().is_strict() %}
+        switch value {
+        case 0: return false
+        case 1: return true
+        default: throw UniffiInternalError.invalidBoolean
+        }
+        {%- else %}
         return value != 0
+        {%- endif %}
     }
 
     public static func lower(_ value: Bool) -> Int8 {
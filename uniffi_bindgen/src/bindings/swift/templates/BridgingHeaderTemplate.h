@@ -1,63 +1,27 @@
 // This file was autogenerated by some hot garbage in the `uniffi` crate.
 // Trust me, you don't want to mess with it!
+// The Rust library must be built with uniffi {{ config.required_runtime_version() }}.
 
 #pragma once
 
-#include <stdbool.h>
-#include <stddef.h>
-#include <stdint.h>
+{% match config.shared_header_filename() -%}
+{%- when Some with (filename) -%}
+// The structs shared by all uniffied crates live in their own header
+#include "{{ filename }}"
+{%- when None -%}
+{% include "SharedHeaderBody.h" %}
+{%- endmatch %}
 
-// The following structs are used to implement the lowest level
-// of the FFI, and thus useful to multiple uniffied crates.
-// We ensure they are declared exactly once, with a header guard, UNIFFI_SHARED_H.
-#ifdef UNIFFI_SHARED_H
-    // We also try to prevent mixing versions of shared uniffi header structs.
-    // If you add anything to the #else block, you must increment the version suffix in UNIFFI_SHARED_HEADER_V4
-    #ifndef UNIFFI_SHARED_HEADER_V4
-        #error Combining helper code from multiple versions of uniffi is not supported
-    #endif // ndef UNIFFI_SHARED_HEADER_V4
-#else
-#define UNIFFI_SHARED_H
-#define UNIFFI_SHARED_HEADER_V4
-// ⚠️ Attention: If you change this #else block (ending in `#endif // def UNIFFI_SHARED_H`) you *must* ⚠️
-// ⚠️ increment the version suffix in all instances of UNIFFI_SHARED_HEADER_V4 in this file.           ⚠️
-
-typedef struct RustBuffer
-{
-    int32_t capacity;
-    int32_t len;
-    uint8_t *_Nullable data;
-} RustBuffer;
-
-typedef int32_t (*ForeignCallback)(uint64_t, int32_t, const uint8_t *_Nonnull, int32_t, RustBuffer *_Nonnull);
-
-// Task defined in Rust that Swift executes
-typedef void (*UniFfiRustTaskCallback)(const void * _Nullable, int8_t);
-
-// Callback to execute Rust tasks using a Swift Task
-//
-// Args:
-//   executor: ForeignExecutor lowered into a size_t value
-//   delay: Delay in MS
-//   task: UniFfiRustTaskCallback to call
-//   task_data: data to pass the task callback
-typedef int8_t (*UniFfiForeignExecutorCallback)(size_t, uint32_t, UniFfiRustTaskCallback _Nullable, const void * _Nullable);
-
-typedef struct ForeignBytes
-{
-    int32_t len;
-    const uint8_t *_Nullable data;
-} ForeignBytes;
-
-// Error definitions
-typedef struct RustCallStatus {
-    int8_t code;
-    RustBuffer errorBuf;
-} RustCallStatus;
-
-// ⚠️ Attention: If you change this #else block (ending in `#endif // def UNIFFI_SHARED_H`) you *must* ⚠️
-// ⚠️ increment the version suffix in all instances of UNIFFI_SHARED_HEADER_V4 in this file.           ⚠️
-#endif // def UNIFFI_SHARED_H
+// `#[repr(C)]` records, which are passed by value
+{%- for rec in ci.record_definitions() %}
+{%- if rec.is_pod() %}
+typedef struct UniFfiStruct{{ rec.name() }} {
+    {%- for field in rec.fields() %}
+    {{ field|ffi_type|header_ffi_type_name }} {{ field.name() }};
+    {%- endfor %}
+} UniFfiStruct{{ rec.name() }};
+{%- endif %}
+{%- endfor %}
 
 // Callbacks for UniFFI Futures
 {%- for ffi_type in ci.iter_future_callback_params() %}
@@ -77,5 +41,37 @@ typedef void (*UniFfiFutureCallback{{ ffi_type|ffi_canonical_name }})(const void
     {% endif %}
 );
 {%- endfor %}
+{%- if config.span_sink() %}
+
+// Span sink, see `uniffiSetSpanSink()`
+typedef void (*UniFfiSpanSinkCallback)(int8_t, uint64_t, const uint8_t *_Nonnull, int32_t);
+void ffi_{{ ci.ffi_namespace() }}_span_sink_set(UniFfiSpanSinkCallback _Nonnull callback);
+{%- endif %}
+{%- if config.panic_observer() %}
+
+// Panic observer, see `uniffiSetPanicObserver()`
+typedef void (*UniFfiPanicObserverCallback)(const uint8_t *_Nonnull, int32_t, const uint8_t *_Nonnull, int32_t, uint32_t);
+void ffi_{{ ci.ffi_namespace() }}_panic_observer_set(UniFfiPanicObserverCallback _Nonnull callback);
+{%- endif %}
+{%- if config.runtime_version().is_some() %}
+
+// The `uniffi` runtime version, see the `runtime_version` option
+uint32_t ffi_{{ ci.ffi_namespace() }}_uniffi_runtime_version(void);
+{%- endif %}
+{%- if config.empty_buffer_sentinels() %}
+
+// Pass empty values as empty buffers, see the `empty_buffer_sentinels` option
+void ffi_{{ ci.ffi_namespace() }}_empty_buffer_sentinels_enable(void);
+{%- endif %}
+{%- if config.max_nesting_depth().is_some() %}
+
+// Limit the nesting of lifted values, see the `max_nesting_depth` option
+void ffi_{{ ci.ffi_namespace() }}_max_nesting_depth_set(uint32_t max_depth);
+{%- endif %}
+{%- if config.capability_registration() %}
+
+// Capability registration, see `uniffiSetCapabilityAvailable()`
+void ffi_{{ ci.ffi_namespace() }}_capability_set(const char *_Nonnull name_data, int32_t name_len, int8_t available);
+{%- endif %}
 
 {% import "macros.swift" as swift %}
@@ -7,6 +7,49 @@ fileprivate extension NSLock {
 }
 
 fileprivate typealias UniFFICallbackHandle = UInt64
+{%- if config.handle_map().is_slab() %}
+// A slab of slots indexed by handle.  Freed slots are reused, so memory stays flat when many
+// callback objects are created and destroyed.
+fileprivate class UniFFICallbackHandleMap<T> {
+    private var slots: [T?] = []
+    private var freeSlots: [Int] = []
+
+    private let lock = NSLock()
+
+    func insert(obj: T) -> UniFFICallbackHandle {
+        lock.withLock {
+            if let index = freeSlots.popLast() {
+                slots[index] = obj
+                return UniFFICallbackHandle(index)
+            }
+            slots.append(obj)
+            return UniFFICallbackHandle(slots.count - 1)
+        }
+    }
+
+    func get(handle: UniFFICallbackHandle) -> T? {
+        lock.withLock {
+            let index = Int(handle)
+            return index < slots.count ? slots[index] : nil
+        }
+    }
+
+    func delete(handle: UniFFICallbackHandle) {
+        remove(handle: handle)
+    }
+
+    @discardableResult
+    func remove(handle: UniFFICallbackHandle) -> T? {
+        lock.withLock {
+            let index = Int(handle)
+            guard index < slots.count, let obj = slots[index] else { return nil }
+            slots[index] = nil
+            freeSlots.append(index)
+            return obj
+        }
+    }
+}
+{%- else %}
 fileprivate class UniFFICallbackHandleMap<T> {
     private var leftMap: [UniFFICallbackHandle: T] = [:]
     private var counter: [UniFFICallbackHandle: UInt64] = [:]
@@ -54,6 +97,7 @@ fileprivate class UniFFICallbackHandleMap<T> {
         }
     }
 }
+{%- endif %}
 
 // Magic number for the Rust proxy to call using the same mechanism as every other method,
 // to free the callback once it's dropped by Rust.
@@ -2,13 +2,13 @@
 {%- let foreign_callback = format!("foreignCallback{}", canonical_type_name) %}
 {%- if self.include_once_check("CallbackInterfaceRuntime.swift") %}{%- include "CallbackInterfaceRuntime.swift" %}{%- endif %}
 
-// Declaration and FfiConverters for {{ type_name }} Callback Interface
+// Declaration and FfiConverters for {{ cbi.name()|class_name }} Callback Interface
 
-public protocol {{ type_name }} : AnyObject {
+public protocol {{ cbi.name()|class_name }} : AnyObject {
     {% for meth in cbi.methods() -%}
     func {{ meth.name()|fn_name }}({% call swift::arg_list_protocol(meth) %}) {% call swift::throws(meth) -%}
     {%- match meth.return_type() -%}
-    {%- when Some with (return_type) %} -> {{ return_type|type_name -}}
+    {%- when Some with (return_type) %} -> {{ return_type|type_name(config) -}}
     {%- else -%}
     {%- endmatch %}
     {% endfor %}
@@ -57,7 +57,7 @@ fileprivate let {{ foreign_callback }} : ForeignCallback =
         {%- when Some(error_type) %}
         do {
             return try makeCall()
-        } catch let error as {{ error_type|type_name }} {
+        } catch let error as {{ error_type|type_name(config) }} {
             out_buf.pointee = {{ error_type|lower_fn }}(error)
             return UNIFFI_CALLBACK_ERROR
         }
@@ -75,7 +75,7 @@ fileprivate let {{ foreign_callback }} : ForeignCallback =
         {% for meth in cbi.methods() -%}
         {% let method_name = format!("invoke_{}", meth.name())|fn_name -%}
         case {{ loop.index }}:
-            let cb: {{ cbi|type_name }}
+            let cb: {{ cbi|type_name(config) }}
             do {
                 cb = try {{ ffi_converter_name }}.lift(handle)
             } catch {
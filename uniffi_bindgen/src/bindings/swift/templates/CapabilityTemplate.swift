@@ -0,0 +1,10 @@
+// Decide if the capabilities of functions exported with `requires_capability` are available.
+//
+// This replaces the predicate that the Rust code registered for the capability, if any.  Calling
+// a function whose capability isn't available throws the function's error instead.
+public func uniffiSetCapabilityAvailable(_ name: String, available: Bool) {
+    name.utf8CString.withUnsafeBufferPointer { nameData in
+        // `utf8CString` ends with a NUL, which isn't part of the name
+        ffi_{{ ci.ffi_namespace() }}_capability_set(nameData.baseAddress!, Int32(nameData.count - 1), available ? 1 : 0)
+    }
+}
@@ -2,7 +2,11 @@ fileprivate struct FfiConverterData: FfiConverterRustBuffer {
     typealias SwiftType = Data
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Data {
+        {%- if config.buffer_checks().is_strict() %}
+        let len = try readLength(&buf, minItemSize: 1)
+        {%- else %}
         let len: Int32 = try readInt(&buf)
+        {%- endif %}
         return Data(try readBytes(&buf, count: Int(len)))
     }
 
@@ -1,3 +1,28 @@
+{%- if e.is_flags() %}
+{%- let flags_type = e.flags_type().unwrap() %}
+public struct {{ type_name }}: OptionSet, Hashable {
+    public let rawValue: {{ flags_type|type_name(config) }}
+
+    public init(rawValue: {{ flags_type|type_name(config) }}) {
+        self.rawValue = rawValue
+    }
+    {% for variant in e.variants() %}
+    public static let {{ variant.name()|enum_variant_swift_quoted }} = {{ type_name }}(rawValue: {{ variant.flag_value().unwrap() }})
+    {%- endfor %}
+}
+
+public struct {{ ffi_converter_name }}: FfiConverterRustBuffer {
+    typealias SwiftType = {{ type_name }}
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> {{ type_name }} {
+        return {{ type_name }}(rawValue: try {{ flags_type|read_fn }}(from: &buf))
+    }
+
+    public static func write(_ value: {{ type_name }}, into buf: inout [UInt8]) {
+        {{ flags_type|write_fn }}(value.rawValue, into: &buf)
+    }
+}
+{%- else %}
 // Note that we don't yet support `indirect` for enums.
 // See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 public enum {{ type_name }} {
@@ -10,6 +35,7 @@ public struct {{ ffi_converter_name }}: FfiConverterRustBuffer {
     typealias SwiftType = {{ type_name }}
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> {{ type_name }} {
+        {%- call swift::enter_nested() %}
         let variant: Int32 = try readInt(&buf)
         switch variant {
         {% for variant in e.variants() %}
@@ -41,6 +67,7 @@ public struct {{ ffi_converter_name }}: FfiConverterRustBuffer {
         }
     }
 }
+{%- endif %}
 
 {#
 We always write these public functions just in case the enum is used as
@@ -54,6 +81,11 @@ public func {{ ffi_converter_name }}_lower(_ value: {{ type_name }}) -> RustBuff
     return {{ ffi_converter_name }}.lower(value)
 }
 
-{% if !contains_object_references %}
+{% if !contains_object_references && !e.is_flags() %}
 extension {{ type_name }}: Equatable, Hashable {}
 {% endif %}
+
+{%- if config.serializable_records() && ci.item_is_serializable(type_) %}
+
+extension {{ type_name }}: Codable {}
+{%- endif %}
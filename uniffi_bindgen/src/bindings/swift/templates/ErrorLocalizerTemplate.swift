@@ -0,0 +1,30 @@
+// Localize the messages of errors from Rust.
+//
+// Once a localizer is registered with `uniffiSetErrorLocalizer()`, the `errorDescription` of every
+// error type is routed through it.
+
+public protocol UniFfiErrorLocalizer: AnyObject {
+    // Return the localized message for an error variant, or `nil` to keep the default message.
+    //
+    // `errorName` and `variantName` are the names of the Rust enum and variant, so that they're the
+    // same in all the bindings.  The default message is the Rust message for flat errors, and a
+    // description of the fields otherwise.
+    func localize(errorName: String, variantName: String, message: String) -> String?
+}
+
+fileprivate let uniffiErrorLocalizerLock = NSLock()
+fileprivate var uniffiErrorLocalizer: UniFfiErrorLocalizer?
+
+fileprivate func uniffiLocalizeError(errorName: String, variantName: String, message: String) -> String {
+    uniffiErrorLocalizerLock.lock()
+    let localizer = uniffiErrorLocalizer
+    uniffiErrorLocalizerLock.unlock()
+    return localizer?.localize(errorName: errorName, variantName: variantName, message: message) ?? message
+}
+
+// Register the localizer for error messages, or pass `nil` to stop localizing them.
+public func uniffiSetErrorLocalizer(_ localizer: UniFfiErrorLocalizer?) {
+    uniffiErrorLocalizerLock.lock()
+    uniffiErrorLocalizer = localizer
+    uniffiErrorLocalizerLock.unlock()
+}
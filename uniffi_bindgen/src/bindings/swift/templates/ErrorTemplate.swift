@@ -23,6 +23,7 @@ public struct {{ ffi_converter_name }}: FfiConverterRustBuffer {
     typealias SwiftType = {{ type_name }}
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> {{ type_name }} {
+        {%- call swift::enter_nested() %}
         let variant: Int32 = try readInt(&buf)
         switch variant {
 
@@ -84,3 +85,21 @@ public struct {{ ffi_converter_name }}: FfiConverterRustBuffer {
 extension {{ type_name }}: Equatable, Hashable {}
 {% endif %}
 extension {{ type_name }}: Error { }
+{%- if config.error_localizer() %}
+
+extension {{ type_name }}: LocalizedError {
+    public var errorDescription: String? {
+        switch self {
+        {%- for variant in e.variants() %}
+        {%- if e.is_flat() %}
+        case let .{{ variant.name()|class_name }}(message):
+            return uniffiLocalizeError(errorName: "{{ e.name() }}", variantName: "{{ variant.name() }}", message: message)
+        {%- else %}
+        case .{{ variant.name()|class_name }}:
+            return uniffiLocalizeError(errorName: "{{ e.name() }}", variantName: "{{ variant.name() }}", message: String(describing: self))
+        {%- endif %}
+        {%- endfor %}
+        }
+    }
+}
+{%- endif %}
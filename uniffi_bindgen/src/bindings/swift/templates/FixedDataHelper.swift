@@ -0,0 +1,19 @@
+// `[u8; {{ len }}]` is `Data` that must have exactly {{ len }} bytes.  Writing can't throw, so Rust
+// checks the length of the values that Swift passes when it lifts them.
+fileprivate struct {{ ffi_converter_name }}: FfiConverterRustBuffer {
+    typealias SwiftType = Data
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Data {
+        let len: Int32 = try readInt(&buf)
+        guard len == {{ len }} else {
+            throw UniffiInternalError.invalidLength
+        }
+        return Data(try readBytes(&buf, count: Int(len)))
+    }
+
+    public static func write(_ value: Data, into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        writeBytes(&buf, value)
+    }
+}
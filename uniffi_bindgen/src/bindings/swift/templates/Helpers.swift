@@ -9,6 +9,10 @@ fileprivate enum UniffiInternalError: LocalizedError {
     case unexpectedRustCallStatusCode
     case unexpectedRustCallError
     case unexpectedStaleHandle
+    case invalidLength
+    case invalidBoolean
+    case invalidUtf8
+    case nestingTooDeep
     case rustPanic(_ message: String)
 
     public var errorDescription: String? {
@@ -21,6 +25,10 @@ fileprivate enum UniffiInternalError: LocalizedError {
         case .unexpectedRustCallStatusCode: return "Unexpected RustCallStatus code"
         case .unexpectedRustCallError: return "CALL_ERROR but no errorClass specified"
         case .unexpectedStaleHandle: return "The object in the handle map has been dropped already"
+        case .invalidLength: return "Length prefix is negative or longer than the rest of the buffer"
+        case .invalidBoolean: return "Unexpected boolean value; should be 0 or 1"
+        case .invalidUtf8: return "String data is not valid UTF-8"
+        case .nestingTooDeep: return "Records and enums are nested deeper than the configured `max_nesting_depth`"
         case let .rustPanic(message): return message
         }
     }
@@ -29,6 +37,7 @@ fileprivate enum UniffiInternalError: LocalizedError {
 fileprivate let CALL_SUCCESS: Int8 = 0
 fileprivate let CALL_ERROR: Int8 = 1
 fileprivate let CALL_PANIC: Int8 = 2
+fileprivate let CALL_CANCELLED: Int8 = 3
 
 fileprivate extension RustCallStatus {
     init() {
@@ -91,6 +100,9 @@ private func uniffiCheckCallStatus(
                 throw UniffiInternalError.rustPanic("Rust panic")
             }
 
+        case CALL_CANCELLED:
+            throw CancellationError()
+
         default:
             throw UniffiInternalError.unexpectedRustCallStatusCode
     }
@@ -0,0 +1,79 @@
+/**
+ * A dynamic JSON value.
+ *
+ * Integers are decoded as `.int` when they fit in an `Int64`, then as `.uint` when they fit in a
+ * `UInt64`, so that they round-trip exactly.  Other numbers are decoded as `.double`.
+ */
+public enum JsonValue: Codable, Equatable, Hashable {
+    case null
+    case bool(Bool)
+    case int(Int64)
+    case uint(UInt64)
+    case double(Double)
+    case string(String)
+    case array([JsonValue])
+    case object([String: JsonValue])
+
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if container.decodeNil() {
+            self = .null
+        } else if let value = try? container.decode(Bool.self) {
+            self = .bool(value)
+        } else if let value = try? container.decode(Int64.self) {
+            self = .int(value)
+        } else if let value = try? container.decode(UInt64.self) {
+            self = .uint(value)
+        } else if let value = try? container.decode(Double.self) {
+            self = .double(value)
+        } else if let value = try? container.decode(String.self) {
+            self = .string(value)
+        } else if let value = try? container.decode([JsonValue].self) {
+            self = .array(value)
+        } else {
+            self = .object(try container.decode([String: JsonValue].self))
+        }
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+        case .null:
+            try container.encodeNil()
+        case let .bool(value):
+            try container.encode(value)
+        case let .int(value):
+            try container.encode(value)
+        case let .uint(value):
+            try container.encode(value)
+        case let .double(value):
+            try container.encode(value)
+        case let .string(value):
+            try container.encode(value)
+        case let .array(value):
+            try container.encode(value)
+        case let .object(value):
+            try container.encode(value)
+        }
+    }
+}
+
+// JSON values are passed as their JSON text.
+fileprivate struct FfiConverterJsonValue: FfiConverterRustBuffer {
+    typealias SwiftType = JsonValue
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> JsonValue {
+        let text = try FfiConverterString.read(from: &buf)
+        return try JSONDecoder().decode(JsonValue.self, from: Data(text.utf8))
+    }
+
+    public static func write(_ value: JsonValue, into buf: inout [UInt8]) {
+        let data: Data
+        do {
+            data = try JSONEncoder().encode(value)
+        } catch {
+            fatalError("Invalid JSON value: \(error)")
+        }
+        FfiConverterString.write(String(decoding: data, as: UTF8.self), into: &buf)
+    }
+}
@@ -1,4 +1,22 @@
 fileprivate struct {{ ffi_converter_name }}: FfiConverterRustBuffer {
+    // Rust can pass empty dictionaries as an empty buffer, see `empty_buffer_sentinels`.  These aren't allocated,
+    // so there's nothing to free.
+    public static func lift(_ buf: RustBuffer) throws -> {{ type_name }} {
+        if buf.len == 0 {
+            return [:]
+        }
+        return try liftFromRustBuffer(buf)
+    }
+    {%- if config.empty_buffer_sentinels() %}
+
+    public static func lower(_ value: {{ type_name }}) -> RustBuffer {
+        if value.isEmpty {
+            return RustBuffer(capacity: 0, len: 0, data: nil)
+        }
+        return lowerIntoRustBuffer(value)
+    }
+    {%- endif %}
+
     public static func write(_ value: {{ type_name }}, into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
@@ -9,7 +27,11 @@ fileprivate struct {{ ffi_converter_name }}: FfiConverterRustBuffer {
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> {{ type_name }} {
+        {%- if config.buffer_checks().is_strict() %}
+        let len = try readLength(&buf, minItemSize: {{ ci.min_serialized_size(key_type) + ci.min_serialized_size(value_type) }})
+        {%- else %}
         let len: Int32 = try readInt(&buf)
+        {%- endif %}
         var dict = {{ type_name }}()
         dict.reserveCapacity(Int(len))
         for _ in 0..<len {
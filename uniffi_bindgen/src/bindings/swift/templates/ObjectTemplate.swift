@@ -1,15 +1,17 @@
 {%- let obj = ci|get_object_definition(name) %}
+{%- let is_builder = config.is_builder(name) %}
 public protocol {{ obj.name() }}Protocol {
     {% for meth in obj.methods() -%}
-    func {{ meth.name()|fn_name }}({% call swift::arg_list_protocol(meth) %}) {% call swift::async(meth) %} {% call swift::throws(meth) -%}
+    func {{ meth.name()|fn_name }}({% call swift::arg_list_protocol(meth) %}{% call swift::async_executor_arg_decl(meth) %}) {% call swift::async(meth) %} {% if meth.is_async() %}{% call swift::async_throws(meth) %}{% else %}{% call swift::throws(meth) %}{% endif -%}
     {%- match meth.return_type() -%}
-    {%- when Some with (return_type) %} -> {{ return_type|type_name -}}
+    {%- when Some with (return_type) %} -> {{ return_type|type_name(config) -}}
     {%- else -%}
+    {%- if is_builder && !meth.is_async() %} -> {{ type_name }}{% endif -%}
     {%- endmatch %}
     {% endfor %}
 }
 
-public class {{ type_name }}: {{ obj.name() }}Protocol {
+public class {{ type_name }}: {{ obj.name() }}Protocol{% if config.sendable_objects() %}, @unchecked Sendable{% endif %} {
     fileprivate let pointer: UnsafeMutableRawPointer
 
     // TODO: We'd like this to be `private` but for Swifty reasons,
@@ -32,10 +34,60 @@ public class {{ type_name }}: {{ obj.name() }}Protocol {
     }
 
     {% for cons in obj.alternate_constructors() %}
+    {%- if cons.is_async() %}
+
+    public static func {{ cons.name()|fn_name }}({%- call swift::arg_list_decl(cons) -%}{% call swift::async_executor_arg_decl(cons) %}) async {% call swift::async_throws(cons) %} -> {{ type_name }} {
+        // Async constructors work like async functions that return the object
+        {%- if config.async_cancellation() %}
+        let continuation = UnsafeMutablePointer<{{ cons.result_type().borrow()|future_continuation_type(config) }}?>.allocate(capacity: 1)
+        continuation.initialize(to: nil)
+        defer {
+            continuation.deinitialize(count: 1)
+            continuation.deallocate()
+        }
+        let callbackData = UInt(bitPattern: continuation)
+        // Rust can only cancel the future once the call started, so check for tasks that were
+        // cancelled before that.
+        try Task.checkCancellation()
+        return try await withTaskCancellationHandler {
+            try await withCheckedThrowingContinuation {
+                continuation.pointee = $0
+                uniffiMarkRustFutureCancellable(callbackData: callbackData)
+                try! rustCall() {
+                    {{ cons.ffi_func().name() }}(
+                        {% call swift::arg_list_lowered(cons) %}
+                        {% call swift::async_executor_lowered() %},
+                        {{ cons.result_type().borrow()|future_callback }},
+                        continuation,
+                        $0
+                    )
+                }
+            }
+        } onCancel: {
+            uniffiCancelRustFuture(callbackData: callbackData)
+        }
+        {%- else %}
+        var continuation: {{ cons.result_type().borrow()|future_continuation_type(config) }}? = nil
+        return {% call swift::try(cons) %} await withCheckedThrowingContinuation {
+            continuation = $0
+            try! rustCall() {
+                {{ cons.ffi_func().name() }}(
+                    {% call swift::arg_list_lowered(cons) %}
+                    {% call swift::async_executor_lowered() %},
+                    {{ cons.result_type().borrow()|future_callback }},
+                    &continuation,
+                    $0
+                )
+            }
+        }
+        {%- endif %}
+    }
+    {%- else %}
 
     public static func {{ cons.name()|fn_name }}({% call swift::arg_list_decl(cons) %}) {% call swift::throws(cons) %} -> {{ type_name }} {
         return {{ type_name }}(unsafeFromRawPointer: {% call swift::to_ffi_call(cons) %})
     }
+    {%- endif %}
 
     {% endfor %}
 
@@ -43,26 +95,62 @@ public class {{ type_name }}: {{ obj.name() }}Protocol {
     {% for meth in obj.methods() -%}
     {%- if meth.is_async() %}
 
-    public func {{ meth.name()|fn_name }}({%- call swift::arg_list_decl(meth) -%}) async {% call swift::throws(meth) %}{% match meth.return_type() %}{% when Some with (return_type) %} -> {{ return_type|type_name }}{% when None %}{% endmatch %} {
+    public func {{ meth.name()|fn_name }}({%- call swift::arg_list_decl(meth) -%}{% call swift::async_executor_arg_decl(meth) %}) async {% call swift::async_throws(meth) %}{% match meth.return_type() %}{% when Some with (return_type) %} -> {{ return_type|type_name(config) }}{% when None %}{% endmatch %} {
+        {%- if config.async_cancellation() %}
+        // Suspend the function and call the scaffolding function, passing it a callback handler from
+        // `AsyncTypes.swift`
+        //
+        // The continuation is stored on the heap, so that its address stays valid until the callback
+        // is invoked and can also be used to cancel the Rust future when the task is cancelled.
+        let continuation = UnsafeMutablePointer<{{ meth.result_type().borrow()|future_continuation_type(config) }}?>.allocate(capacity: 1)
+        continuation.initialize(to: nil)
+        defer {
+            continuation.deinitialize(count: 1)
+            continuation.deallocate()
+        }
+        let callbackData = UInt(bitPattern: continuation)
+        // Rust can only cancel the future once the call started, so check for tasks that were
+        // cancelled before that.
+        try Task.checkCancellation()
+        return try await withTaskCancellationHandler {
+            try await withCheckedThrowingContinuation {
+                continuation.pointee = $0
+                uniffiMarkRustFutureCancellable(callbackData: callbackData)
+                try! rustCall() {
+                    {{ meth.ffi_func().name() }}(
+                        self.pointer,
+                        {% call swift::arg_list_lowered(meth) %}
+                        {% call swift::async_executor_lowered() %},
+                        {{ meth.result_type().borrow()|future_callback }},
+                        continuation,
+                        $0
+                    )
+                }
+            }
+        } onCancel: {
+            uniffiCancelRustFuture(callbackData: callbackData)
+        }
+        {%- else %}
         // Suspend the function and call the scaffolding function, passing it a callback handler from
         // `AsyncTypes.swift`
         //
         // Make sure to hold on to a reference to the continuation in the top-level scope so that
         // it's not freed before the callback is invoked.
-        var continuation: {{ meth.result_type().borrow()|future_continuation_type }}? = nil
+        var continuation: {{ meth.result_type().borrow()|future_continuation_type(config) }}? = nil
         return {% call swift::try(meth) %} await withCheckedThrowingContinuation {
             continuation = $0
             try! rustCall() {
                 {{ meth.ffi_func().name() }}(
                     self.pointer,
                     {% call swift::arg_list_lowered(meth) %}
-                    FfiConverterForeignExecutor.lower(UniFfiForeignExecutor()),
+                    {% call swift::async_executor_lowered() %},
                     {{ meth.result_type().borrow()|future_callback }},
                     &continuation,
                     $0
                 )
             }
         }
+        {%- endif %}
     }
 
     {% else -%}
@@ -71,21 +159,44 @@ public class {{ type_name }}: {{ obj.name() }}Protocol {
 
     {%- when Some with (return_type) %}
 
-    public func {{ meth.name()|fn_name }}({% call swift::arg_list_decl(meth) %}) {% call swift::throws(meth) %} -> {{ return_type|type_name }} {
+    public func {{ meth.name()|fn_name }}({% call swift::arg_list_decl(meth) %}) {% call swift::throws(meth) %} -> {{ return_type|type_name(config) }} {
         return {% call swift::try(meth) %} {{ return_type|lift_fn }}(
             {% call swift::to_ffi_call_with_prefix("self.pointer", meth) %}
         )
     }
 
     {%- when None %}
+    {%- if is_builder %}
+
+    @discardableResult
+    public func {{ meth.name()|fn_name }}({% call swift::arg_list_decl(meth) %}) {% call swift::throws(meth) %} -> {{ type_name }} {
+        {% call swift::to_ffi_call_with_prefix("self.pointer", meth) %}
+        return self
+    }
+    {%- else %}
 
     public func {{ meth.name()|fn_name }}({% call swift::arg_list_decl(meth) %}) {% call swift::throws(meth) %} {
         {% call swift::to_ffi_call_with_prefix("self.pointer", meth) %}
     }
+    {%- endif %}
 
     {%- endmatch -%}
     {%- endif -%}
     {% endfor %}
+
+    {%- for tm in obj.uniffi_traits() %}
+    {%- match tm %}
+    {%- when UniffiTrait::Clone { clone } %}
+
+    /// Create a new object with a copy of this object's Rust value, using its `Clone` implementation.
+    public func copy() -> {{ type_name }} {
+        return {{ clone.return_type().unwrap()|lift_fn }}(
+            {% call swift::to_ffi_call_with_prefix("self.pointer", clone) %}
+        )
+    }
+    {%- else %}
+    {%- endmatch %}
+    {%- endfor %}
 }
 
 public struct {{ ffi_converter_name }}: FfiConverter {
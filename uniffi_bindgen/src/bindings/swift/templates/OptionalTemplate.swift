@@ -1,6 +1,24 @@
 fileprivate struct {{ ffi_converter_name }}: FfiConverterRustBuffer {
     typealias SwiftType = {{ type_name }}
 
+    // Rust can pass `nil` as an empty buffer, see `empty_buffer_sentinels`.  These aren't allocated,
+    // so there's nothing to free.
+    public static func lift(_ buf: RustBuffer) throws -> SwiftType {
+        if buf.len == 0 {
+            return nil
+        }
+        return try liftFromRustBuffer(buf)
+    }
+    {%- if config.empty_buffer_sentinels() %}
+
+    public static func lower(_ value: SwiftType) -> RustBuffer {
+        if value == nil {
+            return RustBuffer(capacity: 0, len: 0, data: nil)
+        }
+        return lowerIntoRustBuffer(value)
+    }
+    {%- endif %}
+
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
             writeInt(&buf, Int8(0))
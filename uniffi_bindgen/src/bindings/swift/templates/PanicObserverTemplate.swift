@@ -0,0 +1,38 @@
+// Report Rust panics to foreign crash-reporting tools.
+//
+// Once an observer is registered with `uniffiSetPanicObserver()`, every Rust panic that unwinds to
+// the scaffolding is reported to it, before it's thrown as an error.
+
+public protocol UniFfiPanicObserver: AnyObject {
+    // Rust code panicked with `message` at `file`:`line`.  `line` is 0 if the location is unknown.
+    func onPanic(message: String, file: String, line: UInt32)
+}
+
+fileprivate let uniffiPanicObserverLock = NSLock()
+fileprivate var uniffiPanicObserver: UniFfiPanicObserver?
+
+fileprivate func uniffiPanicObserverCallback(
+    messageData: UnsafePointer<UInt8>,
+    messageLen: Int32,
+    fileData: UnsafePointer<UInt8>,
+    fileLen: Int32,
+    line: UInt32
+) {
+    uniffiPanicObserverLock.lock()
+    let observer = uniffiPanicObserver
+    uniffiPanicObserverLock.unlock()
+    guard let observer = observer else {
+        return
+    }
+    let message = String(decoding: UnsafeBufferPointer(start: messageData, count: Int(messageLen)), as: UTF8.self)
+    let file = String(decoding: UnsafeBufferPointer(start: fileData, count: Int(fileLen)), as: UTF8.self)
+    observer.onPanic(message: message, file: file, line: line)
+}
+
+// Register the observer that receives Rust panics, or pass `nil` to stop reporting them.
+public func uniffiSetPanicObserver(_ observer: UniFfiPanicObserver?) {
+    uniffiPanicObserverLock.lock()
+    uniffiPanicObserver = observer
+    uniffiPanicObserverLock.unlock()
+    ffi_{{ ci.ffi_namespace() }}_panic_observer_set(uniffiPanicObserverCallback)
+}
@@ -0,0 +1,65 @@
+// Rust ranges keep their bounds even when they're empty or inverted, like `5..3` or `5..=4`, which
+// Swift ranges can't represent.  These types keep the bounds as Rust passed them, so that every
+// range round-trips, and convert to and from the Swift ranges.
+
+/// A Rust `Range`, which contains the values from `lowerBound` up to, but not including,
+/// `upperBound`
+public struct UniffiRange<Bound: Comparable> {
+    public var lowerBound: Bound
+    public var upperBound: Bound
+
+    public init(lowerBound: Bound, upperBound: Bound) {
+        self.lowerBound = lowerBound
+        self.upperBound = upperBound
+    }
+
+    public init(_ range: Range<Bound>) {
+        self.init(lowerBound: range.lowerBound, upperBound: range.upperBound)
+    }
+
+    public var isEmpty: Bool { lowerBound >= upperBound }
+
+    public func contains(_ value: Bound) -> Bool {
+        lowerBound <= value && value < upperBound
+    }
+
+    /// The equivalent Swift range, or `nil` if the bounds are inverted
+    public var range: Range<Bound>? {
+        lowerBound <= upperBound ? lowerBound..<upperBound : nil
+    }
+}
+
+extension UniffiRange: Equatable {}
+extension UniffiRange: Hashable where Bound: Hashable {}
+extension UniffiRange: Codable where Bound: Codable {}
+
+/// A Rust `RangeInclusive`, which contains the values from `lowerBound` up to, and including,
+/// `upperBound`
+public struct UniffiClosedRange<Bound: Comparable> {
+    public var lowerBound: Bound
+    public var upperBound: Bound
+
+    public init(lowerBound: Bound, upperBound: Bound) {
+        self.lowerBound = lowerBound
+        self.upperBound = upperBound
+    }
+
+    public init(_ range: ClosedRange<Bound>) {
+        self.init(lowerBound: range.lowerBound, upperBound: range.upperBound)
+    }
+
+    public var isEmpty: Bool { lowerBound > upperBound }
+
+    public func contains(_ value: Bound) -> Bool {
+        lowerBound <= value && value <= upperBound
+    }
+
+    /// The equivalent Swift range, or `nil` if the range is empty
+    public var range: ClosedRange<Bound>? {
+        lowerBound <= upperBound ? lowerBound...upperBound : nil
+    }
+}
+
+extension UniffiClosedRange: Equatable {}
+extension UniffiClosedRange: Hashable where Bound: Hashable {}
+extension UniffiClosedRange: Codable where Bound: Codable {}
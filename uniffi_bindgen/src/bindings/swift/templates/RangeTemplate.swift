@@ -0,0 +1,16 @@
+{%- if self.include_once_check("RangeRuntime.swift") %}{%- include "RangeRuntime.swift" %}{%- endif %}
+
+fileprivate struct {{ ffi_converter_name }}: FfiConverterRustBuffer {
+    typealias SwiftType = {{ type_name }}
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        {{ inner_type|write_fn }}(value.lowerBound, into: &buf)
+        {{ inner_type|write_fn }}(value.upperBound, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        let lowerBound = try {{ inner_type|read_fn }}(from: &buf)
+        let upperBound = try {{ inner_type|read_fn }}(from: &buf)
+        return SwiftType(lowerBound: lowerBound, upperBound: upperBound)
+    }
+}
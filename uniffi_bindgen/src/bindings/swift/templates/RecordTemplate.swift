@@ -1,7 +1,7 @@
 {%- let rec = ci|get_record_definition(name) %}
 public struct {{ type_name }} {
     {%- for field in rec.fields() %}
-    public var {{ field.name()|var_name }}: {{ field|type_name }}
+    public var {{ field.name()|var_name }}: {{ field|type_name(config) }}
     {%- endfor %}
 
     // Default memberwise initializers are never public by default, so we
@@ -16,7 +16,7 @@ public struct {{ type_name }} {
 {% if !contains_object_references %}
 extension {{ type_name }}: Equatable, Hashable {
     public static func ==(lhs: {{ type_name }}, rhs: {{ type_name }}) -> Bool {
-        {%- for field in rec.fields() %}
+        {%- for field in rec.eq_fields() %}
         if lhs.{{ field.name()|var_name }} != rhs.{{ field.name()|var_name }} {
             return false
         }
@@ -25,15 +25,51 @@ extension {{ type_name }}: Equatable, Hashable {
     }
 
     public func hash(into hasher: inout Hasher) {
-        {%- for field in rec.fields() %}
+        {%- for field in rec.eq_fields() %}
         hasher.combine({{ field.name()|var_name }})
         {%- endfor %}
     }
 }
 {% endif %}
 
+{%- if config.serializable_records() && ci.item_is_serializable(type_) %}
+
+extension {{ type_name }}: Codable {}
+{%- endif %}
+
+{%- let lowered_type %}
+{%- if rec.is_pod() %}
+{%- let lowered_type = "UniFfiStruct{}"|format(rec.name()) %}
+
+// `{{ lowered_type }}` is a `#[repr(C)]` struct that's passed by value to and from Rust
+public struct {{ ffi_converter_name }}: FfiConverter {
+    typealias FfiType = {{ lowered_type }}
+    typealias SwiftType = {{ type_name }}
+
+    public static func lift(_ value: {{ lowered_type }}) throws -> {{ type_name }} {
+        return try {{ type_name }}(
+            {%- for field in rec.fields() %}
+            {{ field.name()|arg_name }}: {{ field|lift_fn }}(value.{{ field.name() }})
+            {%- if !loop.last %}, {% endif %}
+            {%- endfor %}
+        )
+    }
+
+    public static func lower(_ value: {{ type_name }}) -> {{ lowered_type }} {
+        return {{ lowered_type }}(
+            {%- for field in rec.fields() %}
+            {{ field.name() }}: {{ field|lower_fn }}(value.{{ field.name()|var_name }})
+            {%- if !loop.last %}, {% endif %}
+            {%- endfor %}
+        )
+    }
+{% else %}
+{%- let lowered_type = "RustBuffer".to_string() %}
+
 public struct {{ ffi_converter_name }}: FfiConverterRustBuffer {
+{%- endif %}
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> {{ type_name }} {
+        {%- call swift::enter_nested() %}
         return try {{ type_name }}(
             {%- for field in rec.fields() %}
             {{ field.name()|arg_name }}: {{ field|read_fn }}(from: &buf)
@@ -53,10 +89,10 @@ public struct {{ ffi_converter_name }}: FfiConverterRustBuffer {
 We always write these public functions just in case the struct is used as
 an external type by another crate.
 #}
-public func {{ ffi_converter_name }}_lift(_ buf: RustBuffer) throws -> {{ type_name }} {
+public func {{ ffi_converter_name }}_lift(_ buf: {{ lowered_type }}) throws -> {{ type_name }} {
     return try {{ ffi_converter_name }}.lift(buf)
 }
 
-public func {{ ffi_converter_name }}_lower(_ value: {{ type_name }}) -> RustBuffer {
+public func {{ ffi_converter_name }}_lower(_ value: {{ type_name }}) -> {{ lowered_type }} {
     return {{ ffi_converter_name }}.lower(value)
 }
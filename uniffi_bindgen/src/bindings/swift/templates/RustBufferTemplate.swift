@@ -91,6 +91,41 @@ fileprivate func readBytes(_ reader: inout (data: Data, offset: Data.Index), cou
     return value
 }
 
+{%- if config.buffer_checks().is_strict() %}
+// Reads a length prefix at the current offset, and checks it before it's used to allocate
+// anything.  Each item takes at least `minItemSize` bytes in the buffer.
+fileprivate func readLength(_ reader: inout (data: Data, offset: Data.Index), minItemSize: Int) throws -> Int32 {
+    let len: Int32 = try readInt(&reader)
+    guard len >= 0 && Int(len) * minItemSize <= reader.data.count - reader.offset else {
+        throw UniffiInternalError.invalidLength
+    }
+    return len
+}
+
+{% endif -%}
+{%- match config.max_nesting_depth() %}
+{%- when Some with(max_depth) %}
+fileprivate let uniffiNestingDepthKey = "uniffi.nestingDepth"
+
+// Records and enums call this before reading their fields, and `uniffiExitNested()` once they're
+// done, so we know how deeply they're nested on this thread.  Deeply nested values fail cleanly
+// here rather than overflowing the stack.
+fileprivate func uniffiEnterNested() throws {
+    let threadDictionary = Thread.current.threadDictionary
+    let depth = threadDictionary[uniffiNestingDepthKey] as? Int ?? 0
+    guard depth < {{ max_depth }} else {
+        throw UniffiInternalError.nestingTooDeep
+    }
+    threadDictionary[uniffiNestingDepthKey] = depth + 1
+}
+
+fileprivate func uniffiExitNested() {
+    let threadDictionary = Thread.current.threadDictionary
+    let depth = threadDictionary[uniffiNestingDepthKey] as? Int ?? 0
+    threadDictionary[uniffiNestingDepthKey] = depth - 1
+}
+
+{% else %}{% endmatch -%}
 // Reads a float at the current offset.
 fileprivate func readFloat(_ reader: inout (data: Data, offset: Data.Index)) throws -> Float {
     return Float(bitPattern: try readInt(&reader))
@@ -166,6 +201,15 @@ fileprivate protocol FfiConverterRustBuffer: FfiConverter where FfiType == RustB
 
 extension FfiConverterRustBuffer {
     public static func lift(_ buf: RustBuffer) throws -> SwiftType {
+        return try liftFromRustBuffer(buf)
+    }
+
+    public static func lower(_ value: SwiftType) -> RustBuffer {
+        return lowerIntoRustBuffer(value)
+    }
+
+    // The default `lift` and `lower`, for converters that override them
+    static func liftFromRustBuffer(_ buf: RustBuffer) throws -> SwiftType {
         var reader = createReader(data: Data(rustBuffer: buf))
         let value = try read(from: &reader)
         if hasRemaining(reader) {
@@ -175,7 +219,7 @@ extension FfiConverterRustBuffer {
         return value
     }
 
-    public static func lower(_ value: SwiftType) -> RustBuffer {
+    static func lowerIntoRustBuffer(_ value: SwiftType) -> RustBuffer {
           var writer = createWriter()
           write(value, into: &writer)
           return RustBuffer(bytes: writer)
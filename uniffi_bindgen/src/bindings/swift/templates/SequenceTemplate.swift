@@ -1,6 +1,24 @@
 fileprivate struct {{ ffi_converter_name }}: FfiConverterRustBuffer {
     typealias SwiftType = {{ type_name }}
 
+    // Rust can pass empty arrays as an empty buffer, see `empty_buffer_sentinels`.  These aren't allocated,
+    // so there's nothing to free.
+    public static func lift(_ buf: RustBuffer) throws -> SwiftType {
+        if buf.len == 0 {
+            return []
+        }
+        return try liftFromRustBuffer(buf)
+    }
+    {%- if config.empty_buffer_sentinels() %}
+
+    public static func lower(_ value: SwiftType) -> RustBuffer {
+        if value.isEmpty {
+            return RustBuffer(capacity: 0, len: 0, data: nil)
+        }
+        return lowerIntoRustBuffer(value)
+    }
+    {%- endif %}
+
     public static func write(_ value: {{ type_name }}, into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
@@ -10,7 +28,11 @@ fileprivate struct {{ ffi_converter_name }}: FfiConverterRustBuffer {
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> {{ type_name }} {
+        {%- if config.buffer_checks().is_strict() %}
+        let len = try readLength(&buf, minItemSize: {{ ci.min_serialized_size(inner_type) }})
+        {%- else %}
         let len: Int32 = try readInt(&buf)
+        {%- endif %}
         var seq = {{ type_name }}()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
@@ -0,0 +1,40 @@
+// Report Rust spans to foreign tracing tools.
+//
+// Once a sink is registered with `uniffiSetSpanSink()`, every call into Rust is reported as a span
+// named after the function or method, along with any spans that the Rust code reports itself.
+// `spanId` is the same for the enter and exit events of a span.
+
+public protocol UniFfiSpanSink: AnyObject {
+    // The current thread entered a Rust span
+    func enter(spanId: UInt64, name: String)
+    // The current thread exited a Rust span
+    func exit(spanId: UInt64, name: String)
+}
+
+fileprivate let UNIFFI_SPAN_ENTER: Int8 = 0
+
+fileprivate let uniffiSpanSinkLock = NSLock()
+fileprivate var uniffiSpanSink: UniFfiSpanSink?
+
+fileprivate func uniffiSpanSinkCallback(event: Int8, spanId: UInt64, nameData: UnsafePointer<UInt8>, nameLen: Int32) {
+    uniffiSpanSinkLock.lock()
+    let sink = uniffiSpanSink
+    uniffiSpanSinkLock.unlock()
+    guard let sink = sink else {
+        return
+    }
+    let name = String(decoding: UnsafeBufferPointer(start: nameData, count: Int(nameLen)), as: UTF8.self)
+    if event == UNIFFI_SPAN_ENTER {
+        sink.enter(spanId: spanId, name: name)
+    } else {
+        sink.exit(spanId: spanId, name: name)
+    }
+}
+
+// Register the sink that receives Rust spans, or pass `nil` to stop reporting them.
+public func uniffiSetSpanSink(_ sink: UniFfiSpanSink?) {
+    uniffiSpanSinkLock.lock()
+    uniffiSpanSink = sink
+    uniffiSpanSinkLock.unlock()
+    ffi_{{ ci.ffi_namespace() }}_span_sink_set(uniffiSpanSinkCallback)
+}
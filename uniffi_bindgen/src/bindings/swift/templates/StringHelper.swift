@@ -10,7 +10,14 @@ fileprivate struct FfiConverterString: FfiConverter {
             return String()
         }
         let bytes = UnsafeBufferPointer<UInt8>(start: value.data!, count: Int(value.len))
+        {%- if config.buffer_checks().is_strict() %}
+        guard let string = String(bytes: bytes, encoding: String.Encoding.utf8) else {
+            throw UniffiInternalError.invalidUtf8
+        }
+        return string
+        {%- else %}
         return String(bytes: bytes, encoding: String.Encoding.utf8)!
+        {%- endif %}
     }
 
     public static func lower(_ value: String) -> RustBuffer {
@@ -25,8 +32,16 @@ fileprivate struct FfiConverterString: FfiConverter {
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> String {
+        {%- if config.buffer_checks().is_strict() %}
+        let len = try readLength(&buf, minItemSize: 1)
+        guard let value = String(bytes: try readBytes(&buf, count: Int(len)), encoding: String.Encoding.utf8) else {
+            throw UniffiInternalError.invalidUtf8
+        }
+        return value
+        {%- else %}
         let len: Int32 = try readInt(&buf)
         return String(bytes: try readBytes(&buf, count: Int(len)), encoding: String.Encoding.utf8)!
+        {%- endif %}
     }
 
     public static func write(_ value: String, into buf: inout [UInt8]) {
@@ -1,7 +1,41 @@
 {%- if func.is_async() %}
 
-public func {{ func.name()|fn_name }}({%- call swift::arg_list_decl(func) -%}) async {% call swift::throws(func) %}{% match func.return_type() %}{% when Some with (return_type) %} -> {{ return_type|type_name }}{% when None %}{% endmatch %} {
-    var continuation: {{ func.result_type().borrow()|future_continuation_type }}? = nil
+public func {{ func.name()|fn_name }}({%- call swift::arg_list_decl(func) -%}{% call swift::async_executor_arg_decl(func) %}) async {% call swift::async_throws(func) %}{% match func.return_type() %}{% when Some with (return_type) %} -> {{ return_type|type_name(config) }}{% when None %}{% endmatch %} {
+    {%- if config.async_cancellation() %}
+    // Suspend the function and call the scaffolding function, passing it a callback handler from
+    // `AsyncTypes.swift`
+    //
+    // The continuation is stored on the heap, so that its address stays valid until the callback
+    // is invoked and can also be used to cancel the Rust future when the task is cancelled.
+    let continuation = UnsafeMutablePointer<{{ func.result_type().borrow()|future_continuation_type(config) }}?>.allocate(capacity: 1)
+    continuation.initialize(to: nil)
+    defer {
+        continuation.deinitialize(count: 1)
+        continuation.deallocate()
+    }
+    let callbackData = UInt(bitPattern: continuation)
+    // Rust can only cancel the future once the call started, so check for tasks that were
+    // cancelled before that.
+    try Task.checkCancellation()
+    return try await withTaskCancellationHandler {
+        try await withCheckedThrowingContinuation {
+            continuation.pointee = $0
+            uniffiMarkRustFutureCancellable(callbackData: callbackData)
+            try! rustCall() {
+                {{ func.ffi_func().name() }}(
+                    {% call swift::arg_list_lowered(func) %}
+                    {% call swift::async_executor_lowered() %},
+                    {{ func.result_type().borrow()|future_callback }},
+                    continuation,
+                    $0
+                )
+            }
+        }
+    } onCancel: {
+        uniffiCancelRustFuture(callbackData: callbackData)
+    }
+    {%- else %}
+    var continuation: {{ func.result_type().borrow()|future_continuation_type(config) }}? = nil
     // Suspend the function and call the scaffolding function, passing it a callback handler from
     // `AsyncTypes.swift`
     //
@@ -12,13 +46,14 @@ public func {{ func.name()|fn_name }}({%- call swift::arg_list_decl(func) -%}) a
         try! rustCall() {
             {{ func.ffi_func().name() }}(
                 {% call swift::arg_list_lowered(func) %}
-                FfiConverterForeignExecutor.lower(UniFfiForeignExecutor()),
+                {% call swift::async_executor_lowered() %},
                 {{ func.result_type().borrow()|future_callback }},
                 &continuation,
                 $0
             )
         }
     }
+    {%- endif %}
 }
 
 {% else %}
@@ -26,7 +61,7 @@ public func {{ func.name()|fn_name }}({%- call swift::arg_list_decl(func) -%}) a
 {%- match func.return_type() -%}
 {%- when Some with (return_type) %}
 
-public func {{ func.name()|fn_name }}({%- call swift::arg_list_decl(func) -%}) {% call swift::throws(func) %} -> {{ return_type|type_name }} {
+public func {{ func.name()|fn_name }}({%- call swift::arg_list_decl(func) -%}) {% call swift::throws(func) %} -> {{ return_type|type_name(config) }} {
     return {% call swift::try(func) %} {{ return_type|lift_fn }}(
         {% call swift::to_ffi_call(func) %}
     )
@@ -40,3 +75,18 @@ public func {{ func.name()|fn_name }}({% call swift::arg_list_decl(func) %}) {%
 
 {% endmatch %}
 {%- endif %}
+
+{%- for alias in func|fn_aliases %}
+
+public func {{ alias }}({%- call swift::arg_list_decl(func) -%}{% call swift::async_executor_arg_decl(func) %}) {% if func.is_async() %}async {% call swift::async_throws(func) %}{% else %}{% call swift::throws(func) %}{% endif %}{% match func.return_type() %}{% when Some with (return_type) %} -> {{ return_type|type_name(config) }}{% when None %}{% endmatch %} {
+    return {% if func.throws() || (func.is_async() && config.async_cancellation()) %}try {% endif %}{% if func.is_async() %}await {% endif %}{{ func.name()|fn_name }}({% call swift::arg_list_forwarded(func) %})
+}
+{% endfor %}
+
+{%- for alias in config.function_aliases(func.name()) %}
+
+@available(*, deprecated, renamed: "{{ func.name()|fn_name }}")
+public func {{ alias|fn_name }}({%- call swift::arg_list_decl(func) -%}{% call swift::async_executor_arg_decl(func) %}) {% if func.is_async() %}async {% call swift::async_throws(func) %}{% else %}{% call swift::throws(func) %}{% endif %}{% match func.return_type() %}{% when Some with (return_type) %} -> {{ return_type|type_name(config) }}{% when None %}{% endmatch %} {
+    return {% if func.throws() || (func.is_async() && config.async_cancellation()) %}try {% endif %}{% if func.is_async() %}await {% endif %}{{ func.name()|fn_name }}({% call swift::arg_list_forwarded(func) %})
+}
+{% endfor %}
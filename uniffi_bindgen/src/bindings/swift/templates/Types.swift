@@ -1,6 +1,6 @@
 {%- import "macros.swift" as swift %}
 {%- for type_ in ci.iter_types() %}
-{%- let type_name = type_|type_name %}
+{%- let type_name = type_|type_name(config) %}
 {%- let ffi_converter_name = type_|ffi_converter_name %}
 {%- let canonical_type_name = type_|canonical_name %}
 {%- let contains_object_references = ci.item_contains_object_references(type_) %}
@@ -25,6 +25,9 @@
 {%- when Type::Bytes %}
 {%- include "DataHelper.swift" %}
 
+{%- when Type::ByteArray { len } %}
+{%- include "FixedDataHelper.swift" %}
+
 {%- when Type::Int8 %}
 {%- include "Int8Helper.swift" %}
 
@@ -61,6 +64,9 @@
 {%- when Type::Duration %}
 {%- include "DurationHelper.swift" %}
 
+{%- when Type::JsonValue %}
+{%- include "JsonValueHelper.swift" %}
+
 {%- when Type::CallbackInterface { name, module_path } %}
 {%- include "CallbackInterfaceTemplate.swift" %}
 
@@ -81,7 +87,7 @@
 {%- when Type::Object{ name, module_path, imp } %}
 {%- include "ObjectTemplate.swift" %}
 
-{%- when Type::Record { name, module_path } %}
+{%- when Type::Record { name, module_path, pod } %}
 {%- include "RecordTemplate.swift" %}
 
 {%- when Type::Optional { inner_type } %}
@@ -93,6 +99,9 @@
 {%- when Type::Map { key_type, value_type } %}
 {%- include "MapTemplate.swift" %}
 
+{%- when Type::Range { inner_type, inclusive } %}
+{%- include "RangeTemplate.swift" %}
+
 {%- else %}
 {%- endmatch %}
 {%- endfor %}
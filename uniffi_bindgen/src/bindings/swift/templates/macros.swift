@@ -43,24 +43,36 @@
 
 {% macro arg_list_decl(func) %}
     {%- for arg in func.arguments() -%}
-        {% if config.omit_argument_labels() %}_ {% endif %}{{ arg.name()|var_name }}: {{ arg|type_name -}}
+        {% if config.omit_argument_labels() %}_ {% endif %}{{ arg.name()|var_name }}: {% match arg.variadic_element_type() %}{% when Some with (elem) %}{{ elem|type_name(config) }}...{% when None %}{{ arg|type_name(config) }}{% endmatch -%}
         {%- match arg.default_value() %}
-        {%- when Some with(literal) %} = {{ literal|literal_swift(arg) }}
+        {%- when Some with(literal) %} = {{ literal|literal_swift(arg, config) }}
         {%- else %}
         {%- endmatch %}
         {%- if !loop.last %}, {% endif -%}
     {%- endfor %}
 {%- endmacro %}
 
+{#-
+// Arglist as used when one function forwards its arguments to another, for example the
+// deprecated aliases of renamed functions.
+-#}
+{% macro arg_list_forwarded(func) %}
+    {%- for arg in func.arguments() -%}
+        {% if !config.omit_argument_labels() %}{{ arg.name()|var_name }}: {% endif %}{{ arg.name()|var_name }}
+        {%- if !loop.last %}, {% endif -%}
+    {%- endfor %}
+    {%- if func.is_async() && config.async_executor().is_per_call() %}{% if !func.arguments().is_empty() %}, {% endif %}uniffiExecutor: uniffiExecutor{% endif %}
+{%- endmacro %}
+
 {#-
 // Field lists as used in Swift declarations of Records and Enums.
 // Note the var_name and type_name filters.
 -#}
 {% macro field_list_decl(item) %}
     {%- for field in item.fields() -%}
-        {{ field.name()|var_name }}: {{ field|type_name -}}
+        {{ field.name()|var_name }}: {{ field|type_name(config) -}}
         {%- match field.default_value() %}
-            {%- when Some with(literal) %} = {{ literal|literal_swift(field) }}
+            {%- when Some with(literal) %} = {{ literal|literal_swift(field, config) }}
             {%- else %}
         {%- endmatch -%}
         {% if !loop.last %}, {% endif %}
@@ -70,12 +82,29 @@
 
 {% macro arg_list_protocol(func) %}
     {%- for arg in func.arguments() -%}
-        {% if config.omit_argument_labels() %}_ {% endif %}{{ arg.name()|var_name }}: {{ arg|type_name -}}
+        {% if config.omit_argument_labels() %}_ {% endif %}{{ arg.name()|var_name }}: {% match arg.variadic_element_type() %}{% when Some with (elem) %}{{ elem|type_name(config) }}...{% when None %}{{ arg|type_name(config) }}{% endmatch -%}
         {%- if !loop.last %}, {% endif -%}
     {%- endfor %}
 {%- endmacro %}
 
 
+{#-
+// Extra trailing argument for async functions when the executor is injected per-call.
+-#}
+{%- macro async_executor_arg_decl(func) %}
+{%- if func.is_async() && config.async_executor().is_per_call() -%}
+{%- if !func.arguments().is_empty() %}, {% endif %}uniffiExecutor: UniFfiForeignExecutor
+{%- endif %}
+{%- endmacro -%}
+
+{%- macro async_executor_lowered() %}
+{%- if config.async_executor().is_per_call() -%}
+FfiConverterForeignExecutor.lower(uniffiExecutor)
+{%- else -%}
+FfiConverterForeignExecutor.lower(UniFfiForeignExecutor())
+{%- endif %}
+{%- endmacro -%}
+
 {%- macro async(func) %}
 {%- if func.is_async() %}async{% endif %}
 {%- endmacro -%}
@@ -87,3 +116,26 @@
 {%- macro try(func) %}
 {%- if func.throws() %}try {% else %}try! {% endif %}
 {%- endmacro -%}
+
+{#-
+// Async functions also throw `CancellationError` when `async_cancellation` is enabled
+-#}
+{%- macro async_throws(func) %}
+{%- if func.throws() || config.async_cancellation() %}throws{% endif %}
+{%- endmacro -%}
+
+{%- macro async_try(func) %}
+{%- if func.throws() || config.async_cancellation() %}try {% else %}try! {% endif %}
+{%- endmacro -%}
+
+{#-
+// Track how deeply records and enums are nested while reading them, when the `max_nesting_depth`
+// option is set.  Writing can't throw, so values that are written are only limited by the
+// scaffolding when it lifts them.
+-#}
+{%- macro enter_nested() %}
+        {%- if config.max_nesting_depth().is_some() %}
+        try uniffiEnterNested()
+        defer { uniffiExitNested() }
+        {%- endif %}
+{%- endmacro -%}
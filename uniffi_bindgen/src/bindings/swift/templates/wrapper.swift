@@ -1,5 +1,6 @@
 // This file was autogenerated by some hot garbage in the `uniffi` crate.
 // Trust me, you don't want to mess with it!
+// The Rust library must be built with uniffi {{ config.required_runtime_version() }}.
 {%- import "macros.swift" as swift %}
 import Foundation
 {%- for imported_class in self.imports() %}
@@ -19,6 +20,22 @@ import {{ config.ffi_module_name() }}
 // Public interface members begin here.
 {{ type_helper_code }}
 
+{%- if config.span_sink() %}
+{% include "SpanSinkTemplate.swift" %}
+{%- endif %}
+
+{%- if config.panic_observer() %}
+{% include "PanicObserverTemplate.swift" %}
+{%- endif %}
+
+{%- if config.capability_registration() %}
+{% include "CapabilityTemplate.swift" %}
+{%- endif %}
+
+{%- if config.error_localizer() %}
+{% include "ErrorLocalizerTemplate.swift" %}
+{%- endif %}
+
 {%- for func in ci.function_definitions() %}
 {%- include "TopLevelFunctionTemplate.swift" %}
 {%- endfor %}
@@ -26,6 +43,9 @@ import {{ config.ffi_module_name() }}
 private enum InitializationResult {
     case ok
     case contractVersionMismatch
+    {%- if config.runtime_version().is_some() %}
+    case runtimeVersionMismatch(UInt32)
+    {%- endif %}
     case apiChecksumMismatch
 }
 // Use a global variables to perform the versioning checks. Swift ensures that
@@ -38,6 +58,15 @@ private var initializationResult: InitializationResult {
     if bindings_contract_version != scaffolding_contract_version {
         return InitializationResult.contractVersionMismatch
     }
+    {%- match config.runtime_version() %}
+    {%- when Some with (runtime_version) %}
+    // The version is packed as `major * 1_000_000 + minor * 1_000 + patch`
+    let version = ffi_{{ ci.ffi_namespace() }}_uniffi_runtime_version()
+    if version < {{ runtime_version.packed_min() }} || version > {{ runtime_version.packed_max() }} {
+        return InitializationResult.runtimeVersionMismatch(version)
+    }
+    {%- when None %}
+    {%- endmatch %}
 
     {%- for (name, expected_checksum) in ci.iter_checksums() %}
     if ({{ name }}() != {{ expected_checksum }}) {
@@ -48,6 +77,14 @@ private var initializationResult: InitializationResult {
     {% for fn in self.initialization_fns() -%}
     {{ fn }}()
     {% endfor -%}
+    {%- if config.empty_buffer_sentinels() %}
+    ffi_{{ ci.ffi_namespace() }}_empty_buffer_sentinels_enable()
+    {%- endif %}
+    {%- match config.max_nesting_depth() %}
+    {%- when Some with(max_depth) %}
+    ffi_{{ ci.ffi_namespace() }}_max_nesting_depth_set({{ max_depth }})
+    {%- else %}
+    {%- endmatch %}
 
     return InitializationResult.ok
 }
@@ -58,7 +95,62 @@ private func uniffiEnsureInitialized() {
         break
     case .contractVersionMismatch:
         fatalError("UniFFI contract version mismatch: try cleaning and rebuilding your project")
+    {%- match config.runtime_version() %}
+    {%- when Some with (runtime_version) %}
+    case let .runtimeVersionMismatch(version):
+        fatalError("UniFFI runtime version mismatch: these bindings need uniffi {{ runtime_version }}, but the library was built with uniffi \(version / 1000000).\(version / 1000 % 1000).\(version % 1000)")
+    {%- when None %}
+    {%- endmatch %}
     case .apiChecksumMismatch:
         fatalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
 }
+{%- if config.handshake() %}
+
+/// The contract version and interface fingerprint of a Rust library
+///
+/// Two libraries with the same values are interchangeable as far as these bindings are concerned.
+public struct UniffiHandshake: Equatable {
+    public let contractVersion: UInt32
+    public let fingerprint: UInt32
+
+    /// The values that these bindings were generated for
+    public static let expected = UniffiHandshake(contractVersion: {{ ci.uniffi_contract_version() }}, fingerprint: {{ ci.interface_fingerprint() }})
+}
+
+/// Thrown by `uniffiCheckHandshake()` when the Rust library isn't compatible with these bindings
+public struct UniffiHandshakeError: Error, CustomStringConvertible {
+    public let expected: UniffiHandshake
+    public let actual: UniffiHandshake
+
+    public var description: String {
+        return "The Rust library for `{{ ci.namespace() }}` isn't compatible with these bindings: "
+            + "the bindings expect contract version \(expected.contractVersion) and interface fingerprint \(expected.fingerprint), "
+            + "but the library has contract version \(actual.contractVersion) and interface fingerprint \(actual.fingerprint). "
+            + "Regenerate the bindings from the library that you ship."
+    }
+}
+
+/// Read the contract version and interface fingerprint of the loaded Rust library
+///
+/// Unlike every other call into the library, this doesn't check that the library matches the
+/// bindings first.
+public func uniffiHandshake() -> UniffiHandshake {
+    var fingerprint: UInt32 = 0
+    {%- for (name, _) in ci.iter_checksums() %}
+    fingerprint = fingerprint &* 31 &+ UInt32({{ name }}())
+    {%- endfor %}
+    return UniffiHandshake(contractVersion: {{ ci.ffi_uniffi_contract_version().name() }}(), fingerprint: fingerprint)
+}
+
+/// Check that the loaded Rust library is compatible with these bindings
+///
+/// Call this at startup to fail with an error that says what doesn't match, rather than a
+/// `fatalError` on the first call into the library.
+public func uniffiCheckHandshake() throws {
+    let actual = uniffiHandshake()
+    if actual != UniffiHandshake.expected {
+        throw UniffiHandshakeError(expected: UniffiHandshake.expected, actual: actual)
+    }
+}
+{%- endif %}
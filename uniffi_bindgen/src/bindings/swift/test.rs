@@ -126,8 +126,14 @@ struct GeneratedSources {
 
 impl GeneratedSources {
     fn new(crate_name: &str, cdylib_path: &Utf8Path, out_dir: &Utf8Path) -> Result<Self> {
-        let sources =
-            generate_bindings(cdylib_path, None, &[TargetLanguage::Swift], out_dir, false)?;
+        let sources = generate_bindings(
+            cdylib_path,
+            None,
+            None,
+            &[TargetLanguage::Swift],
+            out_dir,
+            false,
+        )?;
         let main_source = sources
             .iter()
             .find(|s| s.package.name == crate_name)
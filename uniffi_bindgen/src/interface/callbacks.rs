@@ -43,6 +43,8 @@ use super::{AsType, Type, TypeIterator};
 pub struct CallbackInterface {
     pub(super) name: String,
     pub(super) module_path: String,
+    #[checksum_ignore]
+    pub(super) full_module_path: String,
     pub(super) methods: Vec<Method>,
     // We don't include the FFIFunc in the hash calculation, because:
     //  - it is entirely determined by the other fields,
@@ -55,19 +57,15 @@ pub struct CallbackInterface {
 }
 
 impl CallbackInterface {
-    pub fn new(name: String) -> CallbackInterface {
-        CallbackInterface {
-            name,
-            module_path: Default::default(),
-            methods: Default::default(),
-            ffi_init_callback: Default::default(),
-        }
-    }
-
     pub fn name(&self) -> &str {
         &self.name
     }
 
+    /// The full path of the Rust module that this item was defined in.
+    pub fn full_module_path(&self) -> &str {
+        &self.full_module_path
+    }
+
     pub fn methods(&self) -> Vec<&Method> {
         self.methods.iter().collect()
     }
@@ -91,6 +89,18 @@ impl CallbackInterface {
     }
 }
 
+impl From<uniffi_meta::CallbackInterfaceMetadata> for CallbackInterface {
+    fn from(meta: uniffi_meta::CallbackInterfaceMetadata) -> Self {
+        CallbackInterface {
+            name: meta.name,
+            module_path: meta.module_path,
+            full_module_path: meta.full_module_path,
+            methods: Default::default(),
+            ffi_init_callback: Default::default(),
+        }
+    }
+}
+
 impl AsType for CallbackInterface {
     fn as_type(&self) -> Type {
         Type::CallbackInterface {
@@ -155,7 +155,7 @@
 //! # Ok::<(), anyhow::Error>(())
 //! ```
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use uniffi_meta::Checksum;
 
 use super::record::Field;
@@ -170,6 +170,8 @@ use super::{AsType, Type, TypeIterator};
 pub struct Enum {
     pub(super) name: String,
     pub(super) module_path: String,
+    #[checksum_ignore]
+    pub(super) full_module_path: String,
     pub(super) variants: Vec<Variant>,
     // NOTE: `flat` is a misleading name and to make matters worse, has 2 different
     // meanings depending on the context :(
@@ -189,6 +191,10 @@ pub struct Enum {
     // * For an Enum not used as an error but which has no variants with data, `flat` will be
     //   false when generating the scaffolding but `true` when generating bindings.
     pub(super) flat: bool,
+    // For enums defined with `#[derive(uniffi::Flags)]`, the unsigned integer type that stores
+    // the bits. Each variant is then a named flag with a `flag_value`, and the enum is passed
+    // across the FFI as that integer rather than as a variant index.
+    pub(super) flags_type: Option<Type>,
 }
 
 impl Enum {
@@ -196,6 +202,11 @@ impl Enum {
         &self.name
     }
 
+    /// The full path of the Rust module that this item was defined in.
+    pub fn full_module_path(&self) -> &str {
+        &self.full_module_path
+    }
+
     pub fn variants(&self) -> &[Variant] {
         &self.variants
     }
@@ -204,8 +215,23 @@ impl Enum {
         self.flat
     }
 
+    /// Is this a set of bit flags rather than a regular enum?
+    pub fn is_flags(&self) -> bool {
+        self.flags_type.is_some()
+    }
+
+    /// The unsigned integer type storing the bits of a flags enum.
+    pub fn flags_type(&self) -> Option<&Type> {
+        self.flags_type.as_ref()
+    }
+
     pub fn iter_types(&self) -> TypeIterator<'_> {
-        Box::new(self.variants.iter().flat_map(Variant::iter_types))
+        Box::new(
+            self.variants
+                .iter()
+                .flat_map(Variant::iter_types)
+                .chain(self.flags_type.iter()),
+        )
     }
 
     // Sadly can't use TryFrom due to the 'is_flat' complication.
@@ -218,12 +244,47 @@ impl Enum {
         Ok(Self {
             name: meta.name,
             module_path: meta.module_path,
+            full_module_path: meta.full_module_path,
             variants: meta
                 .variants
                 .into_iter()
                 .map(TryInto::try_into)
                 .collect::<Result<_>>()?,
             flat,
+            flags_type: None,
+        })
+    }
+}
+
+impl TryFrom<uniffi_meta::FlagsMetadata> for Enum {
+    type Error = anyhow::Error;
+
+    fn try_from(meta: uniffi_meta::FlagsMetadata) -> Result<Self> {
+        if !matches!(
+            meta.repr,
+            Type::UInt8 | Type::UInt16 | Type::UInt32 | Type::UInt64
+        ) {
+            bail!(
+                "Flags `{}` must be stored in an unsigned integer, not {:?}",
+                meta.name,
+                meta.repr
+            );
+        }
+        Ok(Self {
+            name: meta.name,
+            module_path: meta.module_path,
+            full_module_path: meta.full_module_path,
+            variants: meta
+                .flags
+                .into_iter()
+                .map(|flag| Variant {
+                    name: flag.name,
+                    fields: vec![],
+                    flag_value: Some(flag.value),
+                })
+                .collect(),
+            flat: true,
+            flags_type: Some(meta.repr),
         })
     }
 }
@@ -244,6 +305,7 @@ impl AsType for Enum {
 pub struct Variant {
     pub(super) name: String,
     pub(super) fields: Vec<Field>,
+    pub(super) flag_value: Option<u64>,
 }
 
 impl Variant {
@@ -259,6 +321,11 @@ impl Variant {
         !self.fields.is_empty()
     }
 
+    /// The bits of this flag, for variants of a flags enum.
+    pub fn flag_value(&self) -> Option<u64> {
+        self.flag_value
+    }
+
     pub fn iter_types(&self) -> TypeIterator<'_> {
         Box::new(self.fields.iter().flat_map(Field::iter_types))
     }
@@ -275,6 +342,7 @@ impl TryFrom<uniffi_meta::VariantMetadata> for Variant {
                 .into_iter()
                 .map(TryInto::try_into)
                 .collect::<Result<_>>()?,
+            flag_value: None,
         })
     }
 }
@@ -508,4 +576,46 @@ mod test {
         assert!(!error.is_flat());
         assert!(ci.is_name_used_as_error(&error.name));
     }
+
+    #[test]
+    fn test_flags() {
+        let meta = uniffi_meta::FlagsMetadata {
+            module_path: "test".to_string(),
+            name: "Permissions".to_string(),
+            repr: Type::UInt32,
+            flags: vec![
+                uniffi_meta::FlagMetadata {
+                    name: "READ".to_string(),
+                    value: 1,
+                },
+                uniffi_meta::FlagMetadata {
+                    name: "WRITE".to_string(),
+                    value: 2,
+                },
+            ],
+            full_module_path: "test".to_string(),
+        };
+        let e = Enum::try_from(meta.clone()).unwrap();
+        assert!(e.is_flat());
+        assert!(e.is_flags());
+        assert_eq!(e.flags_type(), Some(&Type::UInt32));
+        assert_eq!(
+            e.variants()
+                .iter()
+                .map(|v| (v.name(), v.flag_value()))
+                .collect::<Vec<_>>(),
+            vec![("READ", Some(1)), ("WRITE", Some(2))]
+        );
+        assert!(e.iter_types().any(|t| *t == Type::UInt32));
+
+        let err = Enum::try_from(uniffi_meta::FlagsMetadata {
+            repr: Type::Int32,
+            ..meta
+        })
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Flags `Permissions` must be stored in an unsigned integer, not Int32"
+        );
+    }
 }
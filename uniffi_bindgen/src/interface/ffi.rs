@@ -49,6 +49,9 @@ pub enum FfiType {
     ForeignBytes,
     /// Pointer to a callback function that handles all callbacks on the foreign language side.
     ForeignCallback,
+    /// A `#[repr(C)]` struct of primitive fields, passed by value.
+    /// The inner string is the name of the record that the struct represents.
+    Struct(String),
     /// Pointer-sized opaque handle that represents a foreign executor.  Foreign bindings can
     /// either use an actual pointer or a usized integer.
     ForeignExecutorHandle,
@@ -92,20 +95,31 @@ impl From<&Type> for FfiType {
             Type::String => FfiType::RustBuffer(None),
             // Byte strings are also always owned rust values.
             // We might add a separate type for borrowed byte strings in future as well.
-            Type::Bytes => FfiType::RustBuffer(None),
+            Type::Bytes | Type::ByteArray { .. } => FfiType::RustBuffer(None),
             // Objects are pointers to an Arc<>
             Type::Object { name, .. } => FfiType::RustArcPtr(name.to_owned()),
             // Callback interfaces are passed as opaque integer handles.
             Type::CallbackInterface { .. } => FfiType::UInt64,
             Type::ForeignExecutor => FfiType::ForeignExecutorHandle,
+            // `#[uniffi(pod)]` records are passed by value, as a C struct
+            Type::Record {
+                name, pod: true, ..
+            } => FfiType::Struct(name.clone()),
+            Type::External {
+                name,
+                kind: ExternalKind::PodRecord,
+                ..
+            } => FfiType::Struct(name.clone()),
             // Other types are serialized into a bytebuffer and deserialized on the other side.
             Type::Enum { .. }
             | Type::Record { .. }
             | Type::Optional { .. }
             | Type::Sequence { .. }
             | Type::Map { .. }
+            | Type::Range { .. }
             | Type::Timestamp
-            | Type::Duration => FfiType::RustBuffer(None),
+            | Type::Duration
+            | Type::JsonValue => FfiType::RustBuffer(None),
             Type::External {
                 name,
                 kind: ExternalKind::Interface,
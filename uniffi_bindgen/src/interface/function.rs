@@ -32,7 +32,7 @@
 //! # Ok::<(), anyhow::Error>(())
 //! ```
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 
 use super::ffi::{FfiArgument, FfiFunction, FfiType};
 use super::Literal;
@@ -48,6 +48,9 @@ use uniffi_meta::Checksum;
 #[derive(Debug, Clone, Checksum)]
 pub struct Function {
     pub(super) name: String,
+    // The module path is not part of the function signature, so it shouldn't affect the checksum.
+    #[checksum_ignore]
+    pub(super) full_module_path: String,
     pub(super) is_async: bool,
     pub(super) arguments: Vec<Argument>,
     pub(super) return_type: Option<Type>,
@@ -64,6 +67,9 @@ pub struct Function {
     // Force a checksum value, or we'll fallback to the trait.
     #[checksum_ignore]
     pub(super) checksum: Option<u16>,
+    // Aliases only exist in the foreign bindings, so they don't affect the FFI.
+    #[checksum_ignore]
+    pub(super) aliases: Vec<String>,
 }
 
 impl Function {
@@ -71,10 +77,22 @@ impl Function {
         &self.name
     }
 
+    /// The full path of the Rust module that this item was defined in.
+    pub fn full_module_path(&self) -> &str {
+        &self.full_module_path
+    }
+
     pub fn is_async(&self) -> bool {
         self.is_async
     }
 
+    /// Extra foreign names for this function, from `#[uniffi::export(aliases(...))]`.
+    ///
+    /// The bindings generate a wrapper for each one, which calls the function.
+    pub fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
     pub fn arguments(&self) -> Vec<&Argument> {
         self.arguments.iter().collect()
     }
@@ -142,6 +160,7 @@ impl From<uniffi_meta::FnParamMetadata> for Argument {
             by_ref: meta.by_ref,
             optional: meta.optional,
             default: meta.default,
+            variadic: meta.variadic,
         }
     }
 }
@@ -162,6 +181,7 @@ impl From<uniffi_meta::FnMetadata> for Function {
 
         Self {
             name: meta.name,
+            full_module_path: meta.full_module_path,
             is_async,
             arguments,
             return_type,
@@ -169,6 +189,7 @@ impl From<uniffi_meta::FnMetadata> for Function {
             throws: meta.throws,
             checksum_fn_name,
             checksum: meta.checksum,
+            aliases: meta.aliases,
         }
     }
 }
@@ -183,6 +204,7 @@ pub struct Argument {
     pub(super) by_ref: bool,
     pub(super) optional: bool,
     pub(super) default: Option<Literal>,
+    pub(super) variadic: bool,
 }
 
 impl Argument {
@@ -202,6 +224,18 @@ impl Argument {
         self.default.as_ref()
     }
 
+    pub fn is_variadic(&self) -> bool {
+        self.variadic
+    }
+
+    /// The element type of a variadic argument, which is passed to Rust as a sequence.
+    pub fn variadic_element_type(&self) -> Option<&Type> {
+        match &self.type_ {
+            Type::Sequence { inner_type } if self.variadic => Some(inner_type),
+            _ => None,
+        }
+    }
+
     pub fn iter_types(&self) -> TypeIterator<'_> {
         self.type_.iter_types()
     }
@@ -222,6 +256,19 @@ impl From<&Argument> for FfiArgument {
     }
 }
 
+/// Check that only the last argument of a callable is variadic.
+pub(super) fn check_variadic_arguments(callable_name: &str, arguments: &[Argument]) -> Result<()> {
+    if let Some(pos) = arguments.iter().position(|a| a.variadic) {
+        if pos != arguments.len() - 1 {
+            bail!(
+                "Variadic argument `{}` of `{callable_name}` must be the last argument",
+                arguments[pos].name
+            );
+        }
+    }
+    Ok(())
+}
+
 /// Combines the return and throws type of a function/method
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq)]
 pub struct ResultType {
@@ -250,6 +297,9 @@ pub trait Callable {
             throws_type: self.throws_type(),
         }
     }
+    fn has_variadic_argument(&self) -> bool {
+        self.arguments().iter().any(|a| a.is_variadic())
+    }
 }
 
 impl Callable for Function {
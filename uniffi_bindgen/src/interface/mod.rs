@@ -28,6 +28,11 @@
 //! module, then there should be no opportunities for them to disagree on how the two sides should
 //! interact.
 //!
+//! Items have two module paths: `module_path` is the crate that defines the item and is used for
+//! symbol names and type references, while `full_module_path` is the full path of the Rust module
+//! that defines it, for bindings that mirror the Rust module layout.  Items defined in a UDL file
+//! don't have a module of their own, so both paths are the crate name for them.
+//!
 //! General and incomplete TODO list for this thing:
 //!
 //!   * It should prevent user error and the possibility of generating bad code by doing (at least)
@@ -290,6 +295,65 @@ impl ComponentInterface {
             .any(|t| matches!(t, Type::Object { .. }))
     }
 
+    /// Check whether the given item can implement the foreign serialization protocols enabled by
+    /// the `serializable_records` config option.
+    ///
+    /// Objects, callback interfaces and external types are references to things that live
+    /// elsewhere, errors are exceptions in most languages and the foreign range and JSON types
+    /// don't support all the protocols, so items containing any of them (possibly nested) can't be
+    /// serialized.
+    pub fn item_is_serializable(&self, item: &Type) -> bool {
+        self.iter_types_in_item(item).all(|t| match t {
+            Type::Object { .. }
+            | Type::CallbackInterface { .. }
+            | Type::ForeignExecutor
+            | Type::Range { .. }
+            | Type::JsonValue
+            | Type::External { .. } => false,
+            Type::Enum { name, .. } => !self.is_name_used_as_error(name),
+            _ => true,
+        })
+    }
+
+    /// The minimum number of bytes that a value of `type_` takes when it's serialized
+    ///
+    /// The bindings use this with `buffer_checks = "strict"` to check the length prefixes of
+    /// sequences and maps against the rest of the buffer, before allocating anything.
+    pub fn min_serialized_size(&self, type_: &Type) -> usize {
+        match type_ {
+            Type::UInt8 | Type::Int8 | Type::Boolean => 1,
+            Type::UInt16 | Type::Int16 => 2,
+            Type::UInt32 | Type::Int32 | Type::Float32 => 4,
+            Type::UInt64 | Type::Int64 | Type::Float64 => 8,
+            // Seconds and nanoseconds
+            Type::Timestamp | Type::Duration => 12,
+            // Handles and pointers
+            Type::Object { .. } | Type::CallbackInterface { .. } => 8,
+            // Length prefixes
+            Type::String
+            | Type::Bytes
+            | Type::JsonValue
+            | Type::Sequence { .. }
+            | Type::Map { .. } => 4,
+            // The `Some` tag
+            Type::Optional { .. } => 1,
+            // The variant index
+            Type::Enum { .. } => 4,
+            // The length prefix and the bytes themselves
+            Type::ByteArray { len } => 4 + *len as usize,
+            Type::Range { inner_type, .. } => 2 * self.min_serialized_size(inner_type),
+            Type::Record { name, .. } => self.get_record_definition(name).map_or(0, |rec| {
+                rec.fields()
+                    .iter()
+                    .map(|f| self.min_serialized_size(&f.as_type()))
+                    .sum()
+            }),
+            Type::Custom { builtin, .. } => self.min_serialized_size(builtin),
+            // The layout of external types isn't known
+            Type::External { .. } | Type::ForeignExecutor => 0,
+        }
+    }
+
     /// Check whether the given item contains any (possibly nested) unsigned types
     pub fn item_contains_unsigned_types(&self, item: &Type) -> bool {
         self.iter_types_in_item(item)
@@ -413,6 +477,42 @@ impl ComponentInterface {
         }
     }
 
+    /// Builtin FFI function for cancelling an in-flight async call.
+    /// This is needed so that the foreign language bindings can drop the Rust future when the
+    /// foreign task is cancelled.  We only include this in the FFI if there are async functions.
+    pub fn ffi_rust_future_cancel(&self) -> Option<FfiFunction> {
+        // Note: we can't use `has_async_fns()`, since it iterates over this function.
+        if self
+            .iter_user_ffi_function_definitions()
+            .any(|f| f.is_async())
+        {
+            Some(FfiFunction {
+                name: format!("ffi_{}_rust_future_cancel", self.ffi_namespace()),
+                is_async: false,
+                arguments: vec![FfiArgument {
+                    name: "callback_data".to_string(),
+                    type_: FfiType::FutureCallbackData,
+                }],
+                return_type: None,
+                has_rust_call_status_arg: true,
+                is_object_free_function: false,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Builtin FFI function for marking the next async call as cancellable.
+    /// Bindings that cancel Rust futures call this right before the scaffolding function, so that
+    /// Rust tracks the future for `ffi_rust_future_cancel()`.  We only include this in the FFI if
+    /// there are async functions.
+    pub fn ffi_rust_future_cancellable(&self) -> Option<FfiFunction> {
+        self.ffi_rust_future_cancel().map(|cancel| FfiFunction {
+            name: format!("ffi_{}_rust_future_cancellable", self.ffi_namespace()),
+            ..cancel
+        })
+    }
+
     /// Does this interface contain async functions?
     pub fn has_async_fns(&self) -> bool {
         self.iter_ffi_function_definitions().any(|f| f.is_async())
@@ -446,6 +546,8 @@ impl ComponentInterface {
             .chain(self.iter_rust_buffer_ffi_function_definitions())
             .chain(self.iter_checksum_ffi_functions())
             .chain(self.ffi_foreign_executor_callback_set())
+            .chain(self.ffi_rust_future_cancel())
+            .chain(self.ffi_rust_future_cancellable())
             .chain([self.ffi_uniffi_contract_version()])
     }
 
@@ -537,6 +639,19 @@ impl ComponentInterface {
             .map(|(fn_name, checksum)| (fn_name.to_string(), checksum))
     }
 
+    /// A fingerprint of the whole interface, calculated from the API checksums
+    ///
+    /// The bindings calculate the same value from the checksums that the library reports, so this
+    /// changes whenever a function, method or constructor in the interface does.  Starting from 0,
+    /// each checksum from [Self::iter_checksums] is added to the fingerprint using
+    /// `fingerprint = fingerprint * 31 + checksum`, with wrapping 32-bit arithmetic.
+    pub fn interface_fingerprint(&self) -> u32 {
+        self.iter_checksums()
+            .fold(0u32, |fingerprint, (_, checksum)| {
+                fingerprint.wrapping_mul(31).wrapping_add(checksum.into())
+            })
+    }
+
     pub fn iter_checksum_ffi_functions(&self) -> impl Iterator<Item = FfiFunction> + '_ {
         self.iter_checksums().map(|(name, _)| FfiFunction {
             name,
@@ -600,8 +715,17 @@ impl ComponentInterface {
     pub(super) fn add_function_definition(&mut self, defn: Function) -> Result<()> {
         // Since functions are not a first-class type, we have to check for duplicates here
         // rather than relying on the type-finding pass to catch them.
-        if self.functions.iter().any(|f| f.name == defn.name) {
-            bail!("duplicate function definition: \"{}\"", defn.name);
+        // Aliases become top-level functions in the bindings, so they can't clash either.
+        let is_defined = |name: &String| {
+            self.functions
+                .iter()
+                .any(|f| f.name == *name || f.aliases.contains(name))
+        };
+        if let Some(name) = std::iter::once(&defn.name)
+            .chain(&defn.aliases)
+            .find(|name| is_defined(name))
+        {
+            bail!("duplicate function definition: \"{name}\"");
         }
         if !matches!(self.types.get_type_definition(defn.name()), None) {
             bail!("Conflicting type definition for \"{}\"", defn.name());
@@ -622,6 +746,10 @@ impl ComponentInterface {
         let defn: Constructor = meta.into();
 
         self.types.add_known_types(defn.iter_types())?;
+        if defn.is_async() {
+            // Async functions depend on the foreign executor
+            self.types.add_known_type(&Type::ForeignExecutor)?;
+        }
         object.constructors.push(defn);
 
         Ok(())
@@ -710,6 +838,30 @@ impl ComponentInterface {
             }
         }
 
+        for f in self.functions.iter() {
+            function::check_variadic_arguments(f.name(), &f.arguments)?;
+        }
+        for obj in self.objects.iter() {
+            for cons in obj.constructors.iter() {
+                function::check_variadic_arguments(cons.name(), &cons.arguments)?;
+            }
+            for meth in obj.methods.iter() {
+                function::check_variadic_arguments(meth.name(), &meth.arguments)?;
+            }
+        }
+        for cbi in self.callback_interfaces.iter() {
+            ensure!(
+                !cbi.methods
+                    .iter()
+                    .flat_map(|m| m.arguments.iter())
+                    .any(|a| a.variadic),
+                "Callback interface `{}` can not have variadic arguments",
+                cbi.name,
+            );
+        }
+
+        self.check_pod_records()?;
+
         for ty in self.iter_types() {
             match ty {
                 Type::Object { name, .. } => {
@@ -737,6 +889,53 @@ impl ComponentInterface {
         Ok(())
     }
 
+    /// Check that `#[uniffi(pod)]` records are only used where they can be passed by value
+    fn check_pod_records(&self) -> Result<()> {
+        for ty in self.iter_types() {
+            match ty {
+                // The foreign bindings only know the layout of the structs for their own records
+                Type::External {
+                    name,
+                    module_path,
+                    kind: ExternalKind::PodRecord,
+                } => bail!(
+                    "`#[uniffi(pod)]` record `{name}` from `{module_path}` can't be used by other crates"
+                ),
+                // Custom types are lowered like their builtin type, which would give the foreign
+                // converter for the custom type the wrong FFI type.
+                Type::Custom { name, builtin, .. } => {
+                    if let Type::Record {
+                        name: record,
+                        pod: true,
+                        ..
+                    } = builtin.as_ref()
+                    {
+                        bail!("Custom type `{name}` can't wrap the `#[uniffi(pod)]` record `{record}`")
+                    }
+                }
+                _ => {}
+            }
+        }
+        // The future callbacks only take their return value as a RustBuffer or a primitive
+        let async_returns = self
+            .functions
+            .iter()
+            .filter(|f| f.is_async())
+            .map(|f| (f.name(), f.return_type()))
+            .chain(self.objects.iter().flat_map(|o| {
+                o.methods
+                    .iter()
+                    .filter(|m| m.is_async())
+                    .map(|m| (m.name(), m.return_type()))
+            }));
+        for (name, return_type) in async_returns {
+            if let Some(Type::Record { pod: true, .. }) = return_type {
+                bail!("`{name}`: async functions can't return `#[uniffi(pod)]` records yet");
+            }
+        }
+        Ok(())
+    }
+
     /// Automatically derive the low-level FFI functions from the high-level types in the interface.
     ///
     /// This should only be called after the high-level types have been completed defined, otherwise
@@ -922,32 +1121,40 @@ mod test {
             "Mismatching definition for enum `Testing`!\nexisting definition: Enum {
     name: \"Testing\",
     module_path: \"test\",
+    full_module_path: \"test\",
     variants: [
         Variant {
             name: \"one\",
             fields: [],
+            flag_value: None,
         },
         Variant {
             name: \"two\",
             fields: [],
+            flag_value: None,
         },
     ],
     flat: true,
+    flags_type: None,
 },
 new definition: Enum {
     name: \"Testing\",
     module_path: \"test\",
+    full_module_path: \"test\",
     variants: [
         Variant {
             name: \"three\",
             fields: [],
+            flag_value: None,
         },
         Variant {
             name: \"four\",
             fields: [],
+            flag_value: None,
         },
     ],
     flat: true,
+    flags_type: None,
 }",
         );
 
@@ -963,6 +1170,232 @@ new definition: Enum {
         assert!(format!("{err:#}").contains("Conflicting type definition for \"Testing\""));
     }
 
+    #[test]
+    fn test_function_aliases_must_be_unique() {
+        let func = |name: &str, aliases: &[&str]| -> Function {
+            uniffi_meta::FnMetadata {
+                module_path: "test".into(),
+                name: name.into(),
+                is_async: false,
+                inputs: vec![],
+                return_type: None,
+                throws: None,
+                aliases: aliases.iter().map(|a| a.to_string()).collect(),
+                checksum: None,
+                full_module_path: "test".into(),
+            }
+            .into()
+        };
+        let mut ci = ComponentInterface::default();
+        ci.add_function_definition(func("get_value", &["getValue"]))
+            .unwrap();
+        ci.add_function_definition(func("set_value", &["setValue"]))
+            .unwrap();
+        assert_eq!(
+            ci.get_function_definition("get_value").unwrap().aliases(),
+            ["getValue"]
+        );
+
+        for (name, aliases) in [
+            ("getValue", &[][..]),
+            ("other", &["get_value"][..]),
+            ("x", &["setValue"][..]),
+        ] {
+            let err = ci.add_function_definition(func(name, aliases)).unwrap_err();
+            assert!(err.to_string().starts_with("duplicate function definition"));
+        }
+    }
+
+    #[test]
+    fn test_shared_nested_records_are_defined_once() {
+        const UDL: &str = r#"
+            namespace test{
+                Outer get_outer();
+            };
+            dictionary Outer {
+                Left left;
+                Right right;
+                sequence<Shared> all_shared;
+            };
+            dictionary Left {
+                Shared shared;
+                Leaf? leaf;
+            };
+            dictionary Right {
+                Shared shared;
+                record<string, Leaf> leaves;
+            };
+            dictionary Shared {
+                Leaf leaf;
+            };
+            dictionary Leaf {
+                u32 value;
+            };
+        "#;
+        let ci = ComponentInterface::from_webidl(UDL).unwrap();
+        assert_eq!(ci.record_definitions().count(), 5);
+        for name in ["Outer", "Left", "Right", "Shared", "Leaf"] {
+            let count = ci
+                .iter_types()
+                .filter(|t| matches!(t, Type::Record { name: n, .. } if n == name))
+                .count();
+            assert_eq!(count, 1, "record `{name}` should be defined exactly once");
+        }
+    }
+
+    #[test]
+    fn test_item_is_serializable() {
+        const UDL: &str = r#"
+            namespace test{
+                Outer get_outer();
+                Holder get_holder();
+                [Throws=Failure]
+                Failing get_failing();
+            };
+            dictionary Outer {
+                sequence<Inner> inners;
+                Shape? shape;
+            };
+            dictionary Inner {
+                bytes data;
+            };
+            [Enum]
+            interface Shape {
+                Circle(double radius);
+                Nothing();
+            };
+            interface Thing {};
+            dictionary Holder {
+                record<string, Thing> things;
+            };
+            [Error]
+            enum Failure { "Oops" };
+            dictionary Failing {
+                Failure? failure;
+            };
+        "#;
+        let ci = ComponentInterface::from_webidl(UDL).unwrap();
+        let record = |name: &str| ci.get_record_definition(name).unwrap().as_type();
+        assert!(ci.item_is_serializable(&record("Outer")));
+        assert!(ci.item_is_serializable(&record("Inner")));
+        assert!(!ci.item_is_serializable(&record("Holder")));
+        assert!(!ci.item_is_serializable(&record("Failing")));
+    }
+
+    #[test]
+    fn test_conflicting_types_with_the_same_name_are_an_error() {
+        let mut ci = ComponentInterface::default();
+        ci.types
+            .add_known_type(&Type::Record {
+                name: "Shared".into(),
+                module_path: "crate_a".into(),
+                pod: false,
+            })
+            .unwrap();
+        let err = ci
+            .types
+            .add_known_type(&Type::Sequence {
+                inner_type: Box::new(Type::Record {
+                    name: "Shared".into(),
+                    module_path: "crate_b".into(),
+                    pod: false,
+                }),
+            })
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("Mismatching definitions for type `Shared`!"));
+        // Neither the conflicting record nor the sequence of it is known
+        assert_eq!(ci.iter_types().count(), 1);
+    }
+
+    #[test]
+    fn test_pod_record_checks() {
+        let pod_record = Type::Record {
+            name: "Point".into(),
+            module_path: "crate_a".into(),
+            pod: true,
+        };
+        let mut ci = ComponentInterface::default();
+        ci.types.add_known_type(&pod_record).unwrap();
+        ci.check_pod_records().unwrap();
+
+        ci.types
+            .add_known_type(&Type::Custom {
+                name: "Position".into(),
+                module_path: "crate_a".into(),
+                builtin: Box::new(pod_record),
+            })
+            .unwrap();
+        assert_eq!(
+            ci.check_pod_records().unwrap_err().to_string(),
+            "Custom type `Position` can't wrap the `#[uniffi(pod)]` record `Point`"
+        );
+
+        let mut ci = ComponentInterface::default();
+        ci.types
+            .add_known_type(&Type::External {
+                name: "Point".into(),
+                module_path: "crate_b".into(),
+                kind: ExternalKind::PodRecord,
+            })
+            .unwrap();
+        assert_eq!(
+            ci.check_pod_records().unwrap_err().to_string(),
+            "`#[uniffi(pod)]` record `Point` from `crate_b` can't be used by other crates"
+        );
+    }
+
+    #[test]
+    fn test_min_serialized_size() {
+        const UDL: &str = r#"
+            namespace test{};
+            enum Kind { "A", "B" };
+            dictionary Inner {
+                u8 small;
+                string? label;
+            };
+            dictionary Outer {
+                Inner inner;
+                sequence<Kind> kinds;
+                timestamp when;
+            };
+        "#;
+        let ci = ComponentInterface::from_webidl(UDL).unwrap();
+        let record = |name: &str| ci.get_record_definition(name).unwrap().as_type();
+        assert_eq!(ci.min_serialized_size(&record("Inner")), 1 + 1);
+        assert_eq!(ci.min_serialized_size(&record("Outer")), 2 + 4 + 12);
+        assert_eq!(
+            ci.min_serialized_size(&Type::Map {
+                key_type: Box::new(Type::String),
+                value_type: Box::new(Type::Float64),
+            }),
+            4
+        );
+    }
+
+    #[test]
+    fn test_interface_fingerprint() {
+        let ci = ComponentInterface::from_webidl(
+            "namespace test { u32 one(); string two(u32 value); };",
+        )
+        .unwrap();
+        let checksums: Vec<u32> = ci.iter_checksums().map(|(_, c)| c.into()).collect();
+        assert_eq!(checksums.len(), 2);
+        assert_eq!(
+            ci.interface_fingerprint(),
+            checksums[0].wrapping_mul(31).wrapping_add(checksums[1])
+        );
+
+        // Changing a signature changes the fingerprint
+        let changed = ComponentInterface::from_webidl(
+            "namespace test { u32 one(); string two(u64 value); };",
+        )
+        .unwrap();
+        assert_ne!(ci.interface_fingerprint(), changed.interface_fingerprint());
+        assert_eq!(ComponentInterface::default().interface_fingerprint(), 0);
+    }
+
     #[test]
     fn test_contains_optional_types() {
         let mut ci = ComponentInterface {
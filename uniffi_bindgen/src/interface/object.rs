@@ -86,12 +86,16 @@ pub struct Object {
     /// How this object is implemented in Rust
     pub(super) imp: ObjectImpl,
     pub(super) module_path: String,
+    #[checksum_ignore]
+    pub(super) full_module_path: String,
     pub(super) constructors: Vec<Constructor>,
     pub(super) methods: Vec<Method>,
     // The "trait" methods - they have a (presumably "well known") name, and
     // a regular method (albeit with a generated name)
     // XXX - this should really be a HashSet, but not enough transient types support hash to make it worthwhile now.
     pub(super) uniffi_traits: Vec<UniffiTrait>,
+    /// The Rust path of the type, for objects with the `#[uniffi(type_name)]` attribute
+    pub(super) rust_type_name: Option<String>,
     // We don't include the FfiFunc in the hash calculation, because:
     //  - it is entirely determined by the other fields,
     //    so excluding it is safe.
@@ -107,6 +111,11 @@ impl Object {
         &self.name
     }
 
+    /// The full path of the Rust module that this item was defined in.
+    pub fn full_module_path(&self) -> &str {
+        &self.full_module_path
+    }
+
     /// Returns the fully qualified name that should be used by Rust code for this object.
     /// Includes `r#`, traits get a leading `dyn`. If we ever supported associated types, then
     /// this would also include them.
@@ -118,6 +127,13 @@ impl Object {
         &self.imp
     }
 
+    /// The Rust path of the type, if the object exports it with `#[uniffi(type_name)]`.
+    ///
+    /// These objects also have a generated `uniffi_type_name()` method that returns the same path.
+    pub fn rust_type_name(&self) -> Option<&str> {
+        self.rust_type_name.as_deref()
+    }
+
     pub fn constructors(&self) -> Vec<&Constructor> {
         self.constructors.iter().collect()
     }
@@ -165,7 +181,8 @@ impl Object {
                     .flat_map(|ut| match ut {
                         UniffiTrait::Display { fmt: m }
                         | UniffiTrait::Debug { fmt: m }
-                        | UniffiTrait::Hash { hash: m } => vec![m],
+                        | UniffiTrait::Hash { hash: m }
+                        | UniffiTrait::Clone { clone: m } => vec![m],
                         UniffiTrait::Eq { eq, ne } => vec![eq, ne],
                     })
                     .map(|m| &m.ffi_func),
@@ -225,11 +242,13 @@ impl From<uniffi_meta::ObjectMetadata> for Object {
         let ffi_free_name = meta.free_ffi_symbol_name();
         Object {
             module_path: meta.module_path,
+            full_module_path: meta.full_module_path,
             name: meta.name,
             imp: meta.imp,
             constructors: Default::default(),
             methods: Default::default(),
             uniffi_traits: meta.uniffi_traits.into_iter().map(Into::into).collect(),
+            rust_type_name: meta.rust_type_name,
             ffi_func_free: FfiFunction {
                 name: ffi_free_name,
                 ..Default::default()
@@ -254,6 +273,9 @@ impl From<uniffi_meta::UniffiTraitMetadata> for UniffiTrait {
             uniffi_meta::UniffiTraitMetadata::Hash { hash } => {
                 UniffiTrait::Hash { hash: hash.into() }
             }
+            uniffi_meta::UniffiTraitMetadata::Clone { clone } => UniffiTrait::Clone {
+                clone: clone.into(),
+            },
         }
     }
 }
@@ -267,6 +289,7 @@ pub struct Constructor {
     pub(super) name: String,
     pub(super) object_name: String,
     pub(super) object_module_path: String,
+    pub(super) is_async: bool,
     pub(super) arguments: Vec<Argument>,
     // We don't include the FFIFunc in the hash calculation, because:
     //  - it is entirely determined by the other fields,
@@ -288,6 +311,10 @@ impl Constructor {
         &self.name
     }
 
+    pub fn is_async(&self) -> bool {
+        self.is_async
+    }
+
     pub fn arguments(&self) -> Vec<&Argument> {
         self.arguments.iter().collect()
     }
@@ -320,8 +347,12 @@ impl Constructor {
         self.throws.as_ref()
     }
 
+    /// Is this the constructor that the bindings generate as the foreign constructor?
+    ///
+    /// Foreign constructors can't be async, so async constructors are always generated as static
+    /// factory methods, even when they're named `new`.
     pub fn is_primary_constructor(&self) -> bool {
-        self.name == "new"
+        self.name == "new" && !self.is_async
     }
 
     fn derive_ffi_func(&mut self, ci_namespace: &str, obj_name: &str) {
@@ -332,8 +363,10 @@ impl Constructor {
                 uniffi_meta::constructor_symbol_name(ci_namespace, obj_name, &self.name);
         }
 
-        self.ffi_func.arguments = self.arguments.iter().map(Into::into).collect();
-        self.ffi_func.return_type = Some(FfiType::RustArcPtr(obj_name.to_string()));
+        self.ffi_func.init(
+            Some(FfiType::RustArcPtr(obj_name.to_string())),
+            self.arguments.iter().map(Into::into),
+        );
     }
 
     pub fn iter_types(&self) -> TypeIterator<'_> {
@@ -349,12 +382,14 @@ impl From<uniffi_meta::ConstructorMetadata> for Constructor {
 
         let ffi_func = FfiFunction {
             name: ffi_name,
+            is_async: meta.is_async,
             ..FfiFunction::default()
         };
         Self {
             name: meta.name,
             object_name: meta.self_name,
             object_module_path: meta.module_path,
+            is_async: meta.is_async,
             arguments,
             ffi_func,
             throws: meta.throws.map(Into::into),
@@ -398,6 +433,10 @@ impl Method {
         &self.name
     }
 
+    pub fn object_name(&self) -> &str {
+        &self.object_name
+    }
+
     pub fn is_async(&self) -> bool {
         self.is_async
     }
@@ -421,6 +460,7 @@ impl Method {
             by_ref: !self.takes_self_by_arc,
             optional: false,
             default: None,
+            variadic: false,
         }]
         .into_iter()
         .chain(self.arguments.iter().cloned())
@@ -545,6 +585,7 @@ pub enum UniffiTrait {
     Display { fmt: Method },
     Eq { eq: Method, ne: Method },
     Hash { hash: Method },
+    Clone { clone: Method },
 }
 
 impl UniffiTrait {
@@ -553,7 +594,8 @@ impl UniffiTrait {
             match self {
                 UniffiTrait::Display { fmt: m }
                 | UniffiTrait::Debug { fmt: m }
-                | UniffiTrait::Hash { hash: m } => vec![m.iter_types()],
+                | UniffiTrait::Hash { hash: m }
+                | UniffiTrait::Clone { clone: m } => vec![m.iter_types()],
                 UniffiTrait::Eq { eq, ne } => vec![eq.iter_types(), ne.iter_types()],
             }
             .into_iter()
@@ -565,7 +607,8 @@ impl UniffiTrait {
         match self {
             UniffiTrait::Display { fmt: m }
             | UniffiTrait::Debug { fmt: m }
-            | UniffiTrait::Hash { hash: m } => {
+            | UniffiTrait::Hash { hash: m }
+            | UniffiTrait::Clone { clone: m } => {
                 m.derive_ffi_func(ci_namespace, obj_name)?;
             }
             UniffiTrait::Eq { eq, ne } => {
@@ -55,10 +55,16 @@ use super::{AsType, Type, TypeIterator};
 /// In the FFI these are represented as a byte buffer, which one side explicitly
 /// serializes the data into and the other serializes it out of. So I guess they're
 /// kind of like "pass by clone" values.
+///
+/// The exception is `#[repr(C)]` structs of primitive fields, which are passed by value as
+/// plain C structs.  See [Record::is_pod].
 #[derive(Debug, Clone, PartialEq, Eq, Checksum)]
 pub struct Record {
     pub(super) name: String,
     pub(super) module_path: String,
+    #[checksum_ignore]
+    pub(super) full_module_path: String,
+    pub(super) pod: bool,
     pub(super) fields: Vec<Field>,
 }
 
@@ -67,10 +73,34 @@ impl Record {
         &self.name
     }
 
+    /// The full path of the Rust module that this item was defined in.
+    pub fn full_module_path(&self) -> &str {
+        &self.full_module_path
+    }
+
     pub fn fields(&self) -> &[Field] {
         &self.fields
     }
 
+    /// The fields that the foreign equality and hashing implementations compare
+    ///
+    /// This leaves out fields marked with `#[uniffi(exclude_from_eq)]`.
+    pub fn eq_fields(&self) -> Vec<&Field> {
+        self.fields.iter().filter(|f| !f.exclude_from_eq).collect()
+    }
+
+    /// Does this record need a custom equality implementation, since it excludes some fields?
+    pub fn has_custom_eq(&self) -> bool {
+        self.fields.iter().any(|f| f.exclude_from_eq)
+    }
+
+    /// Is this record passed by value, as a C struct with the same layout as the fields?
+    ///
+    /// This is the case for `#[repr(C)]` structs whose fields are all primitive numbers.
+    pub fn is_pod(&self) -> bool {
+        self.pod
+    }
+
     pub fn iter_types(&self) -> TypeIterator<'_> {
         Box::new(self.fields.iter().flat_map(Field::iter_types))
     }
@@ -81,6 +111,7 @@ impl AsType for Record {
         Type::Record {
             name: self.name.clone(),
             module_path: self.module_path.clone(),
+            pod: self.pod,
         }
     }
 }
@@ -92,6 +123,8 @@ impl TryFrom<uniffi_meta::RecordMetadata> for Record {
         Ok(Self {
             name: meta.name,
             module_path: meta.module_path,
+            full_module_path: meta.full_module_path,
+            pod: meta.pod,
             fields: meta
                 .fields
                 .into_iter()
@@ -107,6 +140,7 @@ pub struct Field {
     pub(super) name: String,
     pub(super) type_: Type,
     pub(super) default: Option<Literal>,
+    pub(super) exclude_from_eq: bool,
 }
 
 impl Field {
@@ -118,6 +152,10 @@ impl Field {
         self.default.as_ref()
     }
 
+    pub fn is_excluded_from_eq(&self) -> bool {
+        self.exclude_from_eq
+    }
+
     pub fn iter_types(&self) -> TypeIterator<'_> {
         self.type_.iter_types()
     }
@@ -140,6 +178,7 @@ impl TryFrom<uniffi_meta::FieldMetadata> for Field {
             name,
             type_,
             default,
+            exclude_from_eq: meta.exclude_from_eq,
         })
     }
 }
@@ -227,4 +266,33 @@ mod test {
             .iter_types()
             .any(|t| matches!(t, Type::Record { name, .. } if name == "Testing")));
     }
+
+    #[test]
+    fn test_fields_excluded_from_eq() {
+        let field = |name: &str, exclude_from_eq| uniffi_meta::FieldMetadata {
+            name: name.into(),
+            ty: Type::UInt32,
+            default: None,
+            exclude_from_eq,
+        };
+        let record = Record::try_from(uniffi_meta::RecordMetadata {
+            module_path: "test".into(),
+            name: "Cached".into(),
+            pod: false,
+            fields: vec![field("id", false), field("cache", true)],
+            full_module_path: "test".into(),
+        })
+        .unwrap();
+        assert!(record.has_custom_eq());
+        let eq_fields: Vec<_> = record.eq_fields().iter().map(|f| f.name()).collect();
+        assert_eq!(eq_fields, vec!["id"]);
+        assert!(record.fields()[1].is_excluded_from_eq());
+
+        let ci =
+            ComponentInterface::from_webidl("namespace test{}; dictionary Simple { u32 field; };")
+                .unwrap();
+        let record = ci.get_record_definition("Simple").unwrap();
+        assert!(!record.has_custom_eq());
+        assert_eq!(record.eq_fields().len(), 1);
+    }
 }
@@ -6,7 +6,7 @@
 //! which can be used by the bindings generator code to determine what type-related helper
 //! functions to emit for a given component.
 //!
-use anyhow::Result;
+use anyhow::{bail, Result};
 use std::{collections::hash_map::Entry, collections::BTreeSet, collections::HashMap};
 
 pub use uniffi_meta::{AsType, ExternalKind, ObjectImpl, Type, TypeIterator};
@@ -37,9 +37,16 @@ impl TypeUniverse {
     fn add_type_definition(&mut self, name: &str, type_: &Type) -> Result<()> {
         match self.type_definitions.entry(name.to_string()) {
             Entry::Occupied(o) => {
-                // all conflicts have been resolved by now in udl.
-                // I doubt procmacros could cause this?
-                assert_eq!(type_, o.get());
+                // A type can be reached through several paths (for example a record that's a
+                // field of several other records), but every path must agree on what it is.
+                let existing_def = o.get();
+                if type_ != existing_def {
+                    bail!(
+                        "Mismatching definitions for type `{name}`! \
+                         existing definition: {existing_def:?}, \
+                         new definition: {type_:?}"
+                    );
+                }
                 Ok(())
             }
             Entry::Vacant(e) => {
@@ -57,8 +64,8 @@ impl TypeUniverse {
     /// Add a [Type] to the set of all types seen in the component interface.
     pub fn add_known_type(&mut self, type_: &Type) -> Result<()> {
         // Types are more likely to already be known than not, so avoid unnecessary cloning.
-        if !self.all_known_types.contains(type_) {
-            self.all_known_types.insert(type_.to_owned());
+        if self.all_known_types.contains(type_) {
+            return Ok(());
         }
         match type_ {
             Type::UInt8 => self.add_type_definition("u8", type_)?,
@@ -74,8 +81,10 @@ impl TypeUniverse {
             Type::Boolean => self.add_type_definition("bool", type_)?,
             Type::String => self.add_type_definition("string", type_)?,
             Type::Bytes => self.add_type_definition("bytes", type_)?,
+            Type::ByteArray { len } => self.add_type_definition(&format!("bytes{len}"), type_)?,
             Type::Timestamp => self.add_type_definition("timestamp", type_)?,
             Type::Duration => self.add_type_definition("duration", type_)?,
+            Type::JsonValue => self.add_type_definition("json", type_)?,
             Type::ForeignExecutor => {
                 self.add_type_definition("ForeignExecutor", type_)?;
             }
@@ -89,7 +98,9 @@ impl TypeUniverse {
                 self.add_known_type(builtin)?;
             }
             // Structurally recursive types.
-            Type::Optional { inner_type, .. } | Type::Sequence { inner_type, .. } => {
+            Type::Optional { inner_type, .. }
+            | Type::Sequence { inner_type, .. }
+            | Type::Range { inner_type, .. } => {
                 self.add_known_type(inner_type)?;
             }
             Type::Map {
@@ -100,6 +111,10 @@ impl TypeUniverse {
                 self.add_known_type(value_type)?;
             }
         }
+        // Only record the type once its name has been checked, so that conflicting types with
+        // the same name never make it into `all_known_types` and bindings never see two
+        // definitions for the same name.
+        self.all_known_types.insert(type_.to_owned());
         Ok(())
     }
 
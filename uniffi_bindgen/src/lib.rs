@@ -98,7 +98,11 @@ use fs_err::{self as fs, File};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::io::prelude::*;
 use std::io::ErrorKind;
-use std::{collections::HashMap, process::Command, str::FromStr};
+use std::{
+    collections::{BTreeSet, HashMap},
+    process::Command,
+    str::FromStr,
+};
 
 pub mod backend;
 pub mod bindings;
@@ -106,6 +110,7 @@ pub mod interface;
 pub mod library_mode;
 pub mod macro_metadata;
 pub mod scaffolding;
+pub mod size_report;
 
 use bindings::TargetLanguage;
 pub use interface::ComponentInterface;
@@ -386,22 +391,45 @@ pub struct Config {
 }
 
 impl Config {
+    /// Load the config for a crate.
+    ///
+    /// Without an override this is the crate's own `uniffi.toml`.  An override file replaces it,
+    /// except for `[bindings.<language>]` sections marked with `merge_crate_config = true`, which
+    /// are merged on top of the same section from the crate's `uniffi.toml`.
     fn load_initial(
         crate_root: &Utf8Path,
         config_file_override: Option<&Utf8Path>,
     ) -> Result<Self> {
-        let path = match config_file_override {
-            Some(cfg) => Some(cfg.to_owned()),
-            None => crate_root.join("uniffi.toml").canonicalize_utf8().ok(),
+        let crate_config = || -> Result<Option<toml::Value>> {
+            match crate_root.join("uniffi.toml").canonicalize_utf8() {
+                Ok(path) => Ok(Some(read_toml_file(&path)?)),
+                Err(_) => Ok(None),
+            }
         };
-        let toml_config = match path {
+        let toml_config = match config_file_override {
             Some(path) => {
-                let contents = fs::read_to_string(path).context("Failed to read config file")?;
-                toml::de::from_str(&contents)?
+                let override_config = read_toml_file(path)?;
+                // Only read the crate's config if the override needs it
+                if merged_sections(&override_config).next().is_some() {
+                    merge_crate_config(crate_config()?, override_config)
+                } else {
+                    override_config
+                }
+            }
+            None => {
+                let crate_config = crate_config()?
+                    .unwrap_or_else(|| toml::Value::from(toml::value::Table::default()));
+                if let Some(language) = merged_sections(&crate_config).next() {
+                    bail!("`bindings.{language}.merge_crate_config` is only supported in a `--config` override file");
+                }
+                crate_config
             }
-            None => toml::Value::from(toml::value::Table::default()),
         };
-        Ok(toml_config.try_into()?)
+        let (toml_config, dependency_configs_disabled) = take_config_markers(toml_config)?;
+        let mut config: Self = toml_config.try_into()?;
+        config.bindings.dependency_configs_disabled = dependency_configs_disabled;
+        config.bindings.apply_shared_options();
+        Ok(config)
     }
 
     fn update_from_ci(&mut self, ci: &ComponentInterface) {
@@ -409,6 +437,7 @@ impl Config {
         self.bindings.swift.update_from_ci(ci);
         self.bindings.python.update_from_ci(ci);
         self.bindings.ruby.update_from_ci(ci);
+        self.bindings.c.update_from_ci(ci);
     }
 
     fn update_from_cdylib_name(&mut self, cdylib_name: &str) {
@@ -416,36 +445,152 @@ impl Config {
         self.bindings.swift.update_from_cdylib_name(cdylib_name);
         self.bindings.python.update_from_cdylib_name(cdylib_name);
         self.bindings.ruby.update_from_cdylib_name(cdylib_name);
+        self.bindings.c.update_from_cdylib_name(cdylib_name);
     }
 
+    // Sections marked with `inherit_dependency_configs = false` don't take any values from
+    // dependent crates.
     fn update_from_dependency_configs(&mut self, config_map: HashMap<&str, &Self>) {
-        self.bindings.kotlin.update_from_dependency_configs(
-            config_map
-                .iter()
-                .map(|(key, config)| (*key, &config.bindings.kotlin))
-                .collect(),
-        );
-        self.bindings.swift.update_from_dependency_configs(
-            config_map
-                .iter()
-                .map(|(key, config)| (*key, &config.bindings.swift))
-                .collect(),
-        );
-        self.bindings.python.update_from_dependency_configs(
-            config_map
-                .iter()
-                .map(|(key, config)| (*key, &config.bindings.python))
-                .collect(),
-        );
-        self.bindings.ruby.update_from_dependency_configs(
-            config_map
-                .iter()
-                .map(|(key, config)| (*key, &config.bindings.ruby))
-                .collect(),
-        );
+        if self.bindings.inherits_dependency_configs("kotlin") {
+            self.bindings.kotlin.update_from_dependency_configs(
+                config_map
+                    .iter()
+                    .map(|(key, config)| (*key, &config.bindings.kotlin))
+                    .collect(),
+            );
+        }
+        if self.bindings.inherits_dependency_configs("swift") {
+            self.bindings.swift.update_from_dependency_configs(
+                config_map
+                    .iter()
+                    .map(|(key, config)| (*key, &config.bindings.swift))
+                    .collect(),
+            );
+        }
+        if self.bindings.inherits_dependency_configs("python") {
+            self.bindings.python.update_from_dependency_configs(
+                config_map
+                    .iter()
+                    .map(|(key, config)| (*key, &config.bindings.python))
+                    .collect(),
+            );
+        }
+        if self.bindings.inherits_dependency_configs("ruby") {
+            self.bindings.ruby.update_from_dependency_configs(
+                config_map
+                    .iter()
+                    .map(|(key, config)| (*key, &config.bindings.ruby))
+                    .collect(),
+            );
+        }
+        if self.bindings.inherits_dependency_configs("c") {
+            self.bindings.c.update_from_dependency_configs(
+                config_map
+                    .iter()
+                    .map(|(key, config)| (*key, &config.bindings.c))
+                    .collect(),
+            );
+        }
+    }
+}
+
+fn read_toml_file(path: &Utf8Path) -> Result<toml::Value> {
+    let contents = fs::read_to_string(path).context("Failed to read config file")?;
+    toml::Value::from_str(&contents).with_context(|| format!("Failed to parse config file {path}"))
+}
+
+// The `[bindings.<language>]` sections marked with `merge_crate_config = true`
+fn merged_sections(toml_config: &toml::Value) -> impl Iterator<Item = &str> {
+    toml_config
+        .get("bindings")
+        .and_then(toml::Value::as_table)
+        .into_iter()
+        .flatten()
+        .filter(|(_, section)| merges_crate_config(section))
+        .map(|(language, _)| language.as_str())
+}
+
+fn merges_crate_config(section: &toml::Value) -> bool {
+    matches!(
+        section.get("merge_crate_config"),
+        Some(toml::Value::Boolean(true))
+    )
+}
+
+// Build the config from an override file, merging in the `[bindings.<language>]` sections from
+// the crate's config for the sections of the override that are marked `merge_crate_config = true`.
+fn merge_crate_config(
+    crate_config: Option<toml::Value>,
+    mut override_config: toml::Value,
+) -> toml::Value {
+    let mut crate_bindings =
+        match crate_config.and_then(|mut c| c.as_table_mut()?.remove("bindings")) {
+            Some(toml::Value::Table(t)) => t,
+            _ => return override_config,
+        };
+    let override_bindings = override_config
+        .get_mut("bindings")
+        .and_then(toml::Value::as_table_mut);
+    for (language, section) in override_bindings.into_iter().flatten() {
+        if !merges_crate_config(section) {
+            continue;
+        }
+        if let Some(inherited) = crate_bindings.remove(language) {
+            let overrides = std::mem::replace(section, inherited);
+            merge_toml(section, overrides);
+        }
+    }
+    override_config
+}
+
+// Merge `overrides` on top of `base`. Tables are merged key by key, any other value replaces the
+// value in `base`.
+fn merge_toml(base: &mut toml::Value, overrides: toml::Value) {
+    match (base, overrides) {
+        (toml::Value::Table(base), toml::Value::Table(overrides)) => {
+            for (key, value) in overrides {
+                match base.get_mut(&key) {
+                    Some(existing) => merge_toml(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, overrides) => *base = overrides,
     }
 }
 
+// Remove the `merge_crate_config` and `inherit_dependency_configs` markers from the
+// `[bindings.<language>]` sections, returning the languages that opted out of dependency configs
+// with `inherit_dependency_configs = false`.
+fn take_config_markers(mut toml_config: toml::Value) -> Result<(toml::Value, BTreeSet<String>)> {
+    let mut dependency_configs_disabled = BTreeSet::new();
+    let sections = toml_config
+        .get_mut("bindings")
+        .and_then(toml::Value::as_table_mut);
+    for (language, section) in sections.into_iter().flatten() {
+        let section = match section.as_table_mut() {
+            Some(section) => section,
+            None => continue,
+        };
+        for key in ["merge_crate_config", "inherit_dependency_configs"] {
+            match section.remove(key) {
+                None | Some(toml::Value::Boolean(true)) => (),
+                Some(toml::Value::Boolean(false)) if key == "merge_crate_config" => (),
+                Some(toml::Value::Boolean(false)) => {
+                    if !bindings::takes_dependency_configs(language) {
+                        bail!("`bindings.{language}.inherit_dependency_configs` has no effect, {language} doesn't take any values from the configs of dependent crates");
+                    }
+                    dependency_configs_disabled.insert(language.clone());
+                }
+                Some(v) => bail!("`bindings.{language}.{key}` must be a boolean, not `{v}`"),
+            }
+        }
+    }
+    Ok((toml_config, dependency_configs_disabled))
+}
+
 // FIXME(HACK):
 // Include the askama config file into the build.
 // That way cargo tracks the file and other tools relying on file tracking see it as well.
@@ -478,4 +623,152 @@ mod test {
         let not_a_crate_root = &this_crate_root.join("src/templates");
         assert!(guess_crate_root(&not_a_crate_root.join("src/example.udl")).is_err());
     }
+
+    #[test]
+    fn test_config_file_override() {
+        let repo_root = Utf8PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap())
+            .parent()
+            .expect("should have a parent directory")
+            .to_path_buf();
+        let crate_root = repo_root.join("examples/arithmetic");
+
+        // Without an override, the crate's `uniffi.toml` is used.
+        let config = Config::load_initial(&crate_root, None).unwrap();
+        assert_eq!(
+            config.bindings.kotlin.package_name(),
+            "org.mozilla.uniffi.example.arithmetic"
+        );
+        assert!(!config.bindings.swift.omit_argument_labels());
+
+        // With an override, the override file is used instead.
+        let override_path = repo_root.join("fixtures/swift-omit-labels/uniffi.toml");
+        let config = Config::load_initial(&crate_root, Some(&override_path)).unwrap();
+        assert_eq!(config.bindings.kotlin.package_name(), "uniffi");
+        assert!(config.bindings.swift.omit_argument_labels());
+
+        assert!(Config::load_initial(&crate_root, Some(&crate_root.join("missing.toml"))).is_err());
+    }
+
+    #[test]
+    fn test_config_merging() {
+        let crate_config = toml::Value::from_str(
+            r#"
+            [bindings.kotlin]
+            package_name = "org.example"
+            cdylib_name = "example"
+
+            [bindings.swift]
+            module_name = "Example"
+            "#,
+        )
+        .unwrap();
+        let override_config = toml::Value::from_str(
+            r#"
+            [bindings.kotlin]
+            merge_crate_config = true
+            cdylib_name = "override"
+
+            [bindings.swift]
+            ffi_module_name = "OverrideFFI"
+
+            [bindings.python]
+            inherit_dependency_configs = false
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            merged_sections(&override_config).collect::<Vec<_>>(),
+            vec!["kotlin"]
+        );
+
+        let (merged, dependency_configs_disabled) =
+            take_config_markers(merge_crate_config(Some(crate_config), override_config)).unwrap();
+        // `merge_crate_config = true` merges the section with the crate's config, with the
+        // override winning.
+        assert_eq!(
+            merged["bindings"]["kotlin"],
+            toml::Value::from_str(
+                r#"
+                package_name = "org.example"
+                cdylib_name = "override"
+                "#
+            )
+            .unwrap()
+        );
+        // Without a marker the override replaces the section.
+        assert_eq!(
+            merged["bindings"]["swift"],
+            toml::Value::from_str(r#"ffi_module_name = "OverrideFFI""#).unwrap()
+        );
+        // The markers themselves are removed.
+        assert_eq!(
+            merged["bindings"]["python"],
+            toml::Value::from(toml::value::Table::default())
+        );
+        assert_eq!(
+            dependency_configs_disabled,
+            BTreeSet::from(["python".to_string()])
+        );
+
+        let mut config: Config = merged.try_into().unwrap();
+        config.bindings.dependency_configs_disabled = dependency_configs_disabled;
+        assert!(config.bindings.inherits_dependency_configs("kotlin"));
+        assert!(!config.bindings.inherits_dependency_configs("python"));
+    }
+
+    #[test]
+    fn test_dependency_configs() {
+        let mut dependency = Config::default();
+        dependency.update_from_ci(&ComponentInterface::from_webidl("namespace dep {};").unwrap());
+        let config_map = HashMap::from([("dep_crate", &dependency)]);
+
+        // External types are imported from the package or module of the dependent crate
+        let mut config = Config::default();
+        config.update_from_dependency_configs(config_map.clone());
+        assert_eq!(
+            config.bindings.python.external_module_name("dep_crate"),
+            "dep"
+        );
+
+        let (toml_config, dependency_configs_disabled) = take_config_markers(
+            toml::Value::from_str(
+                r#"
+                [bindings.python]
+                inherit_dependency_configs = false
+                "#,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        let mut config: Config = toml_config.try_into().unwrap();
+        config.bindings.dependency_configs_disabled = dependency_configs_disabled;
+        config.update_from_dependency_configs(config_map);
+        assert_eq!(
+            config.bindings.python.external_module_name("dep_crate"),
+            "dep_crate"
+        );
+    }
+
+    #[test]
+    fn test_invalid_config_markers() {
+        let err = |config| {
+            take_config_markers(toml::Value::from_str(config).unwrap())
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(
+            err(r#"
+            [bindings.kotlin]
+            merge_crate_config = "yes"
+            "#),
+            "`bindings.kotlin.merge_crate_config` must be a boolean, not `\"yes\"`"
+        );
+        assert_eq!(
+            err(r#"
+            [bindings.swift]
+            inherit_dependency_configs = false
+            "#),
+            "`bindings.swift.inherit_dependency_configs` has no effect, swift doesn't take any values from the configs of dependent crates"
+        );
+    }
 }
@@ -16,24 +16,32 @@
 ///   - UniFFI can figure out the package/module names for each crate, eliminating the external
 ///     package maps.
 use crate::{
-    bindings::{self, TargetLanguage},
-    macro_metadata, ComponentInterface, Config, Result,
+    bindings::{self, GeneratedFile, TargetLanguage},
+    macro_metadata,
+    size_report::SizeReport,
+    ComponentInterface, Config, Result,
 };
 use anyhow::{bail, Context};
-use camino::Utf8Path;
+use camino::{Utf8Path, Utf8PathBuf};
 use cargo_metadata::{MetadataCommand, Package};
 use std::{
-    collections::{HashMap, HashSet},
-    fs,
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    env, fs,
+};
+use uniffi_meta::{
+    group_metadata, EnumMetadata, ErrorMetadata, FieldMetadata, Metadata, MetadataGroup, Type,
 };
-use uniffi_meta::{group_metadata, MetadataGroup};
 
 /// Generate foreign bindings
 ///
+/// If `config_file_override` is given, that file is used instead of each crate's `uniffi.toml`.
+/// When `crate_name` is also given, only that crate's config is overridden.
+///
 /// Returns the list of sources used to generate the bindings, in no particular order.
 pub fn generate_bindings(
     library_path: &Utf8Path,
     crate_name: Option<String>,
+    config_file_override: Option<&Utf8Path>,
     target_languages: &[TargetLanguage],
     out_dir: &Utf8Path,
     try_format_code: bool,
@@ -41,8 +49,115 @@ pub fn generate_bindings(
     let cargo_metadata = MetadataCommand::new()
         .exec()
         .context("error running cargo metadata")?;
+    generate_bindings_with_metadata(
+        &cargo_metadata,
+        library_path,
+        crate_name,
+        config_file_override,
+        target_languages,
+        out_dir,
+        try_format_code,
+        None,
+    )
+}
+
+/// Generate foreign bindings, with extra metadata added to the metadata found in the library
+///
+/// This is an escape hatch for libraries whose metadata is incomplete, for example when the build
+/// couldn't capture a custom-type definition.  `supplementary` is merged into the extracted
+/// metadata before it's grouped by crate, so its items end up in the bindings for
+/// `supplementary.namespace.crate_name`.  The crate doesn't need to be in the library, but if it
+/// is then its namespace must match, and any item that's also in the library must be identical to
+/// the extracted one.
+///
+/// Otherwise this works like [generate_bindings].
+pub fn generate_bindings_with_supplementary_metadata(
+    library_path: &Utf8Path,
+    crate_name: Option<String>,
+    supplementary: MetadataGroup,
+    config_file_override: Option<&Utf8Path>,
+    target_languages: &[TargetLanguage],
+    out_dir: &Utf8Path,
+    try_format_code: bool,
+) -> Result<Vec<Source>> {
+    let cargo_metadata = MetadataCommand::new()
+        .exec()
+        .context("error running cargo metadata")?;
+    generate_bindings_with_metadata(
+        &cargo_metadata,
+        library_path,
+        crate_name,
+        config_file_override,
+        target_languages,
+        out_dir,
+        try_format_code,
+        Some(supplementary),
+    )
+}
+
+/// Generate foreign bindings for a cdylib built by `cargo build`
+///
+/// This finds the workspace crate with a `cdylib` target and generates bindings for the library
+/// that cargo wrote into the `profile` directory of `target_dir`.  For cross-compiled builds,
+/// `target_dir` should include the target triple, e.g. `target/aarch64-linux-android`.
+///
+/// If the workspace has several cdylib crates, `cdylib_crate` selects one of them by package name.
+/// This is separate from `crate_name`, which limits the bindings to one of the crates built into the
+/// library, the same as for [generate_bindings].
+#[allow(clippy::too_many_arguments)]
+pub fn generate_bindings_from_target_dir(
+    target_dir: &Utf8Path,
+    profile: &str,
+    cdylib_crate: Option<&str>,
+    crate_name: Option<String>,
+    config_file_override: Option<&Utf8Path>,
+    target_languages: &[TargetLanguage],
+    out_dir: &Utf8Path,
+    try_format_code: bool,
+) -> Result<Vec<Source>> {
+    let cargo_metadata = MetadataCommand::new()
+        .exec()
+        .context("error running cargo metadata")?;
+    let lib_name = find_cdylib_lib_name(&cargo_metadata, cdylib_crate)?;
+    let library_path = cdylib_path(target_dir, profile, &lib_name);
+    if !library_path.exists() {
+        bail!("{library_path} not found, was it built with `cargo build --profile {profile}`?");
+    }
+    generate_bindings_with_metadata(
+        &cargo_metadata,
+        &library_path,
+        crate_name,
+        config_file_override,
+        target_languages,
+        out_dir,
+        try_format_code,
+        None,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn generate_bindings_with_metadata(
+    cargo_metadata: &cargo_metadata::Metadata,
+    library_path: &Utf8Path,
+    crate_name: Option<String>,
+    config_file_override: Option<&Utf8Path>,
+    target_languages: &[TargetLanguage],
+    out_dir: &Utf8Path,
+    try_format_code: bool,
+    supplementary: Option<MetadataGroup>,
+) -> Result<Vec<Source>> {
     let cdylib_name = calc_cdylib_name(library_path);
-    let mut sources = find_sources(&cargo_metadata, library_path, cdylib_name)?;
+    let config_override = config_file_override.map(|path| ConfigOverride {
+        path,
+        crate_name: crate_name.as_deref(),
+    });
+    let mut sources = find_sources(
+        cargo_metadata,
+        library_path,
+        cdylib_name,
+        config_override,
+        supplementary,
+    )?;
     for i in 0..sources.len() {
         // Partition up the sources list because we're eventually going to call
         // `update_from_dependency_configs()` which requires an exclusive reference to one source and
@@ -75,19 +190,28 @@ pub fn generate_bindings(
             n => bail!("{n} crates named {crate_name} found in {library_path}"),
         }
     }
+    check_for_collisions(
+        sources
+            .iter()
+            .map(|s| (s.crate_name.as_str(), &s.ci, &s.config)),
+        target_languages,
+    )?;
 
-    for source in sources.iter() {
+    for source in sources.iter_mut() {
         for &language in target_languages {
-            if cdylib_name.is_none() && language != TargetLanguage::Swift {
+            if cdylib_name.is_none()
+                && !matches!(language, TargetLanguage::Swift | TargetLanguage::C)
+            {
                 bail!("Generate bindings for {language} requires a cdylib, but {library_path} was given");
             }
-            bindings::write_bindings(
+            let generated = bindings::write_bindings(
                 &source.config.bindings,
                 &source.ci,
                 out_dir,
                 language,
                 try_format_code,
             )?;
+            source.generated_files.extend(generated);
         }
     }
 
@@ -101,6 +225,320 @@ pub struct Source {
     pub crate_name: String,
     pub ci: ComponentInterface,
     pub config: Config,
+    /// Files written for this source, across all target languages
+    pub generated_files: Vec<GeneratedFile>,
+}
+
+impl Source {
+    /// Measure the bindings that were generated for this source
+    pub fn size_report(&self) -> Result<SizeReport> {
+        SizeReport::new(&self.ci, &self.generated_files)
+    }
+}
+
+// Check that crates whose bindings are generated into the same foreign namespace don't define
+// conflicting names.
+//
+// Without this, the generated code either overwrites itself or fails to compile with "duplicate
+// declaration" errors that don't mention the crates involved.
+fn check_for_collisions<'a>(
+    sources: impl Iterator<Item = (&'a str, &'a ComponentInterface, &'a Config)> + Clone,
+    target_languages: &[TargetLanguage],
+) -> Result<()> {
+    let mut errors = vec![];
+    for &language in target_languages {
+        // Maps each output namespace to the crates generated into it
+        let mut namespaces: BTreeMap<String, Vec<(&str, &ComponentInterface)>> = BTreeMap::new();
+        for (crate_name, ci, config) in sources.clone() {
+            namespaces
+                .entry(bindings::output_namespace(&config.bindings, ci, language))
+                .or_default()
+                .push((crate_name, ci));
+        }
+        for (namespace, crates) in namespaces.iter().filter(|(_, crates)| crates.len() > 1) {
+            for (i, (crate_a, ci_a)) in crates.iter().enumerate() {
+                let names_a = top_level_names(ci_a, language);
+                for (crate_b, ci_b) in &crates[i + 1..] {
+                    let colliding: Vec<_> = names_a
+                        .intersection(&top_level_names(ci_b, language))
+                        .map(|name| format!("`{name}`"))
+                        .collect();
+                    if ci_a.namespace() == ci_b.namespace() {
+                        errors.push(format!(
+                            "{language}: crates {crate_a} and {crate_b} both generate bindings for namespace `{namespace}`"
+                        ));
+                    } else if !colliding.is_empty() {
+                        errors.push(format!(
+                            "{language}: crates {crate_a} and {crate_b} both define {} in `{namespace}`",
+                            colliding.join(", "),
+                        ));
+                    }
+                }
+            }
+        }
+    }
+    if !errors.is_empty() {
+        bail!(
+            "Name collisions between crates, configure a different namespace for one of them:\n  {}",
+            errors.join("\n  ")
+        );
+    }
+    Ok(())
+}
+
+// Check that every user-defined type referenced by the metadata is also defined by it.
+//
+// When a crate's metadata is missing from the library, for example because the library was built
+// while one of its dependencies failed to build, the items that use its types still reference them.
+// Without this check we'd generate bindings that call converters that don't exist.  Types from
+// crates with a UDL file are skipped, since they may be defined in the UDL rather than the metadata.
+fn check_for_missing_types(groups: &[MetadataGroup]) -> Result<()> {
+    let udl_crates: HashSet<&str> = groups
+        .iter()
+        .filter(|g| g.items.iter().any(|i| matches!(i, Metadata::UdlFile(_))))
+        .map(|g| g.namespace.crate_name.as_str())
+        .collect();
+    let defined: HashSet<(&str, &str)> = groups
+        .iter()
+        .flat_map(|g| &g.items)
+        .filter_map(|item| {
+            let (module_path, name) = match item {
+                Metadata::Record(meta) => (&meta.module_path, &meta.name),
+                Metadata::Enum(meta) => (&meta.module_path, &meta.name),
+                Metadata::Flags(meta) => (&meta.module_path, &meta.name),
+                Metadata::Object(meta) => (&meta.module_path, &meta.name),
+                Metadata::CallbackInterface(meta) => (&meta.module_path, &meta.name),
+                Metadata::Error(meta) => (meta.module_path(), meta.name()),
+                Metadata::CustomType(meta) => (&meta.module_path, &meta.name),
+                _ => return None,
+            };
+            Some((crate_name_of(module_path), name.as_str()))
+        })
+        .collect();
+
+    let mut missing = BTreeSet::new();
+    for group in groups {
+        for ty in group.items.iter().flat_map(metadata_types) {
+            let (module_path, name) = match ty {
+                Type::Record {
+                    module_path, name, ..
+                }
+                | Type::Enum { module_path, name }
+                | Type::CallbackInterface { module_path, name }
+                | Type::Object {
+                    module_path, name, ..
+                }
+                | Type::Custom {
+                    module_path, name, ..
+                }
+                | Type::External {
+                    module_path, name, ..
+                } => (module_path, name),
+                _ => continue,
+            };
+            let crate_name = crate_name_of(module_path);
+            if !udl_crates.contains(crate_name) && !defined.contains(&(crate_name, name.as_str())) {
+                missing.insert(format!(
+                    "`{name}` from crate {crate_name} (used by crate {})",
+                    group.namespace.crate_name
+                ));
+            }
+        }
+    }
+    if !missing.is_empty() {
+        bail!(
+            "No metadata found for these types, the library build is likely incomplete.  \
+             Make sure all the crates built successfully and rebuild the library:\n  {}",
+            missing.into_iter().collect::<Vec<_>>().join("\n  ")
+        );
+    }
+    Ok(())
+}
+
+// Merge user-supplied metadata into the metadata extracted from the library
+//
+// Items that are already in the library are skipped, as long as they're identical to the extracted
+// ones.  Anything else that clashes with the extracted metadata is an error, since we can't tell
+// which of the definitions the library actually implements.
+fn merge_supplementary_metadata(
+    items: &mut Vec<Metadata>,
+    supplementary: MetadataGroup,
+) -> Result<()> {
+    let crate_name = supplementary.namespace.crate_name.clone();
+    let existing_namespace = items.iter().find_map(|item| match item {
+        Metadata::Namespace(meta) if meta.crate_name == crate_name => Some(meta),
+        _ => None,
+    });
+    match existing_namespace {
+        Some(meta) if *meta != supplementary.namespace => bail!(
+            "Supplementary metadata for crate {crate_name} uses namespace `{}`, but the library uses `{}`",
+            supplementary.namespace.name,
+            meta.name,
+        ),
+        Some(_) => (),
+        None => items.push(Metadata::Namespace(supplementary.namespace)),
+    }
+
+    for item in supplementary.items {
+        let (kind, module_path, name) = match item_identity(&item) {
+            Some(identity) => identity,
+            None => bail!("Unsupported supplementary metadata item: {item:?}"),
+        };
+        if crate_name_of(module_path) != crate_name {
+            bail!("Supplementary {kind} `{name}` is from {module_path}, not crate {crate_name}");
+        }
+        match items
+            .iter()
+            .find(|existing| item_identity(existing) == Some((kind, module_path, name)))
+        {
+            Some(existing) if *existing == item => (),
+            Some(existing) => bail!(
+                "Supplementary {kind} `{name}` doesn't match the library's metadata:\n  \
+                 library: {existing:?}\n  supplementary: {item:?}"
+            ),
+            None => items.push(item),
+        }
+    }
+    Ok(())
+}
+
+// The kind, module path and name that identify a metadata item
+fn item_identity(item: &Metadata) -> Option<(&'static str, &str, String)> {
+    let identity: (&'static str, &str, String) = match item {
+        Metadata::Namespace(_) => return None,
+        Metadata::UdlFile(meta) => ("udl_file", &meta.module_path, meta.name.clone()),
+        Metadata::Func(meta) => ("function", &meta.module_path, meta.name.clone()),
+        Metadata::Constructor(meta) => (
+            "constructor",
+            &meta.module_path,
+            format!("{}.{}", meta.self_name, meta.name),
+        ),
+        Metadata::Method(meta) => (
+            "method",
+            &meta.module_path,
+            format!("{}.{}", meta.self_name, meta.name),
+        ),
+        Metadata::TraitMethod(meta) => (
+            "trait method",
+            &meta.module_path,
+            format!("{}.{}", meta.trait_name, meta.name),
+        ),
+        Metadata::Record(meta) => ("type", &meta.module_path, meta.name.clone()),
+        Metadata::Enum(meta) => ("type", &meta.module_path, meta.name.clone()),
+        Metadata::Flags(meta) => ("type", &meta.module_path, meta.name.clone()),
+        Metadata::Object(meta) => ("type", &meta.module_path, meta.name.clone()),
+        Metadata::CallbackInterface(meta) => ("type", &meta.module_path, meta.name.clone()),
+        Metadata::Error(meta) => ("type", meta.module_path(), meta.name().to_string()),
+        Metadata::CustomType(meta) => ("type", &meta.module_path, meta.name.clone()),
+    };
+    Some(identity)
+}
+
+// All the types used by a metadata item, including the types nested inside them
+fn metadata_types(item: &Metadata) -> Vec<&Type> {
+    fn fields(fields: &[FieldMetadata]) -> Vec<&Type> {
+        fields.iter().map(|f| &f.ty).collect()
+    }
+    fn enum_fields(meta: &EnumMetadata) -> Vec<&Type> {
+        meta.variants
+            .iter()
+            .flat_map(|v| fields(&v.fields))
+            .collect()
+    }
+    let direct: Vec<&Type> = match item {
+        Metadata::Func(meta) => meta
+            .inputs
+            .iter()
+            .map(|i| &i.ty)
+            .chain(&meta.return_type)
+            .chain(&meta.throws)
+            .collect(),
+        Metadata::Constructor(meta) => meta
+            .inputs
+            .iter()
+            .map(|i| &i.ty)
+            .chain(&meta.throws)
+            .collect(),
+        Metadata::Method(meta) => meta
+            .inputs
+            .iter()
+            .map(|i| &i.ty)
+            .chain(&meta.return_type)
+            .chain(&meta.throws)
+            .collect(),
+        Metadata::TraitMethod(meta) => meta
+            .inputs
+            .iter()
+            .map(|i| &i.ty)
+            .chain(&meta.return_type)
+            .chain(&meta.throws)
+            .collect(),
+        Metadata::Record(meta) => fields(&meta.fields),
+        Metadata::Enum(meta) => enum_fields(meta),
+        Metadata::Error(ErrorMetadata::Enum { enum_, .. }) => enum_fields(enum_),
+        _ => vec![],
+    };
+    direct.into_iter().flat_map(Type::iter_types).collect()
+}
+
+fn crate_name_of(module_path: &str) -> &str {
+    module_path.split("::").next().unwrap()
+}
+
+// Runtime helpers that every Kotlin file declares at the top level of its package.  Kotlin files in
+// the same package share a namespace, so two crates generated into one package always collide on
+// these, even when none of their own items do.
+const KOTLIN_RUNTIME_NAMES: &[&str] = &[
+    "RustBuffer",
+    "RustBufferByReference",
+    "ForeignBytes",
+    "RustCallStatus",
+    "InternalException",
+    "CallStatusErrorHandler",
+    "NullCallStatusErrorHandler",
+    "USize",
+    "UniFfiHandleMap",
+    "FfiConverter",
+    "FfiConverterRustBuffer",
+    "_UniFFILib",
+];
+
+// The names of all the items that are declared at the top level of the generated bindings
+fn top_level_names(ci: &ComponentInterface, language: TargetLanguage) -> BTreeSet<&str> {
+    let runtime_names = match language {
+        TargetLanguage::Kotlin => KOTLIN_RUNTIME_NAMES,
+        _ => &[],
+    };
+    ci.function_definitions()
+        .iter()
+        .map(|f| f.name())
+        .chain(ci.object_definitions().iter().map(|o| o.name()))
+        .chain(ci.record_definitions().map(|r| r.name()))
+        .chain(ci.enum_definitions().map(|e| e.name()))
+        .chain(
+            ci.callback_interface_definitions()
+                .iter()
+                .map(|cbi| cbi.name()),
+        )
+        .chain(runtime_names.iter().copied())
+        .collect()
+}
+
+// Config file passed explicitly by the user, rather than discovered from the crate root
+#[derive(Clone, Copy)]
+struct ConfigOverride<'a> {
+    path: &'a Utf8Path,
+    // Only apply the override to this crate.  If `None`, apply it to all crates.
+    crate_name: Option<&'a str>,
+}
+
+impl<'a> ConfigOverride<'a> {
+    fn path_for(&self, crate_name: &str) -> Option<&'a Utf8Path> {
+        match self.crate_name {
+            Some(name) if name != crate_name => None,
+            _ => Some(self.path),
+        }
+    }
 }
 
 // If `library_path` is a C dynamic library, return its name
@@ -116,12 +554,72 @@ pub fn calc_cdylib_name(library_path: &Utf8Path) -> Option<&str> {
     None
 }
 
+// Find the library name of the workspace crate that builds a cdylib
+fn find_cdylib_lib_name(
+    metadata: &cargo_metadata::Metadata,
+    cdylib_crate: Option<&str>,
+) -> Result<String> {
+    // (package name, library name) of each cdylib target
+    let cdylibs: Vec<(&str, String)> = metadata
+        .workspace_packages()
+        .into_iter()
+        .flat_map(|p| p.targets.iter().map(move |t| (p, t)))
+        .filter(|(_, t)| t.kind.iter().any(|k| k == "cdylib"))
+        .map(|(p, t)| (p.name.as_str(), t.name.replace('-', "_")))
+        .collect();
+    match cdylib_crate {
+        Some(cdylib_crate) => match cdylibs.into_iter().find(|(p, _)| *p == cdylib_crate) {
+            Some((_, lib_name)) => Ok(lib_name),
+            None => bail!("{cdylib_crate} is not a cdylib crate in this workspace"),
+        },
+        None => match cdylibs.len() {
+            1 => Ok(cdylibs.into_iter().next().unwrap().1),
+            0 => bail!("No cdylib crates found in this workspace"),
+            _ => bail!(
+                "Several cdylib crates found in this workspace ({}), use the cdylib crate option to \
+                 pick one",
+                cdylibs
+                    .iter()
+                    .map(|(p, _)| *p)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        },
+    }
+}
+
+// Path of the cdylib that cargo builds for `lib_name` on the current platform
+fn cdylib_path(target_dir: &Utf8Path, profile: &str, lib_name: &str) -> Utf8PathBuf {
+    // Cargo uses different directory names for its built-in profiles
+    let profile_dir = match profile {
+        "dev" | "test" => "debug",
+        "bench" => "release",
+        profile => profile,
+    };
+    target_dir.join(profile_dir).join(format!(
+        "{}{lib_name}{}",
+        env::consts::DLL_PREFIX,
+        env::consts::DLL_SUFFIX
+    ))
+}
+
 fn find_sources(
     cargo_metadata: &cargo_metadata::Metadata,
     library_path: &Utf8Path,
     cdylib_name: Option<&str>,
+    config_override: Option<ConfigOverride<'_>>,
+    supplementary: Option<MetadataGroup>,
 ) -> Result<Vec<Source>> {
-    group_metadata(macro_metadata::extract_from_library(library_path)?)?
+    let mut items = macro_metadata::extract_from_library_with_sections(
+        library_path,
+        &metadata_sections(cargo_metadata),
+    )?;
+    if let Some(supplementary) = supplementary {
+        merge_supplementary_metadata(&mut items, supplementary)?;
+    }
+    let groups = group_metadata(items)?;
+    check_for_missing_types(&groups)?;
+    groups
         .into_iter()
         .map(|group| {
             let package = find_package_by_crate_name(cargo_metadata, &group.namespace.crate_name)?;
@@ -135,7 +633,8 @@ fn find_sources(
                 ci.add_metadata(metadata)?;
             };
             ci.add_metadata(group)?;
-            let mut config = Config::load_initial(crate_root, None)?;
+            let config_file_override = config_override.and_then(|o| o.path_for(&crate_name));
+            let mut config = Config::load_initial(crate_root, config_file_override)?;
             if let Some(cdylib_name) = cdylib_name {
                 config.update_from_cdylib_name(cdylib_name);
             }
@@ -145,11 +644,26 @@ fn find_sources(
                 crate_name,
                 ci,
                 package,
+                generated_files: Vec::new(),
             })
         })
         .collect()
 }
 
+// The custom metadata sections that crates configured with `metadata_section` in the
+// `[package.metadata.uniffi]` table of their `Cargo.toml`.  The macros read the same value, so the
+// metadata is looked up in the section it was placed in.
+fn metadata_sections(cargo_metadata: &cargo_metadata::Metadata) -> Vec<String> {
+    let sections: BTreeSet<String> = cargo_metadata
+        .packages
+        .iter()
+        .filter_map(|package| package.metadata.get("uniffi")?.get("metadata_section"))
+        .filter_map(|section| section.as_str())
+        .map(str::to_string)
+        .collect();
+    sections.into_iter().collect()
+}
+
 fn find_package_by_crate_name(
     metadata: &cargo_metadata::Metadata,
     crate_name: &str,
@@ -220,6 +734,31 @@ mod test {
         );
     }
 
+    #[test]
+    fn cdylib_path_uses_profile_directory() {
+        let file = format!(
+            "{}uniffi{}",
+            env::consts::DLL_PREFIX,
+            env::consts::DLL_SUFFIX
+        );
+        assert_eq!(
+            cdylib_path("target".into(), "dev", "uniffi"),
+            Utf8Path::new("target/debug").join(&file)
+        );
+        assert_eq!(
+            cdylib_path("target".into(), "release", "uniffi"),
+            Utf8Path::new("target/release").join(&file)
+        );
+        assert_eq!(
+            cdylib_path("target".into(), "release-lto", "uniffi"),
+            Utf8Path::new("target/release-lto").join(&file)
+        );
+        assert_eq!(
+            calc_cdylib_name(&cdylib_path("target".into(), "dev", "uniffi")),
+            Some("uniffi")
+        );
+    }
+
     /// Right now we unconditionally strip the `lib` prefix.
     ///
     /// Technically Windows DLLs do not start with a `lib` prefix,
@@ -233,4 +772,217 @@ mod test {
             calc_cdylib_name("/path/to/libuniffi.dll".into()).unwrap()
         );
     }
+
+    #[test]
+    fn config_override_applies_to_the_selected_crate() {
+        let path = Utf8Path::new("override.toml");
+        let all_crates = ConfigOverride {
+            path,
+            crate_name: None,
+        };
+        assert_eq!(all_crates.path_for("crate_a"), Some(path));
+        assert_eq!(all_crates.path_for("crate_b"), Some(path));
+
+        let one_crate = ConfigOverride {
+            path,
+            crate_name: Some("crate_a"),
+        };
+        assert_eq!(one_crate.path_for("crate_a"), Some(path));
+        assert_eq!(one_crate.path_for("crate_b"), None);
+    }
+
+    #[test]
+    fn check_for_collisions_reports_crates_and_names() {
+        let ci_a = ComponentInterface::from_webidl(
+            "namespace a { u32 shared(); }; dictionary Point { u32 x; };",
+        )
+        .unwrap();
+        let ci_b = ComponentInterface::from_webidl(
+            "namespace b { u32 shared(); }; dictionary Other { u32 x; };",
+        )
+        .unwrap();
+        let mut config_a = Config::default();
+        config_a.update_from_ci(&ci_a);
+        let mut config_b = Config::default();
+        config_b.update_from_ci(&ci_b);
+
+        // Different namespaces, so no collisions
+        let sources = [("crate_a", &ci_a, &config_a), ("crate_b", &ci_b, &config_b)];
+        check_for_collisions(sources.into_iter(), &[TargetLanguage::Kotlin]).unwrap();
+
+        // Generating both crates into the same Kotlin package makes `shared` collide
+        let config_b: Config = toml::from_str(
+            r#"
+            [bindings.kotlin]
+            package_name = "uniffi.a"
+        "#,
+        )
+        .unwrap();
+        let sources = [("crate_a", &ci_a, &config_a), ("crate_b", &ci_b, &config_b)];
+        let err = check_for_collisions(sources.into_iter(), &[TargetLanguage::Kotlin])
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("kotlin: crates crate_a and crate_b both define `"));
+        assert!(err.contains("`shared` in `uniffi.a`"));
+        assert!(!err.contains("Point"));
+    }
+
+    #[test]
+    fn check_for_collisions_includes_kotlin_runtime_helpers() {
+        let ci_a = ComponentInterface::from_webidl("namespace a { u32 first(); };").unwrap();
+        let ci_b = ComponentInterface::from_webidl("namespace b { u32 second(); };").unwrap();
+        let config: Config = toml::from_str(
+            r#"
+            [bindings.kotlin]
+            package_name = "uniffi.shared"
+        "#,
+        )
+        .unwrap();
+
+        // The crates don't share any items, but each Kotlin file declares the runtime helpers
+        let sources = [("crate_a", &ci_a, &config), ("crate_b", &ci_b, &config)];
+        let err = check_for_collisions(sources.into_iter(), &[TargetLanguage::Kotlin])
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("`RustBuffer`"));
+        assert!(err.contains("`_UniFFILib`"));
+        assert!(!err.contains("`first`"));
+
+        // Python modules are separate files, so they don't share the runtime
+        let sources = [("crate_a", &ci_a, &config), ("crate_b", &ci_b, &config)];
+        check_for_collisions(sources.into_iter(), &[TargetLanguage::Python]).unwrap();
+    }
+
+    #[test]
+    fn check_for_missing_types_reports_incomplete_builds() {
+        let namespace = |crate_name: &str| {
+            Metadata::Namespace(uniffi_meta::NamespaceMetadata {
+                crate_name: crate_name.into(),
+                name: crate_name.into(),
+            })
+        };
+        let record = |crate_name: &str, name: &str, fields: Vec<FieldMetadata>| {
+            Metadata::Record(uniffi_meta::RecordMetadata {
+                module_path: crate_name.into(),
+                name: name.into(),
+                pod: false,
+                fields,
+                full_module_path: crate_name.into(),
+            })
+        };
+        // `crate_b::Line` has fields with the `Point` type from `crate_a`
+        let line = record(
+            "crate_b",
+            "Line",
+            vec![FieldMetadata {
+                name: "start".into(),
+                ty: Type::Optional {
+                    inner_type: Box::new(Type::Record {
+                        module_path: "crate_a".into(),
+                        name: "Point".into(),
+                        pod: false,
+                    }),
+                },
+                default: None,
+                exclude_from_eq: false,
+            }],
+        );
+
+        let groups = group_metadata(vec![
+            namespace("crate_a"),
+            namespace("crate_b"),
+            line.clone(),
+        ])
+        .unwrap();
+        let err = check_for_missing_types(&groups).unwrap_err().to_string();
+        assert!(err.contains("the library build is likely incomplete"));
+        assert!(err.contains("`Point` from crate crate_a (used by crate crate_b)"));
+
+        let groups = group_metadata(vec![
+            namespace("crate_a"),
+            namespace("crate_b"),
+            record("crate_a", "Point", vec![]),
+            line.clone(),
+        ])
+        .unwrap();
+        check_for_missing_types(&groups).unwrap();
+
+        // Types from UDL-based crates might be defined in the UDL file
+        let groups = group_metadata(vec![
+            namespace("crate_a"),
+            namespace("crate_b"),
+            Metadata::UdlFile(uniffi_meta::UdlFile {
+                module_path: "crate_a".into(),
+                name: "crate_a".into(),
+            }),
+            line,
+        ])
+        .unwrap();
+        check_for_missing_types(&groups).unwrap();
+    }
+
+    #[test]
+    fn merge_supplementary_metadata_checks_consistency() {
+        let namespace = |name: &str| uniffi_meta::NamespaceMetadata {
+            crate_name: "crate_a".into(),
+            name: name.into(),
+        };
+        let record = |module_path: &str, pod: bool| {
+            Metadata::Record(uniffi_meta::RecordMetadata {
+                module_path: module_path.into(),
+                name: "Point".into(),
+                pod,
+                fields: vec![],
+                full_module_path: module_path.into(),
+            })
+        };
+        let custom = Metadata::CustomType(uniffi_meta::CustomTypeMetadata {
+            module_path: "crate_a".into(),
+            name: "Handle".into(),
+            builtin: Type::UInt64,
+        });
+        let extracted = vec![
+            Metadata::Namespace(namespace("crate_a")),
+            record("crate_a", false),
+        ];
+        let supplementary = |name: &str, items: Vec<Metadata>| MetadataGroup {
+            namespace: namespace(name),
+            items: items.into_iter().collect(),
+        };
+
+        // New items are added, items that match the library are skipped
+        let mut items = extracted.clone();
+        merge_supplementary_metadata(
+            &mut items,
+            supplementary("crate_a", vec![record("crate_a", false), custom.clone()]),
+        )
+        .unwrap();
+        assert_eq!(items.len(), 3);
+        assert!(items.contains(&custom));
+        group_metadata(items).unwrap();
+
+        let mut items = extracted.clone();
+        let err = merge_supplementary_metadata(&mut items, supplementary("other", vec![]))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("uses namespace `other`, but the library uses `crate_a`"));
+
+        let mut items = extracted.clone();
+        let err = merge_supplementary_metadata(
+            &mut items,
+            supplementary("crate_a", vec![record("crate_a", true)]),
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("Supplementary type `Point` doesn't match the library's metadata"));
+
+        let mut items = extracted;
+        let err = merge_supplementary_metadata(
+            &mut items,
+            supplementary("crate_a", vec![record("crate_b", false)]),
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("Supplementary type `Point` is from crate_b, not crate crate_a"));
+    }
 }
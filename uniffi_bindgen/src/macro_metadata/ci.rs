@@ -2,9 +2,11 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use crate::interface::{CallbackInterface, ComponentInterface, Enum, Record, Type};
+use crate::interface::{ComponentInterface, Enum, Record, Type};
 use anyhow::{bail, Context};
-use uniffi_meta::{group_metadata, EnumMetadata, ErrorMetadata, Metadata, MetadataGroup};
+use uniffi_meta::{
+    group_metadata, EnumMetadata, ErrorMetadata, Metadata, MetadataGroup, DYN_ERROR_NAME,
+};
 
 /// Add Metadata items to the ComponentInterface
 ///
@@ -42,9 +44,19 @@ pub fn add_group_to_ci(iface: &mut ComponentInterface, group: MetadataGroup) ->
         );
     }
 
-    for item in group.items {
+    // `setup_scaffolding!` defines a `DynError` for every crate, but it's only added to the
+    // interface when something uses `Box<dyn Error>`, so that other bindings don't get an unused
+    // error class.
+    let (dyn_errors, items): (Vec<_>, Vec<_>) =
+        group.items.into_iter().partition(is_dyn_error_definition);
+    for item in items {
         add_item_to_ci(iface, item)?
     }
+    for item in dyn_errors {
+        if iface.get_type(DYN_ERROR_NAME).is_some() {
+            add_item_to_ci(iface, item)?
+        }
+    }
 
     iface
         .derive_ffi_funcs()
@@ -55,6 +67,15 @@ pub fn add_group_to_ci(iface: &mut ComponentInterface, group: MetadataGroup) ->
     Ok(())
 }
 
+fn is_dyn_error_definition(item: &Metadata) -> bool {
+    match item {
+        Metadata::Error(ErrorMetadata::Enum { enum_, .. }) => {
+            enum_.name == DYN_ERROR_NAME && !enum_.module_path.contains("::")
+        }
+        _ => false,
+    }
+}
+
 fn add_enum_to_ci(
     iface: &mut ComponentInterface,
     meta: EnumMetadata,
@@ -88,6 +109,7 @@ fn add_item_to_ci(iface: &mut ComponentInterface, item: Metadata) -> anyhow::Res
             let ty = Type::Record {
                 name: meta.name.clone(),
                 module_path: meta.module_path.clone(),
+                pod: meta.pod,
             };
             iface.types.add_known_type(&ty)?;
             let record: Record = meta.try_into()?;
@@ -97,6 +119,13 @@ fn add_item_to_ci(iface: &mut ComponentInterface, item: Metadata) -> anyhow::Res
             let flat = meta.variants.iter().all(|v| v.fields.is_empty());
             add_enum_to_ci(iface, meta, flat)?;
         }
+        Metadata::Flags(meta) => {
+            iface.types.add_known_type(&Type::Enum {
+                name: meta.name.clone(),
+                module_path: meta.module_path.clone(),
+            })?;
+            iface.add_enum_definition(meta.try_into()?)?;
+        }
         Metadata::Object(meta) => {
             iface.types.add_known_type(&Type::Object {
                 module_path: meta.module_path.clone(),
@@ -110,7 +139,7 @@ fn add_item_to_ci(iface: &mut ComponentInterface, item: Metadata) -> anyhow::Res
                 module_path: meta.module_path.clone(),
                 name: meta.name.clone(),
             })?;
-            iface.add_callback_interface_definition(CallbackInterface::new(meta.name));
+            iface.add_callback_interface_definition(meta.into());
         }
         Metadata::TraitMethod(meta) => {
             iface.add_trait_method_meta(meta)?;
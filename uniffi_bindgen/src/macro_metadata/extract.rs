@@ -7,13 +7,13 @@ use camino::Utf8Path;
 use fs_err as fs;
 use goblin::{
     archive::Archive,
-    elf::Elf,
+    elf::{section_header::SHT_NOBITS, Elf},
     mach::{segment::Section, symbols, Mach, MachO, SingleArch},
     pe::PE,
     Object,
 };
-use std::collections::HashSet;
-use uniffi_meta::Metadata;
+use std::collections::{BTreeSet, HashSet};
+use uniffi_meta::{read_metadata_items, Metadata};
 
 /// Extract metadata written by the `uniffi::export` macro from a library file
 ///
@@ -24,6 +24,29 @@ pub fn extract_from_library(path: &Utf8Path) -> anyhow::Result<Vec<Metadata>> {
     extract_from_bytes(&fs::read(path)?)
 }
 
+/// Extract metadata from a library file, including the metadata placed in `sections`
+///
+/// Crates can place their metadata in a custom section, with `metadata_section` in the
+/// `[package.metadata.uniffi]` table of their `Cargo.toml`.  The contents of those sections are
+/// read directly, so their metadata is found even if the symbols were stripped from the library.
+pub fn extract_from_library_with_sections(
+    path: &Utf8Path,
+    sections: &[String],
+) -> anyhow::Result<Vec<Metadata>> {
+    let file_data = fs::read(path)?;
+    let mut items = extract_from_bytes(&file_data)?;
+    if !sections.is_empty() {
+        // Items whose symbols weren't stripped are found both ways
+        let mut seen: BTreeSet<Metadata> = items.iter().cloned().collect();
+        for item in extract_sections_from_bytes(&file_data, sections)? {
+            if seen.insert(item.clone()) {
+                items.push(item);
+            }
+        }
+    }
+    Ok(items)
+}
+
 fn extract_from_bytes(file_data: &[u8]) -> anyhow::Result<Vec<Metadata>> {
     match Object::parse(file_data)? {
         Object::Elf(elf) => extract_from_elf(elf, file_data),
@@ -148,6 +171,103 @@ pub fn extract_from_archive(
     Ok(items)
 }
 
+fn extract_sections_from_bytes(
+    file_data: &[u8],
+    sections: &[String],
+) -> anyhow::Result<Vec<Metadata>> {
+    // The offset and size of each matching section
+    let mut ranges = vec![];
+    match Object::parse(file_data)? {
+        Object::Elf(elf) => {
+            for sh in &elf.section_headers {
+                let name = elf.shdr_strtab.get_at(sh.sh_name).unwrap_or_default();
+                if sections.iter().any(|s| s == name) && sh.sh_type != SHT_NOBITS {
+                    ranges.push((sh.sh_offset as usize, sh.sh_size as usize));
+                }
+            }
+        }
+        Object::PE(pe) => {
+            for section in &pe.sections {
+                // Long section names are only kept in object files, images truncate them to 8 bytes
+                let name = match &section.real_name {
+                    Some(name) => name.as_str(),
+                    None => section.name().unwrap_or_default(),
+                };
+                if sections.iter().any(|s| s == name) {
+                    // The raw data is padded to the file alignment, so use the virtual size if
+                    // there is one.  Object files leave it as 0.
+                    let size = match section.virtual_size {
+                        0 => section.size_of_raw_data,
+                        virtual_size => virtual_size.min(section.size_of_raw_data),
+                    };
+                    ranges.push((section.pointer_to_raw_data as usize, size as usize));
+                }
+            }
+        }
+        Object::Mach(mach) => {
+            let macho = match mach {
+                Mach::Binary(macho) => macho,
+                // Multi-binary library, just extract the first one
+                Mach::Fat(multi_arch) => match multi_arch.get(0)? {
+                    SingleArch::MachO(macho) => macho,
+                    SingleArch::Archive(archive) => {
+                        return extract_sections_from_archive(archive, file_data, sections)
+                    }
+                },
+            };
+            for sects in macho.segments.sections() {
+                for section in sects {
+                    let (section, _) = section?;
+                    // Mach-O section names are given as `segment,section`
+                    let name = format!("{},{}", section.segname()?, section.name()?);
+                    if sections.iter().any(|s| *s == name) {
+                        ranges.push((section.offset as usize, section.size as usize));
+                    }
+                }
+            }
+        }
+        Object::Archive(archive) => {
+            return extract_sections_from_archive(archive, file_data, sections)
+        }
+        Object::Unknown(_) => bail!("Unknown library format"),
+    }
+
+    let mut items = vec![];
+    for (offset, size) in ranges {
+        let data = file_data
+            .get(offset..offset + size)
+            .context("Metadata section is outside of the library file")?;
+        items.append(&mut read_metadata_items(data).context("Error reading metadata section")?);
+    }
+    Ok(items)
+}
+
+fn extract_sections_from_archive(
+    archive: Archive<'_>,
+    file_data: &[u8],
+    sections: &[String],
+) -> anyhow::Result<Vec<Metadata>> {
+    let mut items = vec![];
+    for member_name in archive.members() {
+        let member_data = archive
+            .extract(member_name, file_data)
+            .with_context(|| format!("Failed to extract archive member `{member_name}`"))?;
+        // Archives can hold other files besides objects, like the symbol table
+        if !matches!(
+            Object::parse(member_data),
+            Ok(Object::Elf(_) | Object::PE(_) | Object::Mach(_))
+        ) {
+            continue;
+        }
+        items.append(
+            &mut extract_sections_from_bytes(member_data, sections).with_context(|| {
+                format!("Failed to extract data from archive member `{member_name}`")
+            })?,
+        );
+    }
+    Ok(items)
+}
+
 /// Container for extracted metadata items
 #[derive(Default)]
 struct ExtractedItems {
@@ -10,7 +10,7 @@ mod ci;
 mod extract;
 
 pub use ci::{add_group_to_ci, add_to_ci};
-pub use extract::extract_from_library;
+pub use extract::{extract_from_library, extract_from_library_with_sections};
 
 pub fn add_to_ci_from_library(
     iface: &mut ComponentInterface,
@@ -37,8 +37,10 @@ mod filters {
             Type::Boolean => "bool".into(),
             Type::String => "String".into(),
             Type::Bytes => "Vec<u8>".into(),
+            Type::ByteArray { len } => format!("[u8; {len}]"),
             Type::Timestamp => "std::time::SystemTime".into(),
             Type::Duration => "std::time::Duration".into(),
+            Type::JsonValue => "::uniffi::deps::serde_json::Value".into(),
             Type::Enum { name, .. } | Type::Record { name, .. } => format!("r#{name}"),
             Type::Object { name, imp, .. } => {
                 format!("std::sync::Arc<{}>", imp.rust_name_for(name))
@@ -57,6 +59,14 @@ mod filters {
                 type_rs(key_type)?,
                 type_rs(value_type)?
             ),
+            Type::Range {
+                inner_type,
+                inclusive: false,
+            } => format!("std::ops::Range<{}>", type_rs(inner_type)?),
+            Type::Range {
+                inner_type,
+                inclusive: true,
+            } => format!("std::ops::RangeInclusive<{}>", type_rs(inner_type)?),
             Type::Custom { name, .. } => format!("r#{name}"),
             Type::External {
                 name,
@@ -82,6 +92,7 @@ mod filters {
             FfiType::RustArcPtr(_) => "*const std::os::raw::c_void".into(),
             FfiType::RustBuffer(_) => "::uniffi::RustBuffer".into(),
             FfiType::ForeignBytes => "::uniffi::ForeignBytes".into(),
+            FfiType::Struct(name) => format!("r#{name}"),
             FfiType::ForeignCallback => "::uniffi::ForeignCallback".into(),
             FfiType::ForeignExecutorHandle => "::uniffi::ForeignExecutorHandle".into(),
             FfiType::FutureCallback { return_type } => {
@@ -92,6 +103,12 @@ mod filters {
         })
     }
 
+    // Whether values of a type are converted with `Into` when they're passed to or returned from
+    // the Rust function.  This lets UDL sequences be `Cow<[T]>` as well as `Vec<T>`.
+    pub fn converts_with_into(type_: &Type) -> Result<bool, askama::Error> {
+        Ok(matches!(type_, Type::Sequence { .. } | Type::Bytes))
+    }
+
     // Map a type to Rust code that specifies the FfiConverter implementation.
     //
     // This outputs something like `<TheFfiConverterStruct as FfiConverter>`
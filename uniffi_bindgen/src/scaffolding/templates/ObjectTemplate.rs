@@ -49,6 +49,7 @@ pub extern "C" fn {{ ffi_free.name() }}(ptr: *const std::os::raw::c_void, call_s
         {%- call rs::arg_list_ffi_decl(cons.ffi_func()) %}
     ) -> *const std::os::raw::c_void /* *const {{ obj.name() }} */ {
         uniffi::deps::log::debug!("{{ cons.ffi_func().name() }}");
+        let _uniffi_span = uniffi::enter_span("{{ obj.name() }}.{{ cons.name() }}");
 
         // If the constructor does not have the same signature as declared in the UDL, then
         // this attempt to call it will fail with a (somewhat) helpful compiler error.
@@ -112,6 +113,16 @@ pub extern "C" fn {{ ffi_free.name() }}(ptr: *const std::os::raw::c_void, call_s
                 s.finish()
             }
     {% call rs::method_decl_postscript(hash) %}
+{%          when UniffiTrait::Clone { clone }%}
+    {% call rs::method_decl_prelude(clone) %}
+            {
+                uniffi::deps::static_assertions::assert_impl_all!({{ obj.rust_name() }}: Clone); // This object has a trait method which requires `Clone` be implemented.
+                ::std::sync::Arc::new(<{{ obj.rust_name() }} as Clone>::clone(match<std::sync::Arc<{{ obj.rust_name() }}> as ::uniffi::FfiConverter<crate::UniFfiTag>>::try_lift(r#ptr) {
+                    Ok(ref val) => val,
+                    Err(err) => panic!("Failed to convert arg '{}': {}", "ptr", err),
+                }))
+            }
+    {% call rs::method_decl_postscript(clone) %}
 {%          when UniffiTrait::Eq { eq, ne }%}
         {# PartialEq::Eq #}
         {% call rs::method_decl_prelude(eq) %}
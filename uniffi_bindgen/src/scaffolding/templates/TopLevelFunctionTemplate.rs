@@ -13,7 +13,8 @@ pub extern "C" fn r#{{ func.ffi_func().name() }}(
     // If the provided function does not match the signature specified in the UDL
     // then this attempt to call it will not compile, and will give guidance as to why.
     uniffi::deps::log::debug!("{{ func.ffi_func().name() }}");
+    let _uniffi_span = uniffi::enter_span("{{ func.name() }}");
     uniffi::rust_call(call_status, || {{ func|return_ffi_converter }}::lower_return(
-            {% call rs::to_rs_call(func) %}){% if func.throws() %}.map_err(Into::into){% endif %}
+            {% call rs::to_rs_call(func) %}{% call rs::into_return(func) %}){% if func.throws() %}.map_err(Into::into){% endif %}
     )
 }
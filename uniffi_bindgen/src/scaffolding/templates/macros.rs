@@ -16,8 +16,11 @@ r#{{ func.name() }}({% call _arg_list_rs_call(func) -%})
         {%-     else %}
             Ok(ref val) => val,
         {%-     endif %}
+        {#  sequences get passed through `Into`, so that the function can take a `Cow<[T]>` #}
+        {%- elif arg.as_type().borrow()|converts_with_into %}
+            Ok(val) => ::std::convert::Into::into(val),
         {%- else %}
-        {#  args not passed by reference get passed directly #}
+        {#  other args not passed by reference get passed directly #}
             Ok(val) => val,
         {%- endif %}
 
@@ -71,12 +74,26 @@ pub extern "C" fn r#{{ meth.ffi_func().name() }}(
     {%- call arg_list_ffi_decl(meth.ffi_func()) %}
 ) {% call return_signature(meth) %} {
     uniffi::deps::log::debug!("{{ meth.ffi_func().name() }}");
+    let _uniffi_span = uniffi::enter_span("{{ meth.object_name() }}.{{ meth.name() }}");
     uniffi::rust_call(call_status, || {
         {{ meth|return_ffi_converter }}::lower_return(
 {%- endmacro %}
 
+{#-
+// Convert returned sequences through `Into`, so that the function can return a `Cow<[T]>`
+-#}
+{%- macro into_return(func) %}
+{%- match func.return_type() %}
+{%- when Some with (return_type) %}
+{%- if return_type|converts_with_into %}
+{%- if func.throws() %}.map(::std::convert::Into::into){% else %}.into(){% endif %}
+{%- endif %}
+{%- else %}
+{%- endmatch %}
+{%- endmacro -%}
+
 {%- macro method_decl_postscript(meth) %}
-            {% if meth.throws() %}.map_err(Into::into){% endif %}
+            {% call into_return(meth) %}{% if meth.throws() %}.map_err(Into::into){% endif %}
         )
     })
 }
@@ -11,4 +11,5 @@ const {{ const_var }}: ::uniffi::MetadataBuffer = ::uniffi::MetadataBuffer::from
 
 #[doc(hidden)]
 #[no_mangle]
+#[::uniffi::metadata_section]
 pub static {{ static_var }}: [u8; {{ const_var }}.size] = {{ const_var }}.into_array();
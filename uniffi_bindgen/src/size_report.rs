@@ -0,0 +1,325 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Report the size of generated bindings.
+//!
+//! Large generated bindings can add noticeably to the size of a shipped app.  This module measures
+//! the files that the backends wrote and breaks their size down by the kind of interface item that
+//! the code was generated for, so that users can see what is contributing to the output.  It only
+//! looks at files that have already been written and formatted, it never changes them.
+//!
+//! The breakdown is based on top-level declarations: each top-level line that mentions an item by
+//! its foreign name starts a section for that item, which lasts until the next top-level
+//! declaration.  Top-level code that doesn't mention any item, like the FFI declarations and the
+//! helpers that all bindings share, counts as runtime code.
+
+use std::fmt;
+
+use anyhow::{Context, Result};
+use camino::Utf8PathBuf;
+use fs_err as fs;
+use heck::{ToLowerCamelCase, ToSnakeCase, ToUpperCamelCase};
+
+use crate::bindings::{kotlin, GeneratedFile, TargetLanguage};
+use crate::interface::ComponentInterface;
+
+/// Size information for all the bindings generated for a single `ComponentInterface`.
+#[derive(Debug, Clone)]
+pub struct SizeReport {
+    pub namespace: String,
+    pub files: Vec<FileSize>,
+}
+
+/// Size of a single generated file.
+#[derive(Debug, Clone)]
+pub struct FileSize {
+    pub language: TargetLanguage,
+    pub path: Utf8PathBuf,
+    pub bytes: u64,
+    pub lines: usize,
+    pub by_kind: KindSizes,
+}
+
+/// Number of bytes of generated code for each kind of interface item.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct KindSizes {
+    pub runtime: u64,
+    pub functions: u64,
+    pub objects: u64,
+    pub records: u64,
+    pub enums: u64,
+    pub errors: u64,
+    pub callback_interfaces: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ItemKind {
+    Runtime,
+    Function,
+    Object,
+    Record,
+    Enum,
+    Error,
+    CallbackInterface,
+}
+
+impl SizeReport {
+    pub fn new(ci: &ComponentInterface, files: &[GeneratedFile]) -> Result<Self> {
+        let files = files
+            .iter()
+            .map(|file| {
+                let contents = fs::read_to_string(&file.path)
+                    .with_context(|| format!("Failed to read generated file {}", file.path))?;
+                Ok(FileSize {
+                    language: file.language,
+                    path: file.path.clone(),
+                    bytes: contents.len() as u64,
+                    lines: contents.lines().count(),
+                    by_kind: KindSizes::measure(&contents, &item_names(ci, file.language)),
+                })
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self {
+            namespace: ci.namespace().to_string(),
+            files,
+        })
+    }
+
+    /// Total number of bytes across all generated files
+    pub fn total_bytes(&self) -> u64 {
+        self.files.iter().map(|f| f.bytes).sum()
+    }
+
+    /// Total number of lines across all generated files
+    pub fn total_lines(&self) -> usize {
+        self.files.iter().map(|f| f.lines).sum()
+    }
+
+    /// Total number of bytes for each kind of item across all generated files
+    pub fn total_by_kind(&self) -> KindSizes {
+        self.files
+            .iter()
+            .fold(KindSizes::default(), |total, f| total + f.by_kind)
+    }
+}
+
+impl KindSizes {
+    fn measure(contents: &str, names: &[(String, ItemKind)]) -> Self {
+        let mut sizes = Self::default();
+        let mut current = ItemKind::Runtime;
+        // Annotations and decorators belong to the declaration after them
+        let mut annotations = 0;
+        for line in contents.split_inclusive('\n') {
+            if line.starts_with('@') {
+                annotations += line.len() as u64;
+                continue;
+            }
+            if starts_declaration(line) {
+                current = find_item(line, names).unwrap_or(ItemKind::Runtime);
+            }
+            *sizes.get_mut(current) += annotations + line.len() as u64;
+            annotations = 0;
+        }
+        *sizes.get_mut(current) += annotations;
+        sizes
+    }
+
+    fn get_mut(&mut self, kind: ItemKind) -> &mut u64 {
+        match kind {
+            ItemKind::Runtime => &mut self.runtime,
+            ItemKind::Function => &mut self.functions,
+            ItemKind::Object => &mut self.objects,
+            ItemKind::Record => &mut self.records,
+            ItemKind::Enum => &mut self.enums,
+            ItemKind::Error => &mut self.errors,
+            ItemKind::CallbackInterface => &mut self.callback_interfaces,
+        }
+    }
+
+    fn iter(&self) -> impl Iterator<Item = (&'static str, u64)> {
+        [
+            ("runtime", self.runtime),
+            ("functions", self.functions),
+            ("objects", self.objects),
+            ("records", self.records),
+            ("enums", self.enums),
+            ("errors", self.errors),
+            ("callback interfaces", self.callback_interfaces),
+        ]
+        .into_iter()
+    }
+}
+
+impl std::ops::Add for KindSizes {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            runtime: self.runtime + other.runtime,
+            functions: self.functions + other.functions,
+            objects: self.objects + other.objects,
+            records: self.records + other.records,
+            enums: self.enums + other.enums,
+            errors: self.errors + other.errors,
+            callback_interfaces: self.callback_interfaces + other.callback_interfaces,
+        }
+    }
+}
+
+// Does this line start a new top-level declaration?  Indented lines, closing brackets and comments
+// belong to the declaration before them.
+fn starts_declaration(line: &str) -> bool {
+    let first = match line.chars().next() {
+        Some(c) => c,
+        None => return false,
+    };
+    !(first.is_whitespace()
+        || matches!(first, '}' | ')' | ']' | '#' | '*')
+        || line.starts_with("//")
+        || line.starts_with("/*"))
+}
+
+// Find the item that a declaration is for, from the first identifier in the line that is the
+// foreign name of an item, or the name of its FFI converter.
+fn find_item(line: &str, names: &[(String, ItemKind)]) -> Option<ItemKind> {
+    line.split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .filter(|ident| !ident.is_empty())
+        .find_map(|ident| {
+            names.iter().find_map(|(name, kind)| {
+                (ident == name || ident.contains(&format!("Type{name}"))).then_some(*kind)
+            })
+        })
+}
+
+// The foreign names of the interface items, as the backend for `language` renders them.  C headers
+// only declare the low-level FFI, so all of their code counts as runtime code.
+fn item_names(ci: &ComponentInterface, language: TargetLanguage) -> Vec<(String, ItemKind)> {
+    let fn_name = |nm: &str| match language {
+        TargetLanguage::Kotlin | TargetLanguage::Swift => nm.to_lower_camel_case(),
+        TargetLanguage::Python | TargetLanguage::Ruby => nm.to_snake_case(),
+        TargetLanguage::C => unreachable!(),
+    };
+    let class_name = |nm: &str| nm.to_upper_camel_case();
+    if language == TargetLanguage::C {
+        return vec![];
+    }
+
+    let mut names = vec![];
+    for func in ci.function_definitions() {
+        names.push((fn_name(func.name()), ItemKind::Function));
+    }
+    for obj in ci.object_definitions() {
+        let name = class_name(obj.name());
+        // The interfaces that Kotlin and Swift declare for each object
+        names.push((format!("{name}Interface"), ItemKind::Object));
+        names.push((format!("{name}Protocol"), ItemKind::Object));
+        names.push((name, ItemKind::Object));
+    }
+    for rec in ci.record_definitions() {
+        names.push((class_name(rec.name()), ItemKind::Record));
+    }
+    for e in ci.enum_definitions() {
+        if ci.is_name_used_as_error(e.name()) {
+            // Kotlin renames errors to follow the `Exception` naming convention
+            if language == TargetLanguage::Kotlin {
+                if let Ok(name) = kotlin::gen_kotlin::filters::error_type_name(e) {
+                    names.push((name, ItemKind::Error));
+                }
+            }
+            names.push((class_name(e.name()), ItemKind::Error));
+        } else {
+            names.push((class_name(e.name()), ItemKind::Enum));
+        }
+    }
+    for cbi in ci.callback_interface_definitions() {
+        names.push((class_name(cbi.name()), ItemKind::CallbackInterface));
+    }
+    names
+}
+
+impl fmt::Display for SizeReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}:", self.namespace)?;
+        for file in &self.files {
+            writeln!(
+                f,
+                "  {:<8} {:>10} bytes {:>8} lines  {}",
+                file.language.to_string(),
+                file.bytes,
+                file.lines,
+                file.path,
+            )?;
+        }
+        writeln!(
+            f,
+            "  {:<8} {:>10} bytes {:>8} lines",
+            "total",
+            self.total_bytes(),
+            self.total_lines(),
+        )?;
+        for (kind, bytes) in self.total_by_kind().iter().filter(|(_, bytes)| *bytes > 0) {
+            writeln!(f, "    {kind:<20} {bytes:>10} bytes")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const UDL: &str = r#"
+        namespace test {
+            u32 add(u32 a, u32 b);
+        };
+        dictionary Point { u32 x; u32 y; };
+        [Error]
+        enum ArithmeticError { "Overflow" };
+        interface Counter {
+            constructor();
+            void increment();
+        };
+    "#;
+
+    #[test]
+    fn test_kind_sizes() {
+        let ci = ComponentInterface::from_webidl(UDL).unwrap();
+        let names = item_names(&ci, TargetLanguage::Kotlin);
+        let runtime = "import java.nio.ByteBuffer\n\ninternal interface _UniFFILib {\n}\n";
+        let record =
+            "data class Point (\n    var x: UInt\n)\n\npublic object FfiConverterTypePoint {\n}\n";
+        let error = "sealed class ArithmeticException: Exception() {\n}\n";
+        let object = "public interface CounterInterface {\n}\n\nclass Counter {\n}\n";
+        let function = "@Throws(ArithmeticException::class)\nfun `add`(`a`: UInt): UInt {\n}\n";
+        let contents = [runtime, record, error, object, function].concat();
+
+        let sizes = KindSizes::measure(&contents, &names);
+        assert_eq!(
+            sizes,
+            KindSizes {
+                runtime: runtime.len() as u64,
+                records: record.len() as u64,
+                errors: error.len() as u64,
+                objects: object.len() as u64,
+                functions: function.len() as u64,
+                ..KindSizes::default()
+            }
+        );
+        assert_eq!(
+            sizes.iter().map(|(_, bytes)| bytes).sum::<u64>(),
+            contents.len() as u64
+        );
+    }
+
+    #[test]
+    fn test_item_names() {
+        let ci = ComponentInterface::from_webidl(UDL).unwrap();
+        let python = item_names(&ci, TargetLanguage::Python);
+        assert!(python.contains(&("add".to_string(), ItemKind::Function)));
+        assert!(python.contains(&("ArithmeticError".to_string(), ItemKind::Error)));
+        let kotlin = item_names(&ci, TargetLanguage::Kotlin);
+        assert!(kotlin.contains(&("ArithmeticException".to_string(), ItemKind::Error)));
+        assert!(item_names(&ci, TargetLanguage::C).is_empty());
+    }
+}
@@ -0,0 +1,157 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Runtime capability checks for functions exported with
+//! `#[uniffi::export(requires_capability = "...")]`
+//!
+//! Libraries register a predicate for each capability, for example from their initialization
+//! function:
+//!
+//! ```ignore
+//! static LICENSED: AtomicBool = AtomicBool::new(false);
+//!
+//! #[uniffi::export]
+//! fn init_sdk(license_key: String) {
+//!     LICENSED.store(check_license(&license_key), Ordering::Relaxed);
+//!     uniffi::register_capability("premium", || LICENSED.load(Ordering::Relaxed));
+//! }
+//! ```
+//!
+//! Foreign code can also decide if a capability is available.  Bindings generated with the
+//! `capability_registration` config option have a function that calls [capability_set], which
+//! replaces the capability's predicate with a fixed answer.  Registering a predicate afterwards
+//! replaces the foreign answer in turn.
+//!
+//! Before calling a guarded function, the scaffolding calls the capability's predicate.  If it
+//! returns false, or no predicate was registered, the function isn't called and a
+//! [MissingCapability] error is returned instead.
+
+use std::{borrow::Cow, fmt, slice, str, sync::RwLock};
+
+/// Predicate that decides if a capability is currently available
+pub type CapabilityPredicate = fn() -> bool;
+
+#[derive(Clone, Copy)]
+enum Availability {
+    // Registered by Rust code
+    Predicate(CapabilityPredicate),
+    // Set by the foreign code
+    Fixed(bool),
+}
+
+static CAPABILITIES: RwLock<Vec<(Cow<'static, str>, Availability)>> = RwLock::new(Vec::new());
+
+fn set_availability(name: Cow<'static, str>, availability: Availability) {
+    let mut capabilities = CAPABILITIES.write().unwrap_or_else(|e| e.into_inner());
+    match capabilities.iter_mut().find(|(n, _)| *n == name) {
+        Some(entry) => entry.1 = availability,
+        None => capabilities.push((name, availability)),
+    }
+}
+
+/// Register the predicate for a capability, replacing any previously registered one
+pub fn register_capability(name: &'static str, predicate: CapabilityPredicate) {
+    set_availability(Cow::Borrowed(name), Availability::Predicate(predicate));
+}
+
+/// Make a capability available or unavailable, replacing any previously registered predicate
+pub fn set_capability_available(name: &str, available: bool) {
+    set_availability(Cow::Owned(name.to_owned()), Availability::Fixed(available));
+}
+
+/// Set a capability from the foreign code.  This is called by the foreign bindings, when they're
+/// generated with the `capability_registration` option.
+///
+/// `name_data`/`name_len` point to the UTF-8 capability name.  Names that aren't valid UTF-8 are
+/// ignored.
+///
+/// # Safety
+///
+/// `name_data` must point to `name_len` readable bytes, unless `name_len` is 0.
+pub unsafe fn capability_set(name_data: *const u8, name_len: i32, available: i8) {
+    let name = match usize::try_from(name_len) {
+        Ok(0) => &[][..],
+        Ok(len) if !name_data.is_null() => slice::from_raw_parts(name_data, len),
+        _ => return,
+    };
+    if let Ok(name) = str::from_utf8(name) {
+        set_capability_available(name, available != 0);
+    }
+}
+
+/// Check if a capability is available
+///
+/// This is called by the scaffolding of functions exported with `requires_capability`.
+/// Capabilities without a registered predicate are never available.
+pub fn check_capability(name: &'static str) -> Result<(), MissingCapability> {
+    let availability = CAPABILITIES
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .iter()
+        .find(|(n, _)| *n == name)
+        .map(|(_, availability)| *availability);
+    // Call the predicate without holding the lock, so that it can register capabilities itself.
+    let available = match availability {
+        Some(Availability::Predicate(predicate)) => predicate(),
+        Some(Availability::Fixed(available)) => available,
+        None => false,
+    };
+    if available {
+        Ok(())
+    } else {
+        Err(MissingCapability { name })
+    }
+}
+
+/// A function exported with `#[uniffi::export(requires_capability = "...")]` was called while the
+/// capability wasn't available
+///
+/// The error type of such functions must implement `From<MissingCapability>`, so that this can be
+/// returned to the foreign code as a regular error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingCapability {
+    pub name: &'static str,
+}
+
+impl fmt::Display for MissingCapability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "MissingCapability(name: {:?})", self.name)
+    }
+}
+
+impl std::error::Error for MissingCapability {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_check_capability() {
+        assert_eq!(
+            check_capability("test-unregistered"),
+            Err(MissingCapability {
+                name: "test-unregistered"
+            })
+        );
+        register_capability("test-capability", || false);
+        assert!(check_capability("test-capability").is_err());
+        register_capability("test-capability", || true);
+        assert_eq!(check_capability("test-capability"), Ok(()));
+
+        // Foreign code replaces the predicate, and registering one replaces the foreign answer
+        let name = "test-foreign";
+        unsafe { capability_set(name.as_ptr(), name.len() as i32, 1) };
+        assert_eq!(check_capability("test-foreign"), Ok(()));
+        unsafe { capability_set(name.as_ptr(), name.len() as i32, 0) };
+        assert!(check_capability("test-foreign").is_err());
+        register_capability("test-foreign", || true);
+        assert_eq!(check_capability("test-foreign"), Ok(()));
+        set_capability_available("test-foreign", false);
+        assert!(check_capability("test-foreign").is_err());
+
+        // Invalid names are ignored
+        unsafe { capability_set(b"\xff".as_ptr(), 1, 1) };
+        unsafe { capability_set(std::ptr::null(), -1, 1) };
+    }
+}
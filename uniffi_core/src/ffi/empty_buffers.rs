@@ -0,0 +1,33 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Pass empty sequences and maps, and `None`, without allocating a buffer.
+//!
+//! Sequences, maps and optionals are passed as a [crate::RustBuffer] holding their serialized
+//! value, so even an empty `Vec` or a `None` costs a buffer allocation and, for most bindings, an
+//! extra FFI call to free it.  Bindings generated with the `empty_buffer_sentinels` config option
+//! pass these values as an empty buffer instead, which never needs allocating or freeing.
+//!
+//! This stays wire-compatible: serialized values are never empty (sequences and maps start with
+//! their length and optionals with a tag byte), so lifting always accepts an empty buffer as the
+//! empty value.  Lowering only produces empty buffers once bindings call
+//! [empty_buffer_sentinels_enable].  Like the string encoding, this is a property of the whole
+//! library, since Rust code never knows which bindings a value goes to, so the Kotlin, Swift,
+//! Python and Ruby bindings all accept empty buffers whether or not they enable them.  Bindings
+//! generated by older versions of UniFFI don't, so they can't share a library that enables this.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static EMPTY_BUFFER_SENTINELS: AtomicBool = AtomicBool::new(false);
+
+/// Start lowering empty values as empty buffers.  This is called by the foreign bindings during
+/// initialization.
+pub fn empty_buffer_sentinels_enable() {
+    EMPTY_BUFFER_SENTINELS.store(true, Ordering::Relaxed);
+}
+
+/// Should empty values be lowered as empty buffers?
+pub fn empty_buffer_sentinels_enabled() -> bool {
+    EMPTY_BUFFER_SENTINELS.load(Ordering::Relaxed)
+}
@@ -4,18 +4,30 @@
 
 //! Types that can cross the FFI boundary.
 
+pub mod capabilities;
+pub mod empty_buffers;
 pub mod ffidefault;
 pub mod foreignbytes;
 pub mod foreigncallbacks;
 pub mod foreignexecutor;
+pub mod nesting;
+pub mod panic_observer;
 pub mod rustbuffer;
 pub mod rustcalls;
 pub mod rustfuture;
+pub mod spans;
+pub mod string_encoding;
 
+pub use capabilities::*;
+pub use empty_buffers::*;
 pub use ffidefault::FfiDefault;
 pub use foreignbytes::*;
 pub use foreigncallbacks::*;
 pub use foreignexecutor::*;
+pub use nesting::*;
+pub use panic_observer::*;
 pub use rustbuffer::*;
 pub use rustcalls::*;
 pub use rustfuture::*;
+pub use spans::*;
+pub use string_encoding::*;
@@ -0,0 +1,107 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Limit how deeply records and enums can be nested in lifted values.
+//!
+//! Records and enums are read recursively, so a value that's nested deeply enough overflows the
+//! stack, which aborts the process rather than failing the call.  Foreign bindings generated with
+//! the `max_nesting_depth` config option pass their limit to [max_nesting_depth_set] during
+//! initialization, and the scaffolding then refuses to lift values that are nested deeper.
+//!
+//! The limit is a property of the whole library, since Rust code never knows which bindings a
+//! value comes from.  When several bindings set a limit, the smallest one is used.  Without a
+//! limit, reading a record or enum costs a single atomic load.
+
+use anyhow::{bail, Result};
+use std::{
+    cell::Cell,
+    sync::atomic::{AtomicU32, Ordering},
+};
+
+// 0 means that no bindings have set a limit
+static MAX_NESTING_DEPTH: AtomicU32 = AtomicU32::new(0);
+
+thread_local! {
+    // How deeply records and enums are nested in the value that's currently being read on this
+    // thread
+    static NESTING_DEPTH: Cell<u32> = Cell::new(0);
+}
+
+/// Limit how deeply values lifted by the scaffolding can be nested.  This is called by the
+/// foreign bindings during initialization.
+///
+/// A limit of 0 is ignored, and a limit that's larger than the current one doesn't raise it.
+pub fn max_nesting_depth_set(max_depth: u32) {
+    if max_depth != 0 {
+        let _ = MAX_NESTING_DEPTH.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |current| {
+            (current == 0 || max_depth < current).then_some(max_depth)
+        });
+    }
+}
+
+/// Guard for reading a record or enum one level of nesting deeper, which is left when the guard
+/// is dropped.
+///
+/// The `try_read` methods generated for records and enums enter this before reading their fields.
+#[must_use = "the nesting level is left as soon as the guard is dropped"]
+pub struct NestingGuard {
+    entered: bool,
+}
+
+impl NestingGuard {
+    /// Enter the next nesting level, failing if that's deeper than the limit
+    pub fn enter() -> Result<Self> {
+        let max_depth = MAX_NESTING_DEPTH.load(Ordering::Relaxed);
+        if max_depth == 0 {
+            return Ok(Self { entered: false });
+        }
+        NESTING_DEPTH.with(|depth| {
+            if depth.get() >= max_depth {
+                bail!("Values can't be nested more than {max_depth} levels deep");
+            }
+            depth.set(depth.get() + 1);
+            Ok(Self { entered: true })
+        })
+    }
+}
+
+impl Drop for NestingGuard {
+    fn drop(&mut self) {
+        if self.entered {
+            NESTING_DEPTH.with(|depth| depth.set(depth.get() - 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn enter_levels(count: u32) -> Result<()> {
+        let _guard = NestingGuard::enter()?;
+        if count > 1 {
+            enter_levels(count - 1)?;
+        }
+        Ok(())
+    }
+
+    // All in one test, since the limit is global
+    #[test]
+    fn test_max_nesting_depth() {
+        enter_levels(1000).unwrap();
+        max_nesting_depth_set(0);
+        enter_levels(1000).unwrap();
+        max_nesting_depth_set(10);
+        enter_levels(10).unwrap();
+        enter_levels(11).unwrap_err();
+        // The depth is back to 0 after both the successful and failed reads
+        enter_levels(10).unwrap();
+        // A larger limit doesn't raise the current one, but a smaller one lowers it
+        max_nesting_depth_set(20);
+        enter_levels(11).unwrap_err();
+        max_nesting_depth_set(5);
+        enter_levels(5).unwrap();
+        enter_levels(6).unwrap_err();
+    }
+}
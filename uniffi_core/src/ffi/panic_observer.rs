@@ -0,0 +1,129 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Notify a foreign panic observer about Rust panics.
+//!
+//! Foreign bindings generated with the `panic_observer` config option can register a
+//! [PanicObserverCallback], which lets foreign crash-reporting tools capture Rust panics.  Once an
+//! observer is registered, the scaffolding reports every panic that it catches, with its message
+//! and location, before turning it into an error for the foreign code.
+//!
+//! The location of a panic is only known to the panic hook, so registering an observer installs a
+//! hook that records it and then calls the previously installed hook.  Panics are only reported
+//! when they unwind to the scaffolding, so nothing is reported for panics caught by the Rust code
+//! itself or for libraries built with `panic = "abort"`.
+
+use std::cell::RefCell;
+use std::panic;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Once;
+
+/// Callback that receives panics.  The bindings code registers at most one of these.
+///
+/// `message_data`/`message_len` point to the UTF-8 panic message and `file_data`/`file_len` to
+/// the UTF-8 name of the source file that panicked.  Both are only valid for the duration of the
+/// call.  `line` is 0 when the location is unknown.
+///
+/// This callback is invoked on the thread that panicked, which can be a thread created by Rust.
+pub type PanicObserverCallback = extern "C" fn(
+    message_data: *const u8,
+    message_len: i32,
+    file_data: *const u8,
+    file_len: i32,
+    line: u32,
+);
+
+static PANIC_OBSERVER_CALLBACK: AtomicUsize = AtomicUsize::new(0);
+static INSTALL_HOOK: Once = Once::new();
+
+thread_local! {
+    // Location of the last panic on this thread, recorded by our panic hook
+    static PANIC_LOCATION: RefCell<Option<(String, u32)>> = RefCell::new(None);
+}
+
+/// Set the global PanicObserverCallback.  This is called by the foreign bindings, normally during
+/// initialization.
+pub fn panic_observer_set(callback: PanicObserverCallback) {
+    INSTALL_HOOK.call_once(|| {
+        // Set up the `log_panics` hook first, so that it doesn't replace ours later on.
+        crate::panichook::ensure_setup();
+        let previous_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |panic_info| {
+            if get_panic_observer_callback().is_some() {
+                let location = panic_info
+                    .location()
+                    .map(|loc| (loc.file().to_string(), loc.line()));
+                PANIC_LOCATION.with(|cell| *cell.borrow_mut() = location);
+            }
+            previous_hook(panic_info);
+        }));
+    });
+    PANIC_OBSERVER_CALLBACK.store(callback as usize, Ordering::Relaxed);
+}
+
+fn get_panic_observer_callback() -> Option<PanicObserverCallback> {
+    match PANIC_OBSERVER_CALLBACK.load(Ordering::Relaxed) {
+        0 => None,
+        // SAFETY: The below call is okay because we only store values in
+        // PANIC_OBSERVER_CALLBACK that were cast from a PanicObserverCallback.
+        n => Some(unsafe { std::mem::transmute::<usize, PanicObserverCallback>(n) }),
+    }
+}
+
+/// Report a caught panic to the foreign panic observer, if one is registered
+pub(crate) fn notify_panic_observer(message: &str) {
+    if let Some(callback) = get_panic_observer_callback() {
+        let (file, line) = PANIC_LOCATION
+            .with(|cell| cell.borrow_mut().take())
+            .unwrap_or_else(|| ("<unknown>".to_string(), 0));
+        let message_len = i32::try_from(message.len()).unwrap_or(i32::MAX);
+        let file_len = i32::try_from(file.len()).unwrap_or(i32::MAX);
+        callback(message.as_ptr(), message_len, file.as_ptr(), file_len, line);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{rust_call, RustBuffer, RustCallStatus};
+    use std::{slice, sync::Mutex};
+
+    static PANICS: Mutex<Vec<(String, String, u32)>> = Mutex::new(Vec::new());
+
+    extern "C" fn test_observer(
+        message_data: *const u8,
+        message_len: i32,
+        file_data: *const u8,
+        file_len: i32,
+        line: u32,
+    ) {
+        let message = unsafe { slice::from_raw_parts(message_data, message_len as usize) };
+        let file = unsafe { slice::from_raw_parts(file_data, file_len as usize) };
+        PANICS.lock().unwrap().push((
+            String::from_utf8(message.to_vec()).unwrap(),
+            String::from_utf8(file.to_vec()).unwrap(),
+            line,
+        ));
+    }
+
+    #[test]
+    fn test_panic_observer() {
+        panic_observer_set(test_observer);
+        let mut status = RustCallStatus::default();
+        let expected_line = line!() + 2;
+        rust_call(&mut status, || -> Result<(), RustBuffer> {
+            panic!("observed")
+        });
+        // CALL_PANIC
+        assert_eq!(status.code, 2);
+        // Other tests panic too, now that the observer is registered
+        let panics = PANICS.lock().unwrap();
+        let observed = panics
+            .iter()
+            .find(|(message, _, _)| message == "observed")
+            .unwrap();
+        assert!(observed.1.ends_with("panic_observer.rs"));
+        assert_eq!(observed.2, expected_line);
+    }
+}
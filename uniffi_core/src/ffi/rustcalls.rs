@@ -8,10 +8,14 @@
 //!
 //! It handles:
 //!    - Catching panics
+//!    - Converting panics into errors for functions exported with `catch_panics`
+//!    - Reporting caught panics to the foreign panic observer
 //!    - Adapting the result of `FfiConverter::lower_return()` into either a return value or an
 //!      exception
 
-use crate::{FfiConverter, FfiDefault, RustBuffer, UniFfiTag};
+use crate::{notify_panic_observer, FfiConverter, FfiDefault, RustBuffer, UniFfiTag};
+use std::any::Any;
+use std::fmt;
 use std::mem::MaybeUninit;
 use std::panic;
 
@@ -44,6 +48,7 @@ use std::panic;
 ///  - `CALL_SUCCESS` (0) for successful calls
 ///  - `CALL_ERROR` (1) for calls that returned an `Err` value
 ///  - `CALL_PANIC` (2) for calls that panicked
+///  - `CALL_CANCELLED` (3) for async calls whose future was cancelled by the foreign code
 ///
 /// #### The `error_buf` field.
 ///
@@ -78,6 +83,7 @@ impl Default for RustCallStatus {
 const CALL_SUCCESS: i8 = 0; // CALL_SUCCESS is set by the calling code
 const CALL_ERROR: i8 = 1;
 const CALL_PANIC: i8 = 2;
+pub(crate) const CALL_CANCELLED: i8 = 3;
 
 /// Handle a scaffolding calls
 ///
@@ -143,15 +149,9 @@ where
             // Try to coerce the cause into a RustBuffer containing a String.  Since this code can
             // panic, we need to use a second catch_unwind().
             let message_result = panic::catch_unwind(panic::AssertUnwindSafe(move || {
-                // The documentation suggests that it will *usually* be a str or String.
-                let message = if let Some(s) = cause.downcast_ref::<&'static str>() {
-                    (*s).to_string()
-                } else if let Some(s) = cause.downcast_ref::<String>() {
-                    s.clone()
-                } else {
-                    "Unknown panic!".to_string()
-                };
+                let message = panic_message(&*cause);
                 log::error!("Caught a panic calling rust code: {:?}", message);
+                notify_panic_observer(&message);
                 <String as FfiConverter<UniFfiTag>>::lower(message)
             }));
             if let Ok(buf) = message_result {
@@ -169,6 +169,55 @@ where
     }
 }
 
+/// Extract the message from a panic payload
+fn panic_message(cause: &(dyn Any + Send)) -> String {
+    // The documentation suggests that it will *usually* be a str or String.
+    if let Some(s) = cause.downcast_ref::<&'static str>() {
+        (*s).to_string()
+    } else if let Some(s) = cause.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "Unknown panic!".to_string()
+    }
+}
+
+/// A panic caught in a function exported with `#[uniffi::export(catch_panics)]`
+///
+/// The error type of such functions must implement `From<RustPanic>`, so that the panic can be
+/// returned to the foreign code as a regular error rather than an internal one.
+#[derive(Debug)]
+pub struct RustPanic {
+    pub message: String,
+}
+
+impl fmt::Display for RustPanic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RustPanic(message: {:?})", self.message)
+    }
+}
+
+impl std::error::Error for RustPanic {}
+
+/// Call a function exported with `#[uniffi::export(catch_panics)]`
+///
+/// If `callback` panics, the panic is converted into an `Err` value using the error type's
+/// `From<RustPanic>` impl.  This runs inside `rust_call`, so the panic will never reach the
+/// outer `catch_unwind` and `out_status.code` will be `CALL_ERROR` rather than `CALL_PANIC`.
+pub fn catch_panics<F, T, E>(callback: F) -> Result<T, E>
+where
+    F: FnOnce() -> Result<T, E>,
+    E: From<RustPanic>,
+{
+    // `rust_call` already requires the captured state to be `UnwindSafe`, this just saves the
+    // scaffolding code from having to prove it again.
+    panic::catch_unwind(panic::AssertUnwindSafe(callback)).unwrap_or_else(|cause| {
+        let message = panic_message(&*cause);
+        log::error!("Caught a panic calling rust code: {:?}", message);
+        notify_panic_observer(&message);
+        Err(E::from(RustPanic { message }))
+    })
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -247,4 +296,37 @@ mod test {
             );
         }
     }
+
+    impl From<RustPanic> for TestError {
+        fn from(e: RustPanic) -> Self {
+            TestError(format!("Panic: {}", e.message))
+        }
+    }
+
+    #[test]
+    fn test_rust_call_catch_panics() {
+        let mut status = create_call_status();
+        let return_value = rust_call(&mut status, || {
+            <Result<i8, TestError> as FfiConverter<UniFfiTag>>::lower_return(catch_panics(|| {
+                test_callback(0)
+            }))
+        });
+        assert_eq!(status.code, CALL_SUCCESS);
+        assert_eq!(return_value, 100);
+
+        let mut status = create_call_status();
+        rust_call(&mut status, || {
+            <Result<i8, TestError> as FfiConverter<UniFfiTag>>::lower_return(catch_panics(|| {
+                test_callback(2)
+            }))
+        });
+        assert_eq!(status.code, CALL_ERROR);
+        unsafe {
+            assert_eq!(
+                <TestError as FfiConverter<UniFfiTag>>::try_lift(status.error_buf.assume_init())
+                    .unwrap(),
+                TestError("Panic: Unexpected value: 2".to_owned())
+            );
+        }
+    }
 }
@@ -71,6 +71,25 @@
 //!   - RustCallStatus (used to signal errors/panics when executing the future)
 //! - Rust will stop polling the future, even if it's waker is invoked again.
 //!
+//! ## Cancellation
+//!
+//! The foreign code can cancel an in-flight call with the `ffi_[namespace]_rust_future_cancel`
+//! scaffolding function, passing it the `callback_data` pointer from the scaffolding call.  This
+//! is how the bindings support `Job.cancel()` in Kotlin, `Task.cancel()` in Swift and
+//! `Task.cancel()` in Python.
+//!
+//! Only calls that the bindings opt in can be cancelled, since that costs a registry entry for
+//! each in-flight call.  Bindings generated with the `async_cancellation` config option call
+//! `ffi_[namespace]_rust_future_cancellable` with the `callback_data` pointer right before the
+//! scaffolding function, on the same thread.  `callback_data` must be unique for each in-flight
+//! cancellable call.  The registry entry is removed once the future completes or is dropped.
+//!
+//! Cancelling is cooperative: it schedules a wake like a `Waker` would, then instead of polling
+//! again we drop the future and invoke the callback with the `CALL_CANCELLED` status code.  If the
+//! future already completed, cancelling does nothing.  This way the callback is always invoked
+//! exactly once, which is what the foreign code needs to release the state it's holding for the
+//! call.
+//!
 //! ## How does `Future` work exactly?
 //!
 //! A [`Future`] in Rust does nothing. When calling an async function, it just
@@ -116,17 +135,22 @@
 //! [`RawWaker`]: https://doc.rust-lang.org/std/task/struct.RawWaker.html
 
 use crate::{
-    ffi::foreignexecutor::RustTaskCallbackCode, rust_call_with_out_status, schedule_raw,
-    FfiConverter, FfiDefault, ForeignExecutor, ForeignExecutorHandle, RustCallStatus,
+    ffi::{foreignexecutor::RustTaskCallbackCode, rustcalls::CALL_CANCELLED},
+    rust_call_with_out_status, schedule_raw, FfiConverter, FfiDefault, ForeignExecutor,
+    ForeignExecutorHandle, RustCallStatus,
 };
+use once_cell::sync::Lazy;
 use std::{
-    cell::UnsafeCell,
+    any::TypeId,
+    cell::{Cell, UnsafeCell},
+    collections::HashMap,
     future::Future,
+    mem::ManuallyDrop,
     panic,
     pin::Pin,
     sync::{
-        atomic::{AtomicU32, Ordering},
-        Arc,
+        atomic::{AtomicBool, AtomicU32, Ordering},
+        Arc, Mutex, Weak,
     },
     task::{Context, Poll, RawWaker, RawWakerVTable, Waker},
 };
@@ -151,9 +175,13 @@ where
     F: Future<Output = T> + Send,
     T: FfiConverter<UT>,
 {
-    future: UnsafeCell<F>,
+    // `None` once the future has been dropped because the foreign code cancelled it.
+    future: UnsafeCell<Option<F>>,
     executor: ForeignExecutor,
     wake_counter: AtomicU32,
+    cancelled: AtomicBool,
+    // Key of our `CancellableFutures` entry, if the call was marked as cancellable
+    cancel_key: Option<CancelKey>,
     callback: T::FutureCallback,
     callback_data: *const (),
 }
@@ -180,23 +208,53 @@ impl<F, T, UT> RustFuture<F, T, UT>
 where
     F: Future<Output = T> + Send,
     T: FfiConverter<UT>,
+    UT: 'static,
 {
+    /// Create a new `RustFuture`
+    ///
+    /// The future is driven by the foreign executor after the scaffolding function returns, so it
+    /// can't borrow anything from the scaffolding call.
     pub fn new(
         future: F,
         executor_handle: ForeignExecutorHandle,
         callback: T::FutureCallback,
         callback_data: *const (),
-    ) -> Pin<Arc<Self>> {
+    ) -> Pin<Arc<Self>>
+    where
+        F: 'static,
+    {
         let executor =
             <ForeignExecutor as FfiConverter<crate::UniFfiTag>>::try_lift(executor_handle)
                 .expect("Error lifting ForeignExecutorHandle");
-        Arc::pin(Self {
-            future: UnsafeCell::new(future),
+        let cancel_key = CancellableFutures::take_cancellable_call(callback_data)
+            .then(|| CancelKey::new::<UT>(callback_data));
+        let rust_future = Arc::new(Self {
+            future: UnsafeCell::new(Some(future)),
             wake_counter: AtomicU32::new(0),
+            cancelled: AtomicBool::new(false),
+            cancel_key,
             executor,
             callback,
             callback_data,
-        })
+        });
+        if let Some(key) = cancel_key {
+            CancellableFutures::register(key, CancelHandle::new(&rust_future));
+        }
+        // SAFETY: This is what `Arc::pin()` does, we just needed the unpinned `Arc` to register it.
+        unsafe { Pin::new_unchecked(rust_future) }
+    }
+
+    /// Cancel the future
+    ///
+    /// This schedules a wake, which drops the future and invokes the callback with
+    /// `CALL_CANCELLED` rather than polling it again.  See the module docs for details.
+    pub fn cancel(self: Pin<Arc<Self>>) {
+        self.cancelled.store(true, Ordering::Release);
+        self.wake();
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Acquire)
     }
 
     /// Wake up soon and poll our future.
@@ -221,6 +279,12 @@ where
             // be dropped before we call `from_raw()` on the raw pointer. This means we can safely
             // use its handle to schedule a callback.
             if !schedule_raw(handle, 0, Self::wake_callback, raw_ptr as *const ()) {
+                // The foreign executor is often shutdown as part of cancelling the call, for
+                // example when the Kotlin `CoroutineScope` is cancelled.  We still own the right
+                // to access the future here, so finish the cancellation without the executor.
+                if (*raw_ptr).is_cancelled() {
+                    (*raw_ptr).finish_cancel();
+                }
                 // There was an error scheduling the callback, drop the arc reference since
                 // `wake_callback()` will never be called
                 //
@@ -242,6 +306,11 @@ where
             // `RUST_TASK_CALLBACK_CANCELED` indicates the foreign executor has been cancelled /
             // shutdown and we should not continue.
             task.do_wake();
+        } else if task.is_cancelled() {
+            // The executor was shutdown, but we still need to finish cancelling the future.
+            //
+            // SAFETY: `wake_callback()` runs in place of `do_wake()`, so it's serialized the same way.
+            unsafe { task.finish_cancel() };
         }
     }
 
@@ -250,10 +319,22 @@ where
         // Store 1 in `waker_counter`, which we'll use at the end of this call.
         self.wake_counter.store(1, Ordering::Relaxed);
 
+        if self.is_cancelled() {
+            // Don't decrement `wake_counter`, this way any future calls to `wake()` are ignored.
+            //
+            // SAFETY: calls to this function are serialized.
+            unsafe { self.finish_cancel() };
+            return;
+        }
+
         // Pin<&mut> from our UnsafeCell.  &mut is is safe, since this is the only reference we
         // ever take to `self.future` and calls to this function are serialized.  Pin<> is safe
         // since we never move the future out of `self.future`.
-        let future = unsafe { Pin::new_unchecked(&mut *self.future.get()) };
+        let future = match unsafe { Pin::new_unchecked(&mut *self.future.get()) }.as_pin_mut() {
+            Some(future) => future,
+            // The future was already dropped, there's nothing left to do.
+            None => return,
+        };
         let waker = self.make_waker();
 
         // Run the poll and lift the result if it's ready
@@ -289,12 +370,14 @@ where
             // Don't decrement `wake_counter'.  This way, if wake() is called in the future, we
             // will just ignore it
             Some(Poll::Ready(v)) => {
+                self.unregister();
                 T::invoke_future_callback(self.callback, self.callback_data, v, out_status);
             }
             // Error/panic polling the future.  Call the callback with a default value.
             // `out_status` contains the error code and serialized error.  Again, don't decrement
             // `wake_counter'.
             None => {
+                self.unregister();
                 T::invoke_future_callback(
                     self.callback,
                     self.callback_data,
@@ -305,6 +388,49 @@ where
         };
     }
 
+    /// Drop the future and let the foreign code know that the call was cancelled
+    ///
+    /// SAFETY: This accesses `self.future`, so it must only be called where `do_wake()` could be.
+    unsafe fn finish_cancel(&self) {
+        // Pin::set() drops the future in place, which is allowed by the pinning contract.
+        Pin::new_unchecked(&mut *self.future.get()).set(None);
+        self.unregister();
+        let out_status = RustCallStatus {
+            code: CALL_CANCELLED,
+            ..Default::default()
+        };
+        T::invoke_future_callback(
+            self.callback,
+            self.callback_data,
+            T::ReturnType::ffi_default(),
+            out_status,
+        );
+    }
+
+    /// Remove our entry from `CancellableFutures`, since it can't be cancelled anymore.
+    fn unregister(&self) {
+        if let Some(key) = self.cancel_key {
+            CancellableFutures::unregister(key, self as *const Self as *const ());
+        }
+    }
+
+    /// Cancel a future from the pointer stored in a `CancelHandle`
+    ///
+    /// SAFETY: `self_ptr` must come from `Weak::into_raw()` and still be owned by the handle.
+    unsafe fn cancel_raw(self_ptr: *const ()) {
+        let weak = ManuallyDrop::new(Weak::from_raw(self_ptr as *const Self));
+        if let Some(rust_future) = weak.upgrade() {
+            Pin::new_unchecked(rust_future).cancel();
+        }
+    }
+
+    /// Release the `Weak` reference stored in a `CancelHandle`
+    ///
+    /// SAFETY: `self_ptr` must come from `Weak::into_raw()` and must not be used afterwards.
+    unsafe fn release_raw(self_ptr: *const ()) {
+        drop(Weak::from_raw(self_ptr as *const Self))
+    }
+
     fn make_waker(self: &Pin<Arc<Self>>) -> Waker {
         // This is safe as long as we implement the waker interface correctly.
         unsafe {
@@ -371,6 +497,140 @@ where
     }
 }
 
+impl<F, T, UT> Drop for RustFuture<F, T, UT>
+where
+    F: Future<Output = T> + Send,
+    T: FfiConverter<UT>,
+{
+    fn drop(&mut self) {
+        // Futures that are dropped without completing, for example because the foreign executor
+        // was shutdown, still have an entry to remove.
+        if let Some(key) = self.cancel_key {
+            CancellableFutures::unregister(key, self as *const Self as *const ());
+        }
+    }
+}
+
+/// Weak reference to a `RustFuture` that can cancel it without knowing its type
+///
+/// We store raw pointers plus monomorphized functions rather than `Weak<dyn ...>`, since the
+/// future types aren't necessarily `'static`
+struct CancelHandle {
+    rust_future_ptr: *const (),
+    cancel: unsafe fn(*const ()),
+    release: unsafe fn(*const ()),
+}
+
+// SAFETY: `RustFuture` is `Send` + `Sync` and the pointer is only used through a `Weak` reference.
+unsafe impl Send for CancelHandle {}
+
+impl CancelHandle {
+    fn new<F, T, UT>(rust_future: &Arc<RustFuture<F, T, UT>>) -> Self
+    where
+        F: Future<Output = T> + Send,
+        T: FfiConverter<UT>,
+        UT: 'static,
+    {
+        Self {
+            rust_future_ptr: Weak::into_raw(Arc::downgrade(rust_future)) as *const (),
+            cancel: RustFuture::<F, T, UT>::cancel_raw,
+            release: RustFuture::<F, T, UT>::release_raw,
+        }
+    }
+
+    fn cancel(&self) {
+        unsafe { (self.cancel)(self.rust_future_ptr) }
+    }
+}
+
+impl Drop for CancelHandle {
+    fn drop(&mut self) {
+        unsafe { (self.release)(self.rust_future_ptr) }
+    }
+}
+
+/// Identifies a cancellable call: the component's `UniFfiTag` and the `callback_data` pointer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct CancelKey {
+    tag: TypeId,
+    callback_data: usize,
+}
+
+impl CancelKey {
+    fn new<UT: 'static>(callback_data: *const ()) -> Self {
+        Self {
+            tag: TypeId::of::<UT>(),
+            callback_data: callback_data as usize,
+        }
+    }
+}
+
+/// Registry of the in-flight futures that the foreign code can cancel
+///
+/// Only calls marked with [rust_future_cancellable] get an entry, which is removed once the
+/// future completes, is cancelled or is dropped.
+struct CancellableFutures;
+
+static CANCELLABLE_FUTURES: Lazy<Mutex<HashMap<CancelKey, CancelHandle>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+thread_local! {
+    // `callback_data` of the next scaffolding call on this thread, if the bindings marked it as
+    // cancellable.  0 means the next call isn't cancellable.
+    static CANCELLABLE_CALL: Cell<usize> = Cell::new(0);
+}
+
+impl CancellableFutures {
+    /// Was the call for `callback_data` marked as cancellable?
+    ///
+    /// This consumes the mark, so that it only applies to one call.
+    fn take_cancellable_call(callback_data: *const ()) -> bool {
+        !callback_data.is_null()
+            && CANCELLABLE_CALL.with(|c| c.replace(0)) == callback_data as usize
+    }
+
+    fn register(key: CancelKey, handle: CancelHandle) {
+        CANCELLABLE_FUTURES.lock().unwrap().insert(key, handle);
+    }
+
+    fn unregister(key: CancelKey, rust_future_ptr: *const ()) {
+        let mut futures = CANCELLABLE_FUTURES.lock().unwrap();
+        // Only remove the entry if it's ours, the pointer may have been reused by another call.
+        if futures.get(&key).map(|h| h.rust_future_ptr) == Some(rust_future_ptr) {
+            futures.remove(&key);
+        }
+    }
+
+    fn cancel(key: CancelKey) {
+        let handle = CANCELLABLE_FUTURES.lock().unwrap().remove(&key);
+        // Cancel outside of the lock, since that can end up invoking the foreign callback.
+        if let Some(handle) = handle {
+            handle.cancel();
+        }
+    }
+
+    #[cfg(test)]
+    fn contains(key: CancelKey) -> bool {
+        CANCELLABLE_FUTURES.lock().unwrap().contains_key(&key)
+    }
+}
+
+/// Mark the next async call on this thread as cancellable
+///
+/// This is called by the `ffi_[namespace]_rust_future_cancellable` scaffolding function, right
+/// before the scaffolding function of the async call that uses `callback_data`.
+pub fn rust_future_cancellable(callback_data: *const ()) {
+    CANCELLABLE_CALL.with(|c| c.set(callback_data as usize));
+}
+
+/// Cancel the future for a call from the foreign code
+///
+/// This is called by the `ffi_[namespace]_rust_future_cancel` scaffolding function.  It's a no-op
+/// if the future already completed, or if the call wasn't marked as cancellable.
+pub fn rust_future_cancel<UT: 'static>(callback_data: *const ()) {
+    CancellableFutures::cancel(CancelKey::new::<UT>(callback_data))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -416,8 +676,21 @@ mod tests {
 
     impl TestFutureEnvironment {
         fn new(eventloop: &Arc<MockEventLoop>) -> Self {
+            Self::new_with_cancellation(eventloop, false)
+        }
+
+        // Create a future whose call was marked as cancellable, like the bindings do with the
+        // `async_cancellation` option
+        fn new_cancellable(eventloop: &Arc<MockEventLoop>) -> Self {
+            Self::new_with_cancellation(eventloop, true)
+        }
+
+        fn new_with_cancellation(eventloop: &Arc<MockEventLoop>, cancellable: bool) -> Self {
             let foreign_result = Box::pin(None);
             let foreign_result_ptr = &*foreign_result as *const Option<_> as *const ();
+            if cancellable {
+                rust_future_cancellable(foreign_result_ptr);
+            }
 
             let rust_future = TestRustFuture::new(
                 MockFuture(None),
@@ -443,9 +716,27 @@ mod tests {
 
         fn complete_future(&self, value: Result<bool, String>) {
             unsafe {
-                (*self.rust_future.future.get()).0 = Some(value);
+                (*self.rust_future.future.get()).as_mut().unwrap().0 = Some(value);
             }
         }
+
+        fn cancel_key(&self) -> CancelKey {
+            let callback_data = &*self.foreign_result as *const Option<_> as *const ();
+            CancelKey::new::<crate::UniFfiTag>(callback_data)
+        }
+
+        fn cancel(&self) {
+            let callback_data = &*self.foreign_result as *const Option<_> as *const ();
+            rust_future_cancel::<crate::UniFfiTag>(callback_data);
+        }
+
+        fn is_registered(&self) -> bool {
+            CancellableFutures::contains(self.cancel_key())
+        }
+
+        fn future_dropped(&self) -> bool {
+            unsafe { (*self.rust_future.future.get()).is_none() }
+        }
     }
 
     #[test]
@@ -588,4 +879,120 @@ mod tests {
         drop(test_env);
         assert!(weak_ref.upgrade().is_none());
     }
+
+    #[test]
+    fn test_cancel() {
+        let eventloop = MockEventLoop::new();
+        let mut test_env = TestFutureEnvironment::new_cancellable(&eventloop);
+        test_env.wake();
+        eventloop.run_all_calls();
+
+        // Cancelling should schedule a wake, which drops the future and calls the callback
+        test_env.cancel();
+        assert_eq!(eventloop.call_count(), 1);
+        eventloop.run_all_calls();
+        let result = test_env
+            .foreign_result
+            .take()
+            .expect("Expected result to be set");
+        assert_eq!(result.status.code, CALL_CANCELLED);
+        assert!(test_env.future_dropped());
+        assert!(!test_env.is_registered());
+
+        // Future wakes and cancels shouldn't schedule any calls
+        test_env.wake();
+        test_env.cancel();
+        assert_eq!(eventloop.call_count(), 0);
+    }
+
+    #[test]
+    fn test_cancel_after_complete() {
+        let eventloop = MockEventLoop::new();
+        let mut test_env = TestFutureEnvironment::new_cancellable(&eventloop);
+        test_env.complete_future(Ok(true));
+        test_env.wake();
+        eventloop.run_all_calls();
+        let result = test_env
+            .foreign_result
+            .take()
+            .expect("Expected result to be set");
+        assert_eq!(result.status.code, 0);
+        // Completing the future removes its registry entry
+        assert!(!test_env.is_registered());
+
+        // The future is already complete, so cancelling should be a no-op
+        test_env.cancel();
+        assert_eq!(eventloop.call_count(), 0);
+        assert!(test_env.foreign_result.is_none());
+    }
+
+    // When the executor is shutdown, cancelling should still drop the future and call the
+    // callback, since the foreign code is waiting for it.
+    #[test]
+    fn test_cancel_after_executor_shutdown() {
+        let eventloop = MockEventLoop::new();
+        let mut test_env = TestFutureEnvironment::new_cancellable(&eventloop);
+        let weak_ref = test_env.rust_future_weak();
+        test_env.wake();
+        eventloop.run_all_calls();
+        eventloop.shutdown();
+
+        test_env.cancel();
+        let result = test_env
+            .foreign_result
+            .take()
+            .expect("Expected result to be set");
+        assert_eq!(result.status.code, CALL_CANCELLED);
+        assert!(test_env.future_dropped());
+        drop(test_env);
+        assert!(weak_ref.upgrade().is_none());
+    }
+
+    // Calls that the bindings didn't mark as cancellable don't get a registry entry, so they can't
+    // be cancelled
+    #[test]
+    fn test_cancel_unmarked() {
+        let eventloop = MockEventLoop::new();
+        let mut test_env = TestFutureEnvironment::new(&eventloop);
+        assert!(!test_env.is_registered());
+        test_env.wake();
+        eventloop.run_all_calls();
+
+        test_env.cancel();
+        assert_eq!(eventloop.call_count(), 0);
+        assert!(test_env.foreign_result.is_none());
+        assert!(!test_env.future_dropped());
+
+        test_env.complete_future(Ok(true));
+        test_env.wake();
+        eventloop.run_all_calls();
+        let result = test_env
+            .foreign_result
+            .take()
+            .expect("Expected result to be set");
+        assert_eq!(result.status.code, 0);
+    }
+
+    // The mark only applies to the next call on the same thread
+    #[test]
+    fn test_cancellable_mark_is_consumed() {
+        let eventloop = MockEventLoop::new();
+        let marked = TestFutureEnvironment::new_cancellable(&eventloop);
+        assert!(marked.is_registered());
+        let unmarked = TestFutureEnvironment::new(&eventloop);
+        assert!(!unmarked.is_registered());
+    }
+
+    // Futures that are dropped without completing remove their registry entry
+    #[test]
+    fn test_cancellable_drop_unregisters() {
+        let eventloop = MockEventLoop::new();
+        let test_env = TestFutureEnvironment::new_cancellable(&eventloop);
+        let key = test_env.cancel_key();
+        let weak_ref = test_env.rust_future_weak();
+        assert!(CancellableFutures::contains(key));
+        drop(test_env);
+        assert!(weak_ref.upgrade().is_none());
+        assert!(!CancellableFutures::contains(key));
+    }
 }
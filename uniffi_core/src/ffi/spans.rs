@@ -0,0 +1,297 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Report Rust spans to a foreign span sink.
+//!
+//! Foreign bindings generated with the `span_sink` config option can register a
+//! [SpanSinkCallback], which lets foreign tracing/APM tools see what the Rust code is doing.  Once
+//! a sink is registered, every scaffolding call reports a span for the exported function it calls.
+//! Async functions report their span each time the future is polled.
+//!
+//! Each span gets a unique id, which is passed with both its enter and exit events so that the
+//! foreign code can match them up.  A span can be entered and exited several times, for example
+//! once for each poll of a future, and always reports the same id.
+//!
+//! Rust code can report its own spans with [Span], or forward the spans of the `tracing` crate
+//! with [SpanSinkLayer] when the `tracing` feature is enabled.
+//!
+//! When no sink is registered, reporting a span costs a single atomic load.
+
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::atomic::{AtomicU64, AtomicUsize, Ordering},
+    task::{Context, Poll},
+};
+
+/// Callback that receives span events.  The bindings code registers at most one of these.
+///
+/// `event` is one of the [SpanEvent] values, `span_id` identifies the span, and
+/// `name_data`/`name_len` point to the UTF-8 span name, which is only valid for the duration of
+/// the call.
+///
+/// This callback can be invoked from any thread, including threads created by Rust.  Spans are
+/// properly nested on each thread.
+pub type SpanSinkCallback =
+    extern "C" fn(event: i8, span_id: u64, name_data: *const u8, name_len: i32);
+
+/// Span events passed to the [SpanSinkCallback]
+#[repr(i8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SpanEvent {
+    /// The current thread entered the span
+    Enter = 0,
+    /// The current thread exited the span
+    Exit = 1,
+}
+
+static SPAN_SINK_CALLBACK: AtomicUsize = AtomicUsize::new(0);
+
+// 0 is reserved for spans created while no sink was registered
+static NEXT_SPAN_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Set the global SpanSinkCallback.  This is called by the foreign bindings, normally during
+/// initialization.
+pub fn span_sink_set(callback: SpanSinkCallback) {
+    SPAN_SINK_CALLBACK.store(callback as usize, Ordering::Relaxed);
+}
+
+fn get_span_sink_callback() -> Option<SpanSinkCallback> {
+    match SPAN_SINK_CALLBACK.load(Ordering::Relaxed) {
+        0 => None,
+        // SAFETY: The below call is okay because we only store values in
+        // SPAN_SINK_CALLBACK that were cast from a SpanSinkCallback.
+        n => Some(unsafe { std::mem::transmute::<usize, SpanSinkCallback>(n) }),
+    }
+}
+
+/// Report a span event to the foreign span sink, if one is registered
+///
+/// Prefer [Span], which allocates the span ids and keeps the events balanced.
+pub fn report_span_event(event: SpanEvent, span_id: u64, name: &str) {
+    if let Some(callback) = get_span_sink_callback() {
+        let name_len = i32::try_from(name.len()).unwrap_or(i32::MAX);
+        callback(event as i8, span_id, name.as_ptr(), name_len);
+    }
+}
+
+/// Enter a new span, which is exited when the returned guard is dropped
+pub fn enter_span(name: &'static str) -> SpanGuard {
+    Span::new(name).enter()
+}
+
+/// A span reported to the foreign span sink
+///
+/// Spans created while no sink is registered are disabled, and never report anything.  This
+/// means that the sink never sees unbalanced events, even if it's registered or removed while the
+/// span is active.
+#[derive(Clone, Copy, Debug)]
+pub struct Span {
+    // 0 when the span is disabled
+    id: u64,
+    name: &'static str,
+}
+
+impl Span {
+    pub fn new(name: &'static str) -> Self {
+        let id = match get_span_sink_callback() {
+            Some(_) => NEXT_SPAN_ID.fetch_add(1, Ordering::Relaxed),
+            None => 0,
+        };
+        Self { id, name }
+    }
+
+    /// The id passed to the sink with this span's events, or `None` if the span is disabled
+    pub fn id(&self) -> Option<u64> {
+        (self.id != 0).then_some(self.id)
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Enter the span, which is exited when the returned guard is dropped
+    pub fn enter(&self) -> SpanGuard {
+        self.report(SpanEvent::Enter);
+        SpanGuard { span: *self }
+    }
+
+    /// Enter the span each time the future is polled
+    pub fn instrument<F: Future>(self, future: F) -> Instrumented<F> {
+        Instrumented { span: self, future }
+    }
+
+    fn report(&self, event: SpanEvent) {
+        if self.id != 0 {
+            report_span_event(event, self.id, self.name);
+        }
+    }
+}
+
+/// Guard returned by [Span::enter] and [enter_span]
+#[must_use = "the span is exited as soon as the guard is dropped"]
+pub struct SpanGuard {
+    span: Span,
+}
+
+impl Drop for SpanGuard {
+    fn drop(&mut self) {
+        self.span.report(SpanEvent::Exit);
+    }
+}
+
+/// Future returned by [Span::instrument]
+pub struct Instrumented<F> {
+    span: Span,
+    future: F,
+}
+
+impl<F: Future> Future for Instrumented<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Self::Output> {
+        let _guard = self.span.enter();
+        // SAFETY: `future` is structurally pinned: it's never moved out of `self`, `Instrumented`
+        // doesn't implement `Drop`, and it's only `Unpin` when `F` is.
+        unsafe { self.map_unchecked_mut(|instrumented| &mut instrumented.future) }.poll(context)
+    }
+}
+
+/// [tracing_subscriber::Layer] that reports the spans of the `tracing` crate to the foreign span
+/// sink.
+///
+/// ```ignore
+/// use tracing_subscriber::prelude::*;
+///
+/// tracing_subscriber::registry().with(uniffi::SpanSinkLayer).init();
+/// ```
+///
+/// Like other spans, `tracing` spans created while no sink is registered are never reported.
+#[cfg(feature = "tracing")]
+pub struct SpanSinkLayer;
+
+#[cfg(feature = "tracing")]
+impl<S> tracing_subscriber::Layer<S> for SpanSinkLayer
+where
+    S: tracing_core::Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
+{
+    fn on_new_span(
+        &self,
+        _attrs: &tracing_core::span::Attributes<'_>,
+        id: &tracing_core::span::Id,
+        ctx: tracing_subscriber::layer::Context<'_, S>,
+    ) {
+        if let Some(span) = ctx.span(id) {
+            let sink_span = Span::new(span.name());
+            span.extensions_mut().insert(sink_span);
+        }
+    }
+
+    fn on_enter(
+        &self,
+        id: &tracing_core::span::Id,
+        ctx: tracing_subscriber::layer::Context<'_, S>,
+    ) {
+        if let Some(span) = ctx.span(id) {
+            if let Some(sink_span) = span.extensions().get::<Span>() {
+                sink_span.report(SpanEvent::Enter);
+            }
+        }
+    }
+
+    fn on_exit(&self, id: &tracing_core::span::Id, ctx: tracing_subscriber::layer::Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            if let Some(sink_span) = span.extensions().get::<Span>() {
+                sink_span.report(SpanEvent::Exit);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::{slice, sync::Mutex};
+
+    static EVENTS: Mutex<Vec<(SpanEvent, u64, String)>> = Mutex::new(Vec::new());
+
+    extern "C" fn test_sink(event: i8, span_id: u64, name_data: *const u8, name_len: i32) {
+        let name = unsafe { slice::from_raw_parts(name_data, name_len as usize) };
+        let event = match event {
+            0 => SpanEvent::Enter,
+            _ => SpanEvent::Exit,
+        };
+        EVENTS
+            .lock()
+            .unwrap()
+            .push((event, span_id, String::from_utf8(name.to_vec()).unwrap()));
+    }
+
+    // Future that's pending the first time it's polled
+    struct YieldOnce(bool);
+
+    impl Future for YieldOnce {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<()> {
+            if self.0 {
+                Poll::Ready(())
+            } else {
+                self.0 = true;
+                context.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }
+    }
+
+    struct NoopWaker;
+
+    impl std::task::Wake for NoopWaker {
+        fn wake(self: std::sync::Arc<Self>) {}
+    }
+
+    // All in one test, since the sink is global
+    #[test]
+    fn test_span_events() {
+        // Nothing is reported for spans created without a sink, even once one is registered
+        let before = Span::new("before");
+        assert_eq!(before.id(), None);
+        span_sink_set(test_sink);
+        drop(before.enter());
+        {
+            let _outer = enter_span("outer");
+            let _inner = enter_span("inner");
+        }
+        let outer = EVENTS.lock().unwrap()[0].1;
+        let inner = EVENTS.lock().unwrap()[1].1;
+        assert_ne!(outer, inner);
+        assert_eq!(
+            std::mem::take(&mut *EVENTS.lock().unwrap()),
+            vec![
+                (SpanEvent::Enter, outer, "outer".to_string()),
+                (SpanEvent::Enter, inner, "inner".to_string()),
+                (SpanEvent::Exit, inner, "inner".to_string()),
+                (SpanEvent::Exit, outer, "outer".to_string()),
+            ]
+        );
+
+        // Futures enter their span on each poll, with the same id
+        let span = Span::new("future");
+        let id = span.id().unwrap();
+        let mut future = Box::pin(span.instrument(YieldOnce(false)));
+        let waker = std::task::Waker::from(std::sync::Arc::new(NoopWaker));
+        let mut context = Context::from_waker(&waker);
+        assert!(future.as_mut().poll(&mut context).is_pending());
+        assert!(future.as_mut().poll(&mut context).is_ready());
+        assert_eq!(
+            std::mem::take(&mut *EVENTS.lock().unwrap()),
+            vec![
+                (SpanEvent::Enter, id, "future".to_string()),
+                (SpanEvent::Exit, id, "future".to_string()),
+                (SpanEvent::Enter, id, "future".to_string()),
+                (SpanEvent::Exit, id, "future".to_string()),
+            ]
+        );
+    }
+}
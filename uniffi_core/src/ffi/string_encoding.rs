@@ -0,0 +1,131 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Choose how strings are encoded when they cross the FFI.
+//!
+//! Strings are transferred as UTF-8 by default.  Foreign languages that store strings as UTF-16,
+//! like Kotlin/Java, can avoid transcoding every string by switching the wire encoding to UTF-16LE
+//! with the `string_encoding` config option.  Those bindings then call [string_encoding_set] before
+//! anything else, and the `String` [crate::FfiConverter] impl encodes and decodes strings to match.
+//! Bindings that use the default don't call anything.
+//!
+//! The encoding is a property of the whole library rather than a single component, since Rust
+//! code never knows which bindings a string is going to.  Once it's set, bindings that ask for a
+//! different encoding are refused.  Bindings that use the default can't be checked this way, so
+//! every binding that loads a library with UTF-16 strings must set the option.
+
+use anyhow::{bail, Result};
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Wire encoding for strings
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StringEncoding {
+    /// UTF-8, which Rust strings use.  This is the default.
+    Utf8 = 1,
+    /// UTF-16, with little-endian code units
+    Utf16 = 2,
+}
+
+// 0 means that no bindings have set the encoding yet, which behaves like `Utf8`.
+static STRING_ENCODING: AtomicU8 = AtomicU8::new(0);
+
+impl StringEncoding {
+    fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(Self::Utf8),
+            2 => Some(Self::Utf16),
+            _ => None,
+        }
+    }
+
+    /// The encoding that strings are currently transferred with
+    pub fn current() -> Self {
+        Self::from_u8(STRING_ENCODING.load(Ordering::Relaxed)).unwrap_or(Self::Utf8)
+    }
+}
+
+/// Set the string wire encoding.  This is called by the foreign bindings during initialization.
+///
+/// Returns `false` if `encoding` isn't a [StringEncoding] value, or if other bindings already set
+/// a different encoding.  Setting the same encoding again is fine.
+pub fn string_encoding_set(encoding: u8) -> bool {
+    set_encoding(&STRING_ENCODING, encoding)
+}
+
+fn set_encoding(state: &AtomicU8, encoding: u8) -> bool {
+    if StringEncoding::from_u8(encoding).is_none() {
+        return false;
+    }
+    match state.compare_exchange(0, encoding, Ordering::Relaxed, Ordering::Relaxed) {
+        Ok(_) => true,
+        Err(current) => current == encoding,
+    }
+}
+
+/// Encode a string for the FFI, using the current encoding
+pub fn encode_string(s: String) -> Vec<u8> {
+    encode_string_as(s, StringEncoding::current())
+}
+
+/// Decode a string from the FFI, using the current encoding
+pub fn decode_string(bytes: Vec<u8>) -> Result<String> {
+    decode_string_as(bytes, StringEncoding::current())
+}
+
+fn encode_string_as(s: String, encoding: StringEncoding) -> Vec<u8> {
+    match encoding {
+        StringEncoding::Utf8 => s.into_bytes(),
+        StringEncoding::Utf16 => s.encode_utf16().flat_map(u16::to_le_bytes).collect(),
+    }
+}
+
+fn decode_string_as(bytes: Vec<u8>, encoding: StringEncoding) -> Result<String> {
+    match encoding {
+        StringEncoding::Utf8 => Ok(String::from_utf8(bytes)?),
+        StringEncoding::Utf16 => {
+            if bytes.len() % 2 != 0 {
+                bail!("UTF-16 string has an odd number of bytes");
+            }
+            let units = bytes
+                .chunks_exact(2)
+                .map(|pair| u16::from_le_bytes([pair[0], pair[1]]));
+            Ok(char::decode_utf16(units).collect::<Result<String, _>>()?)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // These tests don't change the global encoding, since other tests lower and lift strings.
+    #[test]
+    fn test_set_encoding() {
+        let state = AtomicU8::new(0);
+        assert!(!set_encoding(&state, 3));
+        assert!(set_encoding(&state, StringEncoding::Utf16 as u8));
+        assert!(set_encoding(&state, StringEncoding::Utf16 as u8));
+        assert!(!set_encoding(&state, StringEncoding::Utf8 as u8));
+        assert_eq!(
+            StringEncoding::from_u8(state.load(Ordering::Relaxed)),
+            Some(StringEncoding::Utf16)
+        );
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let s = "h\u{e9}\u{1f600}".to_string();
+        let utf8 = encode_string_as(s.clone(), StringEncoding::Utf8);
+        assert_eq!(utf8, s.as_bytes());
+        assert_eq!(decode_string_as(utf8, StringEncoding::Utf8).unwrap(), s);
+
+        let utf16 = encode_string_as(s.clone(), StringEncoding::Utf16);
+        assert_eq!(utf16, vec![b'h', 0, 0xe9, 0, 0x3d, 0xd8, 0x00, 0xde]);
+        assert_eq!(decode_string_as(utf16, StringEncoding::Utf16).unwrap(), s);
+        // Odd lengths and lone surrogates are rejected
+        assert!(decode_string_as(vec![b'h', 0, b'i'], StringEncoding::Utf16).is_err());
+        assert!(decode_string_as(vec![0x3d, 0xd8], StringEncoding::Utf16).is_err());
+    }
+}
@@ -5,8 +5,15 @@
 /// This module contains builtin `FFIConverter` implementations.  These cover:
 ///   - Simple privitive types: u8, i32, String, Arc<T>, etc
 ///   - Composite types: Vec<T>, Option<T>, etc.
+///   - Fixed-size byte arrays, `[u8; N]`, which are passed as bytes.
+///   - `Cow<[T]>`, which is passed like `Vec<T>`.
+///   - `NonZero*` integers, which are passed as their underlying integer type.
+///   - `Range` and `RangeInclusive` of integers, which are passed as their start and end.
 ///   - SystemTime and Duration, which maybe shouldn`t be built-in, but have been historically and
 ///     we want to continue to support them for now.
+///   - `chrono::DateTime<Utc>`, when the `chrono` feature is enabled, which is passed as a timestamp.
+///   - `serde_json::Value`, when the `serde_json` feature is enabled, which is passed as its JSON
+///     text.
 ///
 /// As described in
 /// https://mozilla.github.io/uniffi-rs/internals/lifting_and_lowering.html#code-generation-and-the-fficonverter-trait,
@@ -22,16 +29,23 @@
 /// consumer crates.  To do this, it defines blanket impls like `impl<UT> FFIConverter<UT> for u8`.
 /// "UT" means an abitrary `UniFfiTag` type.
 use crate::{
-    check_remaining, ffi_converter_default_return, ffi_converter_rust_buffer_lift_and_lower,
-    lower_into_rust_buffer, metadata, try_lift_from_rust_buffer, FfiConverter, FutureCallback,
-    MetadataBuffer, Result, RustBuffer, RustCallStatus, UnexpectedUniFFICallbackError,
+    check_remaining, decode_string, empty_buffer_sentinels_enabled, encode_string,
+    ffi_converter_default_return, ffi_converter_rust_buffer_lift_and_lower, lower_into_rust_buffer,
+    metadata, try_lift_from_rust_buffer, FfiConverter, FutureCallback, MetadataBuffer, Result,
+    RustBuffer, RustCallStatus, StringEncoding, UnexpectedUniFFICallbackError,
 };
 use anyhow::bail;
 use bytes::buf::{Buf, BufMut};
 use paste::paste;
 use std::{
+    borrow::Cow,
     collections::HashMap,
     convert::{Infallible, TryFrom},
+    num::{
+        NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroU16, NonZeroU32, NonZeroU64,
+        NonZeroU8,
+    },
+    ops::{Range, RangeInclusive},
     time::{Duration, SystemTime},
 };
 
@@ -81,6 +95,110 @@ impl_ffi_converter_for_num_primitive!(i64, metadata::codes::TYPE_I64);
 impl_ffi_converter_for_num_primitive!(f32, metadata::codes::TYPE_F32);
 impl_ffi_converter_for_num_primitive!(f64, metadata::codes::TYPE_F64);
 
+/// Implementation of `FfiConverter` for the `NonZero*` integer types.
+///
+/// These are passed exactly like the underlying primitive and use the same metadata, so foreign
+/// code sees a plain integer.  The non-zero invariant is checked when lifting: if the foreign side
+/// passes a zero, we return an error rather than constructing an invalid value.
+macro_rules! impl_ffi_converter_for_non_zero {
+    ($T:ty, $Inner:ty) => {
+        unsafe impl<UT> FfiConverter<UT> for $T {
+            ffi_converter_default_return!(UT);
+
+            type FfiType = $Inner;
+
+            fn lower(obj: $T) -> Self::FfiType {
+                obj.get()
+            }
+
+            fn try_lift(v: Self::FfiType) -> Result<$T> {
+                match <$T>::new(v) {
+                    Some(v) => Ok(v),
+                    None => bail!("unexpected zero value for {}", stringify!($T)),
+                }
+            }
+
+            fn write(obj: $T, buf: &mut Vec<u8>) {
+                <$Inner as FfiConverter<UT>>::write(obj.get(), buf)
+            }
+
+            fn try_read(buf: &mut &[u8]) -> Result<$T> {
+                <Self as FfiConverter<UT>>::try_lift(<$Inner as FfiConverter<UT>>::try_read(buf)?)
+            }
+
+            const TYPE_ID_META: MetadataBuffer = <$Inner as FfiConverter<UT>>::TYPE_ID_META;
+        }
+    };
+}
+
+impl_ffi_converter_for_non_zero!(NonZeroU8, u8);
+impl_ffi_converter_for_non_zero!(NonZeroI8, i8);
+impl_ffi_converter_for_non_zero!(NonZeroU16, u16);
+impl_ffi_converter_for_non_zero!(NonZeroI16, i16);
+impl_ffi_converter_for_non_zero!(NonZeroU32, u32);
+impl_ffi_converter_for_non_zero!(NonZeroI32, i32);
+impl_ffi_converter_for_non_zero!(NonZeroU64, u64);
+impl_ffi_converter_for_non_zero!(NonZeroI64, i64);
+
+/// Implementation of `FfiConverter` for `Range` and `RangeInclusive` of the integer types.
+///
+/// These are serialized as their start followed by their end, like a record with two fields.
+/// Whether the end is included is part of the type, so it isn't serialized.  Empty and inverted
+/// ranges, like `5..5` or `5..3`, are passed unchanged.
+macro_rules! impl_ffi_converter_for_range {
+    ($T:ty) => {
+        unsafe impl<UT> FfiConverter<UT> for Range<$T> {
+            ffi_converter_rust_buffer_lift_and_lower!(UT);
+            ffi_converter_default_return!(UT);
+
+            fn write(obj: Range<$T>, buf: &mut Vec<u8>) {
+                <$T as FfiConverter<UT>>::write(obj.start, buf);
+                <$T as FfiConverter<UT>>::write(obj.end, buf);
+            }
+
+            fn try_read(buf: &mut &[u8]) -> Result<Range<$T>> {
+                let start = <$T as FfiConverter<UT>>::try_read(buf)?;
+                let end = <$T as FfiConverter<UT>>::try_read(buf)?;
+                Ok(start..end)
+            }
+
+            const TYPE_ID_META: MetadataBuffer =
+                MetadataBuffer::from_code(metadata::codes::TYPE_RANGE)
+                    .concat(<$T as FfiConverter<UT>>::TYPE_ID_META);
+        }
+
+        unsafe impl<UT> FfiConverter<UT> for RangeInclusive<$T> {
+            ffi_converter_rust_buffer_lift_and_lower!(UT);
+            ffi_converter_default_return!(UT);
+
+            fn write(obj: RangeInclusive<$T>, buf: &mut Vec<u8>) {
+                let (start, end) = obj.into_inner();
+                <$T as FfiConverter<UT>>::write(start, buf);
+                <$T as FfiConverter<UT>>::write(end, buf);
+            }
+
+            fn try_read(buf: &mut &[u8]) -> Result<RangeInclusive<$T>> {
+                let start = <$T as FfiConverter<UT>>::try_read(buf)?;
+                let end = <$T as FfiConverter<UT>>::try_read(buf)?;
+                Ok(start..=end)
+            }
+
+            const TYPE_ID_META: MetadataBuffer =
+                MetadataBuffer::from_code(metadata::codes::TYPE_RANGE_INCLUSIVE)
+                    .concat(<$T as FfiConverter<UT>>::TYPE_ID_META);
+        }
+    };
+}
+
+impl_ffi_converter_for_range!(u8);
+impl_ffi_converter_for_range!(i8);
+impl_ffi_converter_for_range!(u16);
+impl_ffi_converter_for_range!(i16);
+impl_ffi_converter_for_range!(u32);
+impl_ffi_converter_for_range!(i32);
+impl_ffi_converter_for_range!(u64);
+impl_ffi_converter_for_range!(i64);
+
 /// Support for passing boolean values via the FFI.
 ///
 /// Booleans are passed as an `i8` in order to avoid problems with handling
@@ -181,12 +299,14 @@ unsafe impl<UT> FfiConverter<UT> for Infallible {
 /// Unlike many other implementations of `FfiConverter`, this passes a struct containing
 /// a raw pointer rather than copying the data from one side to the other. This is a
 /// safety hazard, but turns out to be pretty nice for useability. This struct
-/// *must* be a valid `RustBuffer` and it *must* contain valid utf-8 data (in other
-/// words, it *must* be a `Vec<u8>` suitable for use as an actual rust `String`).
+/// *must* be a valid `RustBuffer` and it *must* contain a validly encoded string.
 ///
 /// When serialized in a buffer, strings are represented as a i32 byte length
-/// followed by utf8-encoded bytes. (It's a signed integer because unsigned types are
+/// followed by the encoded bytes. (It's a signed integer because unsigned types are
 /// currently experimental in Kotlin).
+///
+/// Strings are encoded as utf8, unless the bindings switched to utf16 with
+/// [crate::string_encoding_set].
 unsafe impl<UT> FfiConverter<UT> for String {
     ffi_converter_default_return!(UT);
 
@@ -196,27 +316,32 @@ unsafe impl<UT> FfiConverter<UT> for String {
     // important that it consume ownership of the String, which is relinquished to the
     // foreign language code (and can be restored by it passing the pointer back).
     fn lower(obj: String) -> Self::FfiType {
-        RustBuffer::from_vec(obj.into_bytes())
+        RustBuffer::from_vec(encode_string(obj))
     }
 
     // The argument here *must* be a uniquely-owned `RustBuffer` previously obtained
-    // from `lower` above, and hence must be the bytes of a valid rust string.
+    // from `lower` above, and hence must be the bytes of a valid string.
     fn try_lift(v: Self::FfiType) -> Result<String> {
         let v = v.destroy_into_vec();
-        // This turns the buffer back into a `String` without copying the data
-        // and without re-checking it for validity of the utf8. If the `RustBuffer`
-        // came from a valid String then there's no point in re-checking the utf8,
-        // and if it didn't then bad things are probably going to happen regardless
-        // of whether we check for valid utf8 data or not.
-        Ok(unsafe { String::from_utf8_unchecked(v) })
+        match StringEncoding::current() {
+            // This turns the buffer back into a `String` without copying the data
+            // and without re-checking it for validity of the utf8. If the `RustBuffer`
+            // came from a valid String then there's no point in re-checking the utf8,
+            // and if it didn't then bad things are probably going to happen regardless
+            // of whether we check for valid utf8 data or not.
+            StringEncoding::Utf8 => Ok(unsafe { String::from_utf8_unchecked(v) }),
+            // utf16 needs to be transcoded anyway, which checks it.
+            StringEncoding::Utf16 => decode_string(v),
+        }
     }
 
     fn write(obj: String, buf: &mut Vec<u8>) {
+        let bytes = encode_string(obj);
         // N.B. `len()` gives us the length in bytes, not in chars or graphemes.
         // TODO: it would be nice not to panic here.
-        let len = i32::try_from(obj.len()).unwrap();
+        let len = i32::try_from(bytes.len()).unwrap();
         buf.put_i32(len); // We limit strings to u32::MAX bytes
-        buf.put(obj.as_bytes());
+        buf.put(bytes.as_slice());
     }
 
     fn try_read(buf: &mut &[u8]) -> Result<String> {
@@ -227,7 +352,7 @@ unsafe impl<UT> FfiConverter<UT> for String {
         // But in the specific case of `<&[u8] as Buf>` it returns the full slice,
         // so there is no risk of having less than `len` bytes available here.
         let bytes = &buf.chunk()[..len];
-        let res = String::from_utf8(bytes.to_vec())?;
+        let res = decode_string(bytes.to_vec())?;
         buf.advance(len);
         Ok(res)
     }
@@ -287,6 +412,70 @@ unsafe impl<UT> FfiConverter<UT> for SystemTime {
         MetadataBuffer::from_code(metadata::codes::TYPE_SYSTEM_TIME);
 }
 
+/// Support for passing `chrono::DateTime<Utc>` values via the FFI.
+///
+/// These use the same representation as `SystemTime`, so they map to the same timestamp types
+/// in the foreign bindings.  The foreign timestamp types can represent instants outside of the
+/// range of `DateTime`, which fail to lift.
+#[cfg(feature = "chrono")]
+unsafe impl<UT> FfiConverter<UT> for chrono::DateTime<chrono::Utc> {
+    ffi_converter_rust_buffer_lift_and_lower!(UT);
+    ffi_converter_default_return!(UT);
+
+    fn write(obj: chrono::DateTime<chrono::Utc>, buf: &mut Vec<u8>) {
+        <SystemTime as FfiConverter<UT>>::write(obj.into(), buf)
+    }
+
+    fn try_read(buf: &mut &[u8]) -> Result<chrono::DateTime<chrono::Utc>> {
+        use chrono::TimeZone;
+
+        check_remaining(buf, 12)?;
+        let seconds = buf.get_i64();
+        let nanos = buf.get_u32();
+        if nanos >= 1_000_000_000 {
+            bail!("Invalid timestamp: {nanos} nanoseconds");
+        }
+        // Before the epoch, the nanoseconds are subtracted from the seconds like for `SystemTime`,
+        // while chrono always adds them
+        let (seconds, nanos) = if seconds >= 0 || nanos == 0 {
+            (Some(seconds), nanos)
+        } else {
+            (seconds.checked_sub(1), 1_000_000_000 - nanos)
+        };
+        match seconds.and_then(|seconds| chrono::Utc.timestamp_opt(seconds, nanos).single()) {
+            Some(datetime) => Ok(datetime),
+            None => bail!("Timestamp out of range for chrono::DateTime"),
+        }
+    }
+
+    const TYPE_ID_META: MetadataBuffer =
+        MetadataBuffer::from_code(metadata::codes::TYPE_SYSTEM_TIME);
+}
+
+/// Support for passing dynamic JSON values via the FFI.
+///
+/// These are written as their serialized JSON text, using the `String` representation, and the
+/// foreign bindings parse them into their own dynamic JSON types.  Integers that fit a `u64` or an
+/// `i64` round-trip exactly, other numbers are `f64`s.
+#[cfg(feature = "serde_json")]
+unsafe impl<UT> FfiConverter<UT> for serde_json::Value {
+    ffi_converter_rust_buffer_lift_and_lower!(UT);
+    ffi_converter_default_return!(UT);
+
+    fn write(obj: serde_json::Value, buf: &mut Vec<u8>) {
+        <String as FfiConverter<UT>>::write(obj.to_string(), buf)
+    }
+
+    fn try_read(buf: &mut &[u8]) -> Result<serde_json::Value> {
+        Ok(serde_json::from_str(
+            &<String as FfiConverter<UT>>::try_read(buf)?,
+        )?)
+    }
+
+    const TYPE_ID_META: MetadataBuffer =
+        MetadataBuffer::from_code(metadata::codes::TYPE_JSON_VALUE);
+}
+
 /// Support for passing duration values via the FFI.
 ///
 /// Duration values are currently always passed by serializing to a buffer.
@@ -314,15 +503,40 @@ unsafe impl<UT> FfiConverter<UT> for Duration {
 
 /// Support for passing optional values via the FFI.
 ///
+/// Like `ffi_converter_rust_buffer_lift_and_lower!`, but empty values can also be passed as an
+/// empty buffer.  See `uniffi_core/src/ffi/empty_buffers.rs` for details.
+macro_rules! ffi_converter_rust_buffer_lift_and_lower_or_empty {
+    ($uniffi_tag:ty, $is_empty:expr, $empty:expr) => {
+        type FfiType = RustBuffer;
+
+        fn lower(v: Self) -> RustBuffer {
+            if empty_buffer_sentinels_enabled() && ($is_empty)(&v) {
+                return RustBuffer::new();
+            }
+            lower_into_rust_buffer::<Self, $uniffi_tag>(v)
+        }
+
+        fn try_lift(buf: RustBuffer) -> Result<Self> {
+            // Serialized values are never empty, so this can only be an empty value
+            if buf.is_empty() {
+                buf.destroy();
+                return Ok($empty);
+            }
+            try_lift_from_rust_buffer::<Self, $uniffi_tag>(buf)
+        }
+    };
+}
+
 /// Optional values are currently always passed by serializing to a buffer.
 /// We write either a zero byte for `None`, or a one byte followed by the containing
 /// item for `Some`.
 ///
 /// In future we could do the same optimization as rust uses internally, where the
 /// `None` option is represented as a null pointer and the `Some` as a valid pointer,
-/// but that seems more fiddly and less safe in the short term, so it can wait.
+/// but that seems more fiddly and less safe in the short term, so it can wait.  `None` can be
+/// passed as an empty buffer though, which avoids the allocation.
 unsafe impl<UT, T: FfiConverter<UT>> FfiConverter<UT> for Option<T> {
-    ffi_converter_rust_buffer_lift_and_lower!(UT);
+    ffi_converter_rust_buffer_lift_and_lower_or_empty!(UT, Option::is_none, None);
     ffi_converter_default_return!(UT);
 
     fn write(obj: Option<T>, buf: &mut Vec<u8>) {
@@ -356,9 +570,9 @@ unsafe impl<UT, T: FfiConverter<UT>> FfiConverter<UT> for Option<T> {
 ///
 /// Ideally we would pass `Vec<u8>` directly as a `RustBuffer` rather
 /// than serializing, and perhaps even pass other vector types using a
-/// similar struct. But that's for future work.
+/// similar struct. But that's for future work.  Empty vectors can be passed as an empty buffer.
 unsafe impl<UT, T: FfiConverter<UT>> FfiConverter<UT> for Vec<T> {
-    ffi_converter_rust_buffer_lift_and_lower!(UT);
+    ffi_converter_rust_buffer_lift_and_lower_or_empty!(UT, Vec::is_empty, Vec::new());
     ffi_converter_default_return!(UT);
 
     fn write(obj: Vec<T>, buf: &mut Vec<u8>) {
@@ -384,6 +598,93 @@ unsafe impl<UT, T: FfiConverter<UT>> FfiConverter<UT> for Vec<T> {
         MetadataBuffer::from_code(metadata::codes::TYPE_VEC).concat(T::TYPE_ID_META);
 }
 
+// The length of `[u8; N]`, which fails to compile for arrays that are too long to pass
+struct ByteArrayLen<const N: usize>;
+
+impl<const N: usize> ByteArrayLen<N> {
+    const VALUE: i32 = {
+        assert!(
+            N <= i32::MAX as usize,
+            "byte arrays are limited to i32::MAX bytes"
+        );
+        N as i32
+    };
+}
+
+/// Support for passing fixed-size byte arrays via the FFI, for example keys and hashes.
+///
+/// These are passed exactly like `bytes`, so foreign code sees its regular byte array type.  The
+/// length is recorded in the metadata, so the bindings can check it before lowering, and it's
+/// checked again when lifting: if the foreign side passes the wrong number of bytes, we return an
+/// error rather than truncating or padding the array.
+unsafe impl<UT, const N: usize> FfiConverter<UT> for [u8; N] {
+    ffi_converter_rust_buffer_lift_and_lower!(UT);
+    ffi_converter_default_return!(UT);
+
+    fn write(obj: [u8; N], buf: &mut Vec<u8>) {
+        buf.put_i32(ByteArrayLen::<N>::VALUE);
+        buf.put_slice(&obj);
+    }
+
+    fn try_read(buf: &mut &[u8]) -> Result<[u8; N]> {
+        check_remaining(buf, 4)?;
+        let len = usize::try_from(buf.get_i32())?;
+        if len != N {
+            bail!("expected a byte array of length {N}, got {len} bytes");
+        }
+        check_remaining(buf, N)?;
+        let mut array = [0; N];
+        buf.copy_to_slice(&mut array);
+        Ok(array)
+    }
+
+    const TYPE_ID_META: MetadataBuffer =
+        MetadataBuffer::from_code(metadata::codes::TYPE_BYTE_ARRAY)
+            .concat_u32(ByteArrayLen::<N>::VALUE as u32);
+}
+
+// `Cow<[u8]>` is passed as `bytes`, which is written the same way as a sequence of `u8`
+const fn cow_slice_type_id_meta(item: MetadataBuffer) -> MetadataBuffer {
+    if item.size == 1 && item.bytes[0] == metadata::codes::TYPE_U8 {
+        MetadataBuffer::from_code(metadata::codes::TYPE_BYTES)
+    } else {
+        MetadataBuffer::from_code(metadata::codes::TYPE_VEC).concat(item)
+    }
+}
+
+/// Support for passing `Cow<[T]>` via the FFI.
+///
+/// These are passed exactly like `Vec<T>`, so foreign code sees a regular sequence, except for
+/// `Cow<[u8]>`, which foreign code sees as its byte array type.  Lowering a borrowed slice clones
+/// its items into the buffer, and lifting always returns `Cow::Owned`.
+unsafe impl<'a, UT, T> FfiConverter<UT> for Cow<'a, [T]>
+where
+    T: FfiConverter<UT> + Clone,
+{
+    type FfiType = RustBuffer;
+    ffi_converter_default_return!(UT);
+
+    // Go through `Vec<T>` for lowering and lifting too, since it also handles empty buffers
+    fn lower(obj: Cow<'a, [T]>) -> RustBuffer {
+        <Vec<T> as FfiConverter<UT>>::lower(obj.into_owned())
+    }
+
+    fn try_lift(buf: RustBuffer) -> Result<Cow<'a, [T]>> {
+        Ok(Cow::Owned(<Vec<T> as FfiConverter<UT>>::try_lift(buf)?))
+    }
+
+    fn write(obj: Cow<'a, [T]>, buf: &mut Vec<u8>) {
+        <Vec<T> as FfiConverter<UT>>::write(obj.into_owned(), buf)
+    }
+
+    fn try_read(buf: &mut &[u8]) -> Result<Cow<'a, [T]>> {
+        Ok(Cow::Owned(<Vec<T> as FfiConverter<UT>>::try_read(buf)?))
+    }
+
+    const TYPE_ID_META: MetadataBuffer =
+        cow_slice_type_id_meta(<T as FfiConverter<UT>>::TYPE_ID_META);
+}
+
 /// Support for associative arrays via the FFI.
 /// Note that because of webidl limitations,
 /// the key must always be of the String type.
@@ -391,13 +692,13 @@ unsafe impl<UT, T: FfiConverter<UT>> FfiConverter<UT> for Vec<T> {
 /// HashMaps are currently always passed by serializing to a buffer.
 /// We write a `i32` entries count followed by each entry (string
 /// key followed by the value) in turn.
-/// (It's a signed type due to limits of the JVM).
+/// (It's a signed type due to limits of the JVM).  Empty maps can be passed as an empty buffer.
 unsafe impl<K, V, UT> FfiConverter<UT> for HashMap<K, V>
 where
     K: FfiConverter<UT> + std::hash::Hash + Eq,
     V: FfiConverter<UT>,
 {
-    ffi_converter_rust_buffer_lift_and_lower!(UT);
+    ffi_converter_rust_buffer_lift_and_lower_or_empty!(UT, HashMap::is_empty, HashMap::new());
     ffi_converter_default_return!(UT);
 
     fn write(obj: HashMap<K, V>, buf: &mut Vec<u8>) {
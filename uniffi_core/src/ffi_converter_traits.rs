@@ -2,7 +2,7 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use std::sync::Arc;
+use std::{borrow::Borrow, sync::Arc};
 
 use crate::{
     try_lift_from_rust_buffer, FfiDefault, MetadataBuffer, Result, RustBuffer, RustCallStatus,
@@ -57,9 +57,8 @@ pub unsafe trait FfiConverter<UT>: Sized {
     /// which values of the target rust type can be converted.
     ///
     /// For complex data types, we currently recommend using `RustBuffer` and serializing
-    /// the data for transfer. In theory it could be possible to build a matching
-    /// `#[repr(C)]` struct for a complex data type and pass that instead, but explicit
-    /// serialization is simpler and safer as a starting point.
+    /// the data for transfer.  The exception is `#[uniffi(pod)]` records, which use themselves
+    /// as their `FfiType` and are passed by value.
     type FfiType;
 
     /// The type that should be returned by scaffolding functions for this type.
@@ -202,6 +201,23 @@ pub unsafe trait FfiConverterArc<UT> {
     const TYPE_ID_META: MetadataBuffer;
 }
 
+/// Lift arguments that exported functions take by reference
+///
+/// The scaffolding lifts a `LiftType` and passes a reference to it for `&Self` arguments.  This is
+/// `Self` for most types, and `Arc<Self>` for objects, which don't implement `FfiConverter`
+/// themselves.  The `Arc` impls are generated along with the `FfiConverterArc` impls.
+///
+/// ## Safety
+///
+/// This has the same safety considerations as FfiConverter
+pub unsafe trait LiftRef<UT> {
+    type LiftType: FfiConverter<UT> + Borrow<Self>;
+}
+
+unsafe impl<T: FfiConverter<UT>, UT> LiftRef<UT> for T {
+    type LiftType = T;
+}
+
 unsafe impl<T, UT> FfiConverter<UT> for Arc<T>
 where
     T: FfiConverterArc<UT> + ?Sized,
@@ -44,7 +44,7 @@ mod ffi_converter_traits;
 pub mod metadata;
 
 pub use ffi::*;
-pub use ffi_converter_traits::{FfiConverter, FfiConverterArc};
+pub use ffi_converter_traits::{FfiConverter, FfiConverterArc, LiftRef};
 pub use metadata::*;
 
 // Re-export the libs that we use in the generated code,
@@ -55,6 +55,8 @@ pub mod deps {
     pub use async_compat;
     pub use bytes;
     pub use log;
+    #[cfg(feature = "serde_json")]
+    pub use serde_json;
     pub use static_assertions;
 }
 
@@ -117,6 +119,26 @@ pub const fn check_compatible_version(bindgen_version: &'static str) -> bool {
         && package_version.len() < 10
 }
 
+/// The version of this crate, packed as `major * 1_000_000 + minor * 1_000 + patch`.
+///
+/// The scaffolding returns this from `ffi_<namespace>_uniffi_runtime_version`, so that bindings
+/// generated with the `runtime_version` config option can check the version they're pinned to.
+pub const PACKED_PACKAGE_VERSION: u32 = parse_version_component(env!("CARGO_PKG_VERSION_MAJOR"))
+    * 1_000_000
+    + parse_version_component(env!("CARGO_PKG_VERSION_MINOR")) * 1_000
+    + parse_version_component(env!("CARGO_PKG_VERSION_PATCH"));
+
+const fn parse_version_component(component: &str) -> u32 {
+    let bytes = component.as_bytes();
+    let mut value = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        value = value * 10 + (bytes[i] - b'0') as u32;
+        i += 1;
+    }
+    value
+}
+
 /// Assert that the uniffi runtime version matches an expected value.
 ///
 /// This is a helper hook for the generated Rust scaffolding, to produce a compile-time
@@ -310,7 +332,10 @@ macro_rules! do_ffi_converter_forward {
 
 #[cfg(test)]
 mod test {
-    use super::{FfiConverter, UniFfiTag};
+    use super::{FfiConverter, RustBuffer, UniFfiTag};
+    use std::collections::HashMap;
+    use std::num::NonZeroU32;
+    use std::ops::{Range, RangeInclusive};
     use std::time::{Duration, SystemTime};
 
     #[test]
@@ -337,4 +362,145 @@ mod test {
             "Expected results after lowering and lifting to be equal"
         )
     }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_roundtrip() {
+        use chrono::{DateTime, TimeZone, Utc};
+
+        for expected in [
+            Utc.timestamp_opt(100, 100).unwrap(),
+            Utc.timestamp_opt(-100, 100).unwrap(),
+        ] {
+            let result = <DateTime<Utc> as FfiConverter<UniFfiTag>>::try_lift(
+                <DateTime<Utc> as FfiConverter<UniFfiTag>>::lower(expected),
+            )
+            .expect("Failed to lift!");
+            assert_eq!(expected, result)
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_out_of_range() {
+        use bytes::BufMut;
+        use chrono::{DateTime, Utc};
+
+        // Kotlin's `Instant.MAX` and `Instant.MIN`, and the extremes of the representation
+        for (seconds, nanos) in [
+            (31_556_889_864_403_199, 999_999_999),
+            (-31_557_014_167_219_200, 0),
+            (i64::MAX, 0),
+            (i64::MIN, 1),
+            (0, 1_000_000_000),
+        ] {
+            let mut buf = Vec::new();
+            buf.put_i64(seconds);
+            buf.put_u32(nanos);
+            assert!(
+                <DateTime<Utc> as FfiConverter<UniFfiTag>>::try_read(&mut buf.as_slice()).is_err()
+            );
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_matches_system_time() {
+        use chrono::{DateTime, Utc};
+
+        let time = SystemTime::UNIX_EPOCH - Duration::new(100, 100);
+        assert_eq!(
+            <SystemTime as FfiConverter<UniFfiTag>>::lower(time).destroy_into_vec(),
+            <DateTime<Utc> as FfiConverter<UniFfiTag>>::lower(time.into()).destroy_into_vec(),
+        );
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn json_value_roundtrip() {
+        use serde_json::{json, Value};
+
+        let expected = json!({
+            "null": null,
+            "nested": { "list": [1, -2, 0.5, "three", true] },
+            "big": u64::MAX,
+            "small": i64::MIN,
+        });
+        let result =
+            <Value as FfiConverter<UniFfiTag>>::try_lift(
+                <Value as FfiConverter<UniFfiTag>>::lower(expected.clone()),
+            )
+            .expect("Failed to lift!");
+        assert_eq!(expected, result);
+        assert_eq!(result["big"].as_u64(), Some(u64::MAX));
+    }
+
+    #[test]
+    fn packed_package_version() {
+        let expected = format!(
+            "{}.{}.{}",
+            PACKED_PACKAGE_VERSION / 1_000_000,
+            PACKED_PACKAGE_VERSION / 1_000 % 1_000,
+            PACKED_PACKAGE_VERSION % 1_000
+        );
+        assert!(PACKAGE_VERSION.starts_with(&expected));
+    }
+
+    #[test]
+    fn non_zero_roundtrip() {
+        let expected = NonZeroU32::new(42).unwrap();
+        let lowered = <NonZeroU32 as FfiConverter<UniFfiTag>>::lower(expected);
+        let result =
+            <NonZeroU32 as FfiConverter<UniFfiTag>>::try_lift(lowered).expect("Failed to lift!");
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn non_zero_rejects_zero() {
+        assert!(<NonZeroU32 as FfiConverter<UniFfiTag>>::try_lift(0).is_err());
+        let mut buf: &[u8] = &[0, 0, 0, 0];
+        assert!(<NonZeroU32 as FfiConverter<UniFfiTag>>::try_read(&mut buf).is_err());
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn range_roundtrip() {
+        // Empty and inverted ranges are passed unchanged
+        for expected in [1..5, 5..5, 5..3, i64::MIN..i64::MAX] {
+            let lowered = <Range<i64> as FfiConverter<UniFfiTag>>::lower(expected.clone());
+            let result = <Range<i64> as FfiConverter<UniFfiTag>>::try_lift(lowered)
+                .expect("Failed to lift!");
+            assert_eq!(expected, result)
+        }
+        for expected in [1..=5, 5..=5, 5..=3, 0..=u32::MAX] {
+            let lowered = <RangeInclusive<u32> as FfiConverter<UniFfiTag>>::lower(expected.clone());
+            let result = <RangeInclusive<u32> as FfiConverter<UniFfiTag>>::try_lift(lowered)
+                .expect("Failed to lift!");
+            assert_eq!(expected, result)
+        }
+    }
+
+    #[test]
+    fn empty_buffers_lift_as_empty_values() {
+        assert_eq!(
+            <Option<u32> as FfiConverter<UniFfiTag>>::try_lift(RustBuffer::new()).unwrap(),
+            None
+        );
+        assert!(
+            <Vec<String> as FfiConverter<UniFfiTag>>::try_lift(RustBuffer::new())
+                .unwrap()
+                .is_empty()
+        );
+        assert!(
+            <HashMap<String, u8> as FfiConverter<UniFfiTag>>::try_lift(RustBuffer::new())
+                .unwrap()
+                .is_empty()
+        );
+        // Serialized values still work as before
+        let lowered = <Option<u32> as FfiConverter<UniFfiTag>>::lower(Some(1));
+        assert_eq!(
+            <Option<u32> as FfiConverter<UniFfiTag>>::try_lift(lowered).unwrap(),
+            Some(1)
+        );
+    }
 }
@@ -38,6 +38,7 @@ pub mod codes {
     pub const UDL_FILE: u8 = 8;
     pub const CALLBACK_INTERFACE: u8 = 9;
     pub const TRAIT_METHOD: u8 = 10;
+    pub const FLAGS: u8 = 11;
     pub const UNKNOWN: u8 = 255;
 
     // Type codes
@@ -67,6 +68,11 @@ pub mod codes {
     pub const TYPE_RESULT: u8 = 23;
     pub const TYPE_FUTURE: u8 = 24;
     pub const TYPE_FOREIGN_EXECUTOR: u8 = 25;
+    pub const TYPE_BYTE_ARRAY: u8 = 26;
+    pub const TYPE_BYTES: u8 = 27;
+    pub const TYPE_RANGE: u8 = 27;
+    pub const TYPE_RANGE_INCLUSIVE: u8 = 28;
+    pub const TYPE_JSON_VALUE: u8 = 29;
     pub const TYPE_UNIT: u8 = 255;
 
     // Literal codes for LiteralMetadata - note that we don't support
@@ -159,6 +165,22 @@ impl MetadataBuffer {
         self
     }
 
+    // Concatenate a `u64` value to this buffer
+    //
+    // This consumes self, which is convenient for the proc-macro code and also allows us to avoid
+    // allocated an extra buffer.
+    pub const fn concat_u64(mut self, value: u64) -> Self {
+        assert!(self.size + 8 <= BUF_SIZE);
+        // store the value as little-endian
+        let mut i = 0;
+        while i < 8 {
+            self.bytes[self.size + i] = (value >> (8 * i)) as u8;
+            i += 1;
+        }
+        self.size += 8;
+        self
+    }
+
     // Concatenate a `bool` value to this buffer
     //
     // This consumes self, which is convenient for the proc-macro code and also allows us to avoid
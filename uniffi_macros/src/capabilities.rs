@@ -0,0 +1,94 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Declare the optional capabilities that a library can be built with
+//!
+//! `uniffi::declare_capabilities!` takes a list of capability names, each of which can have `cfg`
+//! attributes, and exports a `supported_capabilities()` function that returns the names that were
+//! compiled in and are currently available, according to the same registry that
+//! `requires_capability` checks.  Foreign code can call it to find out which optional modules are
+//! available without calling into them and catching the errors.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Attribute, LitStr, Token,
+};
+
+/// Input to `declare_capabilities!`: a comma-separated list of capability names
+pub(crate) struct Capabilities {
+    capabilities: Punctuated<Capability, Token![,]>,
+}
+
+struct Capability {
+    attrs: Vec<Attribute>,
+    name: LitStr,
+}
+
+impl Parse for Capabilities {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        Ok(Self {
+            capabilities: input.parse_terminated(Capability::parse, Token![,])?,
+        })
+    }
+}
+
+impl Parse for Capability {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        if let Some(attr) = attrs.iter().find(|attr| !attr.path().is_ident("cfg")) {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "only `cfg` attributes are supported on capabilities",
+            ));
+        }
+        Ok(Self {
+            attrs,
+            name: input.parse()?,
+        })
+    }
+}
+
+pub(crate) fn expand_declare_capabilities(input: Capabilities) -> syn::Result<TokenStream> {
+    let mut names: Vec<String> = Vec::new();
+    for capability in &input.capabilities {
+        let name = capability.name.value();
+        if name.is_empty() {
+            return Err(syn::Error::new_spanned(
+                &capability.name,
+                "capability names can't be empty",
+            ));
+        }
+        if names.contains(&name) {
+            return Err(syn::Error::new_spanned(
+                &capability.name,
+                format!("capability `{name}` is declared more than once"),
+            ));
+        }
+        names.push(name);
+    }
+
+    let pushes = input.capabilities.iter().map(|Capability { attrs, name }| {
+        quote! {
+            #(#attrs)*
+            capabilities.push(#name);
+        }
+    });
+
+    Ok(quote! {
+        #[::uniffi::export]
+        pub fn supported_capabilities() -> ::std::vec::Vec<::std::string::String> {
+            #[allow(unused_mut)]
+            let mut capabilities: ::std::vec::Vec<&'static str> = ::std::vec::Vec::new();
+            #(#pushes)*
+            capabilities
+                .into_iter()
+                .filter(|name| ::uniffi::check_capability(*name).is_ok())
+                .map(::std::string::String::from)
+                .collect()
+        }
+    })
+}
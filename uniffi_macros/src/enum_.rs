@@ -138,6 +138,7 @@ fn enum_or_error_ffi_converter_impl(
             }
 
             fn try_read(buf: &mut &[::std::primitive::u8]) -> ::uniffi::deps::anyhow::Result<Self> {
+                let _guard = ::uniffi::NestingGuard::enter()?;
                 #try_read_impl
             }
 
@@ -169,6 +170,7 @@ pub(crate) fn enum_meta_static_var(ident: &Ident, enum_: &DataEnum) -> syn::Resu
             .concat_str(#name)
     };
     metadata_expr.extend(variant_metadata(enum_)?);
+    metadata_expr.extend(quote! { .concat_str(::core::module_path!()) });
     Ok(create_metadata_items("enum", &name, metadata_expr, None))
 }
 
@@ -210,6 +212,8 @@ pub fn variant_metadata(enum_: &DataEnum) -> syn::Result<Vec<TokenStream>> {
                                 .concat(<#field_types as ::uniffi::FfiConverter<crate::UniFfiTag>>::TYPE_ID_META)
                                 // field defaults not yet supported for enums
                                 .concat_bool(false)
+                                // enum fields are always compared
+                                .concat_bool(false)
                             )*
                     })
                 })
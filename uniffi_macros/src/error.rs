@@ -13,6 +13,7 @@ use crate::{
         AttributeSliceExt, UniffiAttributeArgs,
     },
 };
+use uniffi_meta::DYN_ERROR_NAME;
 
 pub fn expand_error(input: DeriveInput) -> syn::Result<TokenStream> {
     let enum_ = match input.data {
@@ -185,9 +186,59 @@ pub(crate) fn error_meta_static_var(
     } else {
         metadata_expr.extend(variant_metadata(enum_)?);
     }
+    metadata_expr.extend(quote! { .concat_str(::core::module_path!()) });
     Ok(create_metadata_items("error", &name, metadata_expr, None))
 }
 
+/// Support `Box<dyn Error>` as the error type of exported functions
+///
+/// These errors don't have a type that the foreign code could know about, so they're lowered as
+/// the only variant of a flat `DynError` enum, keeping just their `Display` message.
+/// `setup_scaffolding!` generates this for each crate.
+pub(crate) fn dyn_error_scaffolding() -> syn::Result<TokenStream> {
+    let ident = Ident::new(DYN_ERROR_NAME, Span::call_site());
+    let input: DeriveInput = syn::parse_quote! { enum #ident { Other } };
+    let enum_ = match input.data {
+        Data::Enum(e) => e,
+        _ => unreachable!(),
+    };
+    let meta_static_var = error_meta_static_var(&ident, &enum_, true)?;
+    let mod_path = mod_path()?;
+
+    let dyn_error_types = [
+        quote! { dyn ::std::error::Error },
+        quote! { dyn ::std::error::Error + ::std::marker::Send },
+        quote! { dyn ::std::error::Error + ::std::marker::Send + ::std::marker::Sync },
+    ];
+    let ffi_converter_impls = dyn_error_types.iter().map(|dyn_error| {
+        quote! {
+            #[automatically_derived]
+            unsafe impl ::uniffi::FfiConverter<crate::UniFfiTag> for ::std::boxed::Box<#dyn_error> {
+                ::uniffi::ffi_converter_rust_buffer_lift_and_lower!(crate::UniFfiTag);
+                ::uniffi::ffi_converter_default_return!(crate::UniFfiTag);
+
+                fn write(obj: Self, buf: &mut ::std::vec::Vec<u8>) {
+                    ::uniffi::deps::bytes::BufMut::put_i32(buf, 1);
+                    <::std::string::String as ::uniffi::FfiConverter<crate::UniFfiTag>>::write(::std::string::ToString::to_string(&obj), buf);
+                }
+
+                fn try_read(_buf: &mut &[::std::primitive::u8]) -> ::uniffi::deps::anyhow::Result<Self> {
+                    ::std::panic!("try_read not supported for Box<dyn Error>")
+                }
+
+                const TYPE_ID_META: ::uniffi::MetadataBuffer = ::uniffi::MetadataBuffer::from_code(::uniffi::metadata::codes::TYPE_ENUM)
+                    .concat_str(#mod_path)
+                    .concat_str(#DYN_ERROR_NAME);
+            }
+        }
+    });
+
+    Ok(quote! {
+        #(#ffi_converter_impls)*
+        #meta_static_var
+    })
+}
+
 pub fn flat_error_variant_metadata(enum_: &DataEnum) -> syn::Result<Vec<TokenStream>> {
     let variants_len =
         try_metadata_value_from_usize(enum_.variants.len(), "UniFFI limits enums to 256 variants")?;
@@ -8,7 +8,9 @@ use syn::{visit_mut::VisitMut, Item, Path, Type};
 
 mod attributes;
 mod callback_interface;
+mod default_methods;
 mod item;
+mod named_returns;
 mod scaffolding;
 
 use self::{
@@ -21,6 +23,8 @@ use crate::{
     util::{ident_to_string, mod_path, tagged_impl_header},
 };
 pub use callback_interface::ffi_converter_callback_interface_impl;
+pub(crate) use default_methods::{expand_default_methods, expand_export_default_methods};
+pub(crate) use named_returns::TupleToRecord;
 use uniffi_meta::free_fn_symbol_name;
 
 // TODO(jplatte): Ensure no generics, …
@@ -38,22 +42,79 @@ pub(crate) fn expand_export(
     rewrite_self_type(&mut item);
 
     let metadata = ExportItem::new(item, &args)?;
+    if let Some(returns) = &args.returns {
+        if !matches!(metadata, ExportItem::Function { .. }) {
+            return Err(syn::Error::new_spanned(
+                returns,
+                "`returns` is only supported on functions",
+            ));
+        }
+    }
+    if let Some(aliases) = &args.aliases {
+        if !matches!(metadata, ExportItem::Function { .. }) {
+            return Err(syn::Error::new_spanned(
+                aliases,
+                "`aliases` is only supported on functions",
+            ));
+        }
+    }
+    if let Some(variadic) = &args.variadic {
+        if !matches!(metadata, ExportItem::Function { .. }) {
+            return Err(syn::Error::new_spanned(
+                variadic,
+                "`variadic` is only supported on functions, use `#[uniffi::variadic]` on methods and constructors",
+            ));
+        }
+    }
+    if let Some(default_methods) = &args.default_methods {
+        if !matches!(
+            metadata,
+            ExportItem::Impl {
+                trait_path: Some(_),
+                ..
+            }
+        ) {
+            return Err(syn::Error::new_spanned(
+                default_methods,
+                "`default_methods` is only supported on trait impls",
+            ));
+        }
+    }
 
     match metadata {
         ExportItem::Function { sig } => gen_fn_scaffolding(sig, &args),
-        ExportItem::Impl { items, self_ident } => {
+        ExportItem::Impl {
+            items,
+            self_ident,
+            trait_path,
+        } => {
             if let Some(rt) = &args.async_runtime {
-                if items
-                    .iter()
-                    .all(|item| !matches!(item, ImplItem::Method(sig) if sig.is_async))
-                {
+                if items.iter().all(|item| match item {
+                    ImplItem::Method(sig) | ImplItem::Constructor(sig) => !sig.is_async,
+                }) {
                     return Err(syn::Error::new_spanned(
                         rt,
-                        "no async methods in this impl block",
+                        "no async methods or constructors in this impl block",
                     ));
                 }
             }
 
+            // Trait impls with `default_methods` also export the trait's default methods, unless
+            // the impl overrides them
+            let default_methods = match &trait_path {
+                Some(trait_path) if args.default_methods.is_some() => {
+                    let overridden: Vec<_> = items
+                        .iter()
+                        .filter_map(|item| match item {
+                            ImplItem::Method(sig) => Some(&sig.ident),
+                            ImplItem::Constructor(_) => None,
+                        })
+                        .collect();
+                    default_methods::invoke_default_methods(trait_path, &self_ident, &overridden)
+                }
+                _ => quote! {},
+            };
+
             let item_tokens: TokenStream = items
                 .into_iter()
                 .map(|item| match item {
@@ -61,7 +122,10 @@ pub(crate) fn expand_export(
                     ImplItem::Method(sig) => gen_method_scaffolding(sig, &args),
                 })
                 .collect::<syn::Result<_>>()?;
-            Ok(quote_spanned! { self_ident.span() => #item_tokens })
+            Ok(quote_spanned! { self_ident.span() =>
+                #item_tokens
+                #default_methods
+            })
         }
         ExportItem::Trait {
             items,
@@ -107,7 +171,7 @@ pub(crate) fn expand_export(
                 })
                 .collect::<syn::Result<_>>()?;
 
-            let meta_static_var = interface_meta_static_var(&self_ident, true, &mod_path)
+            let meta_static_var = interface_meta_static_var(&self_ident, true, false, &mod_path)
                 .unwrap_or_else(syn::Error::into_compile_error);
             let ffi_converter_tokens = ffi_converter_trait_impl(&self_ident, None);
 
@@ -226,6 +290,10 @@ pub(crate) fn ffi_converter_trait_impl(trait_ident: &Ident, tag: Option<&Path>)
                 .concat_str(#name)
                 .concat_bool(true);
         }
+
+        unsafe impl ::uniffi::LiftRef<crate::UniFfiTag> for dyn #trait_ident {
+            type LiftType = ::std::sync::Arc<Self>;
+        }
     }
 }
 
@@ -262,21 +330,26 @@ pub fn rewrite_self_type(item: &mut Item) {
         _ => return,
     };
 
-    struct RewriteSelfVisitor<'a>(&'a Type);
-
-    impl<'a> VisitMut for RewriteSelfVisitor<'a> {
-        fn visit_type_mut(&mut self, i: &mut Type) {
-            match i {
-                Type::Path(p) if p.qself.is_none() && p.path.is_ident("Self") => {
-                    *i = self.0.clone();
-                }
-                _ => syn::visit_mut::visit_type_mut(self, i),
-            }
-        }
-    }
-
     let mut visitor = RewriteSelfVisitor(&item.self_ty);
     for item in &mut item.items {
         visitor.visit_impl_item_mut(item);
     }
 }
+
+/// Rewrite Self type alias usage in a single signature, like [rewrite_self_type] does for impls
+fn rewrite_self_in_signature(sig: &mut syn::Signature, self_ty: &Type) {
+    RewriteSelfVisitor(self_ty).visit_signature_mut(sig);
+}
+
+struct RewriteSelfVisitor<'a>(&'a Type);
+
+impl<'a> VisitMut for RewriteSelfVisitor<'a> {
+    fn visit_type_mut(&mut self, i: &mut Type) {
+        match i {
+            Type::Path(p) if p.qself.is_none() && p.path.is_ident("Self") => {
+                *i = self.0.clone();
+            }
+            _ => syn::visit_mut::visit_type_mut(self, i),
+        }
+    }
+}
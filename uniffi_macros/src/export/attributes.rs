@@ -1,21 +1,35 @@
+use super::named_returns::NamedReturns;
 use crate::util::{either_attribute_arg, parse_comma_separated, UniffiAttributeArgs};
 
 use proc_macro2::TokenStream;
 use quote::ToTokens;
 use syn::{
     parse::{Parse, ParseStream},
+    punctuated::Punctuated,
     Attribute, LitStr, Meta, PathArguments, PathSegment, Token,
 };
 
 pub(crate) mod kw {
+    syn::custom_keyword!(aliases);
     syn::custom_keyword!(async_runtime);
     syn::custom_keyword!(callback_interface);
+    syn::custom_keyword!(catch_panics);
+    syn::custom_keyword!(default_methods);
+    syn::custom_keyword!(requires_capability);
+    syn::custom_keyword!(returns);
+    syn::custom_keyword!(variadic);
 }
 
 #[derive(Default)]
 pub struct ExportAttributeArguments {
     pub(crate) async_runtime: Option<AsyncRuntime>,
     pub(crate) callback_interface: Option<kw::callback_interface>,
+    pub(crate) catch_panics: Option<kw::catch_panics>,
+    pub(crate) default_methods: Option<kw::default_methods>,
+    pub(crate) returns: Option<NamedReturns>,
+    pub(crate) aliases: Option<FnAliases>,
+    pub(crate) requires_capability: Option<LitStr>,
+    pub(crate) variadic: Option<kw::variadic>,
 }
 
 impl Parse for ExportAttributeArguments {
@@ -39,6 +53,41 @@ impl UniffiAttributeArgs for ExportAttributeArguments {
                 callback_interface: input.parse()?,
                 ..Self::default()
             })
+        } else if lookahead.peek(kw::catch_panics) {
+            Ok(Self {
+                catch_panics: input.parse()?,
+                ..Self::default()
+            })
+        } else if lookahead.peek(kw::default_methods) {
+            Ok(Self {
+                default_methods: input.parse()?,
+                ..Self::default()
+            })
+        } else if lookahead.peek(kw::returns) {
+            let _: kw::returns = input.parse()?;
+            let content;
+            syn::parenthesized!(content in input);
+            Ok(Self {
+                returns: Some(content.parse()?),
+                ..Self::default()
+            })
+        } else if lookahead.peek(kw::aliases) {
+            Ok(Self {
+                aliases: Some(input.parse()?),
+                ..Self::default()
+            })
+        } else if lookahead.peek(kw::requires_capability) {
+            let _: kw::requires_capability = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            Ok(Self {
+                requires_capability: Some(input.parse()?),
+                ..Self::default()
+            })
+        } else if lookahead.peek(kw::variadic) {
+            Ok(Self {
+                variadic: input.parse()?,
+                ..Self::default()
+            })
         } else {
             Ok(Self::default())
         }
@@ -51,10 +100,75 @@ impl UniffiAttributeArgs for ExportAttributeArguments {
                 self.callback_interface,
                 other.callback_interface,
             )?,
+            catch_panics: either_attribute_arg(self.catch_panics, other.catch_panics)?,
+            default_methods: either_attribute_arg(self.default_methods, other.default_methods)?,
+            returns: either_attribute_arg(self.returns, other.returns)?,
+            aliases: either_attribute_arg(self.aliases, other.aliases)?,
+            requires_capability: either_attribute_arg(
+                self.requires_capability,
+                other.requires_capability,
+            )?,
+            variadic: either_attribute_arg(self.variadic, other.variadic)?,
+        })
+    }
+}
+
+/// Extra foreign names for an exported function: `aliases("fooBar", "foo_bar")`
+///
+/// The bindings generate a wrapper for each alias that calls the function, so there's still only
+/// one scaffolding function.  Aliases are used as-is, rather than converted to each language's
+/// naming convention.
+pub(crate) struct FnAliases {
+    kw: kw::aliases,
+    names: Punctuated<LitStr, Token![,]>,
+}
+
+impl FnAliases {
+    pub(crate) fn names(&self, fn_name: &str) -> syn::Result<Vec<String>> {
+        let mut names = Vec::new();
+        for lit in &self.names {
+            let name = lit.value();
+            let is_identifier = name.starts_with(|c: char| c == '_' || c.is_alphabetic())
+                && name.chars().all(|c| c == '_' || c.is_alphanumeric());
+            if !is_identifier {
+                return Err(syn::Error::new_spanned(
+                    lit,
+                    "aliases must be valid identifiers",
+                ));
+            }
+            if name == fn_name {
+                return Err(syn::Error::new_spanned(
+                    lit,
+                    "aliases must differ from the function name",
+                ));
+            }
+            if names.contains(&name) {
+                return Err(syn::Error::new_spanned(lit, "duplicate alias"));
+            }
+            names.push(name);
+        }
+        Ok(names)
+    }
+}
+
+impl Parse for FnAliases {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let kw = input.parse()?;
+        let content;
+        syn::parenthesized!(content in input);
+        Ok(Self {
+            kw,
+            names: content.parse_terminated(<LitStr as Parse>::parse, Token![,])?,
         })
     }
 }
 
+impl ToTokens for FnAliases {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.kw.to_tokens(tokens)
+    }
+}
+
 pub(crate) enum AsyncRuntime {
     Tokio(LitStr),
 }
@@ -83,6 +197,7 @@ impl ToTokens for AsyncRuntime {
 #[derive(Default)]
 pub(super) struct ExportedImplFnAttributes {
     pub constructor: bool,
+    pub variadic: bool,
 }
 
 impl ExportedImplFnAttributes {
@@ -125,6 +240,15 @@ impl ExportedImplFnAttributes {
                     }
                     this.constructor = true;
                 }
+                "variadic" => {
+                    if this.variadic {
+                        return Err(syn::Error::new_spanned(
+                            attr,
+                            "duplicate variadic attribute",
+                        ));
+                    }
+                    this.variadic = true;
+                }
                 _ => return Err(syn::Error::new_spanned(snd, "unknown uniffi attribute")),
             }
         }
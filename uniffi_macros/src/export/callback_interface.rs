@@ -145,6 +145,12 @@ fn gen_method_impl(sig: &FnSignature, internals_ident: &Ident) -> syn::Result<To
             "callback interface methods must take &self as their first argument",
         ));
     }
+    if sig.returns_ref.is_some() {
+        return Err(syn::Error::new(
+            sig.span,
+            "callback interface methods can not return references",
+        ));
+    }
     let params = sig.params();
     let buf_ident = Ident::new("uniffi_args_buf", Span::call_site());
     let write_exprs = sig.write_exprs(&buf_ident);
@@ -174,6 +180,7 @@ pub(super) fn metadata_items(
             ::uniffi::MetadataBuffer::from_code(::uniffi::metadata::codes::CALLBACK_INTERFACE)
                 .concat_str(#module_path)
                 .concat_str(#trait_name)
+                .concat_str(::core::module_path!())
         },
         None,
     );
@@ -0,0 +1,124 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Export the default methods of a trait, for objects that implement it
+//!
+//! `#[uniffi::export]` on `impl Trait for Object` only sees the methods written in the impl block,
+//! so it can't know about the trait's default methods by itself.  `#[uniffi::default_methods]` on
+//! the trait definition records their signatures in a `macro_rules!` helper, which is imported
+//! under the trait's name (macros live in their own namespace, so this doesn't clash with the
+//! trait).  Impl blocks exported with `#[uniffi::export(default_methods)]` then invoke
+//! `Trait!(Object, [overridden methods])`, wherever the trait is in scope, and the helper forwards
+//! the default methods to `export_default_methods!`.
+
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    braced, bracketed,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Path, Token, TraitItem, TraitItemFn, Type,
+};
+
+use super::{
+    attributes::ExportAttributeArguments, gen_method_scaffolding, rewrite_self_in_signature,
+};
+use crate::fnsig::FnSignature;
+
+/// Expand `#[uniffi::default_methods]`, which goes on a trait definition
+pub(crate) fn expand_default_methods(item: syn::ItemTrait) -> syn::Result<TokenStream> {
+    if !item.generics.params.is_empty() || item.generics.where_clause.is_some() {
+        return Err(syn::Error::new_spanned(
+            &item.generics,
+            "generic traits are not currently supported by uniffi::default_methods",
+        ));
+    }
+
+    let trait_ident = &item.ident;
+    let macro_ident = format_ident!("__uniffi_default_methods_{trait_ident}");
+    // Only methods are exported, associated functions with a default are left alone
+    let default_methods = item.items.iter().filter_map(|item| match item {
+        TraitItem::Fn(tim) if tim.default.is_some() && tim.sig.receiver().is_some() => {
+            let sig = &tim.sig;
+            Some(quote! { #sig; })
+        }
+        _ => None,
+    });
+
+    Ok(quote! {
+        #[doc(hidden)]
+        #[allow(unused_macros)]
+        macro_rules! #macro_ident {
+            ($self_ident:ident, [$($overridden:ident),*]) => {
+                ::uniffi::export_default_methods!(
+                    $self_ident, [$($overridden),*], { #(#default_methods)* }
+                );
+            };
+        }
+
+        #[doc(hidden)]
+        #[allow(unused_imports)]
+        pub(crate) use #macro_ident as #trait_ident;
+    })
+}
+
+/// Invoke the helper generated by `#[uniffi::default_methods]` for an exported trait impl
+pub(super) fn invoke_default_methods(
+    trait_path: &Path,
+    self_ident: &Ident,
+    overridden: &[&Ident],
+) -> TokenStream {
+    quote! {
+        #trait_path!(#self_ident, [#(#overridden),*]);
+    }
+}
+
+/// Input to `export_default_methods!`: the object, the methods that its impl block overrides and
+/// the signatures of the trait's default methods
+pub(crate) struct DefaultMethods {
+    self_ident: Ident,
+    overridden: Punctuated<Ident, Token![,]>,
+    methods: Vec<TraitItemFn>,
+}
+
+impl Parse for DefaultMethods {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let self_ident = input.parse()?;
+        let _: Token![,] = input.parse()?;
+        let overridden;
+        bracketed!(overridden in input);
+        let _: Token![,] = input.parse()?;
+        let methods;
+        braced!(methods in input);
+        let mut parsed = Vec::new();
+        while !methods.is_empty() {
+            parsed.push(methods.parse()?);
+        }
+        Ok(Self {
+            self_ident,
+            overridden: overridden.parse_terminated(Ident::parse, Token![,])?,
+            methods: parsed,
+        })
+    }
+}
+
+/// Expand `export_default_methods!`, generating the scaffolding for each default method that
+/// isn't overridden
+pub(crate) fn expand_export_default_methods(input: DefaultMethods) -> syn::Result<TokenStream> {
+    let DefaultMethods {
+        self_ident,
+        overridden,
+        methods,
+    } = input;
+    let self_ty: Type = syn::parse_quote! { #self_ident };
+    let args = ExportAttributeArguments::default();
+    methods
+        .into_iter()
+        .filter(|tim| !overridden.iter().any(|ident| *ident == tim.sig.ident))
+        .map(|mut tim| {
+            rewrite_self_in_signature(&mut tim.sig, &self_ty);
+            gen_method_scaffolding(FnSignature::new_method(self_ident.clone(), tim.sig)?, &args)
+        })
+        .collect()
+}
@@ -15,6 +15,8 @@ pub(super) enum ExportItem {
     Impl {
         self_ident: Ident,
         items: Vec<ImplItem>,
+        /// The trait, for `impl Trait for Object` blocks
+        trait_path: Option<syn::Path>,
     },
     Trait {
         self_ident: Ident,
@@ -68,6 +70,7 @@ impl ExportItem {
             }
         };
 
+        let trait_path = item.trait_.map(|(_, path, _)| path);
         let items = item
             .items
             .into_iter()
@@ -83,13 +86,18 @@ impl ExportItem {
                 };
 
                 let attrs = ExportedImplFnAttributes::new(&impl_fn.attrs)?;
+                let mut sig = if attrs.constructor {
+                    FnSignature::new_constructor(self_ident.clone(), impl_fn.sig)?
+                } else {
+                    FnSignature::new_method(self_ident.clone(), impl_fn.sig)?
+                };
+                if attrs.variadic {
+                    sig.set_variadic()?;
+                }
                 let item = if attrs.constructor {
-                    ImplItem::Constructor(FnSignature::new_constructor(
-                        self_ident.clone(),
-                        impl_fn.sig,
-                    )?)
+                    ImplItem::Constructor(sig)
                 } else {
-                    ImplItem::Method(FnSignature::new_method(self_ident.clone(), impl_fn.sig)?)
+                    ImplItem::Method(sig)
                 };
 
                 Ok(item)
@@ -99,6 +107,7 @@ impl ExportItem {
         Ok(Self::Impl {
             items,
             self_ident: self_ident.to_owned(),
+            trait_path,
         })
     }
 
@@ -132,6 +141,11 @@ impl ExportItem {
                         tim,
                         "exported traits can not have constructors",
                     ));
+                } else if attrs.variadic {
+                    return Err(syn::Error::new_spanned(
+                        tim,
+                        "exported traits can not have variadic arguments",
+                    ));
                 } else {
                     ImplItem::Method(FnSignature::new_trait_method(
                         self_ident.clone(),
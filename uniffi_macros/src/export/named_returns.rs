@@ -0,0 +1,165 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{
+    braced,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Attribute, GenericArgument, Ident, PathArguments, Token, Type, TypeTuple,
+};
+
+use crate::fnsig::FnSignature;
+
+/// Names for the elements of the tuple returned by an exported function
+///
+/// `#[uniffi::export(returns(DivisionResult { quotient, remainder }))]` generates a record named
+/// `DivisionResult`, whose fields hold the tuple elements in order, and exports the function as
+/// returning that record.  Doc comments on the record and its fields are kept on the generated
+/// struct.
+pub(crate) struct NamedReturns {
+    attrs: Vec<Attribute>,
+    ident: Ident,
+    fields: Punctuated<NamedReturnField, Token![,]>,
+}
+
+struct NamedReturnField {
+    attrs: Vec<Attribute>,
+    ident: Ident,
+}
+
+impl Parse for NamedReturns {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let ident = input.parse()?;
+        let content;
+        braced!(content in input);
+        Ok(Self {
+            attrs,
+            ident,
+            fields: content.parse_terminated(NamedReturnField::parse, Token![,])?,
+        })
+    }
+}
+
+impl Parse for NamedReturnField {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        Ok(Self {
+            attrs: input.call(Attribute::parse_outer)?,
+            ident: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for NamedReturns {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.ident.to_tokens(tokens)
+    }
+}
+
+/// How the scaffolding converts the returned tuple into the generated record
+pub(crate) struct TupleToRecord {
+    conversion: TokenStream,
+    is_result: bool,
+}
+
+impl NamedReturns {
+    /// Generate the record, and switch `sig` over to returning it
+    pub(crate) fn apply(&self, sig: &mut FnSignature) -> syn::Result<TokenStream> {
+        let (tuple, error_ty) = split_return_ty(&sig.return_ty, &self.ident)?;
+        if tuple.elems.len() != self.fields.len() {
+            return Err(syn::Error::new_spanned(
+                &self.ident,
+                format!(
+                    "expected {} field names, one for each element of the returned tuple",
+                    tuple.elems.len()
+                ),
+            ));
+        }
+
+        let Self { attrs, ident, .. } = self;
+        let fields = self.fields.iter().zip(&tuple.elems).map(|(field, ty)| {
+            let NamedReturnField { attrs, ident } = field;
+            quote! {
+                #(#attrs)*
+                pub #ident: #ty,
+            }
+        });
+        let names: Vec<_> = self.fields.iter().map(|f| &f.ident).collect();
+
+        sig.return_ty = match &error_ty {
+            Some(error_ty) => quote! { ::std::result::Result<#ident, #error_ty> },
+            None => quote! { #ident },
+        };
+        sig.tuple_to_record = Some(TupleToRecord {
+            conversion: quote! { |(#(#names,)*)| #ident { #(#names,)* } },
+            is_result: error_ty.is_some(),
+        });
+
+        Ok(quote! {
+            #(#attrs)*
+            #[derive(::uniffi::Record)]
+            pub struct #ident {
+                #(#fields)*
+            }
+        })
+    }
+}
+
+impl TupleToRecord {
+    pub(crate) fn wrap_call(&self, rust_fn_call: TokenStream, is_async: bool) -> TokenStream {
+        let conversion = &self.conversion;
+        let convert = |value: TokenStream| {
+            if self.is_result {
+                quote! { (#value).map(#conversion) }
+            } else {
+                quote! { (#conversion)(#value) }
+            }
+        };
+        if is_async {
+            let converted = convert(quote! { uniffi_future.await });
+            quote! {
+                {
+                    let uniffi_future = #rust_fn_call;
+                    async move { #converted }
+                }
+            }
+        } else {
+            convert(rust_fn_call)
+        }
+    }
+}
+
+/// Split a return type of `(A, B, ..)` or `Result<(A, B, ..), E>` into the tuple and the error type
+fn split_return_ty(
+    return_ty: &TokenStream,
+    record_ident: &Ident,
+) -> syn::Result<(TypeTuple, Option<Type>)> {
+    let err = || {
+        syn::Error::new_spanned(
+            record_ident,
+            "`returns` requires the function to return a tuple, or a `Result` of a tuple",
+        )
+    };
+    match syn::parse2(return_ty.clone())? {
+        Type::Tuple(tuple) if !tuple.elems.is_empty() => Ok((tuple, None)),
+        Type::Path(p) if p.qself.is_none() => {
+            let last = p.path.segments.last().ok_or_else(err)?;
+            let args = match &last.arguments {
+                PathArguments::AngleBracketed(a) if last.ident == "Result" => &a.args,
+                _ => return Err(err()),
+            };
+            match (args.first(), args.iter().nth(1), args.len()) {
+                (
+                    Some(GenericArgument::Type(Type::Tuple(tuple))),
+                    Some(GenericArgument::Type(error_ty)),
+                    2,
+                ) if !tuple.elems.is_empty() => Ok((tuple.clone(), Some(error_ty.clone()))),
+                _ => Err(err()),
+            }
+        }
+        _ => Err(err()),
+    }
+}
@@ -3,14 +3,18 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
 use proc_macro2::{Ident, TokenStream};
-use quote::quote;
+use quote::{quote, quote_spanned};
 use std::iter;
+use syn::{spanned::Spanned, GenericArgument, PathArguments, Type, TypePath};
 
 use super::attributes::{AsyncRuntime, ExportAttributeArguments};
-use crate::fnsig::{FnKind, FnSignature, NamedArg};
+use crate::{
+    fnsig::{FnKind, FnSignature, NamedArg, ReturnedRef, ReturnedSelf},
+    util::ident_to_string,
+};
 
 pub(super) fn gen_fn_scaffolding(
-    sig: FnSignature,
+    mut sig: FnSignature,
     arguments: &ExportAttributeArguments,
 ) -> syn::Result<TokenStream> {
     if sig.receiver.is_some() {
@@ -27,9 +31,20 @@ pub(super) fn gen_fn_scaffolding(
             ));
         }
     }
+    let record = match &arguments.returns {
+        Some(returns) => returns.apply(&mut sig)?,
+        None => quote! {},
+    };
+    if let Some(aliases) = &arguments.aliases {
+        sig.aliases = aliases.names(&sig.name)?;
+    }
+    if arguments.variadic.is_some() {
+        sig.set_variadic()?;
+    }
     let metadata_items = sig.metadata_items()?;
     let scaffolding_func = gen_ffi_function(&sig, arguments)?;
     Ok(quote! {
+        #record
         #scaffolding_func
         #metadata_items
     })
@@ -45,9 +60,6 @@ pub(super) fn gen_constructor_scaffolding(
             "constructors must not have a self parameter",
         ));
     }
-    if sig.is_async {
-        return Err(syn::Error::new(sig.span, "constructors can't be async"));
-    }
     let metadata_items = sig.metadata_items()?;
     let scaffolding_func = gen_ffi_function(&sig, arguments)?;
     Ok(quote! {
@@ -92,10 +104,27 @@ impl ScaffoldingBits {
         let params: Vec<_> = sig.args.iter().map(NamedArg::scaffolding_param).collect();
         let param_lifts = sig.lift_exprs();
 
-        Self {
-            params,
-            pre_fn_call: quote! {},
-            rust_fn_call: quote! { #ident(#(#param_lifts,)*) },
+        if sig.is_async {
+            // Lift the arguments up front and move them into the future, so that it can borrow
+            // the arguments that the function takes by reference.
+            let param_owned_lifts = sig.args.iter().map(NamedArg::lift_owned_expr);
+            let param_idents = sig.args.iter().map(|a| &a.ident);
+            let call_args = sig.args.iter().map(NamedArg::call_expr);
+            Self {
+                params,
+                pre_fn_call: quote! {
+                    #(let #param_idents = #param_owned_lifts;)*
+                },
+                rust_fn_call: quote! {
+                    async move { #ident(#(#call_args,)*).await }
+                },
+            }
+        } else {
+            Self {
+                params,
+                pre_fn_call: quote! {},
+                rust_fn_call: quote! { #ident(#(#param_lifts,)*) },
+            }
         }
     }
 
@@ -114,15 +143,35 @@ impl ScaffoldingBits {
             .chain(sig.scaffolding_params())
             .collect();
         let param_lifts = sig.lift_exprs();
+        let lift_self = quote! {
+            let uniffi_self = #ffi_converter::try_lift(uniffi_self_lowered).unwrap_or_else(|err| {
+                ::std::panic!("Failed to convert arg 'self': {}", err)
+            });
+        };
 
-        Self {
-            params,
-            pre_fn_call: quote! {
-                let uniffi_self = #ffi_converter::try_lift(uniffi_self_lowered).unwrap_or_else(|err| {
-                    ::std::panic!("Failed to convert arg 'self': {}", err)
-                });
-            },
-            rust_fn_call: quote! { uniffi_self.#ident(#(#param_lifts,)*) },
+        if sig.is_async {
+            // The future returned by a `&self` method borrows `uniffi_self`, so move the `Arc`
+            // into the future to keep the object alive until the future completes.  The
+            // arguments are lifted up front, since the lowered values may not be `Send`.
+            let param_owned_lifts = sig.args.iter().map(NamedArg::lift_owned_expr);
+            let param_idents = sig.args.iter().map(|a| &a.ident);
+            let call_args = sig.args.iter().map(NamedArg::call_expr);
+            Self {
+                params,
+                pre_fn_call: quote! {
+                    #lift_self
+                    #(let #param_idents = #param_owned_lifts;)*
+                },
+                rust_fn_call: quote! {
+                    async move { uniffi_self.#ident(#(#call_args,)*).await }
+                },
+            }
+        } else {
+            Self {
+                params,
+                pre_fn_call: lift_self,
+                rust_fn_call: quote! { uniffi_self.#ident(#(#param_lifts,)*) },
+            }
         }
     }
 
@@ -131,10 +180,26 @@ impl ScaffoldingBits {
         let params: Vec<_> = sig.args.iter().map(NamedArg::scaffolding_param).collect();
         let param_lifts = sig.lift_exprs();
 
-        Self {
-            params,
-            pre_fn_call: quote! {},
-            rust_fn_call: quote! { #self_ident::#ident(#(#param_lifts,)*) },
+        if sig.is_async {
+            // Like async functions, lift the arguments up front and move them into the future
+            let param_owned_lifts = sig.args.iter().map(NamedArg::lift_owned_expr);
+            let param_idents = sig.args.iter().map(|a| &a.ident);
+            let call_args = sig.args.iter().map(NamedArg::call_expr);
+            Self {
+                params,
+                pre_fn_call: quote! {
+                    #(let #param_idents = #param_owned_lifts;)*
+                },
+                rust_fn_call: quote! {
+                    async move { #self_ident::#ident(#(#call_args,)*).await }
+                },
+            }
+        } else {
+            Self {
+                params,
+                pre_fn_call: quote! {},
+                rust_fn_call: quote! { #self_ident::#ident(#(#param_lifts,)*) },
+            }
         }
     }
 }
@@ -147,6 +212,15 @@ fn gen_ffi_function(
     sig: &FnSignature,
     arguments: &ExportAttributeArguments,
 ) -> syn::Result<TokenStream> {
+    if sig.is_async {
+        if let Some(catch_panics) = &arguments.catch_panics {
+            return Err(syn::Error::new_spanned(
+                catch_panics,
+                "this attribute is not supported on async functions",
+            ));
+        }
+    }
+
     let ScaffoldingBits {
         params,
         pre_fn_call,
@@ -160,9 +234,111 @@ fn gen_ffi_function(
         FnKind::Constructor { self_ident } => ScaffoldingBits::new_for_constructor(sig, self_ident),
     };
 
+    // Functions returning a reference, into the object or one of the arguments, can't hand that
+    // borrow across the FFI, so convert it to the owned value right after the call.
+    // Async functions can only return `'static` references, which are converted once the future
+    // completes.
+    let to_owned = |value: TokenStream| match sig.returns_ref {
+        Some(ReturnedRef::Value) => quote! { ::std::borrow::ToOwned::to_owned(#value) },
+        Some(ReturnedRef::Wrapped) => {
+            quote! { (#value).map(::std::borrow::ToOwned::to_owned) }
+        }
+        None => value,
+    };
+    let rust_fn_call = match sig.returns_ref {
+        Some(_) if sig.is_async => {
+            let converted = to_owned(quote! { uniffi_future.await });
+            quote! {
+                {
+                    let uniffi_future = #rust_fn_call;
+                    async move { #converted }
+                }
+            }
+        }
+        Some(_) => to_owned(rust_fn_call),
+        None => rust_fn_call,
+    };
+    let rust_fn_call = match &sig.tuple_to_record {
+        Some(tuple_to_record) => tuple_to_record.wrap_call(rust_fn_call, sig.is_async),
+        None => rust_fn_call,
+    };
+    // Objects are always passed across the FFI in an `Arc`.  Async constructors wrap the object
+    // once the future completes.
+    let wrap_self = |value: TokenStream| match sig.returned_self {
+        Some(ReturnedSelf::Value) => quote! { ::std::sync::Arc::new(#value) },
+        Some(ReturnedSelf::Result) => quote! { (#value).map(::std::sync::Arc::new) },
+        None => value,
+    };
+    let rust_fn_call = match sig.returned_self {
+        Some(_) if sig.is_async => {
+            let wrapped = wrap_self(quote! { uniffi_future.await });
+            quote! {
+                {
+                    let uniffi_future = #rust_fn_call;
+                    async move { #wrapped }
+                }
+            }
+        }
+        Some(_) => wrap_self(rust_fn_call),
+        None => rust_fn_call,
+    };
+    let rust_fn_call = match &arguments.requires_capability {
+        Some(capability) => {
+            let error_ty = capability_error_type(sig)?;
+            // Point at the error type when it doesn't implement `From<MissingCapability>`, rather
+            // than at the conversion in the generated code.
+            let error_ty_check = quote_spanned! { error_ty.span() =>
+                ::uniffi::deps::static_assertions::assert_impl_all!(
+                    #error_ty: ::std::convert::From<::uniffi::MissingCapability>
+                );
+            };
+            // Check the capability before calling the function, converting a missing capability
+            // into the function's error type.
+            if sig.is_async {
+                quote! {
+                    {
+                        #error_ty_check
+                        let uniffi_capability = ::uniffi::check_capability(#capability);
+                        let uniffi_future = #rust_fn_call;
+                        async move {
+                            match uniffi_capability {
+                                ::std::result::Result::Ok(()) => uniffi_future.await,
+                                ::std::result::Result::Err(e) => ::std::result::Result::Err(::std::convert::From::from(e)),
+                            }
+                        }
+                    }
+                }
+            } else {
+                quote! {
+                    {
+                        #error_ty_check
+                        match ::uniffi::check_capability(#capability) {
+                            ::std::result::Result::Ok(()) => #rust_fn_call,
+                            ::std::result::Result::Err(e) => ::std::result::Result::Err(::std::convert::From::from(e)),
+                        }
+                    }
+                }
+            }
+        }
+        None => rust_fn_call,
+    };
+    let rust_fn_call = if arguments.catch_panics.is_some() {
+        // Convert panics into the function's error type, rather than letting `rust_call` report
+        // them as internal errors.
+        quote! { ::uniffi::catch_panics(|| #rust_fn_call) }
+    } else {
+        rust_fn_call
+    };
     let ffi_ident = sig.scaffolding_fn_ident()?;
     let name = &sig.name;
     let return_ty = &sig.return_ty;
+    // Name of the span reported to the foreign span sink, see `uniffi_core::ffi::spans`
+    let span_name = match &sig.kind {
+        FnKind::Function => name.clone(),
+        FnKind::Method { self_ident }
+        | FnKind::TraitMethod { self_ident, .. }
+        | FnKind::Constructor { self_ident } => format!("{}.{name}", ident_to_string(self_ident)),
+    };
 
     Ok(if !sig.is_async {
         quote! {
@@ -173,6 +349,7 @@ fn gen_ffi_function(
                 call_status: &mut ::uniffi::RustCallStatus,
             ) -> <#return_ty as ::uniffi::FfiConverter<crate::UniFfiTag>>::ReturnType {
                 ::uniffi::deps::log::debug!(#name);
+                let _uniffi_span = ::uniffi::enter_span(#span_name);
                 ::uniffi::rust_call(call_status, || {
                     #pre_fn_call
                     <#return_ty as ::uniffi::FfiConverter<crate::UniFfiTag>>::lower_return(#rust_fn_call)
@@ -197,13 +374,18 @@ fn gen_ffi_function(
             ) {
                 ::uniffi::deps::log::debug!(#name);
                 ::uniffi::rust_call(uniffi_call_status, || {
-                    #pre_fn_call;
-                    let uniffi_rust_future = ::uniffi::RustFuture::<_, #return_ty, crate::UniFfiTag>::new(
-                        #future_expr,
-                        uniffi_executor_handle,
-                        uniffi_callback,
-                        uniffi_callback_data
-                    );
+                    // The same span covers lifting the arguments and every poll of the future
+                    let uniffi_span = ::uniffi::Span::new(#span_name);
+                    let uniffi_rust_future = {
+                        let _uniffi_span_guard = uniffi_span.enter();
+                        #pre_fn_call;
+                        ::uniffi::RustFuture::<_, #return_ty, crate::UniFfiTag>::new(
+                            uniffi_span.instrument(#future_expr),
+                            uniffi_executor_handle,
+                            uniffi_callback,
+                            uniffi_callback_data
+                        )
+                    };
                     uniffi_rust_future.wake();
                     Ok(())
                 });
@@ -211,3 +393,33 @@ fn gen_ffi_function(
         }
     })
 }
+
+/// The error type of a function exported with `requires_capability`
+///
+/// These functions must return a `Result<T, E>`, so that a missing capability can be converted into
+/// `E`.  This is checked here, since the conversion in the scaffolding would otherwise fail with a
+/// confusing type error.
+fn capability_error_type(sig: &FnSignature) -> syn::Result<Type> {
+    let error_ty = match syn::parse2::<Type>(sig.return_ty.clone()) {
+        Ok(Type::Path(TypePath { qself: None, path })) => match path.segments.last() {
+            Some(segment) if segment.ident == "Result" => match &segment.arguments {
+                PathArguments::AngleBracketed(args) if args.args.len() == 2 => {
+                    match &args.args[1] {
+                        GenericArgument::Type(ty) => Some(ty.clone()),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    };
+    error_ty.ok_or_else(|| {
+        syn::Error::new_spanned(
+            &sig.return_ty,
+            "functions exported with `requires_capability` must return `Result<T, E>`, where \
+             `E` implements `From<uniffi::MissingCapability>`",
+        )
+    })
+}
@@ -0,0 +1,156 @@
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Data, DeriveInput, Fields, Member, Token, Type,
+};
+
+use crate::util::{
+    create_metadata_items, either_attribute_arg, ident_to_string, mod_path, tagged_impl_header,
+    try_metadata_value_from_usize, AttributeSliceExt, UniffiAttributeArgs,
+};
+
+pub fn expand_flags(input: DeriveInput) -> syn::Result<TokenStream> {
+    let ident = &input.ident;
+    let (member, repr) = bits_field(&input)?;
+    let attr: FlagsAttr = input.attrs.parse_uniffi_attr_args()?;
+    let flags = match attr.flags {
+        Some(flags) if !flags.is_empty() => flags,
+        _ => {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "expected a `#[uniffi(flags(...))]` attribute listing the flag constants",
+            ))
+        }
+    };
+
+    let ffi_converter = flags_ffi_converter_impl(ident, &member, &repr)?;
+    let meta_static_var = flags_meta_static_var(ident, &member, &repr, &flags)?;
+
+    Ok(quote! {
+        #ffi_converter
+        #meta_static_var
+    })
+}
+
+/// Find the single unsigned integer field that stores the bits.
+///
+/// This supports both newtypes (`struct Flags(u32)`) and the `bitflags` 1.x layout
+/// (`struct Flags { bits: u32 }`).
+fn bits_field(input: &DeriveInput) -> syn::Result<(Member, Type)> {
+    let fields = match &input.data {
+        Data::Struct(s) => &s.fields,
+        _ => {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "This derive must only be used on structs",
+            ))
+        }
+    };
+    let field = match fields {
+        Fields::Named(f) if f.named.len() == 1 => &f.named[0],
+        Fields::Unnamed(f) if f.unnamed.len() == 1 => &f.unnamed[0],
+        _ => {
+            return Err(syn::Error::new_spanned(
+                fields,
+                "flags structs must have exactly one (unsigned integer) field",
+            ))
+        }
+    };
+    let member = match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(0.into()),
+    };
+    Ok((member, field.ty.clone()))
+}
+
+fn flags_ffi_converter_impl(
+    ident: &Ident,
+    member: &Member,
+    repr: &Type,
+) -> syn::Result<TokenStream> {
+    let impl_spec = tagged_impl_header("FfiConverter", ident, None);
+    let name = ident_to_string(ident);
+    let mod_path = mod_path()?;
+
+    Ok(quote! {
+        #[automatically_derived]
+        unsafe #impl_spec {
+            ::uniffi::ffi_converter_rust_buffer_lift_and_lower!(crate::UniFfiTag);
+            ::uniffi::ffi_converter_default_return!(crate::UniFfiTag);
+
+            fn write(obj: Self, buf: &mut ::std::vec::Vec<u8>) {
+                <#repr as ::uniffi::FfiConverter<crate::UniFfiTag>>::write(obj.#member, buf);
+            }
+
+            fn try_read(buf: &mut &[::std::primitive::u8]) -> ::uniffi::deps::anyhow::Result<Self> {
+                Ok(Self { #member: <#repr as ::uniffi::FfiConverter<crate::UniFfiTag>>::try_read(buf)? })
+            }
+
+            const TYPE_ID_META: ::uniffi::MetadataBuffer = ::uniffi::MetadataBuffer::from_code(::uniffi::metadata::codes::TYPE_ENUM)
+                .concat_str(#mod_path)
+                .concat_str(#name);
+        }
+    })
+}
+
+fn flags_meta_static_var(
+    ident: &Ident,
+    member: &Member,
+    repr: &Type,
+    flags: &Punctuated<Ident, Token![,]>,
+) -> syn::Result<TokenStream> {
+    let name = ident_to_string(ident);
+    let module_path = mod_path()?;
+    let flags_len =
+        try_metadata_value_from_usize(flags.len(), "UniFFI limits flags to 256 values")?;
+    let concat_flags = flags.iter().map(|flag| {
+        let flag_name = ident_to_string(flag);
+        quote! {
+            .concat_str(#flag_name)
+            .concat_u64(#ident::#flag.#member as u64)
+        }
+    });
+
+    Ok(create_metadata_items(
+        "flags",
+        &name,
+        quote! {
+            ::uniffi::MetadataBuffer::from_code(::uniffi::metadata::codes::FLAGS)
+                .concat_str(#module_path)
+                .concat_str(#name)
+                .concat(<#repr as ::uniffi::FfiConverter<crate::UniFfiTag>>::TYPE_ID_META)
+                .concat_value(#flags_len)
+                #(#concat_flags)*
+                .concat_str(::core::module_path!())
+        },
+        None,
+    ))
+}
+
+mod kw {
+    syn::custom_keyword!(flags);
+}
+
+#[derive(Default)]
+struct FlagsAttr {
+    flags: Option<Punctuated<Ident, Token![,]>>,
+}
+
+impl UniffiAttributeArgs for FlagsAttr {
+    fn parse_one(input: ParseStream<'_>) -> syn::Result<Self> {
+        let _: kw::flags = input.parse()?;
+        let content;
+        syn::parenthesized!(content in input);
+        Ok(Self {
+            flags: Some(content.parse_terminated(Ident::parse, Token![,])?),
+        })
+    }
+
+    fn merge(self, other: Self) -> syn::Result<Self> {
+        Ok(Self {
+            flags: either_attribute_arg(self.flags, other.flags)?,
+        })
+    }
+}
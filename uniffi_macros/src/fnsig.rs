@@ -2,12 +2,16 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+use crate::export::TupleToRecord;
 use crate::util::{
     create_metadata_items, ident_to_string, mod_path, try_metadata_value_from_usize,
 };
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
-use syn::{spanned::Spanned, FnArg, Ident, Pat, Receiver, ReturnType};
+use syn::{
+    spanned::Spanned, FnArg, GenericArgument, Ident, Pat, PathArguments, Receiver, ReturnType,
+    Type, TypeImplTrait, TypeParamBound, TypePath, TypeReference,
+};
 
 pub(crate) struct FnSignature {
     pub kind: FnKind,
@@ -19,6 +23,18 @@ pub(crate) struct FnSignature {
     pub receiver: Option<ReceiverArg>,
     pub args: Vec<NamedArg>,
     pub return_ty: TokenStream,
+    // Set when the Rust function returns a reference (`&str`, `&[T]`, `&T`), possibly wrapped in an
+    // `Option` or `Result`.  In that case `return_ty` is the owned counterpart and the scaffolding
+    // converts the borrowed value with `ToOwned` before lowering it.
+    pub returns_ref: Option<ReturnedRef>,
+    // Set when a method or constructor returns its object type by value, rather than in an `Arc`.
+    // In that case `return_ty` uses `Arc<Self>` and the scaffolding wraps the returned value.
+    pub returned_self: Option<ReturnedSelf>,
+    // Set for functions exported with `#[uniffi::export(returns(...))]`.  In that case `return_ty`
+    // is the generated record and the scaffolding builds it from the returned tuple.
+    pub tuple_to_record: Option<TupleToRecord>,
+    // Extra foreign names for functions exported with `#[uniffi::export(aliases(...))]`
+    pub aliases: Vec<String>,
 }
 
 impl FnSignature {
@@ -45,18 +61,40 @@ impl FnSignature {
     pub(crate) fn new(kind: FnKind, sig: syn::Signature) -> syn::Result<Self> {
         let span = sig.span();
         let ident = sig.ident;
-        let output = match sig.output {
-            ReturnType::Default => quote! { () },
-            ReturnType::Type(_, ty) => quote! { #ty },
-        };
         let is_async = sig.asyncness.is_some();
-
-        if is_async && matches!(kind, FnKind::Constructor { .. }) {
-            return Err(syn::Error::new(
-                span,
-                "Async constructors are not supported",
+        let returned_self = match (&kind, &sig.output) {
+            (
+                FnKind::Method { self_ident } | FnKind::Constructor { self_ident },
+                ReturnType::Type(_, ty),
+            ) => returned_self(ty, self_ident),
+            _ => None,
+        };
+        if is_async && returned_self.is_some() && matches!(kind, FnKind::Method { .. }) {
+            return Err(syn::Error::new_spanned(
+                &sig.output,
+                "async methods can not return their object by value, return `Arc<Self>` instead",
             ));
         }
+        let (output, returns_ref) = match sig.output {
+            ReturnType::Default => (quote! { () }, None),
+            ReturnType::Type(_, ty) => match returned_ref(&ty)? {
+                // The future outlives the call, so it can only return references that live
+                // forever, like `&'static str` constants.
+                Some((r, _, _)) if is_async && !is_static_ref(r) => {
+                    return Err(syn::Error::new_spanned(
+                        r,
+                        "async functions can only return `'static` references",
+                    ));
+                }
+                Some((_, returned_ref, owned_ty)) => (owned_ty, Some(returned_ref)),
+                None => (quote! { #ty }, None),
+            },
+        };
+        let output = match &returned_self {
+            Some((_, return_ty)) => return_ty.clone(),
+            None => output,
+        };
+        let returned_self = returned_self.map(|(returned_self, _)| returned_self);
 
         let mut input_iter = sig.inputs.into_iter().map(Arg::try_from).peekable();
 
@@ -90,9 +128,33 @@ impl FnSignature {
             receiver,
             args,
             return_ty: output,
+            returns_ref,
+            returned_self,
+            tuple_to_record: None,
+            aliases: vec![],
         })
     }
 
+    /// Expose the last argument as a variadic argument in the foreign bindings.
+    ///
+    /// The argument must be a sequence, since that's what the bindings pass the values in.
+    pub(crate) fn set_variadic(&mut self) -> syn::Result<()> {
+        match self.args.last_mut() {
+            Some(arg) if arg.is_sequence() => {
+                arg.variadic = true;
+                Ok(())
+            }
+            Some(arg) => Err(syn::Error::new(
+                arg.ident.span(),
+                "variadic arguments must be a `&[T]`, `Vec<T>` or `Cow<[T]>`",
+            )),
+            None => Err(syn::Error::new(
+                self.span,
+                "variadic functions must have at least one argument",
+            )),
+        }
+    }
+
     /// Lift expressions for each of our arguments
     pub fn lift_exprs(&self) -> impl Iterator<Item = TokenStream> + '_ {
         self.args.iter().map(NamedArg::lift_expr)
@@ -150,20 +212,30 @@ impl FnSignature {
         let arg_metadata_calls = self.args.iter().map(NamedArg::metadata_calls);
 
         match &self.kind {
-            FnKind::Function => Ok(create_metadata_items(
-                "func",
-                name,
-                quote! {
-                    ::uniffi::MetadataBuffer::from_code(::uniffi::metadata::codes::FUNC)
-                        .concat_str(#mod_path)
-                        .concat_str(#name)
-                        .concat_bool(#is_async)
-                        .concat_value(#args_len)
-                        #(#arg_metadata_calls)*
-                        .concat(<#return_ty as ::uniffi::FfiConverter<crate::UniFfiTag>>::TYPE_ID_META)
-                },
-                Some(self.checksum_symbol_name()),
-            )),
+            FnKind::Function => {
+                let aliases = &self.aliases;
+                let aliases_len = try_metadata_value_from_usize(
+                    aliases.len(),
+                    "UniFFI limits functions to 256 aliases",
+                )?;
+                Ok(create_metadata_items(
+                    "func",
+                    name,
+                    quote! {
+                        ::uniffi::MetadataBuffer::from_code(::uniffi::metadata::codes::FUNC)
+                            .concat_str(#mod_path)
+                            .concat_str(#name)
+                            .concat_bool(#is_async)
+                            .concat_value(#args_len)
+                            #(#arg_metadata_calls)*
+                            .concat(<#return_ty as ::uniffi::FfiConverter<crate::UniFfiTag>>::TYPE_ID_META)
+                            .concat_value(#aliases_len)
+                            #(.concat_str(#aliases))*
+                            .concat_str(::core::module_path!())
+                    },
+                    Some(self.checksum_symbol_name()),
+                ))
+            }
 
             FnKind::Method { self_ident } => {
                 let object_name = ident_to_string(self_ident);
@@ -214,6 +286,7 @@ impl FnSignature {
                             .concat_str(#mod_path)
                             .concat_str(#object_name)
                             .concat_str(#name)
+                            .concat_bool(#is_async)
                             .concat_value(#args_len)
                             #(#arg_metadata_calls)*
                             .concat(<#return_ty as ::uniffi::FfiConverter<crate::UniFfiTag>>::TYPE_ID_META)
@@ -269,12 +342,10 @@ impl TryFrom<FnArg> for Arg {
         let kind = match syn_arg {
             FnArg::Typed(p) => match *p.pat {
                 Pat::Ident(i) => {
-                    let ty = *p.ty;
-                    let ty = quote! { #ty };
                     if i.ident == "self" {
                         Ok(ArgKind::Receiver(ReceiverArg))
                     } else {
-                        Ok(ArgKind::Named(NamedArg::new(i.ident, ty)))
+                        NamedArg::from_type(i.ident, *p.ty).map(ArgKind::Named)
                     }
                 }
                 _ => Err(syn::Error::new_spanned(p, "Argument name missing")),
@@ -294,6 +365,20 @@ pub(crate) struct NamedArg {
     pub(crate) ident: Ident,
     pub(crate) name: String,
     pub(crate) ty: TokenStream,
+    // Set when the Rust function takes the argument by reference (`&str`, `&[T]`, `&T`).  In that
+    // case `ty` is the owned counterpart, which is what gets passed across the FFI, and this is
+    // the declared reference type.
+    pub(crate) ref_ty: Option<TokenStream>,
+    // Set when the Rust function takes an `impl Into<T>` argument.  In that case `ty` is `T`,
+    // which is what gets passed across the FFI, and this is the declared `impl Into<T>` type.
+    pub(crate) impl_into_ty: Option<TokenStream>,
+    // Set when the Rust function takes a `Cow<[T]>` argument.  In that case `ty` is
+    // `Cow<'static, [T]>`, which is what gets lifted and coerces to the declared `Cow` whatever its
+    // lifetime, and this is the declared `Cow` type.
+    pub(crate) cow_ty: Option<TokenStream>,
+    // Set with `#[uniffi::export(variadic)]` or `#[uniffi::variadic]` on the last argument, which
+    // must be a sequence.
+    pub(crate) variadic: bool,
 }
 
 impl NamedArg {
@@ -302,7 +387,79 @@ impl NamedArg {
             name: ident_to_string(&ident),
             ident,
             ty,
+            ref_ty: None,
+            impl_into_ty: None,
+            cow_ty: None,
+            variadic: false,
+        }
+    }
+
+    /// Is this argument passed across the FFI as a sequence?
+    fn is_sequence(&self) -> bool {
+        let ty = &self.ty;
+        let is_sequence_path = |path: &syn::Path| {
+            path.segments
+                .last()
+                .map_or(false, |seg| seg.ident == "Vec" || seg.ident == "Cow")
+        };
+        match syn::parse2::<Type>(quote! { #ty }) {
+            // `&T` arguments are lifted as `<T as LiftRef<_>>::LiftType`
+            Ok(Type::Path(p)) => match p.qself {
+                Some(qself) => match *qself.ty {
+                    Type::Path(inner) => is_sequence_path(&inner.path),
+                    _ => false,
+                },
+                None => is_sequence_path(&p.path),
+            },
+            _ => false,
+        }
+    }
+
+    fn from_type(ident: Ident, ty: Type) -> syn::Result<Self> {
+        let r = match ty {
+            Type::Reference(r) => r,
+            Type::ImplTrait(impl_trait) => {
+                // `T` implements `Into<T>`, so the scaffolding can pass the lifted `T` as-is.
+                let target_ty = impl_into_target(&impl_trait)?;
+                return Ok(Self {
+                    impl_into_ty: Some(quote! { #impl_trait }),
+                    ..Self::new(ident, quote! { #target_ty })
+                });
+            }
+            Type::Path(p) => {
+                return Ok(match cow_slice_elem(&p) {
+                    Some(elem) => Self {
+                        cow_ty: Some(quote! { #p }),
+                        ..Self::new(ident, quote! { ::std::borrow::Cow<'static, [#elem]> })
+                    },
+                    None => Self::new(ident, quote! { #p }),
+                })
+            }
+            ty => return Ok(Self::new(ident, quote! { #ty })),
+        };
+        if r.mutability.is_some() {
+            return Err(syn::Error::new_spanned(
+                r,
+                "mutable reference arguments are not supported",
+            ));
         }
+        // The scaffolding passes a reference to the lifted value, which derefs to `str` and
+        // `[T]` for the strings and vecs that those are lifted to, and to `T` for the `Arc<T>`
+        // that objects are lifted to.
+        let owned_ty = match &*r.elem {
+            Type::Path(p) if p.qself.is_none() && p.path.is_ident("str") => {
+                quote! { ::std::string::String }
+            }
+            Type::Slice(s) => {
+                let elem = &s.elem;
+                quote! { ::std::vec::Vec<#elem> }
+            }
+            elem => quote! { <#elem as ::uniffi::LiftRef<crate::UniFfiTag>>::LiftType },
+        };
+        Ok(Self {
+            ref_ty: Some(quote! { #r }),
+            ..Self::new(ident, owned_ty)
+        })
     }
 
     /// Generate the expression for this argument's FfiConverter
@@ -320,7 +477,12 @@ impl NamedArg {
     /// Generate the parameter for this Arg
     pub(crate) fn param(&self) -> TokenStream {
         let ident = &self.ident;
-        let ty = &self.ty;
+        let ty = self
+            .ref_ty
+            .as_ref()
+            .or(self.impl_into_ty.as_ref())
+            .or(self.cow_ty.as_ref())
+            .unwrap_or(&self.ty);
         quote! { #ident: #ty }
     }
 
@@ -333,6 +495,18 @@ impl NamedArg {
 
     /// Generate the expression to lift the scaffolding parameter for this arg
     pub(crate) fn lift_expr(&self) -> TokenStream {
+        let lifted = self.lift_owned_expr();
+        if self.ref_ty.is_some() {
+            quote! { &#lifted }
+        } else {
+            lifted
+        }
+    }
+
+    /// Generate the expression to lift the scaffolding parameter for this arg into its owned type
+    ///
+    /// Use `call_expr` to pass the result to the Rust function.
+    pub(crate) fn lift_owned_expr(&self) -> TokenStream {
         let ident = &self.ident;
         let ffi_converter = self.ffi_converter();
         let panic_fmt = format!("Failed to convert arg '{}': {{}}", self.name);
@@ -342,20 +516,193 @@ impl NamedArg {
         }
     }
 
+    /// Generate the expression to pass a local variable holding the owned, lifted arg to the
+    /// Rust function
+    pub(crate) fn call_expr(&self) -> TokenStream {
+        let ident = &self.ident;
+        if self.ref_ty.is_some() {
+            quote! { &#ident }
+        } else {
+            quote! { #ident }
+        }
+    }
+
     /// Generate the expression to write the scaffolding parameter for this arg
     pub(crate) fn write_expr(&self, buf_ident: &Ident) -> TokenStream {
         let ident = &self.ident;
         let ffi_converter = self.ffi_converter();
-        quote! { #ffi_converter::write(#ident, &mut #buf_ident) }
+        if self.ref_ty.is_some() {
+            quote! { #ffi_converter::write(::std::borrow::ToOwned::to_owned(#ident), &mut #buf_ident) }
+        } else if self.impl_into_ty.is_some() {
+            quote! { #ffi_converter::write(::std::convert::Into::into(#ident), &mut #buf_ident) }
+        } else if self.cow_ty.is_some() {
+            quote! {
+                #ffi_converter::write(
+                    ::std::borrow::Cow::Owned(::std::borrow::Cow::into_owned(#ident)),
+                    &mut #buf_ident,
+                )
+            }
+        } else {
+            quote! { #ffi_converter::write(#ident, &mut #buf_ident) }
+        }
     }
 
     pub(crate) fn metadata_calls(&self) -> TokenStream {
         let name = &self.name;
         let ffi_converter = self.ffi_converter();
+        let variadic = self.variadic;
         quote! {
             .concat_str(#name)
             .concat(#ffi_converter::TYPE_ID_META)
+            .concat_bool(#variadic)
+        }
+    }
+}
+
+/// Get `T` from an `impl Into<T>` argument type
+fn impl_into_target(impl_trait: &TypeImplTrait) -> syn::Result<&Type> {
+    let err = || {
+        syn::Error::new_spanned(
+            impl_trait,
+            "`impl Trait` arguments are only supported for `impl Into<T>`",
+        )
+    };
+    let bound = match (impl_trait.bounds.first(), impl_trait.bounds.len()) {
+        (Some(TypeParamBound::Trait(bound)), 1) => bound,
+        _ => return Err(err()),
+    };
+    let last = bound.path.segments.last().ok_or_else(err)?;
+    match &last.arguments {
+        PathArguments::AngleBracketed(a) if last.ident == "Into" && a.args.len() == 1 => {
+            match a.args.first() {
+                Some(GenericArgument::Type(ty)) => Ok(ty),
+                _ => Err(err()),
+            }
+        }
+        _ => Err(err()),
+    }
+}
+
+/// Get `T` from a `Cow<[T]>` or `Cow<'a, [T]>` argument type
+fn cow_slice_elem(p: &TypePath) -> Option<&Type> {
+    if p.qself.is_some() {
+        return None;
+    }
+    let last = p.path.segments.last()?;
+    let args = match &last.arguments {
+        PathArguments::AngleBracketed(a) if last.ident == "Cow" => &a.args,
+        _ => return None,
+    };
+    // The lifetime is optional, since it can be elided
+    match args.iter().last()? {
+        GenericArgument::Type(Type::Slice(s)) => Some(&s.elem),
+        _ => None,
+    }
+}
+
+/// How a method or constructor returns its own object type by value
+#[derive(Clone, Copy)]
+pub(crate) enum ReturnedSelf {
+    /// `-> Self`
+    Value,
+    /// `-> Result<Self, E>`
+    Result,
+}
+
+/// How a function returns a reference
+#[derive(Clone, Copy)]
+pub(crate) enum ReturnedRef {
+    /// `-> &T`
+    Value,
+    /// `-> Option<&T>` or `-> Result<&T, E>`
+    Wrapped,
+}
+
+// Check if `ty` is a reference, or an `Option` or `Result` of one.  If so, also return the
+// reference and the owned type that replaces `ty` across the FFI.
+//
+// This is what lets functions return slices of their arguments, like `fn first_word(text: &str)
+// -> &str`.  The returned slice is copied into a new `String`, since there's no way to hand a
+// borrow to foreign code.
+fn returned_ref(ty: &Type) -> syn::Result<Option<(&TypeReference, ReturnedRef, TokenStream)>> {
+    let to_owned = |r: &TypeReference| {
+        if r.mutability.is_some() {
+            return Err(syn::Error::new_spanned(
+                r,
+                "functions returning mutable references are not supported",
+            ));
+        }
+        let elem = &r.elem;
+        Ok(quote! { <#elem as ::std::borrow::ToOwned>::Owned })
+    };
+    let p = match ty {
+        Type::Reference(r) => return Ok(Some((r, ReturnedRef::Value, to_owned(r)?))),
+        Type::Path(p) if p.qself.is_none() => p,
+        _ => return Ok(None),
+    };
+    let last = match p.path.segments.last() {
+        Some(last) => last,
+        None => return Ok(None),
+    };
+    let args = match &last.arguments {
+        PathArguments::AngleBracketed(args) => args.args.iter().collect::<Vec<_>>(),
+        _ => return Ok(None),
+    };
+    Ok(match args.as_slice() {
+        [GenericArgument::Type(Type::Reference(r))] if last.ident == "Option" => {
+            let owned = to_owned(r)?;
+            Some((
+                r,
+                ReturnedRef::Wrapped,
+                quote! { ::std::option::Option<#owned> },
+            ))
+        }
+        [GenericArgument::Type(Type::Reference(r)), GenericArgument::Type(err)]
+            if last.ident == "Result" =>
+        {
+            let owned = to_owned(r)?;
+            Some((
+                r,
+                ReturnedRef::Wrapped,
+                quote! { ::std::result::Result<#owned, #err> },
+            ))
         }
+        _ => None,
+    })
+}
+
+/// Is this a `&'static T` reference?
+fn is_static_ref(r: &TypeReference) -> bool {
+    matches!(&r.lifetime, Some(lifetime) if lifetime.ident == "static")
+}
+
+// Check if `ty` is the object type `self_ident` (`Self` has already been rewritten to it), or a
+// `Result` of it.  If so, also return the type that replaces it across the FFI, since objects are
+// always passed in an `Arc`.
+fn returned_self(ty: &Type, self_ident: &Ident) -> Option<(ReturnedSelf, TokenStream)> {
+    let is_self =
+        |ty: &Type| matches!(ty, Type::Path(p) if p.qself.is_none() && p.path.is_ident(self_ident));
+    if is_self(ty) {
+        return Some((
+            ReturnedSelf::Value,
+            quote! { ::std::sync::Arc<#self_ident> },
+        ));
+    }
+    let p = match ty {
+        Type::Path(p) if p.qself.is_none() => p,
+        _ => return None,
+    };
+    let last = p.path.segments.last()?;
+    let args = match &last.arguments {
+        PathArguments::AngleBracketed(args) if last.ident == "Result" => args,
+        _ => return None,
+    };
+    match args.args.iter().collect::<Vec<_>>().as_slice() {
+        [GenericArgument::Type(ok), GenericArgument::Type(err)] if is_self(ok) => Some((
+            ReturnedSelf::Result,
+            quote! { ::std::result::Result<::std::sync::Arc<#self_ident>, #err> },
+        )),
+        _ => None,
     }
 }
 
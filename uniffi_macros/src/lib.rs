@@ -17,10 +17,12 @@ use syn::{
     parse_macro_input, Ident, LitStr, Path, Token,
 };
 
+mod capabilities;
 mod custom;
 mod enum_;
 mod error;
 mod export;
+mod flags;
 mod fnsig;
 mod object;
 mod record;
@@ -29,8 +31,8 @@ mod test;
 mod util;
 
 use self::{
-    enum_::expand_enum, error::expand_error, export::expand_export, object::expand_object,
-    record::expand_record,
+    enum_::expand_enum, error::expand_error, export::expand_export, flags::expand_flags,
+    object::expand_object, record::expand_record,
 };
 
 struct IdentPair {
@@ -127,7 +129,7 @@ pub fn derive_enum(input: TokenStream) -> TokenStream {
     expand_enum(parse_macro_input!(input)).into()
 }
 
-#[proc_macro_derive(Object)]
+#[proc_macro_derive(Object, attributes(uniffi))]
 pub fn derive_object(input: TokenStream) -> TokenStream {
     let mod_path = match util::mod_path() {
         Ok(p) => p,
@@ -145,6 +147,13 @@ pub fn derive_error(input: TokenStream) -> TokenStream {
         .into()
 }
 
+#[proc_macro_derive(Flags, attributes(uniffi))]
+pub fn derive_flags(input: TokenStream) -> TokenStream {
+    expand_flags(parse_macro_input!(input))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
 /// Generate the `FfiConverter` implementation for a Custom Type - ie,
 /// for a `<T>` which implements `UniffiCustomTypeConverter`.
 #[proc_macro]
@@ -174,6 +183,19 @@ pub fn custom_newtype(tokens: TokenStream) -> TokenStream {
     .into()
 }
 
+/// Export a `supported_capabilities()` function that lists the optional capabilities that this
+/// build of the library supports and that are currently available.
+///
+/// Each capability is a string literal, which can have `cfg` attributes to only declare it when
+/// the corresponding code is compiled in.  Capabilities are available when
+/// `uniffi::check_capability` accepts them, like for `requires_capability`.
+#[proc_macro]
+pub fn declare_capabilities(tokens: TokenStream) -> TokenStream {
+    capabilities::expand_declare_capabilities(parse_macro_input!(tokens))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
 /// Generate the FfiConverter implementation for a Record
 ///
 /// This is used by the Askama scaffolding code.  It this inputs a struct definition, but only
@@ -230,6 +252,19 @@ pub fn ffi_converter_interface(attrs: TokenStream, input: TokenStream) -> TokenS
     .into()
 }
 
+/// Place a metadata static from the UDL scaffolding in the crate's configured metadata section
+#[doc(hidden)]
+#[proc_macro_attribute]
+pub fn metadata_section(_attrs: TokenStream, input: TokenStream) -> TokenStream {
+    let input = proc_macro2::TokenStream::from(input);
+    let section_attrs = util::metadata_section_attrs();
+    quote! {
+        #section_attrs
+        #input
+    }
+    .into()
+}
+
 /// Generate the FfiConverter implementation for an trait interface for the scaffolding code
 #[doc(hidden)]
 #[proc_macro]
@@ -397,6 +432,32 @@ pub fn generate_and_include_scaffolding(udl_file: TokenStream) -> TokenStream {
     }.into()
 }
 
+/// Record the default methods of a trait, so that `#[uniffi::export(default_methods)]` on an impl
+/// of the trait for an object exports them as well.
+#[proc_macro_attribute]
+pub fn default_methods(_attr_args: TokenStream, input: TokenStream) -> TokenStream {
+    let input2 = proc_macro2::TokenStream::from(input.clone());
+    let output = syn::parse(input)
+        .and_then(export::expand_default_methods)
+        .unwrap_or_else(syn::Error::into_compile_error);
+
+    quote! {
+        #input2
+        #output
+    }
+    .into()
+}
+
+/// Generate the scaffolding for the default methods of a trait.  This is invoked by the helper
+/// macro that `#[uniffi::default_methods]` generates.
+#[doc(hidden)]
+#[proc_macro]
+pub fn export_default_methods(tokens: TokenStream) -> TokenStream {
+    export::expand_export_default_methods(parse_macro_input!(tokens))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
 /// A dummy macro that does nothing.
 ///
 /// This exists so `#[uniffi::export]` can emit its input verbatim without
@@ -409,3 +470,12 @@ pub fn generate_and_include_scaffolding(udl_file: TokenStream) -> TokenStream {
 pub fn constructor(_attrs: TokenStream, input: TokenStream) -> TokenStream {
     input
 }
+
+/// A dummy macro that does nothing.
+///
+/// Like `#[uniffi::constructor]`, this marks a method or constructor in an `#[uniffi::export]`
+/// impl block, making its last argument variadic in the foreign bindings.
+#[proc_macro_attribute]
+pub fn variadic(_attrs: TokenStream, input: TokenStream) -> TokenStream {
+    input
+}
@@ -1,23 +1,23 @@
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
-use syn::{DeriveInput, Path};
+use syn::{parse::ParseStream, DeriveInput, Path};
 use uniffi_meta::free_fn_symbol_name;
 
 use crate::util::{
-    create_metadata_items, ident_to_string, mod_path, tagged_impl_header, ArgumentNotAllowedHere,
-    AttributeSliceExt, CommonAttr,
+    create_metadata_items, either_attribute_arg, ident_to_string, mod_path, tagged_impl_header,
+    AttributeSliceExt, CommonAttr, UniffiAttributeArgs,
 };
 
 pub fn expand_object(input: DeriveInput, module_path: String) -> TokenStream {
     let ident = &input.ident;
-    let attr_error = input
-        .attrs
-        .parse_uniffi_attr_args::<ArgumentNotAllowedHere>()
-        .err()
-        .map(syn::Error::into_compile_error);
+    let (attr_error, exports_type_name) = match input.attrs.parse_uniffi_attr_args::<ObjectAttr>() {
+        Ok(attr) => (None, attr.type_name.is_some()),
+        Err(e) => (Some(e.into_compile_error()), false),
+    };
+    let type_name_method = exports_type_name.then(|| type_name_method(ident));
     let name = ident_to_string(ident);
     let free_fn_ident = Ident::new(&free_fn_symbol_name(&module_path, &name), Span::call_site());
-    let meta_static_var = interface_meta_static_var(ident, false, &module_path)
+    let meta_static_var = interface_meta_static_var(ident, false, exports_type_name, &module_path)
         .unwrap_or_else(syn::Error::into_compile_error);
     let interface_impl = interface_impl(ident, None);
 
@@ -41,6 +41,62 @@ pub fn expand_object(input: DeriveInput, module_path: String) -> TokenStream {
         #attr_error
         #interface_impl
         #meta_static_var
+        #type_name_method
+    }
+}
+
+/// Export a `uniffi_type_name()` method, for objects with the `#[uniffi(type_name)]` attribute
+///
+/// This goes through the normal method scaffolding, so the bindings don't need to know about it.
+/// The same name is recorded in the object metadata by [interface_meta_static_var].
+fn type_name_method(ident: &Ident) -> TokenStream {
+    let rust_type_name = rust_type_name(ident);
+    quote! {
+        #[::uniffi::export]
+        impl #ident {
+            /// The Rust type behind this object, for logging and debugging
+            pub fn uniffi_type_name(&self) -> ::std::string::String {
+                ::std::string::String::from(#rust_type_name)
+            }
+        }
+    }
+}
+
+/// The path of the module that defines the type followed by its name, which unlike
+/// `std::any::type_name()` is stable between compiler versions.
+///
+/// This expands to a string literal, so it can be used in the metadata statics.
+fn rust_type_name(ident: &Ident) -> TokenStream {
+    let name = ident_to_string(ident);
+    quote! { ::std::concat!(::std::module_path!(), "::", #name) }
+}
+
+mod kw {
+    syn::custom_keyword!(type_name);
+}
+
+#[derive(Default)]
+struct ObjectAttr {
+    /// Export a method that returns the Rust type name of the object
+    type_name: Option<kw::type_name>,
+}
+
+impl UniffiAttributeArgs for ObjectAttr {
+    fn parse_one(input: ParseStream<'_>) -> syn::Result<Self> {
+        let lookahead = input.lookahead1();
+        if lookahead.peek(kw::type_name) {
+            Ok(Self {
+                type_name: input.parse()?,
+            })
+        } else {
+            Err(lookahead.error())
+        }
+    }
+
+    fn merge(self, other: Self) -> syn::Result<Self> {
+        Ok(Self {
+            type_name: either_attribute_arg(self.type_name, other.type_name)?,
+        })
     }
 }
 
@@ -138,15 +194,26 @@ pub(crate) fn interface_impl(ident: &Ident, tag: Option<&Path>) -> TokenStream {
                 .concat_str(#name)
                 .concat_bool(false);
         }
+
+        unsafe impl ::uniffi::LiftRef<crate::UniFfiTag> for #ident {
+            type LiftType = ::std::sync::Arc<Self>;
+        }
     }
 }
 
 pub(crate) fn interface_meta_static_var(
     ident: &Ident,
     is_trait: bool,
+    exports_type_name: bool,
     module_path: &str,
 ) -> syn::Result<TokenStream> {
     let name = ident_to_string(ident);
+    let rust_type_name = if exports_type_name {
+        let rust_type_name = rust_type_name(ident);
+        quote! { .concat_bool(true).concat_str(#rust_type_name) }
+    } else {
+        quote! { .concat_bool(false) }
+    };
     Ok(create_metadata_items(
         "interface",
         &name,
@@ -155,6 +222,8 @@ pub(crate) fn interface_meta_static_var(
                     .concat_str(#module_path)
                     .concat_str(#name)
                     .concat_bool(#is_trait)
+                    #rust_type_name
+                    .concat_str(::core::module_path!())
         },
         None,
     ))
@@ -2,13 +2,13 @@ use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, ToTokens};
 use syn::{
     parse::{Parse, ParseStream},
-    Data, DataStruct, DeriveInput, Field, Lit, Path, Token,
+    Data, DataStruct, DeriveInput, Field, Lit, Path, Token, Type,
 };
 
 use crate::util::{
     create_metadata_items, either_attribute_arg, ident_to_string, mod_path, tagged_impl_header,
-    try_metadata_value_from_usize, try_read_field, ArgumentNotAllowedHere, AttributeSliceExt,
-    CommonAttr, UniffiAttributeArgs,
+    try_metadata_value_from_usize, try_read_field, AttributeSliceExt, CommonAttr,
+    UniffiAttributeArgs,
 };
 
 pub fn expand_record(input: DeriveInput) -> TokenStream {
@@ -24,18 +24,20 @@ pub fn expand_record(input: DeriveInput) -> TokenStream {
     };
 
     let ident = &input.ident;
-    let attr_error = input
+    let pod = match input
         .attrs
-        .parse_uniffi_attr_args::<ArgumentNotAllowedHere>()
-        .err()
-        .map(syn::Error::into_compile_error);
-    let ffi_converter = record_ffi_converter_impl(ident, &record, None)
+        .parse_uniffi_attr_args::<RecordAttributeArguments>()
+        .and_then(|attrs| check_pod_record(&input, &record, attrs))
+    {
+        Ok(pod) => pod,
+        Err(e) => return e.into_compile_error(),
+    };
+    let ffi_converter = record_ffi_converter_impl(ident, &record, pod, None)
         .unwrap_or_else(syn::Error::into_compile_error);
     let meta_static_var =
-        record_meta_static_var(ident, &record).unwrap_or_else(syn::Error::into_compile_error);
+        record_meta_static_var(ident, &record, pod).unwrap_or_else(syn::Error::into_compile_error);
 
     quote! {
-        #attr_error
         #ffi_converter
         #meta_static_var
     }
@@ -43,7 +45,8 @@ pub fn expand_record(input: DeriveInput) -> TokenStream {
 
 pub(crate) fn expand_record_ffi_converter(attr: CommonAttr, input: DeriveInput) -> TokenStream {
     match input.data {
-        Data::Struct(s) => record_ffi_converter_impl(&input.ident, &s, attr.tag.as_ref())
+        // UDL dictionaries are always serialized into a `RustBuffer`
+        Data::Struct(s) => record_ffi_converter_impl(&input.ident, &s, false, attr.tag.as_ref())
             .unwrap_or_else(syn::Error::into_compile_error),
         _ => syn::Error::new(
             proc_macro2::Span::call_site(),
@@ -53,9 +56,69 @@ pub(crate) fn expand_record_ffi_converter(attr: CommonAttr, input: DeriveInput)
     }
 }
 
+/// Check that a `#[uniffi(pod)]` record can be passed by value, as a plain C struct
+///
+/// This requires a `#[repr(C)]` struct whose fields are all primitive numbers.  Records without
+/// the attribute are always serialized into a `RustBuffer`, even if they're `#[repr(C)]`.
+fn check_pod_record(
+    input: &DeriveInput,
+    record: &DataStruct,
+    attrs: RecordAttributeArguments,
+) -> syn::Result<bool> {
+    let pod = match attrs.pod {
+        Some(pod) => pod,
+        None => return Ok(false),
+    };
+    let mut repr_c = false;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("repr"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("C") {
+                repr_c = true;
+            } else if meta.path.is_ident("packed") || meta.path.is_ident("align") {
+                return Err(meta.error(
+                    "`#[uniffi(pod)]` records can't change their alignment, \
+                     since the foreign bindings use the default C layout",
+                ));
+            }
+            // Skip the arguments of other representations, like `align(8)`
+            if meta.input.peek(syn::token::Paren) {
+                meta.input.parse::<proc_macro2::Group>()?;
+            }
+            Ok(())
+        })?;
+    }
+    if !repr_c {
+        return Err(syn::Error::new_spanned(
+            pod,
+            "`#[uniffi(pod)]` records must be `#[repr(C)]`",
+        ));
+    }
+    if record.fields.is_empty() {
+        return Err(syn::Error::new_spanned(
+            pod,
+            "`#[uniffi(pod)]` records must have at least one field",
+        ));
+    }
+    for field in record.fields.iter() {
+        if !is_pod_field(&field.ty) {
+            return Err(syn::Error::new_spanned(
+                &field.ty,
+                "`#[uniffi(pod)]` record fields must be primitive numbers \
+                 (`u8`-`u64`, `i8`-`i64`, `f32` or `f64`)",
+            ));
+        }
+    }
+    Ok(true)
+}
+
 pub(crate) fn record_ffi_converter_impl(
     ident: &Ident,
     record: &DataStruct,
+    pod: bool,
     tag: Option<&Path>,
 ) -> syn::Result<TokenStream> {
     let impl_spec = tagged_impl_header("FfiConverter", ident, tag);
@@ -63,11 +126,46 @@ pub(crate) fn record_ffi_converter_impl(
     let mod_path = mod_path()?;
     let write_impl: TokenStream = record.fields.iter().map(write_field).collect();
     let try_read_fields: TokenStream = record.fields.iter().map(try_read_field).collect();
+    let (lift_and_lower, ffi_default) = if pod {
+        // Plain C structs are passed by value, so lowering and lifting are no-ops.
+        let default_fields = record.fields.iter().map(|f| {
+            let ident = &f.ident;
+            quote! { #ident: ::uniffi::FfiDefault::ffi_default(), }
+        });
+        (
+            quote! {
+                type FfiType = Self;
+
+                fn lower(obj: Self) -> Self {
+                    obj
+                }
+
+                fn try_lift(v: Self) -> ::uniffi::Result<Self> {
+                    Ok(v)
+                }
+            },
+            quote! {
+                #[automatically_derived]
+                impl ::uniffi::FfiDefault for #ident {
+                    fn ffi_default() -> Self {
+                        Self { #(#default_fields)* }
+                    }
+                }
+            },
+        )
+    } else {
+        (
+            quote! { ::uniffi::ffi_converter_rust_buffer_lift_and_lower!(crate::UniFfiTag); },
+            quote! {},
+        )
+    };
 
     Ok(quote! {
+        #ffi_default
+
         #[automatically_derived]
         unsafe #impl_spec {
-            ::uniffi::ffi_converter_rust_buffer_lift_and_lower!(crate::UniFfiTag);
+            #lift_and_lower
             ::uniffi::ffi_converter_default_return!(crate::UniFfiTag);
 
             fn write(obj: Self, buf: &mut ::std::vec::Vec<u8>) {
@@ -75,12 +173,14 @@ pub(crate) fn record_ffi_converter_impl(
             }
 
             fn try_read(buf: &mut &[::std::primitive::u8]) -> ::uniffi::deps::anyhow::Result<Self> {
+                let _guard = ::uniffi::NestingGuard::enter()?;
                 Ok(Self { #try_read_fields })
             }
 
             const TYPE_ID_META: ::uniffi::MetadataBuffer = ::uniffi::MetadataBuffer::from_code(::uniffi::metadata::codes::TYPE_RECORD)
                 .concat_str(#mod_path)
-                .concat_str(#name);
+                .concat_str(#name)
+                .concat_bool(#pod);
         }
     })
 }
@@ -96,7 +196,29 @@ fn write_field(f: &Field) -> TokenStream {
 
 mod kw {
     syn::custom_keyword!(default);
+    syn::custom_keyword!(exclude_from_eq);
     syn::custom_keyword!(None);
+    syn::custom_keyword!(pod);
+}
+
+#[derive(Default)]
+pub struct RecordAttributeArguments {
+    /// Pass the record by value, as a plain C struct
+    pub(crate) pod: Option<kw::pod>,
+}
+
+impl UniffiAttributeArgs for RecordAttributeArguments {
+    fn parse_one(input: ParseStream<'_>) -> syn::Result<Self> {
+        Ok(Self {
+            pod: Some(input.parse()?),
+        })
+    }
+
+    fn merge(self, other: Self) -> syn::Result<Self> {
+        Ok(Self {
+            pod: either_attribute_arg(self.pod, other.pod)?,
+        })
+    }
 }
 
 pub enum FieldDefault {
@@ -128,21 +250,35 @@ impl Parse for FieldDefault {
 #[derive(Default)]
 pub struct FieldAttributeArguments {
     pub(crate) default: Option<FieldDefault>,
+    /// Leave the field out of the `equals`/`hashCode` implementations of the foreign record
+    pub(crate) exclude_from_eq: Option<kw::exclude_from_eq>,
 }
 
 impl UniffiAttributeArgs for FieldAttributeArguments {
     fn parse_one(input: ParseStream<'_>) -> syn::Result<Self> {
-        let _: kw::default = input.parse()?;
-        let _: Token![=] = input.parse()?;
-        let default = input.parse()?;
-        Ok(Self {
-            default: Some(default),
-        })
+        let lookahead = input.lookahead1();
+        if lookahead.peek(kw::default) {
+            let _: kw::default = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let default = input.parse()?;
+            Ok(Self {
+                default: Some(default),
+                ..Self::default()
+            })
+        } else if lookahead.peek(kw::exclude_from_eq) {
+            Ok(Self {
+                exclude_from_eq: Some(input.parse()?),
+                ..Self::default()
+            })
+        } else {
+            Err(lookahead.error())
+        }
     }
 
     fn merge(self, other: Self) -> syn::Result<Self> {
         Ok(Self {
             default: either_attribute_arg(self.default, other.default)?,
+            exclude_from_eq: either_attribute_arg(self.exclude_from_eq, other.exclude_from_eq)?,
         })
     }
 }
@@ -150,6 +286,7 @@ impl UniffiAttributeArgs for FieldAttributeArguments {
 pub(crate) fn record_meta_static_var(
     ident: &Ident,
     record: &DataStruct,
+    pod: bool,
 ) -> syn::Result<TokenStream> {
     let name = ident_to_string(ident);
     let module_path = mod_path()?;
@@ -176,11 +313,13 @@ pub(crate) fn record_meta_static_var(
                 }
                 None => quote! { .concat_bool(false) },
             };
+            let exclude_from_eq = attrs.exclude_from_eq.is_some();
 
             Ok(quote! {
                 .concat_str(#name)
                 .concat(<#ty as ::uniffi::FfiConverter<crate::UniFfiTag>>::TYPE_ID_META)
                 #default
+                .concat_bool(#exclude_from_eq)
             })
         })
         .collect::<syn::Result<_>>()?;
@@ -192,8 +331,10 @@ pub(crate) fn record_meta_static_var(
             ::uniffi::MetadataBuffer::from_code(::uniffi::metadata::codes::RECORD)
                 .concat_str(#module_path)
                 .concat_str(#name)
+                .concat_bool(#pod)
                 .concat_value(#fields_len)
                 #concat_fields
+                .concat_str(::core::module_path!())
         },
         None,
     ))
@@ -6,12 +6,14 @@ use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use syn::Result;
 
-use crate::util::mod_path;
+use crate::error::dyn_error_scaffolding;
+use crate::util::{metadata_section_attrs, mod_path};
 use uniffi_meta::UNIFFI_CONTRACT_VERSION;
 
 pub fn setup_scaffolding(namespace: String) -> Result<TokenStream> {
     let module_path = mod_path()?;
     let ffi_contract_version_ident = format_ident!("ffi_{namespace}_uniffi_contract_version");
+    let ffi_runtime_version_ident = format_ident!("ffi_{namespace}_uniffi_runtime_version");
     let namespace_upper = namespace.to_ascii_uppercase();
     let namespace_const_ident = format_ident!("UNIFFI_META_CONST_NAMESPACE_{namespace_upper}");
     let namespace_static_ident = format_ident!("UNIFFI_META_NAMESPACE_{namespace_upper}");
@@ -19,7 +21,19 @@ pub fn setup_scaffolding(namespace: String) -> Result<TokenStream> {
     let ffi_rustbuffer_from_bytes_ident = format_ident!("ffi_{namespace}_rustbuffer_from_bytes");
     let ffi_rustbuffer_free_ident = format_ident!("ffi_{namespace}_rustbuffer_free");
     let ffi_rustbuffer_reserve_ident = format_ident!("ffi_{namespace}_rustbuffer_reserve");
+    let ffi_rust_future_cancel_ident = format_ident!("ffi_{namespace}_rust_future_cancel");
+    let ffi_rust_future_cancellable_ident =
+        format_ident!("ffi_{namespace}_rust_future_cancellable");
+    let ffi_span_sink_set_ident = format_ident!("ffi_{namespace}_span_sink_set");
+    let ffi_panic_observer_set_ident = format_ident!("ffi_{namespace}_panic_observer_set");
+    let ffi_string_encoding_set_ident = format_ident!("ffi_{namespace}_string_encoding_set");
+    let ffi_empty_buffer_sentinels_enable_ident =
+        format_ident!("ffi_{namespace}_empty_buffer_sentinels_enable");
+    let ffi_max_nesting_depth_set_ident = format_ident!("ffi_{namespace}_max_nesting_depth_set");
+    let ffi_capability_set_ident = format_ident!("ffi_{namespace}_capability_set");
     let reexport_hack_ident = format_ident!("{namespace}_uniffi_reexport_hack");
+    let section_attrs = metadata_section_attrs();
+    let dyn_error = dyn_error_scaffolding()?;
 
     Ok(quote! {
         // Unit struct to parameterize the FfiConverter trait.
@@ -39,6 +53,16 @@ pub fn setup_scaffolding(namespace: String) -> Result<TokenStream> {
             #UNIFFI_CONTRACT_VERSION
         }
 
+        // The version of the `uniffi` crate, for bindings generated with the `runtime_version`
+        // option.  See `uniffi::PACKED_PACKAGE_VERSION` for how it's encoded.
+
+        #[allow(clippy::missing_safety_doc, missing_docs)]
+        #[doc(hidden)]
+        #[no_mangle]
+        pub extern "C" fn #ffi_runtime_version_ident() -> u32 {
+            uniffi::PACKED_PACKAGE_VERSION
+        }
+
 
         /// Export namespace metadata.
         ///
@@ -50,6 +74,7 @@ pub fn setup_scaffolding(namespace: String) -> Result<TokenStream> {
 
         #[doc(hidden)]
         #[no_mangle]
+        #section_attrs
         pub static #namespace_static_ident: [u8; #namespace_const_ident.size] = #namespace_const_ident.into_array();
 
         // Everybody gets basic buffer support, since it's needed for passing complex types over the FFI.
@@ -84,6 +109,104 @@ pub fn setup_scaffolding(namespace: String) -> Result<TokenStream> {
             uniffi::ffi::uniffi_rustbuffer_reserve(buf, additional, call_status)
         }
 
+        // Mark the next async call as cancellable, then cancel an in-flight async call.
+        //
+        // See `uniffi/src/ffi/rustfuture.rs` for documentation on cancellation
+
+        #[allow(clippy::missing_safety_doc, missing_docs)]
+        #[doc(hidden)]
+        #[no_mangle]
+        pub extern "C" fn #ffi_rust_future_cancellable_ident(callback_data: *const (), call_status: &mut uniffi::RustCallStatus) {
+            uniffi::rust_call(call_status, || {
+                uniffi::ffi::rust_future_cancellable(callback_data);
+                Ok(())
+            });
+        }
+
+        #[allow(clippy::missing_safety_doc, missing_docs)]
+        #[doc(hidden)]
+        #[no_mangle]
+        pub extern "C" fn #ffi_rust_future_cancel_ident(callback_data: *const (), call_status: &mut uniffi::RustCallStatus) {
+            uniffi::rust_call(call_status, || {
+                uniffi::ffi::rust_future_cancel::<crate::UniFfiTag>(callback_data);
+                Ok(())
+            });
+        }
+
+        // Register the foreign span sink, for bindings generated with the `span_sink` option.
+        //
+        // See `uniffi/src/ffi/spans.rs` for documentation on span sinks
+
+        #[allow(clippy::missing_safety_doc, missing_docs)]
+        #[doc(hidden)]
+        #[no_mangle]
+        pub extern "C" fn #ffi_span_sink_set_ident(callback: uniffi::SpanSinkCallback) {
+            uniffi::ffi::span_sink_set(callback);
+        }
+
+        // Register the foreign panic observer, for bindings generated with the `panic_observer`
+        // option.
+        //
+        // See `uniffi/src/ffi/panic_observer.rs` for documentation on panic observers
+
+        #[allow(clippy::missing_safety_doc, missing_docs)]
+        #[doc(hidden)]
+        #[no_mangle]
+        pub extern "C" fn #ffi_panic_observer_set_ident(callback: uniffi::PanicObserverCallback) {
+            uniffi::ffi::panic_observer_set(callback);
+        }
+
+        // Set the string wire encoding, for bindings generated with the `string_encoding` option.
+        // Returns 1 if the encoding was set and 0 if the library already uses a different one.
+        //
+        // See `uniffi/src/ffi/string_encoding.rs` for documentation on string encodings
+
+        #[allow(clippy::missing_safety_doc, missing_docs)]
+        #[doc(hidden)]
+        #[no_mangle]
+        pub extern "C" fn #ffi_string_encoding_set_ident(encoding: u8) -> i8 {
+            uniffi::ffi::string_encoding_set(encoding) as i8
+        }
+
+        // Start passing empty values as empty buffers, for bindings generated with the
+        // `empty_buffer_sentinels` option.
+        //
+        // See `uniffi/src/ffi/empty_buffers.rs` for documentation on empty buffers
+
+        #[allow(clippy::missing_safety_doc, missing_docs)]
+        #[doc(hidden)]
+        #[no_mangle]
+        pub extern "C" fn #ffi_empty_buffer_sentinels_enable_ident() {
+            uniffi::ffi::empty_buffer_sentinels_enable();
+        }
+
+        // Limit the nesting of lifted values, for bindings generated with the `max_nesting_depth`
+        // option.
+        //
+        // See `uniffi/src/ffi/nesting.rs` for documentation on nesting limits
+
+        #[allow(clippy::missing_safety_doc, missing_docs)]
+        #[doc(hidden)]
+        #[no_mangle]
+        pub extern "C" fn #ffi_max_nesting_depth_set_ident(max_depth: u32) {
+            uniffi::ffi::max_nesting_depth_set(max_depth);
+        }
+
+        // Make a capability available or unavailable, for bindings generated with the
+        // `capability_registration` option.
+        //
+        // See `uniffi/src/ffi/capabilities.rs` for documentation on capabilities
+
+        #[allow(clippy::missing_safety_doc, missing_docs)]
+        #[doc(hidden)]
+        #[no_mangle]
+        pub unsafe extern "C" fn #ffi_capability_set_ident(name_data: *const u8, name_len: i32, available: i8) {
+            uniffi::ffi::capability_set(name_data, name_len, available);
+        }
+
+        // Lower `Box<dyn Error>` errors as a flat `DynError`, see `uniffi_macros/src/error.rs`
+        #dyn_error
+
         // Code to re-export the UniFFI scaffolding functions.
         //
         // Rust won't always re-export the functions from dependencies
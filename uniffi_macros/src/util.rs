@@ -75,6 +75,58 @@ pub fn mod_path() -> syn::Result<String> {
     Ok(syn::parse::<syn::LitStr>(expanded_module_path)?.value())
 }
 
+/// Attributes that place a metadata static in the crate's configured metadata section
+///
+/// The section is set with `metadata_section` in the `[package.metadata.uniffi]` table of the
+/// crate's `Cargo.toml`.  Without it, the metadata goes wherever the compiler puts other statics.
+pub fn metadata_section_attrs() -> TokenStream {
+    use fs_err as fs;
+    use once_cell::sync::Lazy;
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    struct CargoToml {
+        package: Package,
+    }
+
+    #[derive(Deserialize)]
+    struct Package {
+        #[serde(default)]
+        metadata: PackageMetadata,
+    }
+
+    #[derive(Default, Deserialize)]
+    struct PackageMetadata {
+        #[serde(default)]
+        uniffi: UniffiMetadata,
+    }
+
+    #[derive(Default, Deserialize)]
+    struct UniffiMetadata {
+        metadata_section: Option<String>,
+    }
+
+    // If `Cargo.toml` can't be read, there's no section to configure
+    static METADATA_SECTION: Lazy<Option<String>> = Lazy::new(|| {
+        let cargo_toml_bytes = fs::read(manifest_path().ok()?).ok()?;
+        toml::from_slice::<CargoToml>(&cargo_toml_bytes)
+            .ok()?
+            .package
+            .metadata
+            .uniffi
+            .metadata_section
+    });
+
+    match METADATA_SECTION.as_deref() {
+        // `#[used]` keeps the static through LTO, the linker script has to keep the section
+        Some(section) => quote! {
+            #[link_section = #section]
+            #[used]
+        },
+        None => quote! {},
+    }
+}
+
 pub fn try_read_field(f: &syn::Field) -> TokenStream {
     let ident = &f.ident;
     let ty = &f.ty;
@@ -116,11 +168,13 @@ pub fn create_metadata_items(
             }
         }
     });
+    let section_attrs = metadata_section_attrs();
 
     quote! {
         const #const_ident: ::uniffi::MetadataBuffer = #metadata_expr;
         #[no_mangle]
         #[doc(hidden)]
+        #section_attrs
         pub static #static_ident: [u8; #const_ident.size] = #const_ident.into_array();
 
         #checksum_fn
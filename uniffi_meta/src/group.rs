@@ -39,6 +39,7 @@ pub fn group_metadata(items: Vec<Metadata>) -> Result<Vec<MetadataGroup>> {
             ),
             Metadata::Record(meta) => (format!("record `{}`", meta.name), &meta.module_path),
             Metadata::Enum(meta) => (format!("enum `{}`", meta.name), &meta.module_path),
+            Metadata::Flags(meta) => (format!("flags `{}`", meta.name), &meta.module_path),
             Metadata::Object(meta) => (format!("object `{}`", meta.name), &meta.module_path),
             Metadata::CallbackInterface(meta) => (
                 format!("callback interface `{}`", meta.name),
@@ -166,15 +167,25 @@ impl<'a> ExternalTypeConverter<'a> {
     fn convert_type(&self, ty: Type) -> Type {
         match ty {
             // Convert `ty` if it's external
-            Type::Enum { module_path, name } | Type::Record { module_path, name }
-                if self.is_module_path_external(&module_path) =>
-            {
-                Type::External {
-                    module_path,
-                    name,
-                    kind: ExternalKind::DataClass,
-                }
-            }
+            Type::Enum { module_path, name }
+            | Type::Record {
+                module_path,
+                name,
+                pod: false,
+            } if self.is_module_path_external(&module_path) => Type::External {
+                module_path,
+                name,
+                kind: ExternalKind::DataClass,
+            },
+            Type::Record {
+                module_path,
+                name,
+                pod: true,
+            } if self.is_module_path_external(&module_path) => Type::External {
+                module_path,
+                name,
+                kind: ExternalKind::PodRecord,
+            },
             Type::Custom {
                 module_path, name, ..
             } if self.is_module_path_external(&module_path) => {
@@ -222,6 +233,13 @@ impl<'a> ExternalTypeConverter<'a> {
                 key_type: Box::new(self.convert_type(*key_type)),
                 value_type: Box::new(self.convert_type(*value_type)),
             },
+            Type::Range {
+                inner_type,
+                inclusive,
+            } => Type::Range {
+                inner_type: Box::new(self.convert_type(*inner_type)),
+                inclusive,
+            },
             // Otherwise, just return the type unchanged
             _ => ty,
         }
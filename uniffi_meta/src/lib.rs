@@ -14,7 +14,7 @@ mod group;
 pub use group::{group_metadata, MetadataGroup};
 
 mod reader;
-pub use reader::{read_metadata, read_metadata_type};
+pub use reader::{read_metadata, read_metadata_items, read_metadata_type};
 
 mod types;
 pub use types::{AsType, ExternalKind, ObjectImpl, Type, TypeIterator};
@@ -27,6 +27,10 @@ mod metadata;
 // Once we get to 1.0, then we'll need to update the scheme to something like 100 + major_version
 pub const UNIFFI_CONTRACT_VERSION: u32 = 23;
 
+/// Name of the flat error that `Box<dyn Error>` errors are lowered as.  `setup_scaffolding!`
+/// defines it for each crate.
+pub const DYN_ERROR_NAME: &str = "DynError";
+
 /// Similar to std::hash::Hash.
 ///
 /// Implementations of this trait are expected to update the hasher state in
@@ -141,7 +145,12 @@ pub struct FnMetadata {
     pub inputs: Vec<FnParamMetadata>,
     pub return_type: Option<Type>,
     pub throws: Option<Type>,
+    // Extra foreign names for the function, from `#[uniffi::export(aliases(...))]`
+    pub aliases: Vec<String>,
     pub checksum: Option<u16>,
+    /// The full path of the Rust module that defines the item, from `module_path!()`.  Unlike
+    /// `module_path`, which is only the crate name for proc-macro items, this includes the submodules.
+    pub full_module_path: String,
 }
 
 impl FnMetadata {
@@ -159,6 +168,7 @@ pub struct ConstructorMetadata {
     pub module_path: String,
     pub self_name: String,
     pub name: String,
+    pub is_async: bool,
     pub inputs: Vec<FnParamMetadata>,
     pub throws: Option<Type>,
     pub checksum: Option<u16>,
@@ -235,6 +245,8 @@ pub struct FnParamMetadata {
     pub by_ref: bool,
     pub optional: bool,
     pub default: Option<LiteralMetadata>,
+    /// Exposed as a variadic argument in the foreign bindings.  `ty` is always a sequence type.
+    pub variadic: bool,
 }
 
 impl FnParamMetadata {
@@ -245,6 +257,7 @@ impl FnParamMetadata {
             by_ref: false,
             optional: false,
             default: None,
+            variadic: false,
         }
     }
 }
@@ -282,7 +295,11 @@ pub enum Radix {
 pub struct RecordMetadata {
     pub module_path: String,
     pub name: String,
+    /// Is this a `#[uniffi(pod)]` record, which is passed by value as a C struct?
+    pub pod: bool,
     pub fields: Vec<FieldMetadata>,
+    /// See [FnMetadata::full_module_path]
+    pub full_module_path: String,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
@@ -291,6 +308,8 @@ pub struct FieldMetadata {
     #[serde(rename = "type")]
     pub ty: Type,
     pub default: Option<LiteralMetadata>,
+    /// Leave this field out of the foreign equality and hashing implementations
+    pub exclude_from_eq: bool,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
@@ -298,6 +317,8 @@ pub struct EnumMetadata {
     pub module_path: String,
     pub name: String,
     pub variants: Vec<VariantMetadata>,
+    /// See [FnMetadata::full_module_path]
+    pub full_module_path: String,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
@@ -306,18 +327,42 @@ pub struct VariantMetadata {
     pub fields: Vec<FieldMetadata>,
 }
 
+/// A "flags" type: an unsigned integer newtype whose values are combinations of named bits.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct FlagsMetadata {
+    pub module_path: String,
+    pub name: String,
+    /// The unsigned integer type that stores the bits.
+    pub repr: Type,
+    pub flags: Vec<FlagMetadata>,
+    /// See [FnMetadata::full_module_path]
+    pub full_module_path: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct FlagMetadata {
+    pub name: String,
+    pub value: u64,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
 pub struct ObjectMetadata {
     pub module_path: String,
     pub name: String,
     pub imp: types::ObjectImpl,
     pub uniffi_traits: Vec<UniffiTraitMetadata>,
+    /// The Rust path of the type, for objects with the `#[uniffi(type_name)]` attribute
+    pub rust_type_name: Option<String>,
+    /// See [FnMetadata::full_module_path]
+    pub full_module_path: String,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
 pub struct CallbackInterfaceMetadata {
     pub module_path: String,
     pub name: String,
+    /// See [FnMetadata::full_module_path]
+    pub full_module_path: String,
 }
 
 impl ObjectMetadata {
@@ -345,6 +390,9 @@ pub enum UniffiTraitMetadata {
     Hash {
         hash: MethodMetadata,
     },
+    Clone {
+        clone: MethodMetadata,
+    },
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
@@ -393,6 +441,7 @@ pub enum Metadata {
     CallbackInterface(CallbackInterfaceMetadata),
     Record(RecordMetadata),
     Enum(EnumMetadata),
+    Flags(FlagsMetadata),
     Error(ErrorMetadata),
     Constructor(ConstructorMetadata),
     Method(MethodMetadata),
@@ -448,6 +497,12 @@ impl From<EnumMetadata> for Metadata {
     }
 }
 
+impl From<FlagsMetadata> for Metadata {
+    fn from(f: FlagsMetadata) -> Self {
+        Self::Flags(f)
+    }
+}
+
 impl From<ErrorMetadata> for Metadata {
     fn from(e: ErrorMetadata) -> Self {
         Self::Error(e)
@@ -21,6 +21,7 @@ pub mod codes {
     pub const UDL_FILE: u8 = 8;
     pub const CALLBACK_INTERFACE: u8 = 9;
     pub const TRAIT_METHOD: u8 = 10;
+    pub const FLAGS: u8 = 11;
     //pub const UNKNOWN: u8 = 255;
 
     // Type codes
@@ -50,6 +51,11 @@ pub mod codes {
     pub const TYPE_RESULT: u8 = 23;
     //pub const TYPE_FUTURE: u8 = 24;
     pub const TYPE_FOREIGN_EXECUTOR: u8 = 25;
+    pub const TYPE_BYTE_ARRAY: u8 = 26;
+    pub const TYPE_BYTES: u8 = 27;
+    pub const TYPE_RANGE: u8 = 27;
+    pub const TYPE_RANGE_INCLUSIVE: u8 = 28;
+    pub const TYPE_JSON_VALUE: u8 = 29;
     pub const TYPE_UNIT: u8 = 255;
 
     // Literal codes
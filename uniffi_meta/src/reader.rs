@@ -10,6 +10,18 @@ pub fn read_metadata(data: &[u8]) -> Result<Metadata> {
     MetadataReader::new(data).read_metadata()
 }
 
+/// Read the metadata items stored back-to-back in `data`, like the contents of the section that a
+/// crate's metadata statics are placed in
+pub fn read_metadata_items(mut data: &[u8]) -> Result<Vec<Metadata>> {
+    let mut items = vec![];
+    while !data.is_empty() {
+        let mut reader = MetadataReader::new(data);
+        items.push(reader.read_metadata()?);
+        data = reader.buf;
+    }
+    Ok(items)
+}
+
 // Read a metadat type, this is pub so that we can test it in the metadata fixture
 pub fn read_metadata_type(data: &[u8]) -> Result<Type> {
     MetadataReader::new(data).read_type()
@@ -33,8 +45,9 @@ impl<'a> MetadataReader<'a> {
 
     // Read a top-level metadata item
     //
-    // This consumes self because MetadataReader is only intended to read a single item.
-    fn read_metadata(mut self) -> Result<Metadata> {
+    // MetadataReader is only intended to read a single item, since checksums are calculated from
+    // the start of `initial_data`.  Afterwards, `buf` holds the data following the item.
+    fn read_metadata(&mut self) -> Result<Metadata> {
         let value = self.read_u8()?;
         Ok(match value {
             codes::NAMESPACE => NamespaceMetadata {
@@ -52,6 +65,7 @@ impl<'a> MetadataReader<'a> {
             codes::METHOD => self.read_method()?.into(),
             codes::RECORD => self.read_record()?.into(),
             codes::ENUM => self.read_enum(false)?.into(),
+            codes::FLAGS => self.read_flags()?.into(),
             codes::ERROR => self.read_error()?.into(),
             codes::INTERFACE => self.read_object()?.into(),
             codes::CALLBACK_INTERFACE => self.read_callback_interface()?.into(),
@@ -92,6 +106,17 @@ impl<'a> MetadataReader<'a> {
         }
     }
 
+    fn read_u64(&mut self) -> Result<u64> {
+        if self.buf.len() >= 8 {
+            // read the value as little-endian
+            let value = (0..8).fold(0, |value, i| value + ((self.buf[i] as u64) << (8 * i)));
+            self.buf = &self.buf[8..];
+            Ok(value)
+        } else {
+            bail!("Not enough data left in buffer to read a u64 value");
+        }
+    }
+
     fn read_bool(&mut self) -> Result<bool> {
         Ok(self.read_u8()? == 1)
     }
@@ -120,10 +145,16 @@ impl<'a> MetadataReader<'a> {
             codes::TYPE_STRING => Type::String,
             codes::TYPE_DURATION => Type::Duration,
             codes::TYPE_SYSTEM_TIME => Type::Timestamp,
+            codes::TYPE_JSON_VALUE => Type::JsonValue,
             codes::TYPE_FOREIGN_EXECUTOR => Type::ForeignExecutor,
+            codes::TYPE_BYTES => Type::Bytes,
+            codes::TYPE_BYTE_ARRAY => Type::ByteArray {
+                len: self.read_u32()?,
+            },
             codes::TYPE_RECORD => Type::Record {
                 module_path: self.read_string()?,
                 name: self.read_string()?,
+                pod: self.read_bool()?,
             },
             codes::TYPE_ENUM => Type::Enum {
                 module_path: self.read_string()?,
@@ -153,12 +184,28 @@ impl<'a> MetadataReader<'a> {
                 key_type: Box::new(self.read_type()?),
                 value_type: Box::new(self.read_type()?),
             },
+            codes::TYPE_RANGE => Type::Range {
+                inner_type: Box::new(self.read_type()?),
+                inclusive: false,
+            },
+            codes::TYPE_RANGE_INCLUSIVE => Type::Range {
+                inner_type: Box::new(self.read_type()?),
+                inclusive: true,
+            },
             codes::TYPE_UNIT => bail!("Unexpected TYPE_UNIT"),
             codes::TYPE_RESULT => bail!("Unexpected TYPE_RESULT"),
             _ => bail!("Unexpected metadata type code: {value:?}"),
         })
     }
 
+    fn read_optional_string(&mut self) -> Result<Option<String>> {
+        Ok(if self.read_bool()? {
+            Some(self.read_string()?)
+        } else {
+            None
+        })
+    }
+
     fn read_optional_type(&mut self) -> Result<Option<Type>> {
         Ok(match self.peek_u8()? {
             codes::TYPE_UNIT => {
@@ -189,6 +236,8 @@ impl<'a> MetadataReader<'a> {
         let is_async = self.read_bool()?;
         let inputs = self.read_inputs()?;
         let (return_type, throws) = self.read_return_type()?;
+        let aliases = self.read_aliases()?;
+        let full_module_path = self.read_string()?;
         Ok(FnMetadata {
             module_path,
             name,
@@ -196,7 +245,9 @@ impl<'a> MetadataReader<'a> {
             inputs,
             return_type,
             throws,
+            aliases,
             checksum: self.calc_checksum(),
+            full_module_path,
         })
     }
 
@@ -204,6 +255,7 @@ impl<'a> MetadataReader<'a> {
         let module_path = self.read_string()?;
         let self_name = self.read_string()?;
         let name = self.read_string()?;
+        let is_async = self.read_bool()?;
         let inputs = self.read_inputs()?;
         let (return_type, throws) = self.read_return_type()?;
 
@@ -220,6 +272,7 @@ impl<'a> MetadataReader<'a> {
             module_path,
             self_name,
             name,
+            is_async,
             inputs,
             throws,
             checksum: self.calc_checksum(),
@@ -250,7 +303,9 @@ impl<'a> MetadataReader<'a> {
         Ok(RecordMetadata {
             module_path: self.read_string()?,
             name: self.read_string()?,
+            pod: self.read_bool()?,
             fields: self.read_fields()?,
+            full_module_path: self.read_string()?,
         })
     }
 
@@ -267,6 +322,30 @@ impl<'a> MetadataReader<'a> {
             module_path,
             name,
             variants,
+            full_module_path: self.read_string()?,
+        })
+    }
+
+    fn read_flags(&mut self) -> Result<FlagsMetadata> {
+        let module_path = self.read_string()?;
+        let name = self.read_string()?;
+        let repr = self.read_type()?;
+        let len = self.read_u8()?;
+        let flags = (0..len)
+            .map(|_| {
+                Ok(FlagMetadata {
+                    name: self.read_string()?,
+                    value: self.read_u64()?,
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(FlagsMetadata {
+            module_path,
+            name,
+            repr,
+            flags,
+            full_module_path: self.read_string()?,
         })
     }
 
@@ -282,6 +361,8 @@ impl<'a> MetadataReader<'a> {
             name: self.read_string()?,
             imp: ObjectImpl::from_is_trait(self.read_bool()?),
             uniffi_traits: vec![], // TODO: not yet emitted
+            rust_type_name: self.read_optional_string()?,
+            full_module_path: self.read_string()?,
         })
     }
 
@@ -289,6 +370,7 @@ impl<'a> MetadataReader<'a> {
         Ok(CallbackInterfaceMetadata {
             module_path: self.read_string()?,
             name: self.read_string()?,
+            full_module_path: self.read_string()?,
         })
     }
 
@@ -321,7 +403,13 @@ impl<'a> MetadataReader<'a> {
                 let name = self.read_string()?;
                 let ty = self.read_type()?;
                 let default = self.read_default(&name, &ty)?;
-                Ok(FieldMetadata { name, ty, default })
+                let exclude_from_eq = self.read_bool()?;
+                Ok(FieldMetadata {
+                    name,
+                    ty,
+                    default,
+                    exclude_from_eq,
+                })
             })
             .collect()
     }
@@ -350,6 +438,11 @@ impl<'a> MetadataReader<'a> {
             .collect()
     }
 
+    fn read_aliases(&mut self) -> Result<Vec<String>> {
+        let len = self.read_u8()?;
+        (0..len).map(|_| self.read_string()).collect()
+    }
+
     fn read_inputs(&mut self) -> Result<Vec<FnParamMetadata>> {
         let len = self.read_u8()?;
         (0..len)
@@ -357,6 +450,7 @@ impl<'a> MetadataReader<'a> {
                 Ok(FnParamMetadata {
                     name: self.read_string()?,
                     ty: self.read_type()?,
+                    variadic: self.read_bool()?,
                     // not emitted by macros
                     by_ref: false,
                     optional: false,
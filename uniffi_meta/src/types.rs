@@ -56,6 +56,8 @@ pub enum ExternalKind {
     Interface,
     // Either a record or enum
     DataClass,
+    // A `#[uniffi(pod)]` record, which is passed by value
+    PodRecord,
 }
 
 /// Represents all the different high-level types that can be used in a component interface.
@@ -77,6 +79,10 @@ pub enum Type {
     Boolean,
     String,
     Bytes,
+    // `[u8; N]`, which is passed like `Bytes` but always has `len` bytes
+    ByteArray {
+        len: u32,
+    },
     Timestamp,
     Duration,
     Object {
@@ -92,6 +98,8 @@ pub enum Type {
     Record {
         module_path: String,
         name: String,
+        // Is this a `#[uniffi(pod)]` record, which is passed by value as a C struct?
+        pod: bool,
     },
     Enum {
         module_path: String,
@@ -112,6 +120,13 @@ pub enum Type {
         key_type: Box<Type>,
         value_type: Box<Type>,
     },
+    // `Range<T>`, or `RangeInclusive<T>` if `inclusive` is set
+    Range {
+        inner_type: Box<Type>,
+        inclusive: bool,
+    },
+    // A dynamic JSON value, passed as its serialized string
+    JsonValue,
     // An FfiConverter we `use` from an external crate
     External {
         module_path: String,
@@ -129,9 +144,9 @@ pub enum Type {
 impl Type {
     pub fn iter_types(&self) -> TypeIterator<'_> {
         let nested_types = match self {
-            Type::Optional { inner_type } | Type::Sequence { inner_type } => {
-                inner_type.iter_types()
-            }
+            Type::Optional { inner_type }
+            | Type::Sequence { inner_type }
+            | Type::Range { inner_type, .. } => inner_type.iter_types(),
             Type::Map {
                 key_type,
                 value_type,
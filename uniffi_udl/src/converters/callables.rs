@@ -41,6 +41,7 @@ impl APIConverter<FieldMetadata> for weedle::argument::SingleArgument<'_> {
             name: self.identifier.0.to_string(),
             ty: type_,
             default: None,
+            exclude_from_eq: false,
         })
     }
 }
@@ -49,7 +50,7 @@ impl APIConverter<FnParamMetadata> for weedle::argument::Argument<'_> {
     fn convert(&self, ci: &mut InterfaceCollector) -> Result<FnParamMetadata> {
         match self {
             weedle::argument::Argument::Single(t) => t.convert(ci),
-            weedle::argument::Argument::Variadic(_) => bail!("variadic arguments not supported"),
+            weedle::argument::Argument::Variadic(t) => t.convert(ci),
         }
     }
 }
@@ -68,6 +69,26 @@ impl APIConverter<FnParamMetadata> for weedle::argument::SingleArgument<'_> {
             by_ref,
             optional: self.optional.is_some(),
             default,
+            variadic: false,
+        })
+    }
+}
+
+// `T... name` arguments are passed to Rust as a `sequence<T>`, the bindings present them using the
+// foreign language's variadic syntax.
+impl APIConverter<FnParamMetadata> for weedle::argument::VariadicArgument<'_> {
+    fn convert(&self, ci: &mut InterfaceCollector) -> Result<FnParamMetadata> {
+        let inner_type = ci.resolve_type_expression(&self.type_)?;
+        let by_ref = ArgumentAttributes::try_from(self.attributes.as_ref())?.by_ref();
+        Ok(FnParamMetadata {
+            name: self.identifier.0.to_string(),
+            ty: Type::Sequence {
+                inner_type: Box::new(inner_type),
+            },
+            by_ref,
+            optional: false,
+            default: None,
+            variadic: true,
         })
     }
 }
@@ -103,7 +124,9 @@ impl APIConverter<FnMetadata> for weedle::namespace::OperationNamespaceMember<'_
             return_type,
             inputs: self.args.body.list.convert(ci)?,
             throws,
+            aliases: vec![],
             checksum: None,
+            full_module_path: ci.module_path(),
         })
     }
 }
@@ -122,6 +145,7 @@ impl APIConverter<ConstructorMetadata> for weedle::interface::ConstructorInterfa
             name: String::from(attributes.get_name().unwrap_or("new")),
             // We don't know the name of the containing `Object` at this point, fill it in later.
             self_name: Default::default(),
+            is_async: false,
             // Also fill in checksum_fn_name later, since it depends on object_name
             inputs: self.args.body.list.convert(ci)?,
             throws,
@@ -28,6 +28,7 @@ impl APIConverter<EnumMetadata> for weedle::EnumDefinition<'_> {
                     })
                 })
                 .collect::<Result<Vec<_>>>()?,
+            full_module_path: ci.module_path(),
         })
     }
 }
@@ -50,6 +51,7 @@ impl APIConverter<ErrorMetadata> for weedle::EnumDefinition<'_> {
                         })
                     })
                     .collect::<Result<Vec<_>>>()?,
+                full_module_path: ci.module_path(),
             },
             is_flat: true,
         })
@@ -78,6 +80,7 @@ impl APIConverter<EnumMetadata> for weedle::InterfaceDefinition<'_> {
                     ),
                 })
                 .collect::<Result<Vec<_>>>()?,
+            full_module_path: ci.module_path(),
             // Enums declared using the `[Enum] interface` syntax might have variants with fields.
             //flat: false,
         })
@@ -107,6 +110,7 @@ impl APIConverter<ErrorMetadata> for weedle::InterfaceDefinition<'_> {
                         ),
                     })
                     .collect::<Result<Vec<_>>>()?,
+                full_module_path: ci.module_path(),
             },
             is_flat: false,
         })
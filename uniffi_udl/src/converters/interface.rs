@@ -97,6 +97,7 @@ impl APIConverter<ObjectMetadata> for weedle::InterfaceDefinition<'_> {
                                 by_ref: true,
                                 default: None,
                                 optional: false,
+                                variadic: false,
                             }],
                             Some(Type::Boolean),
                         )?,
@@ -112,6 +113,7 @@ impl APIConverter<ObjectMetadata> for weedle::InterfaceDefinition<'_> {
                                 by_ref: true,
                                 default: None,
                                 optional: false,
+                                variadic: false,
                             }],
                             Some(Type::Boolean),
                         )?,
@@ -119,6 +121,17 @@ impl APIConverter<ObjectMetadata> for weedle::InterfaceDefinition<'_> {
                     "Hash" => UniffiTraitMetadata::Hash {
                         hash: make_trait_method("uniffi_trait_hash", vec![], Some(Type::UInt64))?,
                     },
+                    "Clone" => UniffiTraitMetadata::Clone {
+                        clone: make_trait_method(
+                            "uniffi_trait_clone",
+                            vec![],
+                            Some(Type::Object {
+                                module_path: ci.module_path(),
+                                name: object_name.to_string(),
+                                imp: object_impl,
+                            }),
+                        )?,
+                    },
                     _ => bail!("Invalid trait name: {}", trait_name),
                 })
             })
@@ -128,6 +141,8 @@ impl APIConverter<ObjectMetadata> for weedle::InterfaceDefinition<'_> {
             name: object_name.to_string(),
             imp: object_impl,
             uniffi_traits,
+            rust_type_name: None,
+            full_module_path: ci.module_path(),
         })
     }
 }
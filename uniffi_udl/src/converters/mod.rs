@@ -85,7 +85,9 @@ impl APIConverter<RecordMetadata> for weedle::DictionaryDefinition<'_> {
         Ok(RecordMetadata {
             module_path: ci.module_path(),
             name: self.identifier.0.to_string(),
+            pod: false,
             fields: self.members.body.convert(ci)?,
+            full_module_path: ci.module_path(),
         })
     }
 }
@@ -104,6 +106,7 @@ impl APIConverter<FieldMetadata> for weedle::dictionary::DictionaryMember<'_> {
             name: self.identifier.0.to_string(),
             ty: type_,
             default,
+            exclude_from_eq: false,
         })
     }
 }
@@ -141,6 +144,7 @@ impl APIConverter<CallbackInterfaceMetadata> for weedle::CallbackInterfaceDefini
         Ok(CallbackInterfaceMetadata {
             module_path: ci.module_path(),
             name: object_name.to_string(),
+            full_module_path: ci.module_path(),
         })
     }
 }
@@ -90,6 +90,8 @@ impl TypeFinder for weedle::DictionaryDefinition<'_> {
             Type::Record {
                 name,
                 module_path: types.module_path(),
+                // UDL dictionaries are always serialized
+                pod: false,
             },
         )
     }
@@ -209,6 +209,7 @@ pub(crate) fn resolve_builtin_type(name: &str) -> Option<Type> {
         "f64" => Some(Type::Float64),
         "timestamp" => Some(Type::Timestamp),
         "duration" => Some(Type::Duration),
+        "json" => Some(Type::JsonValue),
         "ForeignExecutor" => Some(Type::ForeignExecutor),
         _ => None,
     }
@@ -227,6 +228,7 @@ mod test {
             Type::Record {
                 name: "TestRecord".into(),
                 module_path: "".into(),
+                pod: false,
             },
         )?;
         assert_eq!(types.type_definitions.len(), 1);
@@ -257,6 +259,7 @@ mod test {
             Type::Record {
                 name: "TestRecord".into(),
                 module_path: "".into(),
+                pod: false,
             },
         )?;
         // Oh no, someone made a typo in the type-o...
@@ -274,6 +277,7 @@ mod test {
             Type::Record {
                 name: "TestRecord".into(),
                 module_path: "".into(),
+                pod: false,
             },
         )?;
         let (_, expr) = weedle::types::Type::parse("(TestRecord or u32)").unwrap();